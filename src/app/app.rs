@@ -1,15 +1,22 @@
 use crate::error::Result;
 use crossterm::event::{self, Event, KeyEventKind};
 use ratatui::{backend::Backend, Terminal};
-use tracing::{info, instrument};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, instrument, warn};
+use tui_textarea::{CursorMove, TextArea};
 
 use crate::{
     app::background_operations::BackgroundTaskManager,
-    config::load_config,
+    config::{load_config, validate_config},
+    git::{repository::VersionInfoCache, GitRepo, GitStatus},
     types::{
-        AppConfig, AppScreen, AppState, CommitForm, JiraTask, MondayTask, SemanticReleaseState,
+        AppConfig, AppScreen, AppState, CommitAnalysis, CommitForm, CommitType, GitCommit,
+        JiraTask, MondayTask, SemanticReleaseState,
+    },
+    ui::{
+        keybindings::Keymap, CommitField, RenderStats, ScriptOutputLine, SearchHistories, UIState,
     },
-    ui::UIState,
 };
 
 #[derive(Debug)]
@@ -19,7 +26,10 @@ pub struct App {
     pub current_state: AppState,
     pub ui_state: UIState,
     pub commit_form: CommitForm,
+    /// Monday.com tasks shown in the search, after the board filter
     pub monday_tasks: Vec<MondayTask>,
+    /// Every task returned by the last Monday.com search
+    pub monday_search_results: Vec<MondayTask>,
     pub jira_tasks: Vec<JiraTask>,
     pub selected_monday_tasks: Vec<MondayTask>,
     pub selected_jira_tasks: Vec<JiraTask>,
@@ -32,21 +42,91 @@ pub struct App {
 
     // Keep semantic_release_state for UI results display
     pub semantic_release_state: Option<SemanticReleaseState>,
+
+    // Repository status shown on the main screen and title bar
+    pub git_status: Option<GitStatus>,
+    git_status_refreshed_at: Option<Instant>,
+
+    // Commits listed on the history screen
+    pub recent_commits: Vec<GitCommit>,
+    // The commit preview amends HEAD instead of creating a new commit
+    pub amending_commit: bool,
+    // Text to hand to $EDITOR once the current key has been handled
+    pub external_edit: Option<ExternalEditTarget>,
+    // Next version computed during this run, reused until HEAD moves
+    pub version_info_cache: VersionInfoCache,
+    // Time the last task search waited on the Monday.com rate limiter
+    pub monday_rate_limit_wait: Arc<Mutex<Duration>>,
+    // Last time the Monday.com credentials were confirmed to work
+    pub monday_connection_checked_at: Option<Instant>,
+}
+
+/// Text edited in `$EDITOR` instead of the built-in editor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExternalEditTarget {
+    /// A text field of the commit form
+    Field(CommitField),
+    /// The message in the commit preview
+    Preview,
 }
 
+/// How often the repository status and branch are re-read
+const GIT_STATUS_REFRESH_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Time between spinner frames while loading (~12 FPS)
+const ANIMATION_TICK: Duration = Duration::from_millis(80);
+
+/// Input poll timeout when nothing is animating, to keep the idle loop cheap
+const IDLE_POLL_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Lines of script output kept for the release notes output view
+const SCRIPT_OUTPUT_LIMIT: usize = 1000;
+
 impl App {
     #[instrument]
     pub async fn new() -> Result<Self> {
         info!("Initializing new app instance");
-        let config = load_config().unwrap_or_default();
+        let config = match load_config() {
+            Ok(config) => config,
+            // A file asked for with --config must load; defaults would hide the problem
+            Err(e) if crate::config::explicit_config_file().is_some() => return Err(e),
+            Err(e) => {
+                warn!(error = %e, "Failed to load configuration, using defaults");
+                AppConfig::default()
+            }
+        };
+        for warning in validate_config(&config) {
+            warn!(
+                setting = warning.setting,
+                severity = ?warning.severity,
+                "{}",
+                warning.message
+            );
+        }
+
+        let mut app = Self::from_config(config);
+        app.ui_state.search_history = SearchHistories::load();
+        Ok(app)
+    }
+
+    /// App in its initial state with an already loaded configuration.
+    pub fn from_config(config: AppConfig) -> Self {
+        // Problems in KEYBINDINGS are reported by validate_config
+        let (keymap, _) = Keymap::from_config(config.keybindings.as_deref(), config.language);
+        let commit_preview_wrap = config.commit_preview_wrap;
 
-        Ok(Self {
+        Self {
             config,
             current_screen: AppScreen::Main,
             current_state: AppState::Normal,
-            ui_state: UIState::default(),
+            ui_state: UIState {
+                keymap,
+                commit_preview_wrap,
+                ..UIState::default()
+            },
             commit_form: CommitForm::default(),
             monday_tasks: Vec::new(),
+            monday_search_results: Vec::new(),
             jira_tasks: Vec::new(),
             selected_monday_tasks: Vec::new(),
             selected_jira_tasks: Vec::new(),
@@ -59,7 +139,22 @@ impl App {
 
             // Keep for UI display
             semantic_release_state: None,
-        })
+
+            git_status: None,
+            git_status_refreshed_at: None,
+
+            recent_commits: Vec::new(),
+            amending_commit: false,
+            external_edit: None,
+            version_info_cache: VersionInfoCache::default(),
+            monday_rate_limit_wait: Arc::default(),
+            monday_connection_checked_at: None,
+        }
+    }
+
+    /// Collect render timings and allow toggling their overlay with F12.
+    pub fn enable_render_stats(&mut self) {
+        self.ui_state.render_stats = Some(RenderStats::default());
     }
 
     #[instrument(skip_all)]
@@ -68,182 +163,222 @@ impl App {
 
         // Subscribe to background events
         let mut event_rx = self.background_task_manager.subscribe();
+        let mut last_animation_tick = Instant::now();
 
         loop {
             // Handle background task events (modern async approach)
             while let Ok(event) = event_rx.try_recv() {
                 use crate::app::background_operations::BackgroundEvent;
                 match event {
-                    BackgroundEvent::ReleaseNotesProgress(status) => {
+                    BackgroundEvent::ReleaseNotesProgress { status, progress } => {
                         self.message = Some(format!("🔄 {}", status));
+                        self.ui_state.loading_progress = Some(progress);
                     }
                     BackgroundEvent::ReleaseNotesCompleted(result) => {
+                        self.ui_state.loading_progress = None;
+                        let mut warning = result
+                            .get("warning")
+                            .and_then(|v| v.as_str())
+                            .map(|warning| format!(" ⚠️ {}", warning))
+                            .unwrap_or_default();
+                        if let Some(error) = result.get("gemini_error").and_then(|v| v.as_str()) {
+                            warning.push_str(&format!(
+                                " {} {}",
+                                self.config.tr("release_notes.gemini_failed"),
+                                error
+                            ));
+                        }
                         // Extract and display results
                         if let Some(notes) = result.get("notes").and_then(|v| v.as_str()) {
-                            self.message =
-                                Some("✅ Notas de versión generadas exitosamente".to_string());
+                            self.message = Some(format!(
+                                "{}{}",
+                                self.config.tr("release_notes.completed"),
+                                warning
+                            ));
                             self.current_state = AppState::Normal;
                             self.current_screen = AppScreen::Main;
                             tracing::info!("Release notes completed: {} characters", notes.len());
                         } else {
                             // Handle case where result is a direct string
                             self.message =
-                                Some("✅ Notas de versión generadas exitosamente".to_string());
+                                Some(self.config.tr("release_notes.completed").to_string());
                             self.current_state = AppState::Normal;
                             self.current_screen = AppScreen::Main;
                             tracing::info!("Release notes completed: {:?}", result);
                         }
                     }
                     BackgroundEvent::ReleaseNotesError(error) => {
-                        self.current_state =
-                            AppState::Error(format!("Error en generación: {}", error));
+                        self.ui_state.loading_progress = None;
+                        self.current_state = AppState::Error(format!(
+                            "{}: {}",
+                            self.config.tr("release_notes.generation_error"),
+                            error
+                        ));
                         self.message = Some(format!("❌ {}", error));
                     }
-                    BackgroundEvent::AnalysisProgress(status) => {
-                        self.message = Some(format!("🤖 {}", status));
+                    BackgroundEvent::ScriptOutput { line, is_stderr } => {
+                        self.message = Some(format!("⚙️ {}", line));
+                        self.push_script_output(line, is_stderr);
                     }
-                    BackgroundEvent::AnalysisCompleted(result) => {
+                    BackgroundEvent::ScriptCompleted(message) => {
                         self.current_state = AppState::Normal;
-                        self.message = Some(
-                            "✅ Análisis completado - Formulario poblado automáticamente"
-                                .to_string(),
-                        );
-
-                        // Populate commit form with analysis results
-                        if let Some(title) = result.get("title").and_then(|v| v.as_str()) {
-                            if !title.is_empty() {
-                                self.commit_form.title = title.to_string();
-                                // Update the UI textarea as well
-                                self.ui_state.title_textarea.select_all();
-                                self.ui_state.title_textarea.delete_str(
-                                    self.ui_state.title_textarea.lines().join("\n").len(),
-                                );
-                                self.ui_state.title_textarea.insert_str(title);
-                            }
-                        }
-
-                        if let Some(scope) = result.get("scope").and_then(|v| v.as_str()) {
-                            if !scope.is_empty() {
-                                self.commit_form.scope = scope.to_string();
-                                // Update the UI textarea as well
-                                self.ui_state.scope_textarea.select_all();
-                                self.ui_state.scope_textarea.delete_str(
-                                    self.ui_state.scope_textarea.lines().join("\n").len(),
-                                );
-                                self.ui_state.scope_textarea.insert_str(scope);
-                            }
-                        }
-
-                        if let Some(description) =
-                            result.get("description").and_then(|v| v.as_str())
-                        {
-                            if !description.is_empty() {
-                                self.commit_form.description = description.to_string();
-                                // Update the UI textarea as well
-                                self.ui_state.description_textarea.select_all();
-                                self.ui_state.description_textarea.delete_str(
-                                    self.ui_state.description_textarea.lines().join("\n").len(),
-                                );
-                                self.ui_state.description_textarea.insert_str(description);
-                            }
-                        }
-
-                        if let Some(commit_type) = result.get("commitType").and_then(|v| v.as_str())
-                        {
-                            // Parse the commit type string into CommitType enum
-                            let parsed_commit_type = match commit_type {
-                                "feat" => Some(crate::types::CommitType::Feat),
-                                "fix" => Some(crate::types::CommitType::Fix),
-                                "docs" => Some(crate::types::CommitType::Docs),
-                                "style" => Some(crate::types::CommitType::Style),
-                                "refactor" => Some(crate::types::CommitType::Refactor),
-                                "perf" => Some(crate::types::CommitType::Perf),
-                                "test" => Some(crate::types::CommitType::Test),
-                                "chore" => Some(crate::types::CommitType::Chore),
-                                "revert" => Some(crate::types::CommitType::Revert),
-                                _ => Some(crate::types::CommitType::Feat), // Default fallback
-                            };
-
-                            self.commit_form.commit_type = parsed_commit_type;
-
-                            // Update the selected commit type in UI
-                            let commit_types = [
-                                "feat", "fix", "docs", "style", "refactor", "perf", "test", "chore",
-                            ];
-                            if let Some(index) = commit_types.iter().position(|&t| t == commit_type)
-                            {
-                                self.ui_state.selected_commit_type = index;
-                            }
-                        }
-
-                        if let Some(security) =
-                            result.get("securityAnalysis").and_then(|v| v.as_str())
-                        {
-                            if !security.is_empty() && security != "N/A" {
-                                self.commit_form.security = security.to_string();
-                                // Update the UI textarea as well
-                                self.ui_state.security_textarea.select_all();
-                                self.ui_state.security_textarea.delete_str(
-                                    self.ui_state.security_textarea.lines().join("\n").len(),
-                                );
-                                self.ui_state.security_textarea.insert_str(security);
-                            }
+                        self.message = Some(message);
+                        self.current_screen = AppScreen::Main;
+                    }
+                    BackgroundEvent::ScriptFailed(error) => {
+                        // Stay on the release notes screen so the output can be reviewed
+                        self.current_state = AppState::Error(error.clone());
+                        self.message = Some(format!("❌ {}", error));
+                    }
+                    BackgroundEvent::SemanticReleaseProgress(status) => {
+                        if let Some(state) = self.semantic_release_state.as_mut() {
+                            state.status = status.clone();
                         }
-
-                        if let Some(breaking) =
-                            result.get("breakingChanges").and_then(|v| v.as_str())
-                        {
-                            if !breaking.is_empty() && breaking != "N/A" {
-                                self.commit_form.breaking_change = breaking.to_string();
-                                // Update the UI textarea as well
-                                self.ui_state.breaking_change_textarea.select_all();
-                                self.ui_state.breaking_change_textarea.delete_str(
-                                    self.ui_state
-                                        .breaking_change_textarea
-                                        .lines()
-                                        .join("\n")
-                                        .len(),
-                                );
-                                self.ui_state.breaking_change_textarea.insert_str(breaking);
-                            }
+                        self.message = Some(status);
+                    }
+                    BackgroundEvent::SemanticReleaseFinished(state) => {
+                        self.message = Some(state.status.clone());
+                        self.semantic_release_state = Some(*state);
+                    }
+                    BackgroundEvent::AnalysisProgress(status) => {
+                        self.message = Some(format!("🤖 {}", status));
+                    }
+                    BackgroundEvent::AnalysisPreview(description) => {
+                        self.ui_state.analysis_streaming = true;
+                        self.message = Some(format!(
+                            "🤖 Recibiendo descripción de Gemini... ({} palabras)",
+                            description.split_whitespace().count()
+                        ));
+                        // Text typed by hand is not streamed over; the final result keeps it too
+                        if self.config.ai_inline_suggestions {
+                            self.suggest_field_text(&CommitField::Description, &description);
+                        } else if self.analysis_may_overwrite(&CommitField::Description) {
+                            self.commit_form.description = description.clone();
+                            replace_textarea_text(
+                                &mut self.ui_state.description_textarea,
+                                &description,
+                            );
                         }
+                    }
+                    BackgroundEvent::AnalysisCompleted(analysis) => {
+                        self.ui_state.analysis_streaming = false;
+                        self.current_state = AppState::Normal;
+                        tracing::debug!(?analysis, "Raw AI analysis result");
 
-                        if let Some(test_details) =
-                            result.get("testAnalysis").and_then(|v| v.as_str())
-                        {
-                            if !test_details.is_empty() && test_details != "N/A" {
-                                self.commit_form.test_details = test_details.to_string();
-                                // Update the UI textarea as well
-                                self.ui_state.test_details_textarea.select_all();
-                                self.ui_state.test_details_textarea.delete_str(
-                                    self.ui_state.test_details_textarea.lines().join("\n").len(),
-                                );
-                                self.ui_state.test_details_textarea.insert_str(test_details);
-                            }
-                        }
+                        let kept = self.apply_commit_analysis(&analysis);
+                        self.message = Some(if kept.is_empty() {
+                            self.config
+                                .tr(if self.config.ai_inline_suggestions {
+                                    "analysis.suggestions_ready"
+                                } else {
+                                    "analysis.completed"
+                                })
+                                .to_string()
+                        } else {
+                            format!(
+                                "{} {}",
+                                self.config.tr("analysis.kept_fields"),
+                                kept.iter()
+                                    .map(CommitField::label)
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )
+                        });
 
                         tracing::info!(
                             "Analysis completed and form populated with comprehensive data"
                         );
                     }
+                    BackgroundEvent::FieldAnalysisCompleted { field, text } => {
+                        self.current_state = AppState::Normal;
+                        self.apply_field_analysis(&field, &text);
+                        self.message = Some(format!(
+                            "{} {}{}",
+                            self.config.tr("analysis.field_completed"),
+                            field.label(),
+                            if text.trim().is_empty() || text == "N/A" {
+                                self.config.tr("analysis.field_nothing_found")
+                            } else {
+                                ""
+                            }
+                        ));
+                    }
+                    BackgroundEvent::TaskIdsChecked(results) => {
+                        use crate::app::task_operations::task_id_problems;
+                        let problems = task_id_problems(&self.config, &results);
+                        self.message = Some(if problems.is_empty() {
+                            let ids: Vec<&str> =
+                                results.iter().map(|(id, _)| id.as_str()).collect();
+                            format!(
+                                "{} {}",
+                                self.config.tr("task_check.all_found"),
+                                ids.join(", ")
+                            )
+                        } else {
+                            format!(
+                                "{} {}",
+                                self.config.tr("task_check.problems"),
+                                problems.join("; ")
+                            )
+                        });
+                    }
                     BackgroundEvent::AnalysisError(error) => {
-                        self.current_state =
-                            AppState::Error(format!("Error en análisis: {}", error));
+                        self.ui_state.analysis_streaming = false;
+                        self.current_state = AppState::Error(format!(
+                            "{}: {}",
+                            self.config.tr("analysis.error"),
+                            error
+                        ));
                         self.message = Some(format!("❌ {}", error));
                     }
 
+                    BackgroundEvent::CommitCreated {
+                        hash,
+                        message,
+                        tasks,
+                    } => {
+                        // Audit trail of commits made from the app
+                        tracing::info!(%hash, ?tasks, subject = message.lines().next().unwrap_or_default(), "Commit created");
+                        let config = self.config.clone();
+                        tokio::spawn(async move {
+                            crate::app::commit_operations::post_monday_commit_updates(
+                                &config, &hash, &message, &tasks,
+                            )
+                            .await;
+                        });
+                    }
                     BackgroundEvent::OperationStarted { operation_id } => {
                         self.current_state = AppState::Loading;
                         tracing::info!("Operation started: {}", operation_id);
                     }
                     BackgroundEvent::OperationCompleted { operation_id } => {
                         self.current_state = AppState::Normal;
+                        self.ui_state.loading_progress = None;
+                        self.ui_state.analysis_streaming = false;
                         tracing::info!("Operation completed: {}", operation_id);
                     }
+                    BackgroundEvent::OperationCancelled { operation_id } => {
+                        self.current_state = AppState::Normal;
+                        self.ui_state.loading_progress = None;
+                        self.ui_state.analysis_streaming = false;
+                        self.message = Some(self.config.tr("operation.cancelled").to_string());
+                        tracing::info!("Operation cancelled: {}", operation_id);
+                    }
                 }
             }
 
+            self.refresh_git_status_if_stale();
+
+            // Spinner frames follow the clock, not the number of redraws
+            if matches!(self.current_state, AppState::Loading)
+                && last_animation_tick.elapsed() >= ANIMATION_TICK
+            {
+                self.ui_state.animation_frame = self.ui_state.animation_frame.wrapping_add(1);
+                last_animation_tick = Instant::now();
+            }
+
             // Draw UI
+            let draw_started = Instant::now();
             terminal.draw(|f| {
                 crate::ui::draw(
                     f,
@@ -255,13 +390,23 @@ impl App {
                     &self.jira_tasks,
                     &self.config,
                     self.message.as_deref(),
-                    None, // git_status - not needed in the simplified version
+                    self.git_status.as_ref(),
                     self.semantic_release_state.as_ref(),
+                    &self.recent_commits,
                 );
             })?;
+            if let Some(stats) = self.ui_state.render_stats.as_mut() {
+                stats.record_frame(draw_started.elapsed());
+            }
 
-            // Handle input events
-            if event::poll(std::time::Duration::from_millis(50))? {
+            // Handle input events. While loading, wake up for the next spinner
+            // frame; a key press still returns from poll immediately.
+            let poll_timeout = if matches!(self.current_state, AppState::Loading) {
+                ANIMATION_TICK.saturating_sub(last_animation_tick.elapsed())
+            } else {
+                IDLE_POLL_TIMEOUT
+            };
+            if event::poll(poll_timeout)? {
                 if let Event::Key(key) = event::read()? {
                     if key.kind == KeyEventKind::Press {
                         use crate::app::event_handlers::EventHandlers;
@@ -270,6 +415,10 @@ impl App {
                 }
             }
 
+            if let Some(target) = self.external_edit.take() {
+                self.edit_externally(terminal, target)?;
+            }
+
             if self.should_quit {
                 break;
             }
@@ -280,29 +429,340 @@ impl App {
     }
 
     pub async fn run(mut self) -> Result<()> {
+        use crate::app::terminal::{exit_on_signal, install_panic_hook, TerminalGuard};
+        use ratatui::{backend::CrosstermBackend, Terminal};
+        use std::io;
+
+        // Setup terminal; the guard restores it however run_app ends
+        install_panic_hook();
+        let _terminal_guard = TerminalGuard::enter()?;
+        exit_on_signal();
+        let backend = CrosstermBackend::new(io::stdout());
+        let mut terminal = Terminal::new(backend)?;
+
+        self.run_app(&mut terminal).await
+    }
+
+    /// Suspend the TUI, let `$EDITOR` edit the target's text and put the result
+    /// back. The terminal is restored and fully redrawn whatever the editor did.
+    fn edit_externally<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        target: ExternalEditTarget,
+    ) -> Result<()> {
         use crossterm::{
+            cursor::Show,
             execute,
             terminal::{
                 disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
             },
         };
-        use ratatui::{backend::CrosstermBackend, Terminal};
-        use std::io;
 
-        // Setup terminal
+        let Some(editor) = crate::utils::external_editor() else {
+            return Ok(());
+        };
+        let textarea = match &target {
+            ExternalEditTarget::Field(field) => self.ui_state.get_textarea(field),
+            ExternalEditTarget::Preview => Some(&self.ui_state.commit_preview_textarea),
+        };
+        let Some(text) = textarea.map(|textarea| textarea.lines().join("\n")) else {
+            return Ok(());
+        };
+
+        info!(editor = %editor[0], ?target, "Opening external editor");
+        disable_raw_mode()?;
+        execute!(std::io::stdout(), LeaveAlternateScreen, Show)?;
+        let edited = crate::utils::edit_in_external_editor(&editor, &text);
         enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
-        let backend = CrosstermBackend::new(stdout);
-        let mut terminal = Terminal::new(backend)?;
+        execute!(std::io::stdout(), EnterAlternateScreen)?;
+        terminal.clear()?;
 
-        let result = self.run_app(&mut terminal).await;
+        match edited {
+            Ok(Some(text)) => {
+                self.apply_external_edit(&target, &text);
+                self.message = Some(format!("✏️ Texto actualizado desde {}", editor[0]));
+            }
+            Ok(None) => {
+                self.message = Some(format!(
+                    "{} terminó con error; el texto no se ha cambiado",
+                    editor[0]
+                ));
+            }
+            Err(e) => {
+                warn!(editor = %editor[0], error = %e, "External editor failed");
+                self.message = Some(format!("No se pudo abrir {}: {}", editor[0], e));
+            }
+        }
+        Ok(())
+    }
 
-        // Restore terminal
-        disable_raw_mode()?;
-        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-        terminal.show_cursor()?;
+    /// Put text edited outside the TUI into the form field or the preview.
+    pub fn apply_external_edit(&mut self, target: &ExternalEditTarget, text: &str) {
+        match target {
+            ExternalEditTarget::Field(field) => {
+                // Single-line fields get the edited lines joined
+                let text = if UIState::is_multiline_field(field) {
+                    text.to_string()
+                } else {
+                    text.lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                };
+                if let Some(value) = field.form_text_mut(&mut self.commit_form) {
+                    value.clone_from(&text);
+                }
+                if let Some(textarea) = self.ui_state.get_textarea_mut(field) {
+                    replace_textarea_text(textarea, &text);
+                }
+            }
+            ExternalEditTarget::Preview => {
+                self.preview_commit_message = text.to_string();
+                replace_textarea_text(&mut self.ui_state.commit_preview_textarea, text);
+            }
+        }
+    }
 
-        result
+    /// Re-read the repository status and branch now, e.g. after changing the index.
+    pub(crate) fn refresh_git_status(&mut self) {
+        self.git_status_refreshed_at = None;
+        self.refresh_git_status_if_stale();
+    }
+
+    /// Re-read the repository status and branch, at most every few seconds.
+    fn refresh_git_status_if_stale(&mut self) {
+        if self
+            .git_status_refreshed_at
+            .is_some_and(|refreshed| refreshed.elapsed() < GIT_STATUS_REFRESH_INTERVAL)
+        {
+            return;
+        }
+        self.git_status_refreshed_at = Some(Instant::now());
+
+        self.git_status = match GitRepo::new().and_then(|repo| repo.get_status()) {
+            Ok(status) => Some(status),
+            Err(e) => {
+                debug!(error = %e, "Could not read repository status");
+                None
+            }
+        };
+    }
+
+    /// Append a line of streamed script output, keeping the view steady if it is scrolled up.
+    fn push_script_output(&mut self, text: String, is_stderr: bool) {
+        let output = &mut self.ui_state.script_output;
+        if output.len() == SCRIPT_OUTPUT_LIMIT {
+            output.remove(0);
+        }
+        output.push(ScriptOutputLine { text, is_stderr });
+
+        if self.ui_state.script_output_scroll > 0 {
+            self.ui_state.script_output_scroll =
+                (self.ui_state.script_output_scroll + 1).min(output.len().saturating_sub(1));
+        }
+    }
+
+    /// With `--offline`, report that `feature` needs the network and return
+    /// `true` so the caller does nothing.
+    pub fn unavailable_offline(&mut self, feature: &str) -> bool {
+        if self.config.offline {
+            self.message = Some(format!(
+                "{} {}",
+                self.config.tr("offline.unavailable"),
+                feature
+            ));
+        }
+        self.config.offline
+    }
+
+    /// Whether the AI analysis may write `field`: it was empty when the analysis
+    /// started, or still held what the previous analysis wrote into it.
+    fn analysis_may_overwrite(&self, field: &CommitField) -> bool {
+        let before = self
+            .ui_state
+            .form_before_analysis
+            .as_ref()
+            .unwrap_or(&self.commit_form);
+        field
+            .form_text(before)
+            .is_none_or(|text| text.trim().is_empty() || self.config.is_commit_template(text))
+            || self.ui_state.is_ai_filled(field, before)
+    }
+
+    /// Pre-fill the description with the template of the selected type
+    /// (`COMMIT_TEMPLATE_<TYPE>`). Only an empty description, or one still
+    /// holding an untouched template, is replaced.
+    pub fn prefill_commit_template(&mut self) {
+        let description = &self.commit_form.description;
+        if !description.trim().is_empty() && !self.config.is_commit_template(description) {
+            return;
+        }
+        let template = self
+            .commit_form
+            .commit_type
+            .as_ref()
+            .and_then(|commit_type| self.config.commit_template(commit_type))
+            .unwrap_or_default()
+            .to_string();
+        if template != self.commit_form.description {
+            replace_textarea_text(&mut self.ui_state.description_textarea, &template);
+            self.commit_form.description = template;
+        }
     }
+
+    /// Populate the commit form (and its textareas) from a Gemini commit analysis.
+    ///
+    /// Text typed by hand is never replaced; returns the fields kept because of
+    /// that. The result is remembered so it can be inspected ('i') and the
+    /// previous form restored ('u'). With `AI_INLINE_SUGGESTIONS` the text is
+    /// offered as ghost text instead (see `suggest_field_text`).
+    pub fn apply_commit_analysis(&mut self, analysis: &CommitAnalysis) -> Vec<CommitField> {
+        let mut kept = Vec::new();
+        for field in CommitField::AI_FILLED {
+            let Some(text) = field.analysis_text(analysis) else {
+                continue;
+            };
+            if self.config.ai_inline_suggestions {
+                if !self.suggest_field_text(&field, text) {
+                    kept.push(field);
+                }
+                continue;
+            }
+            if !self.analysis_may_overwrite(&field) {
+                kept.push(field);
+                continue;
+            }
+            if let Some(value) = field.form_text_mut(&mut self.commit_form) {
+                *value = text.to_string();
+            }
+            if let Some(textarea) = self.ui_state.get_textarea_mut(&field) {
+                replace_textarea_text(textarea, text);
+            }
+        }
+        // The suggested type only replaces a type that was not picked manually
+        let chosen_manually = self.commit_form.commit_type.is_some()
+            && self.commit_form.commit_type != self.ui_state.suggested_commit_type;
+        self.ui_state.suggested_commit_type = analysis.commit_type();
+        if let Some(commit_type) = analysis.commit_type().filter(|_| !chosen_manually) {
+            if let Some(index) = CommitType::all().iter().position(|t| *t == commit_type) {
+                self.ui_state.selected_commit_type = index;
+            }
+            self.commit_form.commit_type = Some(commit_type);
+        }
+
+        self.ui_state.last_analysis = Some(analysis.clone());
+        kept
+    }
+
+    /// Replace `field` with a regenerated analysis, leaving every other field
+    /// as it is. The field was asked for explicitly, so it is written even
+    /// when edited by hand; an empty or "N/A" answer clears it.
+    pub fn apply_field_analysis(&mut self, field: &CommitField, text: &str) {
+        let text = if text.trim() == "N/A" {
+            ""
+        } else {
+            text.trim()
+        };
+        if let Some(value) = field.form_text_mut(&mut self.commit_form) {
+            *value = text.to_string();
+        }
+        if let Some(textarea) = self.ui_state.get_textarea_mut(field) {
+            replace_textarea_text(textarea, text);
+        }
+        self.ui_state.ai_suggestions.remove(field);
+
+        // Keep the 'i' overlay and the hand-edit detection in step
+        let analysis = self
+            .ui_state
+            .last_analysis
+            .get_or_insert_with(Default::default);
+        match field {
+            CommitField::Security => analysis.security = text.to_string(),
+            CommitField::BreakingChange => analysis.breaking_change = text.to_string(),
+            _ => {}
+        }
+    }
+
+    /// Offer `text` as ghost text for `field`, accepted with Tab. Only when
+    /// what the field holds is the start of it (e.g. empty); returns `false`
+    /// otherwise, leaving the field without a suggestion.
+    pub fn suggest_field_text(&mut self, field: &CommitField, text: &str) -> bool {
+        let text = crate::utils::normalize_line_endings(text.trim());
+        let fits = self
+            .ui_state
+            .get_textarea(field)
+            .is_some_and(|textarea| text.starts_with(textarea.lines().join("\n").as_str()));
+        if fits {
+            self.ui_state.ai_suggestions.insert(field.clone(), text);
+        } else {
+            self.ui_state.ai_suggestions.remove(field);
+        }
+        fits
+    }
+
+    /// Move the ghost text of the field being edited into it: all of it, or
+    /// only up to the end of its next word. Returns `false` when there is none.
+    pub fn accept_suggestion(&mut self, whole: bool) -> bool {
+        let field = self.ui_state.current_field.clone();
+        let Some(ghost) = self.ui_state.ghost_text(&field) else {
+            return false;
+        };
+        let accepted = if whole {
+            ghost.to_string()
+        } else {
+            let word_start = ghost.len() - ghost.trim_start().len();
+            let word_end = ghost[word_start..]
+                .find(char::is_whitespace)
+                .map_or(ghost.len(), |end| word_start + end);
+            ghost[..word_end].to_string()
+        };
+
+        if let Some(textarea) = self.ui_state.get_textarea_mut(&field) {
+            textarea.move_cursor(CursorMove::Bottom);
+            textarea.move_cursor(CursorMove::End);
+            textarea.insert_str(&accepted);
+        }
+        if self.ui_state.ghost_text(&field).is_none() {
+            self.ui_state.ai_suggestions.remove(&field);
+        }
+        true
+    }
+
+    /// Restore the fields the last AI analysis could change to what they held
+    /// before it started. Selected tasks are left alone. Returns `false` when
+    /// there is nothing to restore.
+    pub fn revert_commit_analysis(&mut self) -> bool {
+        let Some(before) = self.ui_state.form_before_analysis.take() else {
+            return false;
+        };
+        self.ui_state.ai_suggestions.clear();
+
+        for field in CommitField::AI_FILLED {
+            let Some(text) = field.form_text(&before) else {
+                continue;
+            };
+            if let Some(value) = field.form_text_mut(&mut self.commit_form) {
+                *value = text.to_string();
+            }
+            if let Some(textarea) = self.ui_state.get_textarea_mut(&field) {
+                replace_textarea_text(textarea, text);
+            }
+        }
+
+        self.commit_form.commit_type = before.commit_type.clone();
+        // Without a type the selector goes back to its first entry, as on a new form
+        self.ui_state.selected_commit_type = before
+            .commit_type
+            .as_ref()
+            .and_then(|commit_type| CommitType::all().iter().position(|t| t == commit_type))
+            .unwrap_or_default();
+        true
+    }
+}
+
+fn replace_textarea_text(textarea: &mut TextArea<'static>, text: &str) {
+    textarea.select_all();
+    textarea.delete_str(textarea.lines().join("\n").len());
+    textarea.insert_str(crate::utils::normalize_line_endings(text));
 }
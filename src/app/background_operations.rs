@@ -6,29 +6,93 @@ use tokio::sync::RwLock;
 use tracing::{error, info, instrument, warn};
 
 use crate::{
-    app::release_notes::generate_release_notes_task,
+    app::release_notes::{
+        generate_package_release_notes_task, generate_release_notes_task,
+        process_saved_release_notes, run_npm_release_notes_task,
+    },
+    app::semantic_release_operations::{
+        run_ci_setup_task, run_semantic_release_task, run_version_info_task,
+    },
     app::App,
     error::Result,
-    git::repository::GitRepo,
-    types::{AppConfig, AppState, GitCommit},
+    git::{
+        repository::{GitRepo, VersionInfoCache},
+        GitHost,
+    },
+    types::{
+        AppConfig, AppState, CommitAnalysis, GitCommit, ReleasePackage, SemanticReleaseState,
+        TaskIdStatus, VersionOptions,
+    },
+    ui::state::CommitField,
 };
 
+/// Exclusive kind shared by every release notes generation
+pub const RELEASE_NOTES_KIND: &str = "release_notes";
+
+/// Exclusive kind of the Gemini commit analysis
+pub const ANALYSIS_KIND: &str = "comprehensive_analysis";
+
+/// Exclusive kind shared by semantic-release, the version analysis and the
+/// CI setup, which all report to the same results panel
+pub const SEMANTIC_RELEASE_KIND: &str = "semantic_release";
+
 /// Events emitted by background operations
 #[derive(Debug, Clone)]
 pub enum BackgroundEvent {
     // Release notes analysis events
-    ReleaseNotesProgress(String),
+    ReleaseNotesProgress {
+        status: String,
+        progress: f32,
+    },
     ReleaseNotesCompleted(Value),
     ReleaseNotesError(String),
 
+    // npm release notes script events
+    ScriptOutput {
+        line: String,
+        is_stderr: bool,
+    },
+    ScriptCompleted(String),
+    ScriptFailed(String),
+
+    // semantic-release, version analysis and CI setup events
+    SemanticReleaseProgress(String),
+    SemanticReleaseFinished(Box<SemanticReleaseState>),
+
     // Comprehensive analysis events
     AnalysisProgress(String),
-    AnalysisCompleted(Value),
+    /// Description generated so far, while the analysis streams in
+    AnalysisPreview(String),
+    AnalysisCompleted(CommitAnalysis),
+    /// Result of regenerating a single field (security or breaking changes)
+    FieldAnalysisCompleted {
+        field: CommitField,
+        text: String,
+    },
     AnalysisError(String),
 
+    // Scope task ID validation
+    /// Every task ID checked, with what the task system answered
+    TaskIdsChecked(Vec<(String, TaskIdStatus)>),
+
+    // Commit events
+    /// A commit was created from the app. `tasks` are the linked Monday.com IDs or JIRA keys.
+    CommitCreated {
+        hash: String,
+        message: String,
+        tasks: Vec<String>,
+    },
+
     // General operation status
-    OperationStarted { operation_id: String },
-    OperationCompleted { operation_id: String },
+    OperationStarted {
+        operation_id: String,
+    },
+    OperationCompleted {
+        operation_id: String,
+    },
+    OperationCancelled {
+        operation_id: String,
+    },
 }
 
 /// Status of a background operation
@@ -38,6 +102,7 @@ pub enum OperationStatus {
     Running,
     Completed,
     Failed,
+    Cancelled,
 }
 
 /// Manages background operations with async channels
@@ -52,6 +117,25 @@ pub struct BackgroundTaskManager {
 
     /// Active task handles for cancellation
     active_tasks: Arc<RwLock<std::collections::HashMap<String, tokio::task::JoinHandle<()>>>>,
+
+    /// Kinds of exclusive operations currently in flight (see `start_exclusive_operation`)
+    exclusive_kinds: Arc<std::sync::Mutex<std::collections::HashSet<&'static str>>>,
+}
+
+/// Claim on an exclusive operation kind, released when the operation's future
+/// is dropped: on completion, on failure or when the task is aborted.
+#[derive(Debug)]
+struct ExclusiveClaim {
+    kinds: Arc<std::sync::Mutex<std::collections::HashSet<&'static str>>>,
+    kind: &'static str,
+}
+
+impl Drop for ExclusiveClaim {
+    fn drop(&mut self) {
+        if let Ok(mut kinds) = self.kinds.lock() {
+            kinds.remove(self.kind);
+        }
+    }
 }
 
 impl BackgroundTaskManager {
@@ -64,6 +148,7 @@ impl BackgroundTaskManager {
             event_rx,
             operation_status: Arc::new(RwLock::new(std::collections::HashMap::new())),
             active_tasks: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            exclusive_kinds: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
         }
     }
 
@@ -72,6 +157,13 @@ impl BackgroundTaskManager {
         self.event_rx.clone()
     }
 
+    /// Publish an event to every subscriber. Nobody listening is not an error.
+    pub async fn emit(&self, event: BackgroundEvent) {
+        if let Err(e) = self.event_tx.broadcast(event).await {
+            warn!("Failed to broadcast event: {}", e);
+        }
+    }
+
     /// Start a new background operation
     #[instrument(skip(self, operation))]
     pub async fn start_operation<F, Fut>(
@@ -165,136 +257,493 @@ impl BackgroundTaskManager {
         Ok(())
     }
 
+    /// Start an operation unless another one of the same `kind` is still running.
+    ///
+    /// The check and the claim happen under a single lock, so two quick key
+    /// presses cannot both get through. Returns `false` when the operation was
+    /// not started because one of that kind is already in flight.
+    pub async fn start_exclusive_operation<F, Fut>(
+        &self,
+        kind: &'static str,
+        operation_id: String,
+        description: String,
+        operation: F,
+    ) -> Result<bool>
+    where
+        F: FnOnce(Sender<BackgroundEvent>, String) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = Result<()>> + Send,
+    {
+        let claim = {
+            let mut kinds = self
+                .exclusive_kinds
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if !kinds.insert(kind) {
+                warn!(
+                    "Ignoring '{}': an operation of that kind is already running",
+                    kind
+                );
+                return Ok(false);
+            }
+            ExclusiveClaim {
+                kinds: self.exclusive_kinds.clone(),
+                kind,
+            }
+        };
+
+        self.start_operation(operation_id, description, move |event_tx, op_id| {
+            let future = operation(event_tx, op_id);
+            async move {
+                let _claim = claim;
+                future.await
+            }
+        })
+        .await?;
+
+        Ok(true)
+    }
+
+    /// Whether an exclusive operation of `kind` is still running
+    pub fn is_exclusive_running(&self, kind: &str) -> bool {
+        self.exclusive_kinds
+            .lock()
+            .map(|kinds| kinds.contains(kind))
+            .unwrap_or(false)
+    }
+
+    /// Whether any background operation is still running
+    pub async fn has_active_operations(&self) -> bool {
+        !self.active_tasks.read().await.is_empty()
+    }
+
+    /// Abort every running operation, returning how many were cancelled.
+    ///
+    /// Aborting the task drops its future, so in-flight HTTP requests are dropped
+    /// with it and nothing keeps running in the background afterwards.
+    #[instrument(skip(self))]
+    pub async fn cancel_all(&self) -> usize {
+        let cancelled: Vec<String> = {
+            let mut tasks = self.active_tasks.write().await;
+            tasks
+                .drain()
+                .map(|(operation_id, handle)| {
+                    handle.abort();
+                    operation_id
+                })
+                .collect()
+        };
+
+        {
+            let mut status_map = self.operation_status.write().await;
+            for operation_id in &cancelled {
+                status_map.insert(operation_id.clone(), OperationStatus::Cancelled);
+            }
+        }
+
+        for operation_id in &cancelled {
+            info!("Cancelled background operation: {}", operation_id);
+            if let Err(e) = self
+                .event_tx
+                .broadcast(BackgroundEvent::OperationCancelled {
+                    operation_id: operation_id.clone(),
+                })
+                .await
+            {
+                warn!("Failed to broadcast operation cancelled event: {}", e);
+            }
+        }
+
+        cancelled.len()
+    }
+
     /// Start release notes generation as a background task
     #[instrument(skip(self, config, commits))]
     pub async fn start_release_notes_generation(
         &self,
         config: &crate::types::AppConfig,
         commits: Vec<crate::types::GitCommit>,
-    ) -> Result<String> {
+    ) -> Result<Option<String>> {
         let operation_id = format!("release_notes_{}", uuid::Uuid::new_v4());
         let config_clone = config.clone();
         let operation_desc = "Release notes generation".to_string();
 
-        self.start_operation(
-            operation_id.clone(),
-            operation_desc,
-            move |event_tx, op_id| {
-                let config = config_clone;
-                let commits = commits;
-                async move {
-                    match generate_release_notes_task(event_tx, op_id, config, commits).await {
-                        Ok(_) => Ok(()),
+        let started = self
+            .start_exclusive_operation(
+                RELEASE_NOTES_KIND,
+                operation_id.clone(),
+                operation_desc,
+                move |event_tx, op_id| {
+                    let config = config_clone;
+                    let commits = commits;
+                    async move {
+                        match generate_release_notes_task(event_tx, op_id, config, commits).await {
+                            Ok(_) => Ok(()),
+                            Err(e) => {
+                                error!("Release notes generation failed: {}", e);
+                                Err(e)
+                            }
+                        }
+                    }
+                },
+            )
+            .await?;
+
+        Ok(started.then_some(operation_id))
+    }
+
+    /// Start release notes generation for several monorepo packages as one background task
+    #[instrument(skip(self, config, packages))]
+    pub async fn start_package_release_notes_generation(
+        &self,
+        config: &AppConfig,
+        packages: Vec<(ReleasePackage, Vec<GitCommit>)>,
+    ) -> Result<Option<String>> {
+        let operation_id = format!("release_notes_{}", uuid::Uuid::new_v4());
+        let config_clone = config.clone();
+        let operation_desc = format!("Release notes generation ({} packages)", packages.len());
+
+        let started = self
+            .start_exclusive_operation(
+                RELEASE_NOTES_KIND,
+                operation_id.clone(),
+                operation_desc,
+                move |event_tx, op_id| {
+                    let config = config_clone;
+                    async move {
+                        match generate_package_release_notes_task(event_tx, op_id, config, packages)
+                            .await
+                        {
+                            Ok(_) => Ok(()),
+                            Err(e) => {
+                                error!("Package release notes generation failed: {}", e);
+                                Err(e)
+                            }
+                        }
+                    }
+                },
+            )
+            .await?;
+
+        Ok(started.then_some(operation_id))
+    }
+
+    /// Run only the Gemini step again on a saved release notes document
+    #[instrument(skip(self, config))]
+    pub async fn start_release_notes_gemini_retry(
+        &self,
+        config: &AppConfig,
+        document: std::path::PathBuf,
+    ) -> Result<Option<String>> {
+        let operation_id = format!("release_notes_{}", uuid::Uuid::new_v4());
+        let config_clone = config.clone();
+
+        let started = self
+            .start_exclusive_operation(
+                RELEASE_NOTES_KIND,
+                operation_id.clone(),
+                "Release notes Gemini retry".to_string(),
+                move |event_tx, _op_id| async move {
+                    let result =
+                        process_saved_release_notes(&event_tx, &config_clone, &document).await;
+                    match result {
+                        Ok(result) => {
+                            if let Err(e) = event_tx
+                                .broadcast(BackgroundEvent::ReleaseNotesCompleted(result))
+                                .await
+                            {
+                                warn!("Failed to broadcast completion: {}", e);
+                            }
+                            Ok(())
+                        }
                         Err(e) => {
-                            error!("Release notes generation failed: {}", e);
+                            error!("Release notes Gemini retry failed: {}", e);
                             Err(e)
                         }
                     }
-                }
-            },
+                },
+            )
+            .await?;
+
+        Ok(started.then_some(operation_id))
+    }
+
+    /// Run `npm run release-notes` as a background task, streaming its output
+    #[instrument(skip(self))]
+    pub async fn start_npm_release_notes(&self) -> Result<String> {
+        let operation_id = format!("npm_release_notes_{}", uuid::Uuid::new_v4());
+
+        self.start_operation(
+            operation_id.clone(),
+            "npm run release-notes".to_string(),
+            move |event_tx, op_id| async move { run_npm_release_notes_task(event_tx, op_id).await },
         )
         .await?;
 
         Ok(operation_id)
     }
 
-    /// Start comprehensive analysis as a background task
+    /// Run semantic-release (`--dry-run` when asked) as a background task
+    #[instrument(skip(self))]
+    pub async fn start_semantic_release(&self, dry_run: bool) -> Result<Option<String>> {
+        let operation_id = format!("semantic_release_{}", uuid::Uuid::new_v4());
+
+        let started = self
+            .start_exclusive_operation(
+                SEMANTIC_RELEASE_KIND,
+                operation_id.clone(),
+                "semantic-release".to_string(),
+                move |event_tx, _op_id| run_semantic_release_task(event_tx, dry_run),
+            )
+            .await?;
+
+        Ok(started.then_some(operation_id))
+    }
+
+    /// Compute the next version analysis as a background task
+    #[instrument(skip(self, version_info_cache))]
+    pub async fn start_version_info(
+        &self,
+        version_options: VersionOptions,
+        version_info_cache: VersionInfoCache,
+    ) -> Result<Option<String>> {
+        let operation_id = format!("version_info_{}", uuid::Uuid::new_v4());
+
+        let started = self
+            .start_exclusive_operation(
+                SEMANTIC_RELEASE_KIND,
+                operation_id.clone(),
+                "Version analysis".to_string(),
+                move |event_tx, _op_id| {
+                    run_version_info_task(event_tx, version_options, version_info_cache)
+                },
+            )
+            .await?;
+
+        Ok(started.then_some(operation_id))
+    }
+
+    /// Set up semantic-release and the CI pipeline of `git_host` as a background task
+    #[instrument(skip(self, config))]
+    pub async fn start_ci_setup(
+        &self,
+        config: &AppConfig,
+        git_host: GitHost,
+    ) -> Result<Option<String>> {
+        let operation_id = format!("ci_setup_{}", uuid::Uuid::new_v4());
+        let config_clone = config.clone();
+
+        let started = self
+            .start_exclusive_operation(
+                SEMANTIC_RELEASE_KIND,
+                operation_id.clone(),
+                "CI setup".to_string(),
+                move |event_tx, _op_id| run_ci_setup_task(event_tx, config_clone, git_host),
+            )
+            .await?;
+
+        Ok(started.then_some(operation_id))
+    }
+
+    /// Start comprehensive analysis as a background task. Without
+    /// `risk_analysis` the security and breaking changes are left out.
     #[instrument(skip(self))]
     pub async fn start_comprehensive_analysis(
         &self,
         config: &AppConfig,
         _commits: Vec<GitCommit>,
-    ) -> Result<String> {
+        risk_analysis: bool,
+    ) -> Result<Option<String>> {
         let operation_id = format!("comprehensive_analysis_{}", uuid::Uuid::new_v4());
 
         let config_clone = config.clone();
 
         // Start the actual comprehensive analysis task using start_operation
-        self.start_operation(
-            operation_id.clone(),
-            "Comprehensive AI Analysis".to_string(),
-            move |event_tx, _op_id| async move {
-                // Import necessary types
-                use crate::git::GitRepo;
-                use crate::services::GeminiClient;
-
-                // Broadcast progress
-                if let Err(e) = event_tx
-                    .broadcast(BackgroundEvent::AnalysisProgress(
-                        "Analyzing git repository changes...".to_string(),
-                    ))
-                    .await
-                {
-                    warn!("Failed to broadcast analysis progress: {}", e);
-                }
+        let started = self
+            .start_exclusive_operation(
+                ANALYSIS_KIND,
+                operation_id.clone(),
+                "Comprehensive AI Analysis".to_string(),
+                move |event_tx, _op_id| async move {
+                    // Import necessary types
+                    use crate::git::GitRepo;
+                    use crate::services::GeminiClient;
+
+                    // Broadcast progress
+                    if let Err(e) = event_tx
+                        .broadcast(BackgroundEvent::AnalysisProgress(
+                            "Analyzing git repository changes...".to_string(),
+                        ))
+                        .await
+                    {
+                        warn!("Failed to broadcast analysis progress: {}", e);
+                    }
+
+                    let changes = changes_to_analyze(&event_tx).await?;
 
-                // Get git changes
-                let git_repo = GitRepo::new()?;
-                let changes = git_repo.get_detailed_changes()?;
+                    // Broadcast progress
+                    if let Err(e) = event_tx
+                        .broadcast(BackgroundEvent::AnalysisProgress(
+                            "Connecting to Gemini AI...".to_string(),
+                        ))
+                        .await
+                    {
+                        warn!("Failed to broadcast analysis progress: {}", e);
+                    }
 
-                // Check if there are actually any git changes to analyze
-                // The function returns either actual diff content or a message about no changes
-                let has_changes = !changes.trim().is_empty()
-                    && changes.trim() != "No hay cambios detectados en el repositorio.";
+                    // Create Gemini client and run analysis, previewing the description as it arrives
+                    let (preview_tx, mut preview_rx) = tokio::sync::mpsc::unbounded_channel();
+                    let gemini_client =
+                        GeminiClient::new(&config_clone)?.with_description_preview(preview_tx);
 
-                if !has_changes {
+                    // Broadcast progress
                     if let Err(e) = event_tx
-                        .broadcast(BackgroundEvent::AnalysisError(
-                            "No git changes found to analyze".to_string(),
+                        .broadcast(BackgroundEvent::AnalysisProgress(
+                            "Generating comprehensive commit analysis...".to_string(),
                         ))
                         .await
                     {
-                        warn!("Failed to broadcast analysis error: {}", e);
+                        warn!("Failed to broadcast analysis progress: {}", e);
                     }
-                    return Err(crate::error::SemanticReleaseError::git_error(
-                        std::io::Error::other("No git changes found to analyze"),
-                    ));
-                }
 
-                // Broadcast progress
-                if let Err(e) = event_tx
-                    .broadcast(BackgroundEvent::AnalysisProgress(
-                        "Connecting to Gemini AI...".to_string(),
-                    ))
-                    .await
-                {
-                    warn!("Failed to broadcast analysis progress: {}", e);
-                }
+                    // The preview sender lives in the client, so forwarding ends with the analysis
+                    let analysis = async move {
+                        gemini_client
+                            .generate_comprehensive_commit_analysis(&changes, risk_analysis)
+                            .await
+                    };
+                    let forward_preview = async {
+                        while let Some(description) = preview_rx.recv().await {
+                            if let Err(e) = event_tx
+                                .broadcast(BackgroundEvent::AnalysisPreview(description))
+                                .await
+                            {
+                                warn!("Failed to broadcast analysis preview: {}", e);
+                            }
+                        }
+                    };
+                    let (result, ()) = tokio::join!(analysis, forward_preview);
+                    let result = result?;
 
-                // Create Gemini client and run analysis
-                let gemini_client = GeminiClient::new(&config_clone)?;
+                    // Broadcast completion with the full result
+                    if let Err(e) = event_tx
+                        .broadcast(BackgroundEvent::AnalysisCompleted(result))
+                        .await
+                    {
+                        warn!("Failed to broadcast analysis completion: {}", e);
+                    }
 
-                // Broadcast progress
-                if let Err(e) = event_tx
-                    .broadcast(BackgroundEvent::AnalysisProgress(
-                        "Generating comprehensive commit analysis...".to_string(),
-                    ))
-                    .await
-                {
-                    warn!("Failed to broadcast analysis progress: {}", e);
-                }
+                    Ok(())
+                },
+            )
+            .await?;
 
-                let result = gemini_client
-                    .generate_comprehensive_commit_analysis(&changes)
-                    .await?;
+        Ok(started.then_some(operation_id))
+    }
+}
 
-                // Broadcast completion with the full result
-                if let Err(e) = event_tx
-                    .broadcast(BackgroundEvent::AnalysisCompleted(result))
-                    .await
-                {
-                    warn!("Failed to broadcast analysis completion: {}", e);
-                }
+impl BackgroundTaskManager {
+    /// Re-run only the security or the breaking changes analysis as a
+    /// background task. Shares the exclusive kind of the comprehensive analysis.
+    #[instrument(skip(self, config))]
+    pub async fn start_field_analysis(
+        &self,
+        config: &AppConfig,
+        field: CommitField,
+    ) -> Result<Option<String>> {
+        let operation_id = format!("field_analysis_{}", uuid::Uuid::new_v4());
+        let config_clone = config.clone();
 
-                Ok(())
-            },
-        )
-        .await?;
+        let started = self
+            .start_exclusive_operation(
+                ANALYSIS_KIND,
+                operation_id.clone(),
+                format!("AI Analysis: {}", field.label()),
+                move |event_tx, _op_id| async move {
+                    use crate::services::GeminiClient;
 
-        Ok(operation_id)
+                    let changes = changes_to_analyze(&event_tx).await?;
+
+                    if let Err(e) = event_tx
+                        .broadcast(BackgroundEvent::AnalysisProgress(format!(
+                            "Regenerando {}...",
+                            field.label()
+                        )))
+                        .await
+                    {
+                        warn!("Failed to broadcast analysis progress: {}", e);
+                    }
+
+                    let gemini_client = GeminiClient::new(&config_clone)?;
+                    let text = match field {
+                        CommitField::BreakingChange => {
+                            gemini_client.analyze_breaking_changes(&changes).await?
+                        }
+                        _ => gemini_client.analyze_security_risks(&changes).await?,
+                    };
+
+                    if let Err(e) = event_tx
+                        .broadcast(BackgroundEvent::FieldAnalysisCompleted { field, text })
+                        .await
+                    {
+                        warn!("Failed to broadcast analysis completion: {}", e);
+                    }
+
+                    Ok(())
+                },
+            )
+            .await?;
+
+        Ok(started.then_some(operation_id))
     }
 }
 
+impl BackgroundTaskManager {
+    /// Check the scope's task IDs against the task system without blocking the
+    /// UI; the answer arrives as `TaskIdsChecked`. Not tracked as an operation,
+    /// so it finishing doesn't end the loading state of one in progress.
+    pub fn start_task_id_check(&self, config: &AppConfig, ids: Vec<String>) {
+        let config_clone = config.clone();
+        let event_tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let results = crate::app::task_operations::check_task_ids(&config_clone, &ids).await;
+            if let Err(e) = event_tx
+                .broadcast(BackgroundEvent::TaskIdsChecked(results))
+                .await
+            {
+                warn!("Failed to broadcast task ID check: {}", e);
+            }
+        });
+    }
+}
+
+/// Detailed changes of the working tree for the AI analysis. Reports an
+/// `AnalysisError` and fails when there is nothing to analyze.
+async fn changes_to_analyze(event_tx: &Sender<BackgroundEvent>) -> Result<String> {
+    let git_repo = GitRepo::new()?;
+    let changes = git_repo.get_detailed_changes()?;
+
+    // Check if there are actually any git changes to analyze
+    // The function returns either actual diff content or a message about no changes
+    let has_changes = !changes.trim().is_empty()
+        && changes.trim() != "No hay cambios detectados en el repositorio.";
+
+    if !has_changes {
+        if let Err(e) = event_tx
+            .broadcast(BackgroundEvent::AnalysisError(
+                "No git changes found to analyze".to_string(),
+            ))
+            .await
+        {
+            warn!("Failed to broadcast analysis error: {}", e);
+        }
+        return Err(crate::error::SemanticReleaseError::git_error(
+            std::io::Error::other("No git changes found to analyze"),
+        ));
+    }
+
+    Ok(changes)
+}
+
 impl Default for BackgroundTaskManager {
     fn default() -> Self {
         Self::new()
@@ -304,19 +753,41 @@ impl Default for BackgroundTaskManager {
 // Define the trait that was removed
 #[allow(async_fn_in_trait)]
 pub trait ComprehensiveAnalysisOperations {
-    async fn handle_comprehensive_analysis(&mut self) -> Result<()>;
+    async fn handle_comprehensive_analysis(&mut self, full: bool) -> Result<()>;
+    async fn handle_field_analysis(&mut self, field: CommitField) -> Result<()>;
 }
 
 impl ComprehensiveAnalysisOperations for App {
-    async fn handle_comprehensive_analysis(&mut self) -> Result<()> {
+    /// Analyze the changes with Gemini. Commits of a trivial type (as picked
+    /// in the form) skip security and breaking changes unless `full`.
+    async fn handle_comprehensive_analysis(&mut self, full: bool) -> Result<()> {
         // Check if already processing to avoid multiple concurrent analyses
-        if matches!(self.current_state, AppState::Loading) {
+        if matches!(self.current_state, AppState::Loading) || self.unavailable_offline("Gemini") {
             return Ok(());
         }
+        if self
+            .background_task_manager
+            .is_exclusive_running(ANALYSIS_KIND)
+        {
+            self.message = Some(self.config.tr("analysis.already_running").to_string());
+            return Ok(());
+        }
+
+        let trivial_type = self
+            .commit_form
+            .commit_type
+            .clone()
+            .filter(|commit_type| !full && self.config.is_trivial_commit_type(commit_type));
 
         // Set to loading state
         self.current_state = AppState::Loading;
-        self.message = Some("🤖 Iniciando análisis completo con IA...".to_string());
+        self.message = Some(match &trivial_type {
+            Some(commit_type) => format!(
+                "🤖 Iniciando análisis con IA sin seguridad ni breaking changes (commit {}, 'T' para el completo)...",
+                commit_type.as_str()
+            ),
+            None => "🤖 Iniciando análisis completo con IA...".to_string(),
+        });
 
         // Get commits since last tag for analysis
         let git_repo = GitRepo::new()?;
@@ -335,12 +806,53 @@ impl ComprehensiveAnalysisOperations for App {
         // Start comprehensive analysis using background task manager
         match self
             .background_task_manager
-            .start_comprehensive_analysis(&self.config, commits)
+            .start_comprehensive_analysis(&self.config, commits, trivial_type.is_none())
             .await
         {
-            Ok(_operation_id) => {
+            Ok(Some(_operation_id)) => {
+                // Results are applied from the event loop, after this snapshot
+                self.ui_state.form_before_analysis = Some(self.commit_form.clone());
                 info!("Comprehensive analysis started via BackgroundTaskManager");
             }
+            Ok(None) => {
+                self.message = Some(self.config.tr("analysis.already_running").to_string());
+            }
+            Err(e) => {
+                self.current_state = AppState::Error(format!("Error iniciando análisis: {}", e));
+                self.message = Some(format!("❌ {}", e));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_field_analysis(&mut self, field: CommitField) -> Result<()> {
+        if matches!(self.current_state, AppState::Loading) || self.unavailable_offline("Gemini") {
+            return Ok(());
+        }
+        if self
+            .background_task_manager
+            .is_exclusive_running(ANALYSIS_KIND)
+        {
+            self.message = Some(self.config.tr("analysis.already_running").to_string());
+            return Ok(());
+        }
+
+        self.current_state = AppState::Loading;
+        self.message = Some(format!("🤖 Regenerando {} con IA...", field.label()));
+
+        match self
+            .background_task_manager
+            .start_field_analysis(&self.config, field)
+            .await
+        {
+            Ok(Some(_operation_id)) => {
+                // Only this field changes, so 'u' restores just it
+                self.ui_state.form_before_analysis = Some(self.commit_form.clone());
+            }
+            Ok(None) => {
+                self.message = Some(self.config.tr("analysis.already_running").to_string());
+            }
             Err(e) => {
                 self.current_state = AppState::Error(format!("Error iniciando análisis: {}", e));
                 self.message = Some(format!("❌ {}", e));
@@ -395,4 +907,91 @@ mod tests {
         }
         assert!(completed, "Operation should have completed");
     }
+
+    #[tokio::test]
+    async fn test_cancel_all_aborts_running_operations() {
+        let manager = BackgroundTaskManager::new();
+        let mut receiver = manager.subscribe();
+
+        manager
+            .start_operation(
+                "slow_op".to_string(),
+                "Slow operation".to_string(),
+                |_event_tx, _operation_id| async move {
+                    sleep(Duration::from_secs(30)).await;
+                    Ok(())
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(manager.has_active_operations().await);
+        assert_eq!(manager.cancel_all().await, 1);
+        assert!(!manager.has_active_operations().await);
+
+        let mut cancelled = false;
+        while let Ok(event) = timeout(Duration::from_secs(1), receiver.recv()).await {
+            match event {
+                Ok(BackgroundEvent::OperationCancelled { operation_id }) => {
+                    assert_eq!(operation_id, "slow_op");
+                    cancelled = true;
+                    break;
+                }
+                Ok(BackgroundEvent::OperationCompleted { .. }) => {
+                    panic!("Cancelled operation must not complete")
+                }
+                _ => {}
+            }
+        }
+        assert!(cancelled, "Operation should have been cancelled");
+    }
+
+    #[tokio::test]
+    async fn test_exclusive_operation_ignores_double_press() {
+        let manager = BackgroundTaskManager::new();
+        let mut receiver = manager.subscribe();
+        let runs = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        // Two presses in a row, before the first analysis has had a chance to run
+        let mut started = Vec::new();
+        for press in 0..2 {
+            let runs = runs.clone();
+            started.push(
+                manager
+                    .start_exclusive_operation(
+                        ANALYSIS_KIND,
+                        format!("analysis_{}", press),
+                        "Analysis".to_string(),
+                        move |_event_tx, _operation_id| async move {
+                            runs.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                            sleep(Duration::from_millis(100)).await;
+                            Ok(())
+                        },
+                    )
+                    .await
+                    .unwrap(),
+            );
+        }
+        assert_eq!(started, vec![true, false]);
+        assert!(manager.is_exclusive_running(ANALYSIS_KIND));
+
+        while let Ok(event) = timeout(Duration::from_secs(1), receiver.recv()).await {
+            if let Ok(BackgroundEvent::OperationCompleted { .. }) = event {
+                break;
+            }
+        }
+        assert_eq!(runs.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(!manager.is_exclusive_running(ANALYSIS_KIND));
+
+        // Once finished, a new press starts a fresh analysis
+        assert!(manager
+            .start_exclusive_operation(
+                ANALYSIS_KIND,
+                "analysis_again".to_string(),
+                "Analysis".to_string(),
+                |_event_tx, _operation_id| async move { Ok(()) },
+            )
+            .await
+            .unwrap());
+    }
 }
@@ -1,7 +1,71 @@
 use crate::error::Result;
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, error, info, instrument};
 
-use crate::{app::App, git::GitRepo, services::MondayClient};
+use crate::{
+    app::task_operations::{check_task_ids, scope_task_ids, task_id_problems},
+    app::App,
+    error::SemanticReleaseError,
+    git::{GitRepo, RepoState, SecretScanner},
+    services::{JiraClient, MondayClient},
+    types::{
+        CommitAnalysis, CommitForm, CommitType, ConfigWarningSeverity, JiraTask, MondayTask,
+        SecretScanMode, TaskSystem,
+    },
+};
+
+/// Commit contents given on the command line (`commit --no-tui`).
+#[derive(Debug, Default)]
+pub struct CommitFlags {
+    pub commit_type: Option<String>,
+    pub scope: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub breaking: Option<String>,
+    pub test_details: Option<String>,
+    pub security: Option<String>,
+    pub tasks: Vec<String>,
+    /// Print the message instead of committing
+    pub dry_run: bool,
+    /// Commit despite possible secrets when `SECRET_SCAN=confirm`
+    pub allow_secrets: bool,
+}
+
+/// Output format of the `history` command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum HistoryFormat {
+    #[default]
+    Table,
+    Json,
+}
+
+/// Options of the `history` command.
+#[derive(Debug)]
+pub struct HistoryOptions {
+    pub limit: usize,
+    /// Only list commits after this tag/revision, duration or date
+    pub since: Option<String>,
+    pub format: HistoryFormat,
+}
+
+/// What the `artifacts` command does with the generated release notes.
+#[derive(Debug)]
+pub enum ArtifactsAction {
+    List,
+    /// Show the generation log
+    History,
+    /// Open a run (its prefix, one of its files or `latest`) with the system viewer
+    Open(String),
+    /// Delete every file of a run
+    Delete(String),
+    /// Delete runs older than `older_than_days` and/or the failed ones
+    Clean {
+        older_than_days: Option<u64>,
+        failed: bool,
+        dry_run: bool,
+    },
+}
 
 impl App {
     // CLI methods for direct command usage
@@ -30,6 +94,138 @@ impl App {
         Ok(())
     }
 
+    /// Build the commit from command-line flags and create it without opening the TUI.
+    #[instrument(skip(self))]
+    pub async fn commit_from_flags(&mut self, flags: CommitFlags) -> Result<()> {
+        use crate::app::commit_operations::CommitOperations;
+
+        info!("Creating commit from command-line flags");
+
+        let type_name = flags
+            .commit_type
+            .as_deref()
+            .ok_or_else(|| SemanticReleaseError::validation_error("--type is required"))?;
+        let commit_type = CommitType::parse(type_name).ok_or_else(|| {
+            let valid: Vec<&str> = CommitType::all().iter().map(|t| t.as_str()).collect();
+            SemanticReleaseError::validation_error(format!(
+                "unknown commit type '{}', expected one of: {}",
+                type_name,
+                valid.join(", ")
+            ))
+        })?;
+        let title = flags
+            .title
+            .filter(|title| !title.trim().is_empty())
+            .ok_or_else(|| SemanticReleaseError::validation_error("--title is required"))?;
+
+        // Task IDs are the scope by convention (e.g. `feat(8816791718|8816791719): ...`)
+        let scope = flags
+            .scope
+            .filter(|scope| !scope.trim().is_empty())
+            .unwrap_or_else(|| flags.tasks.join("|"));
+
+        self.commit_form = CommitForm {
+            commit_type: Some(commit_type),
+            scope,
+            title,
+            description: flags.description.unwrap_or_default(),
+            breaking_change: flags.breaking.unwrap_or_default(),
+            test_details: flags.test_details.unwrap_or_default(),
+            security: flags.security.unwrap_or_default(),
+            ..CommitForm::default()
+        };
+        match self.config.get_task_system() {
+            TaskSystem::Monday => {
+                self.commit_form.selected_monday_tasks =
+                    flags.tasks.iter().map(|id| monday_task_ref(id)).collect();
+            }
+            TaskSystem::Jira => {
+                self.commit_form.selected_jira_tasks =
+                    flags.tasks.iter().map(|key| jira_task_ref(key)).collect();
+            }
+            TaskSystem::None if !flags.tasks.is_empty() => {
+                eprintln!("⚠️  No task system configured, --task IDs are only used as scope");
+            }
+            TaskSystem::None => {}
+        }
+
+        let message = self.build_commit_message();
+        if flags.dry_run {
+            println!("{}", message);
+            return Ok(());
+        }
+
+        let git_repo = GitRepo::new()?;
+        let status = git_repo.get_status()?;
+        if !status.state.is_clean() {
+            println!(
+                "⚠️  {}: {}",
+                status.state.description(),
+                status.state.hint()
+            );
+        }
+        if status.staged.is_empty() && status.state != RepoState::Merge {
+            return Err(SemanticReleaseError::validation_error(
+                "no staged changes to commit, stage them with `git add` first",
+            ));
+        }
+
+        // There is nobody to confirm here, so possible secrets need --allow-secrets
+        if self.config.secret_scan != SecretScanMode::Off {
+            let findings =
+                git_repo.find_staged_secrets(&SecretScanner::from_config(&self.config))?;
+            if !findings.is_empty() {
+                let blocked = self.config.secret_scan == SecretScanMode::Block;
+                eprintln!(
+                    "{}",
+                    self.config.tr(if blocked {
+                        "secrets.blocked"
+                    } else {
+                        "secrets.found"
+                    })
+                );
+                for finding in &findings {
+                    eprintln!("   {}", finding);
+                }
+                if blocked {
+                    return Err(SemanticReleaseError::validation_error(
+                        "possible secrets in the staged changes (SECRET_SCAN=block)",
+                    ));
+                }
+                if !flags.allow_secrets {
+                    return Err(SemanticReleaseError::validation_error(
+                        self.config.tr("secrets.cli_refused"),
+                    ));
+                }
+            }
+        }
+
+        // --task IDs are typed by hand too, so every ID in the scope is checked
+        if self.config.validate_task_ids && !self.config.offline {
+            let ids = scope_task_ids(&self.commit_form.scope, &self.config.get_task_system());
+            let results = check_task_ids(&self.config, &ids).await;
+            let problems = task_id_problems(&self.config, &results);
+            if !problems.is_empty() {
+                eprintln!("{}", self.config.tr("task_check.problems"));
+                for problem in problems {
+                    eprintln!("   {}", problem);
+                }
+            }
+        }
+
+        let hash = git_repo.create_commit(&message, self.config.no_verify)?;
+        println!("✅ Commit {} created", &hash[..7]);
+        // No event loop here, so run the post-commit update directly
+        crate::app::commit_operations::post_monday_commit_updates(
+            &self.config,
+            &hash,
+            &message,
+            &self.linked_task_ids(),
+        )
+        .await;
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     pub async fn autocommit_flow(&self) -> Result<()> {
         info!("Starting autocommit flow via CLI");
@@ -49,93 +245,8 @@ impl App {
         debug!("Created new app instance for commit editor");
 
         // Populate form with AI analysis results
-        if let Some(title) = analysis_result.get("title").and_then(|v| v.as_str()) {
-            if !title.is_empty() {
-                debug!(title = %title, "Populating title from AI analysis");
-                app.commit_form.title = title.to_string();
-            }
-        }
-
-        if let Some(commit_type) = analysis_result.get("commitType").and_then(|v| v.as_str()) {
-            if !commit_type.is_empty() {
-                debug!(commit_type = %commit_type, "Populating commit type from AI analysis");
-                use crate::types::CommitType;
-                let commit_type_enum = match commit_type {
-                    "feat" => Some(CommitType::Feat),
-                    "fix" => Some(CommitType::Fix),
-                    "docs" => Some(CommitType::Docs),
-                    "style" => Some(CommitType::Style),
-                    "refactor" => Some(CommitType::Refactor),
-                    "perf" => Some(CommitType::Perf),
-                    "test" => Some(CommitType::Test),
-                    "chore" => Some(CommitType::Chore),
-                    "revert" => Some(CommitType::Revert),
-                    _ => None,
-                };
-
-                if let Some(ct) = commit_type_enum {
-                    app.commit_form.commit_type = Some(ct.clone());
-                    // Update UI state to reflect the selected commit type
-                    let commit_types = CommitType::all();
-                    if let Some(index) = commit_types.iter().position(|t| *t == ct) {
-                        app.ui_state.selected_commit_type = index;
-                    }
-                }
-            }
-        }
-
-        if let Some(description) = analysis_result.get("description").and_then(|v| v.as_str()) {
-            if !description.is_empty() {
-                debug!(
-                    description_len = description.len(),
-                    "Populating description from AI analysis"
-                );
-                app.commit_form.description = description.to_string();
-            }
-        }
-
-        if let Some(scope) = analysis_result.get("scope").and_then(|v| v.as_str()) {
-            if !scope.is_empty() && scope != "general" {
-                debug!(scope = %scope, "Populating scope from AI analysis");
-                app.commit_form.scope = scope.to_string();
-            }
-        }
-
-        if let Some(security) = analysis_result
-            .get("securityAnalysis")
-            .and_then(|v| v.as_str())
-        {
-            if !security.is_empty() {
-                debug!(
-                    security_len = security.len(),
-                    "Populating security analysis from AI"
-                );
-                app.commit_form.security = security.to_string();
-            }
-        }
-
-        if let Some(breaking) = analysis_result
-            .get("breakingChanges")
-            .and_then(|v| v.as_str())
-        {
-            if !breaking.is_empty() {
-                debug!(
-                    breaking_len = breaking.len(),
-                    "Populating breaking changes from AI analysis"
-                );
-                app.commit_form.breaking_change = breaking.to_string();
-            }
-        }
-
-        if let Some(test_analysis) = analysis_result.get("testAnalysis").and_then(|v| v.as_str()) {
-            if !test_analysis.is_empty() {
-                debug!(
-                    test_analysis_len = test_analysis.len(),
-                    "Populating test analysis from AI"
-                );
-                app.commit_form.test_details = test_analysis.to_string();
-            }
-        }
+        debug!(analysis = ?analysis_result, "Populating commit form from AI analysis");
+        app.apply_commit_analysis(&analysis_result);
 
         // Generate commit message preview
         use crate::app::commit_operations::CommitOperations;
@@ -173,7 +284,7 @@ impl App {
 
     /// CLI-only comprehensive analysis that doesn't involve TUI state management
     #[instrument(skip(self))]
-    async fn run_comprehensive_analysis_cli(&self) -> Result<serde_json::Value> {
+    async fn run_comprehensive_analysis_cli(&self) -> Result<CommitAnalysis> {
         use crate::git::GitRepo;
         use crate::services::GeminiClient;
 
@@ -204,7 +315,7 @@ impl App {
         crate::observability::log_user_message("🧠 Generating comprehensive commit analysis...");
 
         let result = gemini_client
-            .generate_comprehensive_commit_analysis(&changes)
+            .generate_comprehensive_commit_analysis(&changes, true)
             .await?;
 
         info!("Comprehensive analysis completed successfully");
@@ -219,8 +330,16 @@ impl App {
             query
         ));
 
-        let client = MondayClient::new(&self.config)?;
-        let tasks = client.search_tasks(query).await?;
+        let client = MondayClient::new(&self.config)?.with_rate_limit_listener(Arc::new(
+            |wait: Duration| {
+                crate::observability::log_user_message(&format!(
+                    "⏳ Monday.com rate limit reached, waiting {:.1}s...",
+                    wait.as_secs_f64()
+                ));
+            },
+        ));
+        let mut tasks = client.search_tasks(query).await?;
+        tasks.retain(MondayTask::is_active);
         debug!(task_count = tasks.len(), "Retrieved tasks from Monday.com");
 
         crate::observability::log_user_message(&format!("📋 Found {} tasks:", tasks.len()));
@@ -242,6 +361,384 @@ impl App {
         Ok(())
     }
 
+    /// Generate the release notes without touching the terminal
+    /// (`release-notes --ci`, or no TTY): progress goes to stdout and errors
+    /// end the process with a non-zero exit code.
+    #[instrument(skip(self))]
+    pub async fn release_notes_headless(&self) -> Result<()> {
+        use crate::app::{
+            background_operations::BackgroundEvent,
+            release_notes::{
+                generate_package_release_notes_task, generate_release_notes_task,
+                ReleaseNotesCommits,
+            },
+        };
+
+        info!("Generating release notes headless");
+        let commits = self.collect_release_notes_commits()?;
+        let operation_id = format!("release_notes_{}", uuid::Uuid::new_v4());
+        let (event_tx, mut event_rx) = async_broadcast::broadcast(100);
+
+        let config = self.config.clone();
+        let generation = async move {
+            match commits {
+                ReleaseNotesCommits::Repository(commits) => {
+                    generate_release_notes_task(event_tx, operation_id, config, commits).await
+                }
+                ReleaseNotesCommits::Packages(packages) => {
+                    generate_package_release_notes_task(event_tx, operation_id, config, packages)
+                        .await
+                }
+            }
+        };
+        // Ends once the generation drops its sender
+        let report = async move {
+            while let Ok(event) = event_rx.recv().await {
+                match event {
+                    BackgroundEvent::ReleaseNotesProgress { status, progress } => {
+                        println!("[{:>3.0}%] {}", progress * 100.0, status);
+                    }
+                    BackgroundEvent::ReleaseNotesCompleted(result) => {
+                        let message = result["message"]
+                            .as_str()
+                            .unwrap_or("Release notes generated");
+                        println!("✅ {}", message);
+                        if let Some(warning) = result["warning"].as_str() {
+                            println!("⚠️  {}", warning);
+                        }
+                    }
+                    BackgroundEvent::ReleaseNotesError(error) => {
+                        eprintln!("❌ {}", error);
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        let (result, ()) = tokio::join!(generation, report);
+        result
+    }
+
+    /// Run only the Gemini step on a saved release notes document
+    /// (`release-notes --from-document`), printing progress to stdout.
+    #[instrument(skip(self))]
+    pub async fn release_notes_from_document(&self, document: &std::path::Path) -> Result<()> {
+        use crate::app::{
+            background_operations::BackgroundEvent, release_notes::process_saved_release_notes,
+        };
+
+        if self.config.gemini_token.is_none() {
+            return Err(SemanticReleaseError::config_error(
+                "GEMINI_TOKEN is required to process a release notes document",
+            ));
+        }
+        let (event_tx, mut event_rx) = async_broadcast::broadcast(100);
+
+        let config = self.config.clone();
+        let processing =
+            async move { process_saved_release_notes(&event_tx, &config, document).await };
+        // Ends once the processing drops its sender
+        let report = async move {
+            while let Ok(event) = event_rx.recv().await {
+                match event {
+                    BackgroundEvent::ReleaseNotesProgress { status, progress } => {
+                        println!("[{:>3.0}%] {}", progress * 100.0, status);
+                    }
+                    BackgroundEvent::ReleaseNotesError(error) => {
+                        eprintln!("❌ {}", error);
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        let (result, ()) = tokio::join!(processing, report);
+        let result = result?;
+        println!(
+            "✅ {}",
+            result["message"]
+                .as_str()
+                .unwrap_or("Release notes processed with Gemini")
+        );
+        Ok(())
+    }
+
+    /// Fail when more than `max` commits of the release notes range don't follow
+    /// Conventional Commits (`release-notes --max-non-conventional`, for CI).
+    #[instrument(skip(self))]
+    pub fn check_commit_convention(&self, max: usize) -> Result<()> {
+        let git_repo = GitRepo::new()?.with_trailers(self.config.commit_trailers());
+        let commits = match self.ui_state.release_notes_since {
+            Some(since) => git_repo.get_commits_since_date(since)?,
+            None => git_repo.get_commits_since_tag(git_repo.get_last_tag()?.as_deref())?,
+        };
+
+        let non_conventional = crate::app::release_notes::non_conventional_commits(&commits);
+        info!(
+            count = non_conventional.len(),
+            max, "Checked commit convention for release notes"
+        );
+        if non_conventional.len() > max {
+            return Err(SemanticReleaseError::validation_error(format!(
+                "{} (máximo permitido: {})",
+                crate::app::release_notes::non_conventional_warning(&non_conventional),
+                max
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check that every commit since the last tag (or `since`) parses the way
+    /// the release notes need, listing each problem with the commit's hash.
+    /// Returns `false` when any commit has one.
+    #[instrument(skip(self))]
+    pub fn verify_commits(&self, since: Option<&str>) -> Result<bool> {
+        info!("Verifying commits via CLI");
+        let git_repo = GitRepo::new()?.with_trailers(self.config.commit_trailers());
+
+        let (range, commits) = match since {
+            // Durations and dates first; anything else is a tag or revision
+            Some(since) => match crate::git::parse_since(since) {
+                Some(date) => (since.to_string(), git_repo.get_commits_since_date(date)?),
+                None => (
+                    since.to_string(),
+                    git_repo.get_commits_since_tag(Some(since))?,
+                ),
+            },
+            None => {
+                let tag = git_repo.get_last_tag()?;
+                let commits = git_repo.get_commits_since_tag(tag.as_deref())?;
+                (
+                    tag.unwrap_or_else(|| "the first commit".to_string()),
+                    commits,
+                )
+            }
+        };
+        println!(
+            "🔎 Verifying {} commits since {} (merge commits are skipped)",
+            commits.len(),
+            range
+        );
+
+        let mut failing = 0;
+        for commit in &commits {
+            let problems = git_repo.convention_problems(commit);
+            if problems.is_empty() {
+                continue;
+            }
+            failing += 1;
+            let hash = &commit.hash[..commit.hash.len().min(7)];
+            println!("❌ {} {}", hash, truncate_chars(&commit.subject, 72));
+            for problem in problems {
+                println!("   {} {}", hash, problem);
+            }
+        }
+
+        info!(
+            checked = commits.len(),
+            failing, "Verified commit convention"
+        );
+        if failing == 0 {
+            println!("✅ All commits follow the convention");
+            return Ok(true);
+        }
+        println!(
+            "\n{} of {} commits need fixing; reword them with `git rebase -i` before tagging",
+            failing,
+            commits.len()
+        );
+        Ok(false)
+    }
+
+    /// Print the latest commits parsed as conventional commits.
+    #[instrument(skip(self))]
+    pub async fn print_history(&self, options: HistoryOptions) -> Result<()> {
+        info!("Listing commit history via CLI");
+        let git_repo = GitRepo::new()?.with_trailers(self.config.commit_trailers());
+
+        let commits = match &options.since {
+            Some(since) => {
+                // Durations and dates first; anything else is a tag or revision
+                let mut commits = match crate::git::parse_since(since) {
+                    Some(date) => git_repo.get_commits_since_date(date)?,
+                    None => git_repo.get_commits_since_tag(Some(since))?,
+                };
+                commits.truncate(options.limit);
+                commits
+            }
+            None => git_repo.get_recent_commits(options.limit)?,
+        };
+        debug!(commit_count = commits.len(), "Retrieved commit history");
+
+        match options.format {
+            HistoryFormat::Json => println!("{}", serde_json::to_string_pretty(&commits)?),
+            HistoryFormat::Table => {
+                println!(
+                    "{:<8} {:<10} {:<9} {:<20} {:<60} TASKS",
+                    "HASH", "DATE", "TYPE", "SCOPE", "TITLE"
+                );
+                for commit in &commits {
+                    let tasks: Vec<&str> = commit
+                        .monday_tasks
+                        .iter()
+                        .chain(&commit.jira_tasks)
+                        .map(String::as_str)
+                        .collect();
+                    // Commits outside the convention show their raw subject
+                    let (commit_type, title) = match &commit.commit_type {
+                        Some(commit_type) => (commit_type.as_str(), commit.description.as_str()),
+                        None => ("⚠ none", commit.subject.as_str()),
+                    };
+                    println!(
+                        "{:<8} {:<10} {:<9} {:<20} {:<60} {}",
+                        &commit.hash[..commit.hash.len().min(7)],
+                        commit.date,
+                        commit_type,
+                        truncate_chars(commit.scope.as_deref().unwrap_or("-"), 20),
+                        truncate_chars(title, 60),
+                        tasks.join(",")
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// List, open or delete the files generated in RELEASE_NOTES_DIR.
+    pub fn manage_release_notes_artifacts(&self, action: ArtifactsAction) -> Result<()> {
+        use crate::app::release_notes::{read_release_notes_log, scan_release_notes_artifacts};
+
+        let dir = self.config.release_notes_dir();
+        let artifacts = scan_release_notes_artifacts(&dir)?;
+        debug!(dir = %dir.display(), count = artifacts.len(), "Scanned release notes artifacts");
+
+        match action {
+            ArtifactsAction::List => {
+                if artifacts.is_empty() {
+                    println!("No release notes found in {}", dir.display());
+                    return Ok(());
+                }
+                let latest_valid = artifacts
+                    .iter()
+                    .find(|artifact| !artifact.is_failed())
+                    .map(|artifact| artifact.prefix.as_str());
+                println!(
+                    "  {:<19} {:<12} {:<20} {:>9} {:<6} {:<6} RUN",
+                    "DATE", "VERSION", "PACKAGE", "SIZE", "GEMINI", "STATUS"
+                );
+                for artifact in &artifacts {
+                    let marker = if Some(artifact.prefix.as_str()) == latest_valid {
+                        "★"
+                    } else {
+                        " "
+                    };
+                    println!(
+                        "{} {:<19} {:<12} {:<20} {:>9} {:<6} {:<6} {}",
+                        marker,
+                        artifact.generated_at.format("%Y-%m-%d %H:%M:%S"),
+                        artifact.version.as_deref().unwrap_or("-"),
+                        truncate_chars(artifact.package.as_deref().unwrap_or("-"), 20),
+                        format_size(artifact.size),
+                        if artifact.gemini.is_some() {
+                            "yes"
+                        } else {
+                            "no"
+                        },
+                        if artifact.is_failed() { "failed" } else { "ok" },
+                        artifact.prefix
+                    );
+                }
+                if latest_valid.is_some() {
+                    println!("\n★ latest valid generation");
+                }
+            }
+            ArtifactsAction::History => {
+                let log = read_release_notes_log(&dir);
+                if log.is_empty() {
+                    println!("No release notes generations recorded in {}", dir.display());
+                    return Ok(());
+                }
+                println!(
+                    "{:<16} {:<12} {:<20} {:<16} {:>7} {:<6} {:<7} RUN",
+                    "DATE", "VERSION", "PACKAGE", "RANGE", "COMMITS", "GEMINI", "FILES"
+                );
+                for record in log.iter().rev() {
+                    println!(
+                        "{:<16} {:<12} {:<20} {:<16} {:>7} {:<6} {:<7} {}",
+                        record
+                            .generated_at
+                            .with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M"),
+                        record.version.as_deref().unwrap_or("-"),
+                        truncate_chars(record.package.as_deref().unwrap_or("-"), 20),
+                        record.range(),
+                        record.commit_count,
+                        if record.gemini { "yes" } else { "no" },
+                        if record.files_exist() {
+                            "ok"
+                        } else {
+                            "deleted"
+                        },
+                        record.prefix
+                    );
+                }
+            }
+            ArtifactsAction::Open(run) => {
+                let artifact = find_artifact(&artifacts, &run)?;
+                let file = artifact.preferred_file().ok_or_else(|| {
+                    SemanticReleaseError::validation_error(format!("'{}' has no files", run))
+                })?;
+                open_with_system_viewer(file)?;
+                println!("📂 Opened {}", file.display());
+            }
+            ArtifactsAction::Delete(run) => {
+                let artifact = find_artifact(&artifacts, &run)?;
+                delete_artifact(artifact)?;
+                println!("🗑️  Deleted {}", artifact.prefix);
+            }
+            ArtifactsAction::Clean {
+                older_than_days,
+                failed,
+                dry_run,
+            } => {
+                if older_than_days.is_none() && !failed {
+                    return Err(SemanticReleaseError::validation_error(
+                        "Nothing to clean: pass --older-than DAYS and/or --failed",
+                    ));
+                }
+                let cutoff = older_than_days.map(|days| {
+                    chrono::Local::now().naive_local() - chrono::Duration::days(days as i64)
+                });
+                let to_delete: Vec<_> = artifacts
+                    .iter()
+                    .filter(|artifact| {
+                        cutoff.is_some_and(|cutoff| artifact.generated_at < cutoff)
+                            || (failed && artifact.is_failed())
+                    })
+                    .collect();
+
+                for artifact in &to_delete {
+                    if dry_run {
+                        println!("Would delete {}", artifact.prefix);
+                    } else {
+                        delete_artifact(artifact)?;
+                        println!("🗑️  Deleted {}", artifact.prefix);
+                    }
+                }
+                let freed: u64 = to_delete.iter().map(|artifact| artifact.size).sum();
+                println!(
+                    "{} {} run(s), {}",
+                    if dry_run { "Would delete" } else { "Deleted" },
+                    to_delete.len(),
+                    format_size(freed)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     // Debug methods for troubleshooting
     #[instrument(skip(self))]
     pub async fn debug_monday(&self) -> Result<()> {
@@ -370,6 +867,14 @@ impl App {
         println!("\n2. Checking repository status...");
         match git_repo.get_status() {
             Ok(status) => {
+                if !status.state.is_clean() {
+                    println!(
+                        "⚠️  {}: {}",
+                        status.state.description(),
+                        status.state.hint()
+                    );
+                }
+
                 if status.staged.is_empty() && status.modified.is_empty() {
                     println!("⚠️  No changes to commit");
                     println!("💡 Try making some changes and staging them with 'git add .'");
@@ -422,4 +927,380 @@ impl App {
 
         Ok(())
     }
+
+    /// Run every configuration check and print a report. Returns `false` if any
+    /// critical check failed.
+    pub async fn doctor(&self) -> Result<bool> {
+        info!("Running doctor checks via CLI");
+        println!("🩺 Doctor: Checking configuration...");
+        println!(
+            "👤 Profile: {}",
+            self.config
+                .profile
+                .as_deref()
+                .unwrap_or(crate::config::DEFAULT_PROFILE)
+        );
+        // Process environment variables override every file listed here
+        println!("📂 Config files (highest precedence first, after env vars):");
+        for path in crate::config::get_env_paths() {
+            let marker = if path.exists() { "✓" } else { "·" };
+            println!("   {} {}", marker, path.display());
+        }
+        println!();
+
+        let mut checks = Vec::new();
+
+        // Half-configured integrations are failures, suspicious values only warnings
+        for warning in crate::config::validate_config(&self.config) {
+            checks.push(match warning.severity {
+                ConfigWarningSeverity::Incomplete => DoctorCheck::fail(
+                    warning.setting,
+                    warning.message,
+                    "Complete or remove the setting in .env",
+                ),
+                ConfigWarningSeverity::Suspicious => DoctorCheck::warn(
+                    warning.setting,
+                    warning.message,
+                    "Double-check the value in .env",
+                ),
+            });
+        }
+
+        // Proxy used by the Monday.com, JIRA and Gemini clients
+        if let Some(proxy) = self
+            .config
+            .https_proxy
+            .as_deref()
+            .or(self.config.http_proxy.as_deref())
+        {
+            checks.push(if self.config.offline {
+                DoctorCheck::skipped("HTTP proxy")
+            } else {
+                DoctorCheck::ok(
+                    "HTTP proxy",
+                    match &self.config.no_proxy {
+                        Some(no_proxy) => format!(
+                            "{} (NO_PROXY: {})",
+                            crate::services::redact_proxy_url(proxy),
+                            no_proxy
+                        ),
+                        None => crate::services::redact_proxy_url(proxy),
+                    },
+                )
+            });
+        }
+
+        // Git repository
+        checks.push(match GitRepo::new() {
+            Ok(repo) => match repo.get_current_branch() {
+                Ok(branch) => DoctorCheck::ok("Git repository", format!("branch {}", branch)),
+                Err(e) => DoctorCheck::fail(
+                    "Git repository",
+                    e.to_string(),
+                    "Make sure HEAD points to a valid branch",
+                ),
+            },
+            Err(e) => DoctorCheck::fail(
+                "Git repository",
+                e.to_string(),
+                "Run the tool from inside a git repository",
+            ),
+        });
+
+        // Task management system; --offline skips the connection tests
+        if self.config.offline && self.config.is_monday_configured() {
+            checks.push(DoctorCheck::skipped("Monday.com"));
+        } else if self.config.offline && self.config.is_jira_configured() {
+            checks.push(DoctorCheck::skipped("JIRA"));
+        } else if self.config.is_monday_configured() {
+            checks.push(match MondayClient::new(&self.config) {
+                Ok(client) => match client.test_connection().await {
+                    Ok(_) => DoctorCheck::ok("Monday.com", "connection successful"),
+                    Err(e) => DoctorCheck::fail(
+                        "Monday.com",
+                        e.to_string(),
+                        "Check MONDAY_API_KEY and ACCOUNT_SLUG",
+                    ),
+                },
+                Err(e) => DoctorCheck::fail(
+                    "Monday.com",
+                    e.to_string(),
+                    "Check MONDAY_API_KEY and ACCOUNT_SLUG",
+                ),
+            });
+        } else if self.config.is_jira_configured() {
+            checks.push(match JiraClient::new(&self.config) {
+                Ok(client) => match client.test_connection().await {
+                    Ok(_) => DoctorCheck::ok("JIRA", "connection successful"),
+                    Err(e) => DoctorCheck::fail(
+                        "JIRA",
+                        e.to_string(),
+                        "Check JIRA_URL, JIRA_USERNAME and JIRA_API_TOKEN",
+                    ),
+                },
+                Err(e) => DoctorCheck::fail(
+                    "JIRA",
+                    e.to_string(),
+                    "Check JIRA_URL, JIRA_USERNAME and JIRA_API_TOKEN",
+                ),
+            });
+        } else {
+            checks.push(DoctorCheck::warn(
+                "Task management",
+                "neither Monday.com nor JIRA is configured",
+                "Run `semantic-release-tui config` to add credentials",
+            ));
+        }
+
+        // Gemini
+        checks.push(
+            if self.config.gemini_token.is_some() && self.config.offline {
+                DoctorCheck::skipped("Gemini AI")
+            } else if self.config.gemini_token.is_some() {
+                match crate::services::test_gemini_connection(&self.config).await {
+                    Ok(_) => DoctorCheck::ok("Gemini AI", "connection successful"),
+                    Err(e) => DoctorCheck::fail(
+                        "Gemini AI",
+                        e.to_string(),
+                        "Check that GEMINI_TOKEN is valid",
+                    ),
+                }
+            } else {
+                DoctorCheck::warn(
+                    "Gemini AI",
+                    "no token configured",
+                    "Set GEMINI_TOKEN to enable AI analysis",
+                )
+            },
+        );
+
+        // Release notes template
+        let template_path =
+            crate::app::release_notes::resolve_release_notes_template_path(&self.config);
+        checks.push(if template_path.exists() {
+            DoctorCheck::ok(
+                "Release notes template",
+                template_path.display().to_string(),
+            )
+        } else {
+            DoctorCheck::warn(
+                "Release notes template",
+                format!(
+                    "{} not found, using embedded default",
+                    template_path.display()
+                ),
+                "Set RELEASE_NOTES_TEMPLATE or run `semantic-release-tui config`",
+            )
+        });
+
+        // npm, used by the release notes script flow
+        checks.push(
+            match tokio::process::Command::new("npm")
+                .arg("--version")
+                .output()
+                .await
+            {
+                Ok(output) if output.status.success() => DoctorCheck::ok(
+                    "npm",
+                    format!("v{}", String::from_utf8_lossy(&output.stdout).trim()),
+                ),
+                Ok(_) | Err(_) => DoctorCheck::warn(
+                    "npm",
+                    "not available",
+                    "Install Node.js to use the npm release notes flow",
+                ),
+            },
+        );
+
+        for check in &checks {
+            check.print();
+        }
+
+        let failed = checks
+            .iter()
+            .filter(|c| matches!(c.status, DoctorStatus::Fail))
+            .count();
+        let warnings = checks
+            .iter()
+            .filter(|c| matches!(c.status, DoctorStatus::Warn))
+            .count();
+
+        println!();
+        if failed == 0 {
+            println!("✅ All critical checks passed ({} warnings)", warnings);
+        } else {
+            println!(
+                "❌ {} critical checks failed ({} warnings)",
+                failed, warnings
+            );
+        }
+
+        info!(failed, warnings, "Doctor checks completed");
+        Ok(failed == 0)
+    }
+}
+
+enum DoctorStatus {
+    Ok,
+    Warn,
+    Fail,
+    Skipped,
+}
+
+/// Result of a single `doctor` check.
+struct DoctorCheck {
+    name: &'static str,
+    status: DoctorStatus,
+    detail: String,
+    hint: Option<&'static str>,
+}
+
+impl DoctorCheck {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: DoctorStatus::Ok,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: &'static str) -> Self {
+        Self {
+            name,
+            status: DoctorStatus::Warn,
+            detail: detail.into(),
+            hint: Some(hint),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: &'static str) -> Self {
+        Self {
+            name,
+            status: DoctorStatus::Fail,
+            detail: detail.into(),
+            hint: Some(hint),
+        }
+    }
+
+    /// Network check not run because of `--offline`
+    fn skipped(name: &'static str) -> Self {
+        Self {
+            name,
+            status: DoctorStatus::Skipped,
+            detail: "skipped (offline)".to_string(),
+            hint: None,
+        }
+    }
+
+    fn print(&self) {
+        let icon = match self.status {
+            DoctorStatus::Ok => "✅",
+            DoctorStatus::Warn => "⚠️ ",
+            DoctorStatus::Fail => "❌",
+            DoctorStatus::Skipped => "⏭️ ",
+        };
+        println!("{} {}: {}", icon, self.name, self.detail);
+        if let Some(hint) = self.hint {
+            println!("   💡 {}", hint);
+        }
+    }
+}
+
+/// Monday task known only by its ID, as given with `--task`.
+pub(crate) fn monday_task_ref(id: &str) -> MondayTask {
+    MondayTask {
+        id: id.to_string(),
+        title: format!("Task {}", id),
+        board_id: None,
+        board_name: None,
+        url: String::new(),
+        state: "N/A".to_string(),
+        updates: Vec::new(),
+        group_title: None,
+        column_values: Vec::new(),
+    }
+}
+
+/// JIRA issue known only by its key, as given with `--task`.
+pub(crate) fn jira_task_ref(key: &str) -> JiraTask {
+    JiraTask {
+        id: key.to_string(),
+        key: key.to_string(),
+        summary: key.to_string(),
+        description: None,
+        issue_type: String::new(),
+        status: "N/A".to_string(),
+        priority: None,
+        assignee: None,
+        reporter: None,
+        created: None,
+        updated: None,
+        project_key: key.split('-').next().unwrap_or_default().to_string(),
+        project_name: String::new(),
+        components: None,
+        labels: None,
+    }
+}
+
+/// Run named by its prefix, any of its file names, or `latest` (newest valid run).
+fn find_artifact<'a>(
+    artifacts: &'a [crate::app::release_notes::ReleaseNotesArtifact],
+    run: &str,
+) -> Result<&'a crate::app::release_notes::ReleaseNotesArtifact> {
+    let found = if run == "latest" {
+        artifacts.iter().find(|artifact| !artifact.is_failed())
+    } else {
+        let name = std::path::Path::new(run)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(run);
+        artifacts.iter().find(|artifact| {
+            artifact.prefix == name
+                || artifact
+                    .files()
+                    .iter()
+                    .any(|file| file.file_name().and_then(|f| f.to_str()) == Some(name))
+        })
+    };
+    found.ok_or_else(|| {
+        SemanticReleaseError::validation_error(format!(
+            "No release notes run '{}' (see `artifacts list`)",
+            run
+        ))
+    })
+}
+
+fn delete_artifact(artifact: &crate::app::release_notes::ReleaseNotesArtifact) -> Result<()> {
+    for file in artifact.files() {
+        std::fs::remove_file(file)?;
+        info!(file = %file.display(), "Deleted release notes artifact");
+    }
+    Ok(())
+}
+
+fn open_with_system_viewer(path: &std::path::Path) -> Result<()> {
+    crate::utils::open_externally(path.as_os_str()).map_err(|e| {
+        error!(error = %e, path = %path.display(), "Could not open release notes");
+        SemanticReleaseError::config_error(format!("Could not open {}: {}", path.display(), e))
+    })
+}
+
+/// `1.2 KB`-style size for listings.
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1_048_576 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+/// Shorten `text` to at most `max` characters, marking the cut with `…`.
+fn truncate_chars(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
 }
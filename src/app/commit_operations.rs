@@ -1,12 +1,20 @@
-use crate::error::Result;
-use tracing::{debug, error, info, instrument};
+use crate::error::{Result, SemanticReleaseError};
+use tracing::{debug, error, info, instrument, warn};
 
-use crate::{app::App, git::repository::GitRepo};
+use crate::{
+    app::{background_operations::BackgroundEvent, App},
+    git::repository::GitRepo,
+    services::MondayClient,
+    types::{AppConfig, TaskSystem},
+    utils::{normalize_line_endings, strip_template_placeholders},
+};
 
 #[allow(async_fn_in_trait)]
 pub trait CommitOperations {
     fn build_commit_message(&self) -> String;
-    async fn create_commit_with_message(&self, message: &str) -> Result<()>;
+    fn validate_commit_form(&self) -> Result<()>;
+    async fn create_commit_with_message(&self, message: &str) -> Result<String>;
+    fn linked_task_ids(&self) -> Vec<String>;
 }
 
 impl CommitOperations for App {
@@ -26,9 +34,22 @@ impl CommitOperations for App {
                 message.push_str("(N/A)");
             }
 
+            // Mark the header too, as the BREAKING CHANGE footer below
+            if !self.commit_form.breaking_change.is_empty() {
+                message.push('!');
+            }
+
             message.push_str(": ");
         }
 
+        // Gitmoji goes after the type so the header stays a conventional commit
+        if self.config.use_gitmoji {
+            if let Some(commit_type) = &self.commit_form.commit_type {
+                message.push_str(self.config.gitmoji(commit_type));
+                message.push(' ');
+            }
+        }
+
         // Title
         if !self.commit_form.title.is_empty() {
             message.push_str(&self.commit_form.title);
@@ -36,10 +57,11 @@ impl CommitOperations for App {
             message.push_str("N/A");
         }
 
-        // Body/Description
+        // Body/Description, without the template placeholders left unfilled
         message.push_str("\n\n");
-        if !self.commit_form.description.is_empty() {
-            message.push_str(&self.commit_form.description);
+        let description = strip_template_placeholders(&self.commit_form.description);
+        if !description.is_empty() {
+            message.push_str(&description);
         } else {
             message.push_str("N/A");
         }
@@ -117,6 +139,9 @@ impl CommitOperations for App {
             }
         }
 
+        // Form fields may hold text pasted with CRLF line endings
+        let message = normalize_line_endings(&message);
+
         debug!(
             message_len = message.len(),
             "Commit message built successfully"
@@ -124,8 +149,26 @@ impl CommitOperations for App {
         message
     }
 
+    /// Create the commit and announce it with `BackgroundEvent::CommitCreated`.
+    /// Returns the hash of the new commit.
     #[instrument(skip(self), fields(message_len = message.len()))]
-    async fn create_commit_with_message(&self, message: &str) -> Result<()> {
+    /// A commit needs at least a type and a title; `build_commit_message`
+    /// fills anything missing with `N/A`.
+    fn validate_commit_form(&self) -> Result<()> {
+        if self.commit_form.commit_type.is_none() {
+            return Err(SemanticReleaseError::validation_error(
+                self.config.tr("commit.missing_type"),
+            ));
+        }
+        if self.commit_form.title.trim().is_empty() {
+            return Err(SemanticReleaseError::validation_error(
+                self.config.tr("commit.missing_title"),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn create_commit_with_message(&self, message: &str) -> Result<String> {
         info!("Creating commit with custom message");
         debug!("Initializing git repository...");
 
@@ -141,12 +184,282 @@ impl CommitOperations for App {
 
         // Create the commit
         debug!("Creating git commit...");
-        git_repo.create_commit(message).map_err(|e| {
-            error!(error = %e, "Failed to create commit");
-            crate::error::SemanticReleaseError::git_error(e)
-        })?;
+        let hash = git_repo
+            .create_commit(message, self.config.no_verify)
+            .map_err(|e| {
+                error!(error = %e, "Failed to create commit");
+                crate::error::SemanticReleaseError::git_error(e)
+            })?;
+        info!(%hash, "Commit created successfully");
 
-        info!("Commit created successfully");
-        Ok(())
+        // Post-commit side effects (task updates, auditing) subscribe to this event
+        self.background_task_manager
+            .emit(BackgroundEvent::CommitCreated {
+                hash: hash.clone(),
+                message: message.to_string(),
+                tasks: self.linked_task_ids(),
+            })
+            .await;
+        Ok(hash)
+    }
+
+    /// IDs (Monday.com) or keys (JIRA) of the tasks linked to the commit being built.
+    fn linked_task_ids(&self) -> Vec<String> {
+        match self.config.get_task_system() {
+            TaskSystem::Monday => self
+                .commit_form
+                .selected_monday_tasks
+                .iter()
+                .map(|task| task.id.clone())
+                .collect(),
+            TaskSystem::Jira => self
+                .commit_form
+                .selected_jira_tasks
+                .iter()
+                .map(|task| task.key.clone())
+                .collect(),
+            TaskSystem::None => Vec::new(),
+        }
+    }
+}
+
+/// With MONDAY_POST_COMMIT_UPDATE, leave an update with the commit's hash,
+/// title and link on every linked Monday.com task. The commit already exists,
+/// so failures are only logged.
+#[instrument(skip(config, message))]
+pub async fn post_monday_commit_updates(
+    config: &AppConfig,
+    hash: &str,
+    message: &str,
+    task_ids: &[String],
+) {
+    if !config.monday_post_commit_update
+        || config.offline
+        || config.get_task_system() != TaskSystem::Monday
+        || task_ids.is_empty()
+    {
+        return;
+    }
+
+    let client = match MondayClient::new(config) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!(error = %e, "Monday.com client unavailable, skipping commit updates");
+            return;
+        }
+    };
+
+    let title = message.lines().next().unwrap_or_default();
+    let mut body = format!("Commit {}: {}", &hash[..hash.len().min(8)], title);
+    if let Some(remote) = GitRepo::new()
+        .ok()
+        .and_then(|repo| repo.get_remote_repo(config.git_host()))
+    {
+        body.push_str(&format!("\n{}", remote.commit_url(hash)));
+    }
+
+    for task_id in task_ids {
+        if let Err(e) = client.create_update(task_id, &body).await {
+            warn!(%task_id, error = %e, "Failed to post commit update on Monday.com task");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::cli_operations::{jira_task_ref, monday_task_ref};
+    use crate::types::{CommitType, JiraTask, MondayTask};
+
+    /// App whose commit form has `commit_type` and `title`, the rest left empty
+    fn commit_app(config: AppConfig, commit_type: Option<CommitType>, title: &str) -> App {
+        let mut app = App::from_config(config);
+        app.commit_form.commit_type = commit_type;
+        app.commit_form.title = title.to_string();
+        app
+    }
+
+    fn monday_config() -> AppConfig {
+        AppConfig {
+            monday_api_key: Some("key".to_string()),
+            monday_account_slug: Some("teimas".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn jira_config() -> AppConfig {
+        AppConfig {
+            jira_url: Some("https://jira.example.com".to_string()),
+            jira_username: Some("dev".to_string()),
+            jira_api_token: Some("token".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_type_and_title_only() {
+        let app = commit_app(
+            AppConfig::default(),
+            Some(CommitType::Fix),
+            "handle empty board",
+        );
+
+        assert!(app.validate_commit_form().is_ok());
+        assert_eq!(
+            app.build_commit_message(),
+            "fix(N/A): handle empty board\n\n\
+             N/A\n\n\
+             Test Details: N/A\n\n\
+             Security: N/A\n\n\
+             Migraciones Lentas: N/A\n\n\
+             Partes a Ejecutar: N/A\n\n\
+             RELATED TASKS: N/A"
+        );
+    }
+
+    #[test]
+    fn test_scope_made_of_task_ids() {
+        let mut app = commit_app(
+            AppConfig::default(),
+            Some(CommitType::Feat),
+            "add release notes export",
+        );
+        app.commit_form.scope = "12345|67890".to_string();
+
+        let message = app.build_commit_message();
+
+        assert!(message.starts_with("feat(12345|67890): add release notes export\n\n"));
+    }
+
+    #[test]
+    fn test_unfilled_template_placeholders_are_dropped() {
+        let mut app = commit_app(
+            AppConfig::default(),
+            Some(CommitType::Fix),
+            "handle empty board",
+        );
+        app.commit_form.description =
+            "Problema: the board crashed when empty\nCausa: {{why}}\nSolución: {{what changed}}"
+                .to_string();
+
+        let message = app.build_commit_message();
+
+        assert!(message.contains("\n\nProblema: the board crashed when empty\n\nTest Details:"));
+    }
+
+    #[test]
+    fn test_all_trailers() {
+        let mut app = commit_app(
+            AppConfig::default(),
+            Some(CommitType::Refactor),
+            "split client",
+        );
+        app.commit_form.scope = "api".to_string();
+        app.commit_form.description = "Move HTTP setup out of the clients.".to_string();
+        app.commit_form.breaking_change = "MondayClient::new takes a config".to_string();
+        app.commit_form.test_details = "Ran doctor against staging".to_string();
+        app.commit_form.security = "No credentials are logged".to_string();
+        app.commit_form.migraciones_lentas = "Ninguna".to_string();
+        app.commit_form.partes_a_ejecutar = "Reiniciar el worker".to_string();
+
+        assert_eq!(
+            app.build_commit_message(),
+            "refactor(api)!: split client\n\n\
+             Move HTTP setup out of the clients.\n\n\
+             BREAKING CHANGE: MondayClient::new takes a config\n\n\
+             Test Details: Ran doctor against staging\n\n\
+             Security: No credentials are logged\n\n\
+             Migraciones Lentas: Ninguna\n\n\
+             Partes a Ejecutar: Reiniciar el worker\n\n\
+             RELATED TASKS: N/A"
+        );
+    }
+
+    #[test]
+    fn test_multiple_monday_tasks() {
+        let mut app = commit_app(monday_config(), Some(CommitType::Feat), "sync boards");
+        app.commit_form.selected_monday_tasks = vec![
+            MondayTask {
+                title: "Board sync".to_string(),
+                state: "active".to_string(),
+                ..monday_task_ref("111")
+            },
+            MondayTask {
+                title: "Webhook retries".to_string(),
+                state: "done".to_string(),
+                ..monday_task_ref("222")
+            },
+        ];
+
+        let message = app.build_commit_message();
+
+        assert!(message.ends_with(
+            "Partes a Ejecutar: N/A\n\n\
+             MONDAY TASKS: \n\
+             - Board sync (ID: 111) - active\n\
+             - Webhook retries (ID: 222) - done\n"
+        ));
+    }
+
+    #[test]
+    fn test_monday_configured_without_tasks() {
+        let app = commit_app(monday_config(), Some(CommitType::Chore), "bump deps");
+
+        assert!(app
+            .build_commit_message()
+            .ends_with("\n\nMONDAY TASKS: N/A"));
+    }
+
+    #[test]
+    fn test_jira_tasks() {
+        let mut app = commit_app(jira_config(), Some(CommitType::Fix), "retry login");
+        app.commit_form.selected_jira_tasks = vec![JiraTask {
+            summary: "Login fails".to_string(),
+            status: "Open".to_string(),
+            ..jira_task_ref("PROJ-7")
+        }];
+
+        assert!(app
+            .build_commit_message()
+            .ends_with("\n\nJIRA TASKS: \n- Login fails (Key: PROJ-7) - Open\n"));
+    }
+
+    #[test]
+    fn test_empty_title_is_rejected() {
+        let app = commit_app(AppConfig::default(), Some(CommitType::Docs), "  ");
+
+        assert!(app.validate_commit_form().is_err());
+    }
+
+    #[test]
+    fn test_missing_type_is_rejected() {
+        let app = commit_app(AppConfig::default(), None, "update readme");
+
+        assert!(app.validate_commit_form().is_err());
+    }
+
+    #[test]
+    fn test_gitmoji_goes_after_the_type() {
+        let config = AppConfig {
+            use_gitmoji: true,
+            ..Default::default()
+        };
+        let mut app = commit_app(config, Some(CommitType::Feat), "add history");
+        app.commit_form.scope = "ui".to_string();
+
+        assert!(app
+            .build_commit_message()
+            .starts_with("feat(ui): ✨ add history\n\n"));
+    }
+
+    #[test]
+    fn test_crlf_in_fields_is_normalized() {
+        let mut app = commit_app(AppConfig::default(), Some(CommitType::Fix), "windows paste");
+        app.commit_form.description = "line one\r\nline two".to_string();
+
+        let message = app.build_commit_message();
+
+        assert!(!message.contains('\r'));
+        assert!(message.contains("\n\nline one\nline two\n\n"));
     }
 }
@@ -1,11 +1,27 @@
 use crate::error::Result;
 use crossterm::event::{KeyCode, KeyEvent};
+use tracing::warn;
+
+/// Commits listed on the history screen
+const HISTORY_LIMIT: usize = 50;
+
+/// Lines moved by PgUp/PgDn in the npm output view
+const SCRIPT_OUTPUT_PAGE: usize = 10;
+
+/// Rows moved by PgUp/PgDn in the staged diff view
+const DIFF_PAGE: isize = 20;
+
+/// How long a successful Monday.com healthcheck is trusted before the next search
+const MONDAY_HEALTHCHECK_TTL: std::time::Duration = std::time::Duration::from_secs(300);
 
 use crate::{
     app::semantic_release_operations::SemanticReleaseOperations,
-    app::App,
-    types::{AppScreen, AppState, CommitType},
-    ui::{CommitField, InputMode},
+    app::{app::ExternalEditTarget, App},
+    config::{save_config_keeping_storage, validate_config},
+    types::{AppScreen, AppState, CommitType, ConfigWarningSeverity, TaskSystem},
+    ui::{
+        keybindings::Action, CommitField, ConfigEditor, InputMode, StageSelector, StagedDiffView,
+    },
 };
 
 #[allow(async_fn_in_trait)]
@@ -21,11 +37,86 @@ impl EventHandlers for App {
             return Ok(());
         }
 
+        // Full help of the current screen, closed by Esc or its help/back keys
+        if self.ui_state.show_help {
+            if key.code == KeyCode::Esc
+                || matches!(
+                    self.ui_state.keymap.action_for(&self.current_screen, key),
+                    Some(Action::Help | Action::Back)
+                )
+            {
+                self.ui_state.show_help = false;
+            }
+            return Ok(());
+        }
+        // Raw result of the last AI analysis, closed by Esc or its own key
+        if self.ui_state.show_analysis {
+            if key.code == KeyCode::Esc
+                || matches!(
+                    self.ui_state.keymap.action_for(&self.current_screen, key),
+                    Some(Action::ShowAnalysis | Action::Back)
+                )
+            {
+                self.ui_state.show_analysis = false;
+            }
+            return Ok(());
+        }
+        // Changed files being picked for staging
+        if self.ui_state.stage_selector.is_some() {
+            self.handle_stage_selector(key.code);
+            return Ok(());
+        }
+        if self.ui_state.input_mode == InputMode::Normal
+            && !self.ui_state.editing_selected_tasks_filter
+            && !self.ui_state.config_editor.is_editing()
+            && matches!(self.current_state, AppState::Normal)
+            && self.ui_state.keymap.action_for(&self.current_screen, key) == Some(Action::Help)
+        {
+            self.ui_state.show_help = true;
+            return Ok(());
+        }
+
+        // Dev-mode render stats overlay
+        if key.code == KeyCode::F(12) && self.ui_state.render_stats.is_some() {
+            self.ui_state.show_render_stats = !self.ui_state.show_render_stats;
+            return Ok(());
+        }
+
+        // Esc while a background operation runs requests its cancellation
+        if matches!(self.current_state, AppState::Loading)
+            && key.code == KeyCode::Esc
+            && self.background_task_manager.has_active_operations().await
+        {
+            self.background_task_manager.cancel_all().await;
+            self.current_state = AppState::Normal;
+            self.ui_state.loading_progress = None;
+            self.message = Some(self.config.tr("operation.cancelled").to_string());
+            return Ok(());
+        }
+
         // Handle confirmation dialog for staging all files
         if matches!(self.current_state, AppState::ConfirmingStageAll) {
             return self.handle_stage_confirmation(key.code).await;
         }
 
+        if matches!(self.current_state, AppState::ConfirmingMergeCommit) {
+            return self.handle_merge_commit_confirmation(key.code).await;
+        }
+
+        if matches!(self.current_state, AppState::ConfirmingSecrets) {
+            return self.handle_secrets_confirmation(key.code).await;
+        }
+
+        if matches!(self.current_state, AppState::MondayAuthFailed) {
+            self.current_state = AppState::Normal;
+            if key.code == KeyCode::Char('c') {
+                self.open_config_screen();
+                self.ui_state.input_mode = InputMode::Normal;
+                self.message = Some("Actualiza MONDAY_API_KEY y reinicia la sesión".to_string());
+            }
+            return Ok(());
+        }
+
         match (&self.current_screen, &self.ui_state.input_mode) {
             (_, InputMode::Editing) => {
                 self.handle_input_mode(key).await?;
@@ -51,6 +142,12 @@ impl EventHandlers for App {
             (AppScreen::TaskSearch, _) => {
                 self.handle_task_search_screen(key.code).await?;
             }
+            (AppScreen::History, _) => {
+                self.handle_history_screen(key.code);
+            }
+            (AppScreen::StagedDiff, _) => {
+                self.handle_staged_diff_screen(key.code);
+            }
         }
 
         Ok(())
@@ -58,6 +155,48 @@ impl EventHandlers for App {
 }
 
 impl App {
+    /// Web page of a JIRA issue, built from `JIRA_URL`
+    pub(crate) fn jira_issue_url(&self, key: &str) -> Option<String> {
+        let base = self.config.jira_url.as_deref()?;
+        Some(format!("{}/browse/{}", base.trim_end_matches('/'), key))
+    }
+
+    /// Open a task page in the browser. Without one (e.g. over SSH) the URL is
+    /// shown in the status bar so it can be copied.
+    pub(crate) fn open_task_url(&mut self, url: Option<String>) {
+        let Some(url) = url.filter(|url| !url.is_empty()) else {
+            self.message = Some("La tarea no tiene URL".to_string());
+            return;
+        };
+        self.message = Some(
+            match crate::utils::open_externally(std::ffi::OsStr::new(&url)) {
+                Ok(()) => format!("🌐 Abriendo {}", url),
+                Err(e) => {
+                    warn!(error = %e, %url, "Could not open task URL");
+                    format!("🔗 {} (no se pudo abrir el navegador: {})", url, e)
+                }
+            },
+        );
+    }
+
+    /// URL of the focused task in the selected tasks list
+    fn focused_selected_task_url(&self) -> Option<String> {
+        let index = *self
+            .filtered_selected_task_indices()
+            .get(self.ui_state.selected_tab)?;
+        match self.config.get_task_system() {
+            crate::types::TaskSystem::Monday => self
+                .selected_monday_tasks
+                .get(index)
+                .map(|task| task.url.clone()),
+            crate::types::TaskSystem::Jira => self
+                .selected_jira_tasks
+                .get(index)
+                .and_then(|task| self.jira_issue_url(&task.key)),
+            crate::types::TaskSystem::None => None,
+        }
+    }
+
     // Helper methods to work with the appropriate task collections based on configuration
     fn get_selected_tasks_count(&self) -> usize {
         match self.config.get_task_system() {
@@ -67,6 +206,38 @@ impl App {
         }
     }
 
+    /// Indices into the current selected tasks list that pass the live filter.
+    /// `selected_tab` indexes into this list while the selected tasks are focused.
+    fn filtered_selected_task_indices(&self) -> Vec<usize> {
+        match self.config.get_task_system() {
+            crate::types::TaskSystem::Monday => self
+                .selected_monday_tasks
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| {
+                    self.ui_state
+                        .matches_selected_tasks_filter(&[&t.id, &t.title])
+                })
+                .map(|(i, _)| i)
+                .collect(),
+            crate::types::TaskSystem::Jira => self
+                .selected_jira_tasks
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| {
+                    self.ui_state
+                        .matches_selected_tasks_filter(&[&t.key, &t.id, &t.summary])
+                })
+                .map(|(i, _)| i)
+                .collect(),
+            crate::types::TaskSystem::None => Vec::new(),
+        }
+    }
+
+    fn get_filtered_selected_tasks_count(&self) -> usize {
+        self.filtered_selected_task_indices().len()
+    }
+
     fn remove_selected_task_by_index(&mut self, index: usize) {
         match self.config.get_task_system() {
             crate::types::TaskSystem::Monday => {
@@ -84,27 +255,32 @@ impl App {
     }
 
     async fn handle_main_screen(&mut self, key: KeyCode) -> Result<()> {
-        match key {
-            KeyCode::Char('q') => {
+        match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::Main, key.into())
+        {
+            Some(Action::Quit) => {
                 self.should_quit = true;
             }
-            KeyCode::Tab => {
-                self.ui_state.selected_tab = (self.ui_state.selected_tab + 1) % 5;
+            Some(Action::NextTab) => {
+                self.ui_state.selected_tab = (self.ui_state.selected_tab + 1) % 6;
             }
-            KeyCode::BackTab => {
+            Some(Action::PrevTab) => {
                 self.ui_state.selected_tab = if self.ui_state.selected_tab == 0 {
-                    4
+                    5
                 } else {
                     self.ui_state.selected_tab - 1
                 };
             }
-            KeyCode::Enter => {
+            Some(Action::Select) => {
                 match self.ui_state.selected_tab {
                     0 => self.current_screen = AppScreen::Commit,
                     1 => self.current_screen = AppScreen::ReleaseNotes,
                     2 => self.current_screen = AppScreen::SemanticRelease,
-                    3 => self.current_screen = AppScreen::Config,
-                    4 => {} // Help - stay here
+                    3 => self.open_config_screen(),
+                    4 => self.open_history(),
+                    5 => {} // Help - stay here
                     _ => {}
                 }
             }
@@ -113,9 +289,146 @@ impl App {
         Ok(())
     }
 
+    fn open_history(&mut self) {
+        self.current_screen = AppScreen::History;
+        self.ui_state.history_selected = 0;
+        self.ui_state.history_show_message = false;
+        self.load_recent_commits();
+    }
+
+    pub fn load_recent_commits(&mut self) {
+        match crate::git::GitRepo::new().and_then(|repo| repo.get_recent_commits(HISTORY_LIMIT)) {
+            Ok(commits) => {
+                self.message = Some(format!("Loaded {} recent commits", commits.len()));
+                self.recent_commits = commits;
+            }
+            Err(e) => {
+                self.recent_commits.clear();
+                self.current_state = AppState::Error(format!("Could not read history: {}", e));
+            }
+        }
+        let last = self.recent_commits.len().saturating_sub(1);
+        self.ui_state.history_selected = self.ui_state.history_selected.min(last);
+    }
+
+    fn handle_history_screen(&mut self, key: KeyCode) {
+        match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::History, key.into())
+        {
+            Some(Action::Back) => {
+                self.current_screen = AppScreen::Main;
+            }
+            Some(Action::Up) if self.ui_state.history_selected > 0 => {
+                self.ui_state.history_selected -= 1;
+            }
+            Some(Action::Down)
+                if self.ui_state.history_selected + 1 < self.recent_commits.len() =>
+            {
+                self.ui_state.history_selected += 1;
+            }
+            Some(Action::ToggleDetails) => {
+                self.ui_state.history_show_message = !self.ui_state.history_show_message;
+            }
+            Some(Action::Reload) => {
+                self.load_recent_commits();
+            }
+            Some(Action::Amend) => {
+                self.start_amend_from_history();
+            }
+            _ => {}
+        }
+    }
+
+    /// Open the commit preview with the selected commit's message to amend it.
+    /// Only HEAD can be amended without rewriting later history.
+    fn start_amend_from_history(&mut self) {
+        let Some(commit) = self.recent_commits.get(self.ui_state.history_selected) else {
+            return;
+        };
+        if self.ui_state.history_selected != 0 {
+            self.message = Some(
+                "Only the latest commit (HEAD) can be amended. Select the first entry.".to_string(),
+            );
+            return;
+        }
+
+        let mut message = commit.subject.clone();
+        if !commit.body.trim().is_empty() {
+            message.push_str("\n\n");
+            message.push_str(&commit.body);
+        }
+
+        self.amending_commit = true;
+        self.preview_commit_message = message;
+        self.ui_state.repo_state = crate::git::GitRepo::new()
+            .map(|repo| repo.repo_state())
+            .unwrap_or_default();
+        self.current_screen = AppScreen::CommitPreview;
+        self.ui_state.input_mode = InputMode::Editing;
+        self.ui_state.commit_preview_textarea.select_all();
+        self.ui_state.commit_preview_textarea.delete_str(
+            self.ui_state
+                .commit_preview_textarea
+                .lines()
+                .join("\n")
+                .len(),
+        );
+        self.ui_state
+            .commit_preview_textarea
+            .insert_str(&self.preview_commit_message);
+        self.message = Some(
+            "Amending HEAD: edit the message, Ctrl+C to amend (staged changes are included), Esc to cancel"
+                .to_string(),
+        );
+    }
+
+    /// Show the configuration screen with a fresh copy of the settings.
+    pub(crate) fn open_config_screen(&mut self) {
+        self.ui_state.config_editor = ConfigEditor::new(&self.config);
+        self.current_screen = AppScreen::Config;
+    }
+
     async fn handle_config_screen(&mut self, key: KeyCode) -> Result<()> {
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
+        if self.ui_state.config_editor.is_editing() {
+            let editor = &mut self.ui_state.config_editor;
+            match key {
+                KeyCode::Enter => editor.finish_editing(),
+                KeyCode::Esc => editor.cancel_editing(),
+                KeyCode::Backspace => {
+                    if let Some(input) = editor.input.as_mut() {
+                        input.pop();
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some(input) = editor.input.as_mut() {
+                        input.push(c);
+                    }
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        let action = self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::Config, key.into());
+        let editor = &mut self.ui_state.config_editor;
+        if action != Some(Action::Back) {
+            editor.confirm_discard = false;
+        }
+        match action {
+            Some(Action::Up) => editor.up(),
+            Some(Action::Down) => editor.down(),
+            Some(Action::Select) => editor.activate(),
+            Some(Action::Save) => self.save_config_screen(),
+            Some(Action::Back) if editor.dirty && !editor.confirm_discard => {
+                editor.confirm_discard = true;
+                self.message = Some(self.config.tr("config.unsaved").to_string());
+            }
+            Some(Action::Back) => {
                 self.current_screen = AppScreen::Main;
             }
             _ => {}
@@ -123,15 +436,81 @@ impl App {
         Ok(())
     }
 
+    /// Validate the edited settings and write them to the active profile's file.
+    fn save_config_screen(&mut self) {
+        let editor = &self.ui_state.config_editor;
+        let config = editor.to_config();
+
+        let mut problems: Vec<String> = validate_config(&config)
+            .into_iter()
+            .filter(|warning| warning.severity == ConfigWarningSeverity::Incomplete)
+            .map(|warning| format!("{}: {}", warning.setting, warning.message))
+            .collect();
+        if editor.task_system != TaskSystem::None && config.get_task_system() != editor.task_system
+        {
+            problems.push(self.config.tr("config.missing_credentials").to_string());
+        }
+        if !problems.is_empty() {
+            self.message = Some(format!(
+                "❌ {}: {}",
+                self.config.tr("config.not_saved"),
+                problems.join("; ")
+            ));
+            return;
+        }
+
+        match save_config_keeping_storage(&config, &editor.edited) {
+            Ok(()) => {
+                self.config = config;
+                // New credentials get checked again on the next search
+                self.monday_connection_checked_at = None;
+                self.ui_state.config_editor.draft = self.config.clone();
+                self.ui_state.config_editor.dirty = false;
+                self.ui_state.config_editor.edited.clear();
+                self.message = Some(self.config.tr("config.saved").to_string());
+            }
+            Err(e) => {
+                self.current_state =
+                    AppState::Error(format!("{}: {}", self.config.tr("config.save_error"), e));
+            }
+        }
+    }
+
     async fn handle_commit_screen(&mut self, key: KeyCode) -> Result<()> {
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
+        if self.ui_state.editing_selected_tasks_filter {
+            self.handle_selected_tasks_filter_input(key);
+            return Ok(());
+        }
+
+        match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::Commit, key.into())
+        {
+            Some(Action::Back)
+                if key == KeyCode::Esc && !self.ui_state.selected_tasks_filter.is_empty() =>
+            {
+                self.ui_state.selected_tasks_filter.clear();
+                self.ui_state.selected_tab = 0;
+                self.message = Some("Task filter cleared".to_string());
+            }
+            Some(Action::Back) => {
                 self.current_screen = AppScreen::Main;
             }
-            KeyCode::Char('s') => {
+            Some(Action::Filter)
+                if self.ui_state.task_management_mode
+                    || self.ui_state.current_field == CommitField::SelectedTasks =>
+            {
+                self.ui_state.editing_selected_tasks_filter = true;
+                self.message = Some(
+                    "Type to filter selected tasks by ID or title. Enter to keep, Esc to clear"
+                        .to_string(),
+                );
+            }
+            Some(Action::SearchMonday) => {
                 match self.config.get_task_system() {
                     crate::types::TaskSystem::Monday => {
-                        self.handle_monday_search();
+                        self.handle_monday_search().await;
                     }
                     crate::types::TaskSystem::Jira => {
                         self.message =
@@ -142,7 +521,7 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('j') => {
+            Some(Action::SearchJira) => {
                 match self.config.get_task_system() {
                     crate::types::TaskSystem::Jira => {
                         self.handle_jira_search();
@@ -158,55 +537,97 @@ impl App {
                     }
                 }
             }
-            KeyCode::Char('c') => {
+            Some(Action::PreviewCommit) => {
                 self.handle_commit_preview();
             }
-            KeyCode::Char('t') => {
-                if !matches!(self.current_state, AppState::Loading) {
-                    use crate::app::background_operations::ComprehensiveAnalysisOperations;
-                    if let Err(e) = self.handle_comprehensive_analysis().await {
-                        self.current_state = AppState::Error(format!("Error: {}", e));
-                    }
+            // Only allow if not already processing
+            Some(action @ (Action::Analyze | Action::AnalyzeFull))
+                if matches!(self.current_state, AppState::Normal) =>
+            {
+                use crate::app::background_operations::ComprehensiveAnalysisOperations;
+                let full = action == Action::AnalyzeFull;
+                if let Err(e) = self.handle_comprehensive_analysis(full).await {
+                    self.current_state = AppState::Error(format!("Error: {}", e));
+                }
+            }
+            Some(action @ (Action::AnalyzeSecurity | Action::AnalyzeBreaking))
+                if matches!(self.current_state, AppState::Normal) =>
+            {
+                use crate::app::background_operations::ComprehensiveAnalysisOperations;
+                let field = if action == Action::AnalyzeSecurity {
+                    CommitField::Security
+                } else {
+                    CommitField::BreakingChange
+                };
+                if let Err(e) = self.handle_field_analysis(field).await {
+                    self.current_state = AppState::Error(format!("Error: {}", e));
                 }
             }
-            KeyCode::Char('m') => {
+            Some(Action::ToggleTaskManagement) => {
                 self.handle_task_management_toggle();
             }
-            KeyCode::Tab => {
+            Some(Action::NextTab) => {
                 self.handle_tab_navigation();
             }
-            KeyCode::BackTab => {
+            Some(Action::PrevTab) => {
                 self.handle_back_tab_navigation();
             }
-            KeyCode::Up => {
+            Some(Action::Up) => {
                 self.handle_up_navigation();
             }
-            KeyCode::Down => {
+            Some(Action::Down) => {
                 self.handle_down_navigation();
             }
-            KeyCode::Enter => {
+            Some(Action::Select) => {
                 self.handle_enter_in_commit();
             }
-            KeyCode::Delete | KeyCode::Char(' ') => {
+            Some(Action::RemoveTask) => {
                 self.handle_task_deletion();
             }
-            KeyCode::Char('g') => {
-                // Only allow if not already processing
-                if matches!(self.current_state, AppState::Normal) {
-                    use crate::app::background_operations::ComprehensiveAnalysisOperations;
-                    if let Err(e) = self.handle_comprehensive_analysis().await {
-                        self.current_state = AppState::Error(format!("Error: {}", e));
-                    }
+            Some(Action::OpenTaskUrl)
+                if self.ui_state.task_management_mode
+                    || self.ui_state.current_field == CommitField::SelectedTasks =>
+            {
+                let url = self.focused_selected_task_url();
+                self.open_task_url(url);
+            }
+            Some(Action::RevertAnalysis) => {
+                let key = if self.revert_commit_analysis() {
+                    "analysis.reverted"
+                } else {
+                    "analysis.nothing_to_revert"
+                };
+                self.message = Some(self.config.tr(key).to_string());
+            }
+            Some(Action::ShowAnalysis) => {
+                if self.ui_state.last_analysis.is_some() {
+                    self.ui_state.show_analysis = true;
+                } else {
+                    self.message = Some(self.config.tr("analysis.nothing_to_show").to_string());
                 }
             }
+            Some(Action::StageFiles) => {
+                self.open_stage_selector();
+            }
+            Some(Action::ShowDiff) => {
+                self.open_staged_diff();
+            }
+            Some(Action::ExternalEditor) => {
+                let field = self.ui_state.current_field.clone();
+                self.request_external_edit(ExternalEditTarget::Field(field));
+            }
             _ => {}
         }
         Ok(())
     }
 
     async fn handle_commit_preview_screen(&mut self, key: KeyCode) -> Result<()> {
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
+        match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::CommitPreview, key.into())
+        {
+            Some(Action::Back) => {
                 // Cancel commit and go back to commit screen
                 self.current_screen = AppScreen::Commit;
                 self.ui_state.input_mode = InputMode::Normal;
@@ -224,60 +645,129 @@ impl App {
     async fn handle_release_notes_screen(&mut self, key: KeyCode) -> Result<()> {
         use crate::app::release_notes::ReleaseNotesOperations;
 
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
+        match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::ReleaseNotes, key.into())
+        {
+            Some(Action::Back) => {
                 self.current_screen = AppScreen::Main;
             }
-            KeyCode::Enter => {
+            Some(Action::Select) => {
                 self.handle_release_notes_generation().await?;
             }
-            KeyCode::Char('o') => {
+            Some(Action::RunNpmScript) => {
                 self.generate_release_notes_with_npm_wrapper().await?;
             }
+            Some(Action::TogglePathFilter) => {
+                self.toggle_release_notes_path_filter();
+            }
+            Some(Action::RetryGemini) => {
+                self.retry_release_notes_gemini().await?;
+            }
+            Some(Action::Up) => self.scroll_script_output(1),
+            Some(Action::Down) => {
+                self.ui_state.script_output_scroll =
+                    self.ui_state.script_output_scroll.saturating_sub(1)
+            }
+            Some(Action::PageUp) => self.scroll_script_output(SCRIPT_OUTPUT_PAGE),
+            Some(Action::PageDown) => {
+                self.ui_state.script_output_scroll = self
+                    .ui_state
+                    .script_output_scroll
+                    .saturating_sub(SCRIPT_OUTPUT_PAGE)
+            }
+            Some(Action::End) => self.ui_state.script_output_scroll = 0,
             _ => {}
         }
         Ok(())
     }
 
+    /// Scroll the npm output view up, stopping at its first line.
+    fn scroll_script_output(&mut self, lines: usize) {
+        let max_scroll = self.ui_state.script_output.len().saturating_sub(1);
+        self.ui_state.script_output_scroll =
+            (self.ui_state.script_output_scroll + lines).min(max_scroll);
+    }
+
+    fn toggle_release_notes_path_filter(&mut self) {
+        if self.ui_state.release_notes_path_filter.take().is_some() {
+            self.message = Some("📦 Filtro por paquete desactivado".to_string());
+            return;
+        }
+
+        match crate::git::GitRepo::new().map(|repo| repo.current_package_path()) {
+            Ok(Some(path)) => {
+                self.message = Some(format!("📦 Filtrando commits por paquete: {}", path));
+                self.ui_state.release_notes_path_filter = Some(path);
+            }
+            Ok(None) => {
+                self.message =
+                    Some("⚠️ El directorio actual es la raíz del repositorio".to_string());
+            }
+            Err(e) => {
+                self.message = Some(format!("❌ {}", e));
+            }
+        }
+    }
+
     async fn handle_semantic_release_screen(&mut self, key: KeyCode) -> Result<()> {
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
+        match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::SemanticRelease, key.into())
+        {
+            Some(Action::Back) => {
                 self.current_screen = AppScreen::Main;
             }
-            KeyCode::Char('r') => {
+            Some(Action::ClearResults) => {
                 // Clear results and go back to normal view
                 self.semantic_release_state = None;
                 self.ui_state.scroll_offset = 0;
                 self.message = Some("Results cleared".to_string());
             }
-            KeyCode::Up => {
+            Some(Action::Up) => {
                 // Scroll up in results if we have results
                 if self.semantic_release_state.is_some() && self.ui_state.scroll_offset > 0 {
                     self.ui_state.scroll_offset -= 1;
                 }
             }
-            KeyCode::Down => {
+            Some(Action::Down) => {
                 // Scroll down in results if we have results
-                if let Some(state) = &self.semantic_release_state {
-                    if let Ok(result) = state.result.lock() {
-                        let line_count = result.lines().count();
-                        if self.ui_state.scroll_offset < line_count.saturating_sub(10) {
-                            self.ui_state.scroll_offset += 1;
-                        }
-                    }
+                if self.semantic_release_state.is_some()
+                    && self.ui_state.scroll_offset < self.ui_state.max_scroll_offset()
+                {
+                    self.ui_state.scroll_offset += 1;
                 }
             }
-            KeyCode::Tab => {
+            Some(Action::PageUp) if self.semantic_release_state.is_some() => {
+                self.ui_state.scroll_offset = self
+                    .ui_state
+                    .scroll_offset
+                    .saturating_sub(self.ui_state.scroll_viewport.max(1));
+            }
+            Some(Action::PageDown) if self.semantic_release_state.is_some() => {
+                self.ui_state.scroll_offset = (self.ui_state.scroll_offset
+                    + self.ui_state.scroll_viewport.max(1))
+                .min(self.ui_state.max_scroll_offset());
+            }
+            Some(Action::Home) if self.semantic_release_state.is_some() => {
+                self.ui_state.scroll_offset = 0;
+            }
+            Some(Action::End) if self.semantic_release_state.is_some() => {
+                self.ui_state.scroll_offset = self.ui_state.max_scroll_offset();
+            }
+            Some(Action::NextTab) => {
                 self.ui_state.selected_tab = (self.ui_state.selected_tab + 1) % 6;
             }
-            KeyCode::BackTab => {
+            Some(Action::PrevTab) => {
                 self.ui_state.selected_tab = if self.ui_state.selected_tab == 0 {
                     5
                 } else {
                     self.ui_state.selected_tab - 1
                 };
             }
-            KeyCode::Enter => {
+            Some(Action::Select) => {
                 match self.ui_state.selected_tab {
                     0 => {
                         // Dry run - check what would be released
@@ -300,7 +790,7 @@ impl App {
                         self.view_semantic_release_config().await?;
                     }
                     5 => {
-                        // Setup GitHub Actions for semantic-release
+                        // Setup the CI (GitHub Actions or GitLab CI) for semantic-release
                         self.setup_github_actions_semantic_release().await?;
                     }
                     _ => {}
@@ -335,31 +825,83 @@ impl App {
     }
 
     // Helper methods for commit screen handling
-    fn handle_monday_search(&mut self) {
+    async fn handle_monday_search(&mut self) {
+        use crate::app::task_operations::TaskOperations;
+
+        if self.unavailable_offline("Monday.com") {
+            return;
+        }
+
+        // Healthcheck first, so an expired token isn't mistaken for "0 results"
+        // later. A recent success is reused so 's' doesn't wait on the network.
+        let recently_checked = self
+            .monday_connection_checked_at
+            .is_some_and(|checked_at| checked_at.elapsed() < MONDAY_HEALTHCHECK_TTL);
+        if !recently_checked {
+            match self.check_monday_connection().await {
+                Ok(_) => self.monday_connection_checked_at = Some(std::time::Instant::now()),
+                Err(e) if e.is_monday_auth_error() => {
+                    self.current_state = AppState::MondayAuthFailed;
+                    return;
+                }
+                Err(e) => {
+                    warn!(error = %e, "Monday.com healthcheck failed, opening search anyway");
+                }
+            }
+        }
+
         self.current_screen = AppScreen::TaskSearch;
-        self.ui_state.search_textarea.select_all();
-        self.ui_state
-            .search_textarea
-            .delete_str(self.ui_state.search_textarea.lines().join("\n").len());
         self.monday_tasks.clear();
+        self.monday_search_results.clear();
         self.ui_state.selected_tab = 0;
-        self.message = Some("Monday.com Search - Press 'i' or '/' to start typing".to_string());
+        self.message =
+            Some(self.prefill_last_search("Monday.com Search - Press 'i' or '/' to start typing"));
     }
 
     fn handle_jira_search(&mut self) {
+        if self.unavailable_offline("JIRA") {
+            return;
+        }
         self.current_screen = AppScreen::TaskSearch;
-        self.ui_state.search_textarea.select_all();
-        self.ui_state
-            .search_textarea
-            .delete_str(self.ui_state.search_textarea.lines().join("\n").len());
         self.jira_tasks.clear();
         self.ui_state.selected_tab = 0;
-        self.message = Some("JIRA Search - Press 'i' or '/' to start typing".to_string());
+        self.message =
+            Some(self.prefill_last_search("JIRA Search - Press 'i' or '/' to start typing"));
+    }
+
+    /// Put the last search of the active task system back in the search box so
+    /// Enter repeats it. Returns the status message to show.
+    fn prefill_last_search(&mut self, default_message: &str) -> String {
+        let system = self.config.get_task_system();
+        let last = self
+            .ui_state
+            .search_history
+            .for_system(&system)
+            .and_then(|history| {
+                history.stop_browsing();
+                history.last().map(str::to_string)
+            });
+        match last {
+            Some(query) => {
+                self.set_search_query(&query);
+                format!(
+                    "Última búsqueda: '{}' - Enter para repetirla, ↑↓ al escribir para el historial",
+                    query
+                )
+            }
+            None => {
+                self.set_search_query("");
+                default_message.to_string()
+            }
+        }
     }
 
     fn handle_commit_preview(&mut self) {
         use crate::app::commit_operations::CommitOperations;
         self.preview_commit_message = self.build_commit_message();
+        self.ui_state.repo_state = crate::git::GitRepo::new()
+            .map(|repo| repo.repo_state())
+            .unwrap_or_default();
         self.current_screen = AppScreen::CommitPreview;
         self.ui_state.input_mode = InputMode::Editing;
 
@@ -376,10 +918,16 @@ impl App {
             .commit_preview_textarea
             .insert_str(&self.preview_commit_message);
 
-        self.message = Some(
-            "Review and edit your commit message. Press Ctrl+C to commit, Esc to cancel"
+        self.message = Some(match self.validate_commit_form() {
+            Ok(()) => "Review and edit your commit message. Press Ctrl+C to commit, Esc to cancel"
                 .to_string(),
-        );
+            Err(crate::error::SemanticReleaseError::ValidationError { message }) => {
+                format!("⚠️ {}", message)
+            }
+            Err(e) => format!("⚠️ {}", e),
+        });
+        // A problem replaces the hint above when the answer arrives
+        self.check_scope_task_ids();
     }
 
     fn handle_task_management_toggle(&mut self) {
@@ -399,7 +947,7 @@ impl App {
 
     fn handle_up_navigation(&mut self) {
         if self.ui_state.task_management_mode {
-            if self.get_selected_tasks_count() > 0 && self.ui_state.selected_tab > 0 {
+            if self.get_filtered_selected_tasks_count() > 0 && self.ui_state.selected_tab > 0 {
                 self.ui_state.selected_tab -= 1;
             }
         } else {
@@ -410,7 +958,9 @@ impl App {
                     }
                 }
                 CommitField::SelectedTasks => {
-                    if self.get_selected_tasks_count() > 0 && self.ui_state.selected_tab > 0 {
+                    if self.get_filtered_selected_tasks_count() > 0
+                        && self.ui_state.selected_tab > 0
+                    {
                         self.ui_state.selected_tab -= 1;
                     }
                 }
@@ -421,9 +971,8 @@ impl App {
 
     fn handle_down_navigation(&mut self) {
         if self.ui_state.task_management_mode {
-            if self.get_selected_tasks_count() > 0
-                && self.ui_state.selected_tab < self.get_selected_tasks_count().saturating_sub(1)
-            {
+            let visible = self.get_filtered_selected_tasks_count();
+            if visible > 0 && self.ui_state.selected_tab < visible - 1 {
                 self.ui_state.selected_tab += 1;
             }
         } else {
@@ -435,9 +984,9 @@ impl App {
                     }
                 }
                 CommitField::SelectedTasks => {
-                    if self.get_selected_tasks_count() > 0
+                    if self.get_filtered_selected_tasks_count() > 0
                         && self.ui_state.selected_tab
-                            < self.get_selected_tasks_count().saturating_sub(1)
+                            < self.get_filtered_selected_tasks_count().saturating_sub(1)
                     {
                         self.ui_state.selected_tab += 1;
                     }
@@ -453,6 +1002,7 @@ impl App {
                 let commit_types = CommitType::all();
                 if let Some(selected_type) = commit_types.get(self.ui_state.selected_commit_type) {
                     self.commit_form.commit_type = Some(selected_type.clone());
+                    self.prefill_commit_template();
                 }
             }
             CommitField::SelectedTasks => {
@@ -485,18 +1035,18 @@ impl App {
 
     fn handle_task_deletion(&mut self) {
         let selected_tab = self.ui_state.selected_tab;
-        let selected_tasks_len = self.get_selected_tasks_count();
+        let visible = self.filtered_selected_task_indices();
         let should_delete = (self.ui_state.task_management_mode
             || self.ui_state.current_field == CommitField::SelectedTasks)
-            && selected_tasks_len > 0
-            && selected_tab < selected_tasks_len;
+            && selected_tab < visible.len();
 
         if should_delete {
-            self.remove_selected_task_by_index(selected_tab);
+            // `selected_tab` points into the filtered list; map it back to the real index
+            self.remove_selected_task_by_index(visible[selected_tab]);
 
-            let new_len = self.get_selected_tasks_count();
-            if selected_tab >= new_len && new_len > 0 {
-                self.ui_state.selected_tab = new_len - 1;
+            let new_len = self.get_filtered_selected_tasks_count();
+            if selected_tab >= new_len {
+                self.ui_state.selected_tab = new_len.saturating_sub(1);
             }
 
             use crate::app::task_operations::TaskOperations;
@@ -505,6 +1055,193 @@ impl App {
         }
     }
 
+    fn handle_selected_tasks_filter_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Enter => {
+                self.ui_state.editing_selected_tasks_filter = false;
+                self.message = Some(format!(
+                    "Showing {} of {} selected tasks",
+                    self.get_filtered_selected_tasks_count(),
+                    self.get_selected_tasks_count()
+                ));
+            }
+            KeyCode::Esc => {
+                self.ui_state.editing_selected_tasks_filter = false;
+                self.ui_state.selected_tasks_filter.clear();
+                self.ui_state.selected_tab = 0;
+                self.message = Some("Task filter cleared".to_string());
+            }
+            KeyCode::Backspace => {
+                self.ui_state.selected_tasks_filter.pop();
+                self.ui_state.selected_tab = 0;
+            }
+            KeyCode::Char(c) => {
+                self.ui_state.selected_tasks_filter.push(c);
+                self.ui_state.selected_tab = 0;
+            }
+            _ => {}
+        }
+    }
+
+    async fn handle_merge_commit_confirmation(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.current_state = AppState::Normal;
+                self.commit_preview_message(true, false).await?;
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.current_state = AppState::Normal;
+                self.message =
+                    Some("Commit cancelled. The merge is still in progress.".to_string());
+            }
+            _ => {
+                // Ignore other keys, keep waiting for y/n
+            }
+        }
+        Ok(())
+    }
+
+    async fn handle_secrets_confirmation(&mut self, key: KeyCode) -> Result<()> {
+        match key {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                self.current_state = AppState::Normal;
+                // A merge in progress was already confirmed before the scan
+                self.commit_preview_message(true, true).await?;
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.current_state = AppState::Normal;
+                self.message = Some(self.config.tr("secrets.cancelled").to_string());
+            }
+            _ => {
+                // Ignore other keys, keep waiting for y/n
+            }
+        }
+        Ok(())
+    }
+
+    /// Edit the target in `$EDITOR` after this key, or in the built-in editor
+    /// when no external one is configured.
+    pub(crate) fn request_external_edit(&mut self, target: ExternalEditTarget) {
+        if let ExternalEditTarget::Field(field) = &target {
+            if self.ui_state.get_textarea(field).is_none() {
+                self.message = Some("Select a text field to edit it in $EDITOR".to_string());
+                return;
+            }
+        }
+        if crate::utils::external_editor().is_none() {
+            self.ui_state.input_mode = InputMode::Editing;
+            self.message = Some("$EDITOR is not set, using the built-in editor".to_string());
+            return;
+        }
+        self.external_edit = Some(target);
+    }
+
+    /// Read the staged changes and show them in the diff view.
+    fn open_staged_diff(&mut self) {
+        match crate::git::GitRepo::new().and_then(|repo| repo.get_staged_diff()) {
+            Ok(files) if files.is_empty() => {
+                self.message =
+                    Some("No staged changes. Press 'a' to pick the files to stage".to_string());
+            }
+            Ok(files) => {
+                self.ui_state.staged_diff = StagedDiffView::new(files);
+                self.current_screen = AppScreen::StagedDiff;
+            }
+            Err(e) => {
+                self.current_state = AppState::Error(format!("Could not read the diff: {}", e));
+            }
+        }
+    }
+
+    fn handle_staged_diff_screen(&mut self, key: KeyCode) {
+        let action = self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::StagedDiff, key.into());
+        let view = &mut self.ui_state.staged_diff;
+        match action {
+            Some(Action::Back) => {
+                // The diff can be large; it is read again when reopened
+                self.ui_state.staged_diff = StagedDiffView::default();
+                self.current_screen = AppScreen::Commit;
+            }
+            Some(Action::Up) => view.scroll_by(-1),
+            Some(Action::Down) => view.scroll_by(1),
+            Some(Action::PageUp) => view.scroll_by(-DIFF_PAGE),
+            Some(Action::PageDown) => view.scroll_by(DIFF_PAGE),
+            Some(Action::Home) => view.scroll = 0,
+            Some(Action::End) => view.scroll_to_end(),
+            Some(Action::NextTab) => view.next_file(),
+            Some(Action::PrevTab) => view.previous_file(),
+            Some(Action::ToggleDetails) => view.toggle_current_file(),
+            Some(Action::CollapseAll) => view.toggle_all_files(),
+            _ => {}
+        }
+    }
+
+    /// List the changed files of the repository to pick what gets staged.
+    fn open_stage_selector(&mut self) {
+        match crate::git::GitRepo::new().and_then(|repo| repo.get_status()) {
+            Ok(status) => {
+                self.ui_state.stage_selector = Some(StageSelector::from_status(&status));
+            }
+            Err(e) => {
+                self.current_state = AppState::Error(format!("Git repository error: {}", e));
+            }
+        }
+    }
+
+    fn handle_stage_selector(&mut self, key: KeyCode) {
+        let Some(selector) = self.ui_state.stage_selector.as_mut() else {
+            return;
+        };
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => selector.up(),
+            KeyCode::Down | KeyCode::Char('j') => selector.down(),
+            KeyCode::Char(' ') => selector.toggle_focused(),
+            KeyCode::Char('a') => selector.toggle_all(),
+            KeyCode::Enter => {
+                if let Some(selector) = self.ui_state.stage_selector.take() {
+                    self.apply_stage_selection(&selector);
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.ui_state.stage_selector = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Bring the index in line with the ticked files of the selector.
+    fn apply_stage_selection(&mut self, selector: &StageSelector) {
+        let to_stage = selector.to_stage();
+        let to_unstage = selector.to_unstage();
+        if to_stage.is_empty() && to_unstage.is_empty() {
+            self.message = Some(self.config.tr("stage.unchanged").to_string());
+            return;
+        }
+
+        let result = crate::git::GitRepo::new().and_then(|repo| {
+            repo.unstage_paths(&to_unstage)?;
+            repo.stage_paths(&to_stage)
+        });
+        match result {
+            Ok(()) => {
+                self.message = Some(format!(
+                    "{} +{} / -{}",
+                    self.config.tr("stage.applied"),
+                    to_stage.len(),
+                    to_unstage.len()
+                ));
+            }
+            Err(e) => {
+                self.current_state =
+                    AppState::Error(format!("{}: {}", self.config.tr("stage.error"), e));
+            }
+        }
+        self.refresh_git_status();
+    }
+
     async fn handle_stage_confirmation(&mut self, key: KeyCode) -> Result<()> {
         use crate::app::commit_operations::CommitOperations;
         use crate::git::GitRepo;
@@ -523,19 +1260,26 @@ impl App {
 
                 match git_repo.stage_all() {
                     Ok(_) => {
+                        // What was just staged is scanned like any other change
+                        if !self.check_staged_secrets(&git_repo) {
+                            return Ok(());
+                        }
+
                         // Successfully staged, now proceed with commit
-                        if let Err(e) = self
+                        match self
                             .create_commit_with_message(&self.preview_commit_message)
                             .await
                         {
-                            self.current_state = AppState::Error(e.to_string());
-                        } else {
-                            self.message = Some(
-                                "All changes staged and commit created successfully!".to_string(),
-                            );
-                            self.current_screen = AppScreen::Main;
-                            self.ui_state.input_mode = InputMode::Normal;
-                            self.current_state = AppState::Normal;
+                            Err(e) => self.current_state = AppState::Error(e.to_string()),
+                            Ok(hash) => {
+                                self.message = Some(format!(
+                                    "Cambios añadidos y commit {} creado",
+                                    &hash[..7]
+                                ));
+                                self.current_screen = AppScreen::Main;
+                                self.ui_state.input_mode = InputMode::Normal;
+                                self.current_state = AppState::Normal;
+                            }
                         }
                     }
                     Err(e) => {
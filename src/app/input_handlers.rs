@@ -2,9 +2,9 @@ use crate::error::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 use crate::{
-    app::App,
-    types::{AppScreen, AppState},
-    ui::{CommitField, InputMode},
+    app::{app::ExternalEditTarget, App},
+    types::{AppScreen, AppState, MondayTask},
+    ui::{keybindings::Action, CommitField, InputMode},
 };
 
 impl App {
@@ -103,12 +103,99 @@ impl App {
 
     fn clear_current_tasks(&mut self) {
         match self.config.get_task_system() {
-            crate::types::TaskSystem::Monday => self.monday_tasks.clear(),
+            crate::types::TaskSystem::Monday => {
+                self.monday_tasks.clear();
+                self.monday_search_results.clear();
+            }
             crate::types::TaskSystem::Jira => self.jira_tasks.clear(),
             crate::types::TaskSystem::None => {}
         }
     }
 
+    /// Monday.com results that pass the state filter: active tasks only,
+    /// unless closed ones are included ('c').
+    fn state_filtered_results(&self) -> impl Iterator<Item = &MondayTask> {
+        let include_closed = self.ui_state.monday_include_closed;
+        self.monday_search_results
+            .iter()
+            .filter(move |task| include_closed || task.is_active())
+    }
+
+    /// Show the Monday.com results that pass the state and board filters,
+    /// dropping the board filter when the board isn't among them anymore.
+    fn apply_result_filters(&mut self) {
+        if let Some(board) = &self.ui_state.monday_board_filter {
+            if !self
+                .state_filtered_results()
+                .any(|task| task.board_name.as_ref() == Some(board))
+            {
+                self.ui_state.monday_board_filter = None;
+            }
+        }
+
+        let board = self.ui_state.monday_board_filter.clone();
+        self.monday_tasks = self
+            .state_filtered_results()
+            .filter(|task| board.is_none() || task.board_name == board)
+            .cloned()
+            .collect();
+        self.ui_state.focused_search_index = 0;
+        self.ui_state.search_results_offset = 0;
+    }
+
+    /// Move the board filter to the next board in the results, then back to all boards
+    fn cycle_board_filter(&mut self) {
+        let mut boards: Vec<&String> = Vec::new();
+        for board in self
+            .state_filtered_results()
+            .filter_map(|task| task.board_name.as_ref())
+        {
+            if !boards.contains(&board) {
+                boards.push(board);
+            }
+        }
+        if boards.len() < 2 && self.ui_state.monday_board_filter.is_none() {
+            self.message = Some("Los resultados son de un solo tablero".to_string());
+            return;
+        }
+
+        let next = match &self.ui_state.monday_board_filter {
+            None => boards.first().map(|board| (*board).clone()),
+            Some(current) => boards
+                .iter()
+                .position(|board| *board == current)
+                .and_then(|index| boards.get(index + 1))
+                .map(|board| (*board).clone()),
+        };
+        self.ui_state.monday_board_filter = next;
+        self.apply_result_filters();
+
+        self.message = Some(match &self.ui_state.monday_board_filter {
+            Some(board) => format!("Tablero: {} ({} tareas)", board, self.monday_tasks.len()),
+            None => "Todos los tableros".to_string(),
+        });
+    }
+
+    /// Switch between active Monday.com tasks only and every state
+    fn toggle_closed_tasks(&mut self) {
+        self.ui_state.monday_include_closed = !self.ui_state.monday_include_closed;
+        self.apply_result_filters();
+
+        let hidden = self.monday_search_results.len() - self.state_filtered_results().count();
+        self.message = Some(if self.ui_state.monday_include_closed {
+            format!(
+                "Incluyendo tareas archivadas/eliminadas ({} tareas)",
+                self.monday_tasks.len()
+            )
+        } else {
+            format!(
+                "Solo tareas activas ({} tareas, {} ocultas)",
+                self.monday_tasks.len(),
+                hidden
+            )
+        });
+    }
+
     pub async fn handle_input_mode(&mut self, key: KeyEvent) -> Result<()> {
         // Handle different screens with their appropriate TextArea functions
         match self.current_screen {
@@ -165,70 +252,184 @@ impl App {
         }
     }
 
-    pub async fn handle_commit_preview_text_editing(&mut self, key: KeyEvent) -> Result<()> {
-        use crate::app::commit_operations::CommitOperations;
+    /// Scan the staged changes for secrets (`SECRET_SCAN`). Returns `false`
+    /// when the commit has to wait: blocked, or until the user confirms it.
+    pub(crate) fn check_staged_secrets(&mut self, git_repo: &crate::git::GitRepo) -> bool {
+        use crate::git::{secret_findings_summary, SecretScanner};
+        use crate::types::SecretScanMode;
 
-        // Check for Ctrl+C first (commit action)
-        if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('c')) {
-            let commit_message = self.ui_state.commit_preview_textarea.lines().join("\n");
-
-            // Check if there are staged changes
-            use crate::git::GitRepo;
-            let git_repo = match GitRepo::new() {
-                Ok(repo) => repo,
-                Err(e) => {
-                    self.current_state = AppState::Error(format!("Git repository error: {}", e));
-                    return Ok(());
-                }
-            };
+        if self.config.secret_scan == SecretScanMode::Off {
+            return true;
+        }
+        let findings = match git_repo.find_staged_secrets(&SecretScanner::from_config(&self.config))
+        {
+            Ok(findings) => findings,
+            Err(e) => {
+                // The scan is a safety net; a diff that can't be read shouldn't stop the commit
+                tracing::warn!(error = %e, "Could not scan the staged changes for secrets");
+                return true;
+            }
+        };
+        if findings.is_empty() {
+            return true;
+        }
 
-            let git_status = match git_repo.get_status() {
-                Ok(status) => status,
-                Err(e) => {
-                    self.current_state =
-                        AppState::Error(format!("Could not check git status: {}", e));
-                    return Ok(());
-                }
-            };
+        let summary = secret_findings_summary(&findings, 3);
+        if self.config.secret_scan == SecretScanMode::Block {
+            self.current_state =
+                AppState::Error(format!("{} {}", self.config.tr("secrets.blocked"), summary));
+        } else {
+            self.current_state = AppState::ConfirmingSecrets;
+            self.message = Some(format!(
+                "{} {}. {}",
+                self.config.tr("secrets.found"),
+                summary,
+                self.config.tr("secrets.confirm")
+            ));
+        }
+        false
+    }
 
-            // If no staged changes but there are modified/untracked files, ask user to stage
-            if git_status.staged.is_empty()
-                && (!git_status.modified.is_empty() || !git_status.untracked.is_empty())
-            {
-                self.current_state = AppState::ConfirmingStageAll;
-                self.message = Some(format!(
-                    "No staged changes found. {} modified files and {} untracked files. Press 'y' to stage all (git add -A), 'n' to cancel.",
-                    git_status.modified.len(),
-                    git_status.untracked.len()
-                ));
+    /// Commit the message from the preview editor, asking for confirmation
+    /// first when a merge is in progress, nothing is staged or the staged
+    /// changes look like they contain secrets.
+    pub async fn commit_preview_message(
+        &mut self,
+        merge_confirmed: bool,
+        secrets_confirmed: bool,
+    ) -> Result<()> {
+        use crate::app::commit_operations::CommitOperations;
+        use crate::git::RepoState;
+
+        // Check if there are staged changes
+        use crate::git::GitRepo;
+        let git_repo = match GitRepo::new() {
+            Ok(repo) => repo,
+            Err(e) => {
+                self.current_state = AppState::Error(format!("Git repository error: {}", e));
                 return Ok(());
             }
+        };
 
-            // If no staged changes and no other changes, show error
-            if git_status.staged.is_empty() {
-                self.current_state =
-                    AppState::Error("No changes to commit. Make some changes first.".to_string());
+        // Amending only rewrites HEAD, so none of the staging checks apply
+        if self.amending_commit {
+            match git_repo.amend_commit(&self.preview_commit_message, self.config.no_verify) {
+                Ok(_) => {
+                    self.amending_commit = false;
+                    self.message = Some("Latest commit amended successfully!".to_string());
+                    self.current_state = AppState::Normal;
+                    self.current_screen = AppScreen::History;
+                    self.ui_state.input_mode = InputMode::Normal;
+                    self.load_recent_commits();
+                }
+                Err(e) => self.current_state = AppState::Error(e.to_string()),
+            }
+            return Ok(());
+        }
+
+        let git_status = match git_repo.get_status() {
+            Ok(status) => status,
+            Err(e) => {
+                self.current_state = AppState::Error(format!("Could not check git status: {}", e));
                 return Ok(());
             }
+        };
 
-            // Proceed with commit if there are staged changes
-            if let Err(e) = self.create_commit_with_message(&commit_message).await {
-                self.current_state = AppState::Error(e.to_string());
-            } else {
-                self.message = Some("Commit created successfully!".to_string());
+        // Committing during a merge concludes it, so make the user confirm that explicitly
+        if git_status.state == RepoState::Merge && !merge_confirmed {
+            self.current_state = AppState::ConfirmingMergeCommit;
+            self.message = Some(format!(
+                "⚠️ {}: {}. Press 'y' to commit, 'n' to cancel.",
+                git_status.state.description(),
+                git_status.state.hint()
+            ));
+            return Ok(());
+        }
+
+        // If no staged changes but there are modified/untracked files, ask user to stage
+        if git_status.staged.is_empty()
+            && (!git_status.modified.is_empty() || !git_status.untracked.is_empty())
+        {
+            self.current_state = AppState::ConfirmingStageAll;
+            self.message = Some(format!(
+                "No staged changes found. {} modified files and {} untracked files. Press 'y' to stage all (git add -A), 'n' to cancel.",
+                git_status.modified.len(),
+                git_status.untracked.len()
+            ));
+            return Ok(());
+        }
+
+        // If no staged changes and no other changes, show error (a merge can be
+        // concluded without changes of its own)
+        if git_status.staged.is_empty() && git_status.state != RepoState::Merge {
+            self.current_state =
+                AppState::Error("No changes to commit. Make some changes first.".to_string());
+            return Ok(());
+        }
+
+        if !secrets_confirmed && !self.check_staged_secrets(&git_repo) {
+            return Ok(());
+        }
+
+        // Proceed with commit if there are staged changes
+        match self
+            .create_commit_with_message(&self.preview_commit_message)
+            .await
+        {
+            Err(e) => self.current_state = AppState::Error(e.to_string()),
+            Ok(hash) => {
+                self.message = Some(if git_status.state.is_clean() {
+                    format!("Commit {} creado", &hash[..7])
+                } else {
+                    format!(
+                        "Commit {} creado ⚠️ {}: {}",
+                        &hash[..7],
+                        git_status.state.description(),
+                        git_status.state.hint()
+                    )
+                });
+                self.current_state = AppState::Normal;
                 self.current_screen = AppScreen::Main;
                 self.ui_state.input_mode = InputMode::Normal;
             }
-            return Ok(());
         }
+        Ok(())
+    }
 
-        match key.code {
-            KeyCode::Esc => {
+    pub async fn handle_commit_preview_text_editing(&mut self, key: KeyEvent) -> Result<()> {
+        // Plain characters are text; only other keys can trigger actions
+        let typed_text =
+            matches!(key.code, KeyCode::Char(_)) && !key.modifiers.contains(KeyModifiers::CONTROL);
+        let action = match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::CommitPreview, key)
+        {
+            Some(_) if typed_text => None,
+            action => action,
+        };
+
+        match action {
+            Some(Action::Commit) => {
+                self.preview_commit_message =
+                    self.ui_state.commit_preview_textarea.lines().join("\n");
+                return self.commit_preview_message(false, false).await;
+            }
+            Some(Action::Back) if self.amending_commit => {
+                self.amending_commit = false;
+                self.current_screen = AppScreen::History;
+                self.ui_state.input_mode = InputMode::Normal;
+                self.message = Some("Amend cancelled".to_string());
+            }
+            Some(Action::Back) => {
                 self.current_screen = AppScreen::Commit;
                 self.ui_state.input_mode = InputMode::Normal;
                 self.message = Some("Commit cancelled".to_string());
             }
-            KeyCode::Tab => {
+            Some(Action::ExternalEditor) => {
+                self.request_external_edit(ExternalEditTarget::Preview);
+            }
+            Some(Action::NextTab) => {
                 // Save current textarea content and move to next field
                 self.save_current_textarea_to_form();
                 self.ui_state.input_mode = InputMode::Normal;
@@ -303,6 +504,17 @@ impl App {
                 self.ui_state.input_mode = InputMode::Normal;
                 return Ok(());
             }
+            KeyCode::Tab if self.accept_suggestion(true) => {
+                self.save_current_textarea_to_form();
+                return Ok(());
+            }
+            KeyCode::Right
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.accept_suggestion(false) =>
+            {
+                self.save_current_textarea_to_form();
+                return Ok(());
+            }
             KeyCode::Tab => {
                 // Save current textarea content and move to next field
                 self.save_current_textarea_to_form();
@@ -327,11 +539,19 @@ impl App {
             let input = Self::crossterm_key_to_textarea_input(key);
             textarea.input(input);
         }
+        // Typing something else than the suggestion dismisses it
+        if self.ui_state.ghost_text(&current_field).is_none() {
+            self.ui_state.ai_suggestions.remove(&current_field);
+        }
 
         Ok(())
     }
 
     pub fn handle_tab_navigation(&mut self) {
+        if self.ui_state.current_field == CommitField::Scope {
+            self.check_scope_task_ids();
+        }
+
         // Navigate to next field
         self.ui_state.current_field = match self.ui_state.current_field {
             CommitField::Type => CommitField::Scope,
@@ -350,6 +570,10 @@ impl App {
     }
 
     pub fn handle_back_tab_navigation(&mut self) {
+        if self.ui_state.current_field == CommitField::Scope {
+            self.check_scope_task_ids();
+        }
+
         // Navigate to previous field
         self.ui_state.current_field = match self.ui_state.current_field {
             CommitField::Type => CommitField::SelectedTasks,
@@ -371,8 +595,6 @@ impl App {
 
     // Search input handling
     pub async fn handle_search_input_mode(&mut self, key: KeyEvent) -> Result<()> {
-        use crate::app::task_operations::TaskOperations;
-
         match key.code {
             KeyCode::Esc => {
                 self.ui_state.input_mode = InputMode::Normal;
@@ -380,63 +602,26 @@ impl App {
             }
             KeyCode::Enter => {
                 let search_query = self.ui_state.search_textarea.lines().join(" ");
-                self.message = Some(format!(
-                    "DEBUG: Starting search with query: '{}'",
-                    search_query
-                ));
                 if !search_query.is_empty() {
-                    self.current_state = AppState::Loading;
-
-                    match self.config.get_task_system() {
-                        crate::types::TaskSystem::Monday => {
-                            match self.search_monday_tasks(&search_query).await {
-                                Ok(tasks) => {
-                                    self.monday_tasks = tasks;
-                                    self.ui_state.selected_tab = 0;
-                                    self.current_state = AppState::Normal;
-                                    self.ui_state.input_mode = InputMode::Normal;
-                                    self.message = Some(format!(
-                                        "DEBUG: Monday search completed! Found {} tasks",
-                                        self.monday_tasks.len()
-                                    ));
-                                }
-                                Err(e) => {
-                                    self.current_state = AppState::Error(format!(
-                                        "DEBUG: Monday search failed: {}",
-                                        e
-                                    ));
-                                }
-                            }
-                        }
-                        crate::types::TaskSystem::Jira => {
-                            match self.search_jira_tasks(&search_query).await {
-                                Ok(tasks) => {
-                                    self.jira_tasks = tasks;
-                                    self.ui_state.selected_tab = 0;
-                                    self.current_state = AppState::Normal;
-                                    self.ui_state.input_mode = InputMode::Normal;
-                                    self.message = Some(format!(
-                                        "DEBUG: JIRA search completed! Found {} tasks",
-                                        self.jira_tasks.len()
-                                    ));
-                                }
-                                Err(e) => {
-                                    self.current_state = AppState::Error(format!(
-                                        "DEBUG: JIRA search failed: {}",
-                                        e
-                                    ));
-                                }
-                            }
-                        }
-                        crate::types::TaskSystem::None => {
-                            self.current_state =
-                                AppState::Error("No task management system configured".to_string());
-                        }
-                    }
+                    self.ui_state.input_mode = InputMode::Normal;
+                    self.run_task_search(&search_query).await;
                 } else {
                     self.message = Some("DEBUG: Search query is empty".to_string());
                 }
             }
+            KeyCode::Up | KeyCode::Down => {
+                let system = self.config.get_task_system();
+                if let Some(history) = self.ui_state.search_history.for_system(&system) {
+                    let entry = if key.code == KeyCode::Up {
+                        history.older()
+                    } else {
+                        history.newer()
+                    }
+                    .unwrap_or_default()
+                    .to_string();
+                    self.set_search_query(&entry);
+                }
+            }
             _ => {
                 // Pass all other inputs to the search textarea
                 let input = Self::crossterm_key_to_textarea_input(key);
@@ -446,14 +631,166 @@ impl App {
         Ok(())
     }
 
-    pub async fn handle_search_navigation_mode(&mut self, key: KeyCode) -> Result<()> {
-        use crate::app::task_operations::TaskOperations;
+    /// Replace the contents of the search box.
+    pub fn set_search_query(&mut self, query: &str) {
+        self.ui_state.search_textarea.select_all();
+        self.ui_state
+            .search_textarea
+            .delete_str(self.ui_state.search_textarea.lines().join("\n").len());
+        self.ui_state.search_textarea.insert_str(query);
+    }
+
+    /// Search the configured task system. A query made only of Monday.com IDs
+    /// or JIRA keys fetches those tasks directly; when several are pasted at
+    /// once, the ones found are also selected.
+    async fn run_task_search(&mut self, query: &str) {
+        use crate::app::task_operations::{jira_issue_keys, monday_item_ids, TaskOperations};
 
-        match key {
-            KeyCode::Char('q') => {
+        let system = self.config.get_task_system();
+        if let Some(history) = self.ui_state.search_history.for_system(&system) {
+            history.record(query);
+            self.ui_state.search_history.save();
+        }
+
+        self.current_state = AppState::Loading;
+        let requested_ids = match self.config.get_task_system() {
+            crate::types::TaskSystem::Monday => {
+                let requested = monday_item_ids(query);
+                if let Ok(mut waited) = self.monday_rate_limit_wait.lock() {
+                    *waited = std::time::Duration::ZERO;
+                }
+                match self.search_monday_tasks(query).await {
+                    Ok(tasks) => {
+                        // Tasks asked for by ID are shown whatever their state
+                        if requested.is_some() {
+                            self.ui_state.monday_include_closed = true;
+                        }
+                        self.monday_search_results = tasks;
+                        self.apply_result_filters();
+                    }
+                    Err(e) if e.is_monday_auth_error() => {
+                        self.current_state = AppState::MondayAuthFailed;
+                        self.monday_connection_checked_at = None;
+                        return;
+                    }
+                    Err(e) => {
+                        self.current_state = AppState::Error(e.to_string());
+                        return;
+                    }
+                }
+                requested
+            }
+            crate::types::TaskSystem::Jira => {
+                let requested = jira_issue_keys(query);
+                match self.search_jira_tasks(query).await {
+                    Ok(tasks) => self.jira_tasks = tasks,
+                    Err(e) => {
+                        self.current_state = AppState::Error(e.to_string());
+                        return;
+                    }
+                }
+                requested
+            }
+            crate::types::TaskSystem::None => {
+                self.current_state =
+                    AppState::Error("No task management system configured".to_string());
+                return;
+            }
+        };
+
+        self.current_state = AppState::Normal;
+        self.ui_state.selected_tab = 0;
+        self.ui_state.focused_search_index = 0;
+        let found = self.get_current_tasks_count();
+        let rate_limit_note = self.search_rate_limit_note();
+
+        let Some(requested_ids) = requested_ids else {
+            let hidden = self.monday_search_results.len() - self.state_filtered_results().count();
+            let mut message = if hidden > 0 {
+                format!(
+                    "Found {} tasks ({} archivadas/eliminadas ocultas, 'c' para verlas)",
+                    found, hidden
+                )
+            } else {
+                format!("Found {} tasks", found)
+            };
+            message.push_str(&rate_limit_note);
+            self.message = Some(message);
+            return;
+        };
+
+        if requested_ids.len() > 1 {
+            for index in 0..found {
+                if let Some(task_id) = self.get_current_task_id(index) {
+                    if !self.is_task_selected(&task_id) {
+                        self.add_task_to_selection(index);
+                    }
+                }
+            }
+            self.update_task_selection();
+        }
+
+        let found_ids: Vec<&str> = match self.config.get_task_system() {
+            crate::types::TaskSystem::Jira => self
+                .jira_tasks
+                .iter()
+                .map(|task| task.key.as_str())
+                .collect(),
+            _ => self
+                .monday_tasks
+                .iter()
+                .map(|task| task.id.as_str())
+                .collect(),
+        };
+        let missing: Vec<&str> = requested_ids
+            .iter()
+            .map(String::as_str)
+            .filter(|id| !found_ids.contains(id))
+            .collect();
+        let mut message = if requested_ids.len() > 1 {
+            format!(
+                "🔎 {} de {} tareas encontradas y seleccionadas",
+                found,
+                requested_ids.len()
+            )
+        } else {
+            format!("🔎 {} tarea encontrada por ID", found)
+        };
+        if !missing.is_empty() {
+            message.push_str(&format!(" · no encontradas: {}", missing.join(", ")));
+        }
+        message.push_str(&rate_limit_note);
+        self.message = Some(message);
+    }
+
+    /// " · <waited Xs>" when the last Monday.com search was held back by the
+    /// rate limiter, empty otherwise.
+    fn search_rate_limit_note(&self) -> String {
+        let waited = self
+            .monday_rate_limit_wait
+            .lock()
+            .map(|waited| *waited)
+            .unwrap_or_default();
+        if waited.is_zero() {
+            return String::new();
+        }
+        format!(
+            " · {} {:.1}s",
+            self.config.tr("monday.rate_limited_waited"),
+            waited.as_secs_f64()
+        )
+    }
+
+    pub async fn handle_search_navigation_mode(&mut self, key: KeyCode) -> Result<()> {
+        match self
+            .ui_state
+            .keymap
+            .action_for(&AppScreen::TaskSearch, key.into())
+        {
+            Some(Action::Back) => {
                 self.current_screen = AppScreen::Commit;
             }
-            KeyCode::Esc => {
+            Some(Action::ClearSearch) => {
                 self.clear_current_tasks();
                 self.ui_state.search_textarea.select_all();
                 self.ui_state
@@ -462,76 +799,62 @@ impl App {
                 self.ui_state.focused_search_index = 0;
                 self.message = Some("Search cleared".to_string());
             }
-            KeyCode::Char('i') | KeyCode::Char('/') => {
+            Some(Action::StartTyping) => {
                 self.ui_state.input_mode = InputMode::Editing;
                 self.message = Some("DEBUG: Entered edit mode - you can now type".to_string());
+                // Backspace keeps editing the query instead of only switching modes
+                if key == KeyCode::Backspace {
+                    self.ui_state.search_textarea.delete_char();
+                }
             }
-            KeyCode::Enter => {
+            Some(Action::Select) => {
                 let search_query = self.ui_state.search_textarea.lines().join(" ");
                 if !search_query.is_empty() {
-                    self.current_state = AppState::Loading;
-
-                    match self.config.get_task_system() {
-                        crate::types::TaskSystem::Monday => {
-                            match self.search_monday_tasks(&search_query).await {
-                                Ok(tasks) => {
-                                    self.monday_tasks = tasks;
-                                    self.ui_state.selected_tab = 0;
-                                    self.current_state = AppState::Normal;
-                                    self.message = Some(format!(
-                                        "Found {} Monday tasks",
-                                        self.monday_tasks.len()
-                                    ));
-                                }
-                                Err(e) => {
-                                    self.current_state = AppState::Error(e.to_string());
-                                }
-                            }
-                        }
-                        crate::types::TaskSystem::Jira => {
-                            match self.search_jira_tasks(&search_query).await {
-                                Ok(tasks) => {
-                                    self.jira_tasks = tasks;
-                                    self.ui_state.selected_tab = 0;
-                                    self.current_state = AppState::Normal;
-                                    self.message =
-                                        Some(format!("Found {} JIRA tasks", self.jira_tasks.len()));
-                                }
-                                Err(e) => {
-                                    self.current_state = AppState::Error(e.to_string());
-                                }
-                            }
-                        }
-                        crate::types::TaskSystem::None => {
-                            self.current_state =
-                                AppState::Error("No task management system configured".to_string());
-                        }
-                    }
+                    self.run_task_search(&search_query).await;
                 } else {
                     self.ui_state.input_mode = InputMode::Editing;
                 }
             }
-            KeyCode::Up => {
+            Some(Action::Up) => {
                 self.handle_search_up_navigation();
             }
-            KeyCode::Down => {
+            Some(Action::Down) => {
                 self.handle_search_down_navigation();
             }
-            KeyCode::Delete | KeyCode::Char('r') => {
+            Some(Action::RemoveTask) => {
                 self.handle_search_task_removal();
             }
-            KeyCode::Char(' ') => {
+            Some(Action::ToggleTask) => {
                 self.handle_search_task_toggle();
             }
-            KeyCode::Char(c) if c.is_ascii_digit() => {
-                self.handle_numeric_task_selection(c);
+            Some(Action::SelectByNumber) => {
+                if let KeyCode::Char(c) = key {
+                    self.handle_numeric_task_selection(c);
+                }
             }
-            KeyCode::Backspace => {
-                self.ui_state.search_textarea.select_all();
-                self.ui_state
-                    .search_textarea
-                    .delete_str(self.ui_state.search_textarea.lines().join("\n").len());
-                self.clear_current_tasks();
+            Some(Action::OpenTaskUrl) => {
+                let index = self.ui_state.focused_search_index;
+                let url = match self.config.get_task_system() {
+                    crate::types::TaskSystem::Monday => {
+                        self.monday_tasks.get(index).map(|task| task.url.clone())
+                    }
+                    crate::types::TaskSystem::Jira => self
+                        .jira_tasks
+                        .get(index)
+                        .and_then(|task| self.jira_issue_url(&task.key)),
+                    crate::types::TaskSystem::None => None,
+                };
+                self.open_task_url(url);
+            }
+            Some(Action::FilterBoard)
+                if self.config.get_task_system() == crate::types::TaskSystem::Monday =>
+            {
+                self.cycle_board_filter();
+            }
+            Some(Action::ToggleClosed)
+                if self.config.get_task_system() == crate::types::TaskSystem::Monday =>
+            {
+                self.toggle_closed_tasks();
             }
             _ => {}
         }
@@ -644,3 +967,231 @@ impl App {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AppConfig;
+    use tui_textarea::TextArea;
+
+    /// App editing the (multiline) description, holding `text`
+    fn editing(text: &str) -> App {
+        let mut app = App::from_config(AppConfig::default());
+        app.ui_state.current_field = CommitField::Description;
+        app.ui_state.input_mode = InputMode::Editing;
+        app.ui_state.description_textarea = TextArea::from(text.split('\n'));
+        app
+    }
+
+    async fn press(app: &mut App, code: KeyCode) {
+        app.handle_commit_text_editing(KeyEvent::new(code, KeyModifiers::NONE))
+            .await
+            .unwrap();
+    }
+
+    async fn press_times(app: &mut App, code: KeyCode, times: usize) {
+        for _ in 0..times {
+            press(app, code).await;
+        }
+    }
+
+    /// (row, column in characters)
+    fn cursor(app: &App) -> (usize, usize) {
+        app.ui_state.description_textarea.cursor()
+    }
+
+    /// Leave the field with Tab and return what was saved to the form
+    async fn saved(app: &mut App) -> String {
+        press(app, KeyCode::Tab).await;
+        app.commit_form.description.clone()
+    }
+
+    #[tokio::test]
+    async fn test_up_down_keep_the_column_between_lines_of_equal_length() {
+        let mut app = editing("abcdef\nghijkl\nmnopqr");
+        press_times(&mut app, KeyCode::Right, 3).await;
+
+        press(&mut app, KeyCode::Down).await;
+        assert_eq!(cursor(&app), (1, 3));
+        press(&mut app, KeyCode::Down).await;
+        assert_eq!(cursor(&app), (2, 3));
+        press(&mut app, KeyCode::Up).await;
+        assert_eq!(cursor(&app), (1, 3));
+    }
+
+    #[tokio::test]
+    async fn test_moving_to_a_shorter_line_goes_to_its_end() {
+        let mut app = editing("abcdefgh\nab\nabcdefgh");
+        press(&mut app, KeyCode::End).await;
+        assert_eq!(cursor(&app), (0, 8));
+
+        press(&mut app, KeyCode::Down).await;
+        assert_eq!(cursor(&app), (1, 2));
+
+        // Typing there appends to the short line instead of panicking or padding it
+        press(&mut app, KeyCode::Char('!')).await;
+        assert_eq!(saved(&mut app).await, "abcdefgh\nab!\nabcdefgh");
+    }
+
+    #[tokio::test]
+    async fn test_cursor_stops_at_the_start_and_end_of_the_text() {
+        let mut app = editing("first\nlast");
+        press(&mut app, KeyCode::Up).await;
+        press(&mut app, KeyCode::Left).await;
+        assert_eq!(cursor(&app), (0, 0));
+
+        press_times(&mut app, KeyCode::Down, 3).await;
+        press(&mut app, KeyCode::End).await;
+        press_times(&mut app, KeyCode::Right, 3).await;
+        assert_eq!(cursor(&app), (1, 4));
+
+        assert_eq!(saved(&mut app).await, "first\nlast");
+    }
+
+    #[tokio::test]
+    async fn test_home_and_end_move_within_the_current_line() {
+        let mut app = editing("one\ntwo words\nthree");
+        press(&mut app, KeyCode::Down).await;
+        press(&mut app, KeyCode::End).await;
+        assert_eq!(cursor(&app), (1, 9));
+        press(&mut app, KeyCode::Home).await;
+        assert_eq!(cursor(&app), (1, 0));
+    }
+
+    #[tokio::test]
+    async fn test_columns_count_characters_not_bytes() {
+        // Accents are two bytes and the emoji four, but each is one column
+        let mut app = editing("ñandú 🦀 café\nxy");
+        press(&mut app, KeyCode::End).await;
+        assert_eq!(cursor(&app), (0, 12));
+
+        press_times(&mut app, KeyCode::Left, 5).await;
+        assert_eq!(cursor(&app), (0, 7));
+        press(&mut app, KeyCode::Backspace).await;
+        assert_eq!(cursor(&app), (0, 6));
+
+        // The column clamped on the short line is kept when going back up
+        press(&mut app, KeyCode::Down).await;
+        assert_eq!(cursor(&app), (1, 2));
+        press(&mut app, KeyCode::Up).await;
+        assert_eq!(cursor(&app), (0, 2));
+        press(&mut app, KeyCode::Char('✓')).await;
+
+        assert_eq!(saved(&mut app).await, "ña✓ndú  café\nxy");
+    }
+
+    #[tokio::test]
+    async fn test_moving_over_a_line_break() {
+        let mut app = editing("ab\ncd");
+        press(&mut app, KeyCode::End).await;
+        assert_eq!(cursor(&app), (0, 2));
+
+        // Right at the end of a line continues at the start of the next one
+        press(&mut app, KeyCode::Right).await;
+        assert_eq!(cursor(&app), (1, 0));
+        press(&mut app, KeyCode::Left).await;
+        assert_eq!(cursor(&app), (0, 2));
+
+        // Delete right before the break joins both lines
+        press(&mut app, KeyCode::Delete).await;
+        assert_eq!(saved(&mut app).await, "abcd");
+    }
+
+    #[tokio::test]
+    async fn test_enter_splits_a_line_with_multibyte_characters() {
+        let mut app = editing("añ🦀b");
+        press_times(&mut app, KeyCode::Right, 3).await;
+        press(&mut app, KeyCode::Enter).await;
+        assert_eq!(cursor(&app), (1, 0));
+        assert_eq!(saved(&mut app).await, "añ🦀\nb");
+    }
+
+    #[tokio::test]
+    async fn test_navigation_in_empty_text() {
+        let mut app = editing("");
+        for code in [
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Left,
+            KeyCode::Right,
+            KeyCode::Home,
+            KeyCode::End,
+            KeyCode::Backspace,
+            KeyCode::Delete,
+        ] {
+            press(&mut app, code).await;
+            assert_eq!(cursor(&app), (0, 0), "after {:?}", code);
+        }
+        assert_eq!(saved(&mut app).await, "");
+    }
+
+    #[tokio::test]
+    async fn test_tab_accepts_the_suggestion_before_moving_on() {
+        let mut app = editing("Adds");
+        assert!(app.suggest_field_text(&CommitField::Description, "Adds retries\nto uploads"));
+        assert_eq!(
+            app.ui_state.ghost_text(&CommitField::Description),
+            Some(" retries\nto uploads")
+        );
+
+        press(&mut app, KeyCode::Tab).await;
+        assert_eq!(app.ui_state.current_field, CommitField::Description);
+        assert_eq!(app.commit_form.description, "Adds retries\nto uploads");
+        assert!(app.ui_state.ai_suggestions.is_empty());
+
+        // With nothing left to accept, Tab moves on as usual
+        press(&mut app, KeyCode::Tab).await;
+        assert_eq!(app.ui_state.current_field, CommitField::BreakingChange);
+    }
+
+    #[tokio::test]
+    async fn test_ctrl_right_accepts_one_word_at_a_time() {
+        let mut app = editing("");
+        app.suggest_field_text(&CommitField::Description, "Adds  retries");
+        let ctrl_right = KeyEvent::new(KeyCode::Right, KeyModifiers::CONTROL);
+
+        app.handle_commit_text_editing(ctrl_right).await.unwrap();
+        assert_eq!(app.commit_form.description, "Adds");
+        app.handle_commit_text_editing(ctrl_right).await.unwrap();
+        assert_eq!(app.commit_form.description, "Adds  retries");
+        assert_eq!(app.ui_state.ghost_text(&CommitField::Description), None);
+    }
+
+    #[tokio::test]
+    async fn test_typing_something_else_dismisses_the_suggestion() {
+        let mut app = editing("");
+        app.suggest_field_text(&CommitField::Description, "Adds retries");
+
+        // Typing what the suggestion says keeps it
+        press(&mut app, KeyCode::Char('A')).await;
+        assert_eq!(
+            app.ui_state.ghost_text(&CommitField::Description),
+            Some("dds retries")
+        );
+
+        press(&mut app, KeyCode::Char('x')).await;
+        assert!(app.ui_state.ai_suggestions.is_empty());
+        assert_eq!(saved(&mut app).await, "Ax");
+    }
+
+    #[tokio::test]
+    async fn test_no_suggestion_over_text_typed_by_hand() {
+        let mut app = editing("Fixes the login");
+        assert!(!app.suggest_field_text(&CommitField::Description, "Adds retries"));
+        assert_eq!(app.ui_state.ghost_text(&CommitField::Description), None);
+    }
+
+    #[tokio::test]
+    async fn test_backspace_in_the_search_results_edits_the_query() {
+        let mut app = App::from_config(AppConfig::default());
+        app.current_screen = AppScreen::TaskSearch;
+        app.set_search_query("OPS-12");
+
+        app.handle_search_navigation_mode(KeyCode::Backspace)
+            .await
+            .unwrap();
+
+        assert_eq!(app.ui_state.input_mode, InputMode::Editing);
+        assert_eq!(app.ui_state.search_textarea.lines().join(" "), "OPS-1");
+    }
+}
@@ -8,5 +8,6 @@ pub mod input_handlers;
 pub mod release_notes;
 pub mod semantic_release_operations;
 pub mod task_operations;
+pub mod terminal;
 
 pub use app::App;
@@ -1,21 +1,113 @@
 use crate::error::Result;
-use std::process::{Command, Stdio};
-use std::sync::{Arc, Mutex};
-use std::thread;
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::LazyLock;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader, Lines};
+use tokio::process::Command;
 
 use crate::{
-    app::{background_operations::BackgroundEvent, App},
+    app::{
+        background_operations::{BackgroundEvent, RELEASE_NOTES_KIND},
+        App,
+    },
     error::SemanticReleaseError,
-    git::GitRepo,
-    types::{AppConfig, AppState, GitCommit},
+    git::{GitHost, GitLogFilter, GitRepo, RemoteRepo},
+    i18n::{detect_language, Language},
+    services::MondayClient,
+    types::{
+        AppConfig, AppState, BodyFormat, CommitTrailer, GitCommit, GroupingStrategy,
+        ReleasePackage, VersionInfo, VersionStrategy,
+    },
+    utils::strip_template_placeholders,
 };
 use async_broadcast::Sender;
+use futures::StreamExt;
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use pulldown_cmark_escape::{escape_href, escape_html};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use tracing::{info, instrument, warn};
 
+/// Commits the release notes are generated from.
+pub enum ReleaseNotesCommits {
+    /// Whole repository, or the package picked as path filter
+    Repository(Vec<GitCommit>),
+    /// Monorepo: one set of commits per configured package
+    Packages(Vec<(ReleasePackage, Vec<GitCommit>)>),
+}
+
+impl App {
+    /// Commits since the last tag (or the `--since` date), split per package
+    /// when RELEASE_PACKAGES is set and no path filter is active. Shared by
+    /// the TUI and the headless `release-notes --ci` run.
+    pub fn collect_release_notes_commits(&self) -> Result<ReleaseNotesCommits> {
+        let git_repo = GitRepo::new()?.with_trailers(self.config.commit_trailers());
+        let since = self.ui_state.release_notes_since;
+
+        // Monorepo: one set of notes per configured package, each since its own tag
+        if !self.config.release_packages.is_empty()
+            && self.ui_state.release_notes_path_filter.is_none()
+        {
+            let mut packages = Vec::new();
+            for package in &self.config.release_packages {
+                // A --since date replaces the package tag as the starting point
+                let last_tag = match since {
+                    Some(_) => None,
+                    None => git_repo.get_last_tag_with_prefix(Some(&package.tag_prefix))?,
+                };
+                let filter = GitLogFilter {
+                    author: self.ui_state.release_notes_author.clone(),
+                    since,
+                    until: self.ui_state.release_notes_until,
+                    paths: vec![package.path.clone()],
+                };
+                let commits = git_repo.get_commits_filtered(last_tag.as_deref(), &filter)?;
+                info!(
+                    package = %package.path,
+                    ?last_tag,
+                    commit_count = commits.len(),
+                    "Collected package commits for release notes"
+                );
+                packages.push((package.clone(), commits));
+            }
+            return Ok(ReleaseNotesCommits::Packages(packages));
+        }
+
+        let last_tag = match since {
+            Some(_) => None,
+            None => git_repo.get_last_tag()?,
+        };
+        let filter = GitLogFilter {
+            author: self.ui_state.release_notes_author.clone(),
+            since,
+            until: self.ui_state.release_notes_until,
+            paths: self
+                .ui_state
+                .release_notes_path_filter
+                .iter()
+                .cloned()
+                .collect(),
+        };
+        let commits = git_repo.get_commits_filtered(last_tag.as_deref(), &filter)?;
+
+        if let Some(since) = since {
+            info!(%since, "Generating release notes for commits since date");
+        } else if let Some(tag) = &last_tag {
+            info!("Generating release notes for commits since tag: {}", tag);
+        } else {
+            info!("No previous tag found, generating release notes for all commits");
+        }
+
+        Ok(ReleaseNotesCommits::Repository(commits))
+    }
+}
+
 #[allow(async_fn_in_trait)]
 pub trait ReleaseNotesOperations {
     async fn handle_release_notes_generation(&mut self) -> Result<()>;
     async fn generate_release_notes_with_npm_wrapper(&mut self) -> Result<()>;
+    async fn retry_release_notes_gemini(&mut self) -> Result<()>;
 }
 
 impl ReleaseNotesOperations for App {
@@ -24,168 +116,453 @@ impl ReleaseNotesOperations for App {
         if matches!(self.current_state, AppState::Loading) {
             return Ok(());
         }
+        if self
+            .background_task_manager
+            .is_exclusive_running(RELEASE_NOTES_KIND)
+        {
+            self.message = Some(self.config.tr("release_notes.already_running").to_string());
+            return Ok(());
+        }
+
+        let commits = self.collect_release_notes_commits()?;
+
+        // Same commits as an earlier run whose files are still there: point to
+        // it, and only regenerate when asked again
+        if let Some(previous) = previous_generation(&self.config, &commits) {
+            if self.ui_state.release_notes_regenerate.as_ref() != Some(&previous.head_commit) {
+                self.message = Some(format!(
+                    "{} {} ({}). {}",
+                    self.config.tr("release_notes.already_generated"),
+                    previous
+                        .generated_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M"),
+                    previous.prefix,
+                    self.config.tr("release_notes.regenerate_hint")
+                ));
+                self.ui_state.release_notes_regenerate = Some(previous.head_commit);
+                return Ok(());
+            }
+        }
+        self.ui_state.release_notes_regenerate = None;
 
         // MODERN ASYNC APPROACH: Use BackgroundTaskManager
         self.current_state = AppState::Loading;
-        self.message = Some("🚀 Iniciando generación de notas de versión...".to_string());
+        self.message = Some(self.config.tr("release_notes.started").to_string());
 
-        // Get commits since last tag only (for release notes)
-        let git_repo = GitRepo::new()?;
-        let last_tag = git_repo.get_last_tag()?;
-        let commits = git_repo.get_commits_since_tag(last_tag.as_deref())?;
+        let started = match commits {
+            ReleaseNotesCommits::Packages(packages) => {
+                self.background_task_manager
+                    .start_package_release_notes_generation(&self.config, packages)
+                    .await
+            }
+            ReleaseNotesCommits::Repository(commits) => {
+                self.background_task_manager
+                    .start_release_notes_generation(&self.config, commits)
+                    .await
+            }
+        };
 
-        if let Some(tag) = &last_tag {
-            info!("Generating release notes for commits since tag: {}", tag);
-        } else {
-            info!("No previous tag found, generating release notes for all commits");
+        match started {
+            Ok(Some(_operation_id)) => {
+                info!("Release notes generation started via BackgroundTaskManager");
+            }
+            Ok(None) => {
+                self.message = Some(self.config.tr("release_notes.already_running").to_string());
+            }
+            Err(e) => {
+                self.current_state = AppState::Error(format!(
+                    "{}: {}",
+                    self.config.tr("release_notes.start_error"),
+                    e
+                ));
+                self.message = Some(format!("❌ {}", e));
+            }
         }
 
-        // Start async release notes generation
+        Ok(())
+    }
+
+    async fn generate_release_notes_with_npm_wrapper(&mut self) -> Result<()> {
+        if matches!(self.current_state, AppState::Loading)
+            || self.unavailable_offline("npm run release-notes")
+        {
+            return Ok(());
+        }
+
+        // Missing npm or script: stay on the screen so 'i' can be pressed right away
+        if let Err(problem) = check_npm_release_notes_script() {
+            warn!(%problem, "npm release notes generation unavailable");
+            self.message = Some(format!(
+                "❌ {}. {}",
+                problem,
+                self.config.tr("release_notes.use_internal")
+            ));
+            return Ok(());
+        }
+
+        self.current_state = AppState::Loading;
+        self.message = Some(self.config.tr("release_notes.npm_running").to_string());
+        self.ui_state.script_output.clear();
+        self.ui_state.script_output_scroll = 0;
+
+        if let Err(e) = self.background_task_manager.start_npm_release_notes().await {
+            self.current_state = AppState::Error(format!("Error ejecutando npm: {}", e));
+            self.message = Some(format!("❌ {}", e));
+        }
+
+        Ok(())
+    }
+
+    async fn retry_release_notes_gemini(&mut self) -> Result<()> {
+        if matches!(self.current_state, AppState::Loading) || self.unavailable_offline("Gemini") {
+            return Ok(());
+        }
+        if self
+            .background_task_manager
+            .is_exclusive_running(RELEASE_NOTES_KIND)
+        {
+            self.message = Some(self.config.tr("release_notes.already_running").to_string());
+            return Ok(());
+        }
+        if self.config.gemini_token.is_none() {
+            self.message = Some(self.config.tr("release_notes.no_gemini").to_string());
+            return Ok(());
+        }
+        let Some(document) = release_notes_retry_document(&self.config) else {
+            self.message = Some(self.config.tr("release_notes.nothing_to_retry").to_string());
+            return Ok(());
+        };
+
+        info!(document = %document.display(), "Retrying the Gemini step of the release notes");
+        self.current_state = AppState::Loading;
+        self.message = Some(format!(
+            "{} {}",
+            self.config.tr("release_notes.retrying_gemini"),
+            document.display()
+        ));
+
         match self
             .background_task_manager
-            .start_release_notes_generation(&self.config, commits)
+            .start_release_notes_gemini_retry(&self.config, document)
             .await
         {
-            Ok(_operation_id) => {
-                info!("Release notes generation started via BackgroundTaskManager");
+            Ok(Some(_operation_id)) => {}
+            Ok(None) => {
+                self.message = Some(self.config.tr("release_notes.already_running").to_string());
             }
             Err(e) => {
-                self.current_state = AppState::Error(format!("Error iniciando generación: {}", e));
+                self.current_state = AppState::Error(format!(
+                    "{}: {}",
+                    self.config.tr("release_notes.start_error"),
+                    e
+                ));
                 self.message = Some(format!("❌ {}", e));
             }
         }
 
         Ok(())
     }
+}
 
-    async fn generate_release_notes_with_npm_wrapper(&mut self) -> Result<()> {
-        self.current_state = crate::types::AppState::Loading;
+/// npm script run by the 'o' option of the release notes screen.
+const NPM_RELEASE_NOTES_SCRIPT: &str = "release-notes";
 
-        if let Err(e) = self.generate_release_notes_with_npm().await {
-            self.current_state = crate::types::AppState::Error(e.to_string());
-        } else {
-            self.current_state = crate::types::AppState::Normal;
-            self.message = Some("✅ Notas de versión generadas exitosamente".to_string());
-            self.current_screen = crate::types::AppScreen::Main;
+/// Why `npm run release-notes` can't be run in this repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NpmScriptProblem {
+    NpmNotInstalled,
+    PackageJsonMissing(PathBuf),
+    ScriptNotDefined(PathBuf),
+}
+
+impl std::fmt::Display for NpmScriptProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NpmNotInstalled => write!(f, "npm no está instalado o no está en el PATH"),
+            Self::PackageJsonMissing(path) => {
+                write!(f, "No se encontró {}", path.display())
+            }
+            Self::ScriptNotDefined(path) => write!(
+                f,
+                "El script '{}' no está definido en {}",
+                NPM_RELEASE_NOTES_SCRIPT,
+                path.display()
+            ),
         }
+    }
+}
+
+/// Check that npm is on the PATH and the repository's package.json defines the
+/// release notes script, so a missing setup is reported before running anything.
+pub fn check_npm_release_notes_script() -> std::result::Result<(), NpmScriptProblem> {
+    if find_in_path("npm").is_none() {
+        return Err(NpmScriptProblem::NpmNotInstalled);
+    }
+
+    let package_json = repository_root().unwrap_or_default().join("package.json");
+    let content = std::fs::read_to_string(&package_json)
+        .map_err(|_| NpmScriptProblem::PackageJsonMissing(package_json.clone()))?;
+
+    let has_script = serde_json::from_str::<serde_json::Value>(&content)
+        .ok()
+        .and_then(|manifest| {
+            manifest
+                .get("scripts")?
+                .get(NPM_RELEASE_NOTES_SCRIPT)
+                .map(|script| script.is_string())
+        })
+        .unwrap_or(false);
 
+    if has_script {
         Ok(())
+    } else {
+        Err(NpmScriptProblem::ScriptNotDefined(package_json))
     }
 }
 
-impl App {
-    pub async fn generate_release_notes_with_npm(&mut self) -> Result<()> {
-        // Shared state for communication between thread and UI
-        let npm_status = Arc::new(Mutex::new(String::from(
-            "🚀 Iniciando npm run release-notes...",
-        )));
-        let npm_finished = Arc::new(Mutex::new(false));
-        let npm_success = Arc::new(Mutex::new(true));
-
-        // Clone for the thread
-        let status_clone = npm_status.clone();
-        let finished_clone = npm_finished.clone();
-        let success_clone = npm_success.clone();
-
-        // Update initial status
-        self.message = Some("🚀 Iniciando npm run release-notes...".to_string());
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-
-        // Spawn npm command in background thread
-        thread::spawn(move || {
-            // Update status to indicate command execution
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "⚙️ Ejecutando npm run release-notes...".to_string();
-            }
-
-            // Execute npm command
-            let npm_output = Command::new("npm")
-                .args(["run", "release-notes"])
-                .stdin(Stdio::null())
-                .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .output();
-
-            match npm_output {
-                Ok(output) => {
-                    if output.status.success() {
-                        let stdout = String::from_utf8_lossy(&output.stdout);
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-
-                        // Parse output to extract information
-                        let mut status_message =
-                            "✅ Notas de versión generadas exitosamente".to_string();
-
-                        // Look for generated files in the output
-                        if stdout.contains("✅") || stderr.contains("✅") {
-                            status_message = stdout
-                                .lines()
-                                .find(|line| line.contains("✅"))
-                                .unwrap_or("✅ Notas de versión generadas exitosamente")
-                                .to_string();
-                        }
+/// Locate an executable on the PATH (also trying `.cmd`/`.exe` on Windows).
+fn find_in_path(program: &str) -> Option<PathBuf> {
+    let candidates: &[String] = if cfg!(windows) {
+        &[
+            format!("{}.cmd", program),
+            format!("{}.exe", program),
+            program.to_string(),
+        ]
+    } else {
+        &[program.to_string()]
+    };
 
-                        if let Ok(mut status) = status_clone.lock() {
-                            *status = status_message;
-                        }
-                    } else {
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        if let Ok(mut status) = status_clone.lock() {
-                            *status = format!("❌ Error en npm: {}", stderr);
-                        }
-                        if let Ok(mut success) = success_clone.lock() {
-                            *success = false;
-                        }
-                    }
-                }
-                Err(e) => {
-                    if let Ok(mut status) = status_clone.lock() {
-                        *status = format!("❌ Error ejecutando npm: {}", e);
-                    }
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
-            }
+    std::env::split_paths(&std::env::var_os("PATH")?).find_map(|dir| {
+        candidates
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|candidate| candidate.is_file())
+    })
+}
 
-            // Mark as finished
-            if let Ok(mut finished) = finished_clone.lock() {
-                *finished = true;
-            }
-        });
+/// Working directory of the enclosing git repository, if any.
+fn repository_root() -> Option<PathBuf> {
+    git2::Repository::discover(".")
+        .ok()
+        .and_then(|repo| repo.workdir().map(|dir| dir.to_path_buf()))
+}
 
-        // Wait for npm to finish and update UI
-        let mut current_status = String::new();
-        loop {
-            // Check if npm is finished
-            let is_finished = { npm_finished.lock().map(|f| *f).unwrap_or(false) };
-
-            // Update status message if it changed
-            if let Ok(status) = npm_status.lock() {
-                if *status != current_status {
-                    current_status = status.clone();
-                    self.message = Some(current_status.clone());
-                }
-            }
+/// Lines of the script's output quoted in the error when it fails.
+const SCRIPT_ERROR_TAIL_LINES: usize = 5;
+
+/// Run `npm run release-notes`, broadcasting every stdout/stderr line as it is
+/// printed. The child is killed if the operation is cancelled.
+pub async fn run_npm_release_notes_task(
+    event_tx: Sender<BackgroundEvent>,
+    _operation_id: String,
+) -> crate::error::Result<()> {
+    let spawned = Command::new("npm")
+        .args(["run", NPM_RELEASE_NOTES_SCRIPT])
+        .current_dir(repository_root().unwrap_or_else(|| PathBuf::from(".")))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            return fail_npm_release_notes(&event_tx, format!("Error ejecutando npm: {}", e)).await
+        }
+    };
+
+    let mut stdout_lines = child.stdout.take().map(|out| BufReader::new(out).lines());
+    let mut stderr_lines = child.stderr.take().map(|err| BufReader::new(err).lines());
+    let mut stdout_tail = VecDeque::new();
+    let mut stderr_tail = VecDeque::new();
+    let mut status_line = None;
 
-            if is_finished {
-                break;
+    while stdout_lines.is_some() || stderr_lines.is_some() {
+        let (line, is_stderr) = tokio::select! {
+            line = next_output_line(&mut stdout_lines), if stdout_lines.is_some() => (line, false),
+            line = next_output_line(&mut stderr_lines), if stderr_lines.is_some() => (line, true),
+        };
+
+        let Some(line) = line else {
+            if is_stderr {
+                stderr_lines = None;
+            } else {
+                stdout_lines = None;
             }
+            continue;
+        };
 
-            // Yield control to UI with short sleep
-            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        let tail = if is_stderr {
+            &mut stderr_tail
+        } else {
+            &mut stdout_tail
+        };
+        if tail.len() == SCRIPT_ERROR_TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(line.clone());
+        if !is_stderr && line.contains("✅") {
+            status_line = Some(line.clone());
         }
 
-        // Check if npm succeeded
-        let success = npm_success.lock().map(|s| *s).unwrap_or(false);
-        if !success {
-            return Err(crate::error::SemanticReleaseError::release_error(format!(
-                "Release notes operation failed: {}",
-                current_status
-            )));
+        if let Err(e) = event_tx
+            .broadcast(BackgroundEvent::ScriptOutput { line, is_stderr })
+            .await
+        {
+            warn!("Failed to broadcast script output: {}", e);
         }
+    }
 
-        Ok(())
+    let status = match child.wait().await {
+        Ok(status) => status,
+        Err(e) => {
+            return fail_npm_release_notes(&event_tx, format!("Error esperando a npm: {}", e)).await
+        }
+    };
+
+    if !status.success() {
+        let exit_code = status
+            .code()
+            .map(|code| code.to_string())
+            .unwrap_or_else(|| "señal".to_string());
+        let tail = if stderr_tail.is_empty() {
+            stdout_tail
+        } else {
+            stderr_tail
+        };
+        return fail_npm_release_notes(
+            &event_tx,
+            format!(
+                "El script '{}' falló (código {}): {}",
+                NPM_RELEASE_NOTES_SCRIPT,
+                exit_code,
+                Vec::from(tail).join(" | ")
+            ),
+        )
+        .await;
+    }
+
+    let message =
+        status_line.unwrap_or_else(|| "✅ Notas de versión generadas exitosamente".to_string());
+    if let Err(e) = event_tx
+        .broadcast(BackgroundEvent::ScriptCompleted(message))
+        .await
+    {
+        warn!("Failed to broadcast script completion: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Next line of a child output stream; `None` once it is closed or unreadable.
+async fn next_output_line<R: AsyncBufRead + Unpin>(lines: &mut Option<Lines<R>>) -> Option<String> {
+    match lines.as_mut()?.next_line().await {
+        Ok(line) => line,
+        Err(e) => {
+            warn!("Failed to read script output: {}", e);
+            None
+        }
+    }
+}
+
+async fn fail_npm_release_notes(
+    event_tx: &Sender<BackgroundEvent>,
+    message: String,
+) -> crate::error::Result<()> {
+    if let Err(e) = event_tx
+        .broadcast(BackgroundEvent::ScriptFailed(message.clone()))
+        .await
+    {
+        warn!("Failed to broadcast script failure: {}", e);
+    }
+    Err(SemanticReleaseError::release_error(format!(
+        "Release notes operation failed: {}",
+        message
+    )))
+}
+
+/// Template used when no release notes template file can be found.
+pub const DEFAULT_RELEASE_NOTES_TEMPLATE: &str = include_str!("../../scripts/plantilla.md");
+
+/// Default release notes template location, relative to the repository root.
+const DEFAULT_RELEASE_NOTES_TEMPLATE_PATH: &str = "scripts/plantilla.md";
+
+/// Resolve the configured release notes template path. Relative paths are
+/// resolved from the git repository root so the tool works from subdirectories.
+pub fn resolve_release_notes_template_path(config: &AppConfig) -> PathBuf {
+    let path = PathBuf::from(
+        config
+            .release_notes_template_path
+            .as_deref()
+            .unwrap_or(DEFAULT_RELEASE_NOTES_TEMPLATE_PATH),
+    );
+
+    if path.is_absolute() {
+        return path;
+    }
+
+    match repository_root() {
+        Some(root) => root.join(path),
+        None => path,
+    }
+}
+
+/// Fail when the release notes template file does not exist. Used by the
+/// strict CLI flow so CI never ships notes built from the embedded fallback.
+pub fn require_release_notes_template(config: &AppConfig) -> Result<PathBuf> {
+    let template_path = resolve_release_notes_template_path(config);
+    if template_path.is_file() {
+        return Ok(template_path);
+    }
+    Err(SemanticReleaseError::config_error(format!(
+        "Release notes template not found at {} (strict mode; set RELEASE_NOTES_TEMPLATE or add the file)",
+        template_path.display()
+    )))
+}
+
+/// Write the embedded default template to the configured template path when
+/// nothing is there yet. Returns whether the file was created.
+pub fn ensure_release_notes_template(config: &AppConfig) -> Result<bool> {
+    let template_path = resolve_release_notes_template_path(config);
+    if template_path.exists() {
+        return Ok(false);
+    }
+    if let Some(parent) = template_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&template_path, DEFAULT_RELEASE_NOTES_TEMPLATE)?;
+    info!(path = %template_path.display(), "Created release notes template");
+    Ok(true)
+}
+
+/// Load the release notes template, falling back to the embedded default.
+pub fn load_release_notes_template(config: &AppConfig) -> String {
+    let template_path = resolve_release_notes_template_path(config);
+    match std::fs::read_to_string(&template_path) {
+        Ok(content) => content,
+        Err(e) => {
+            warn!(
+                "Failed to read template file {}, using embedded default: {}",
+                template_path.display(),
+                e
+            );
+            DEFAULT_RELEASE_NOTES_TEMPLATE.to_string()
+        }
+    }
+}
+
+/// Broadcast a release notes progress update. `progress` goes from 0.0 to 1.0.
+async fn broadcast_progress(event_tx: &Sender<BackgroundEvent>, status: &str, progress: f32) {
+    if let Err(e) = event_tx
+        .broadcast(BackgroundEvent::ReleaseNotesProgress {
+            status: status.to_string(),
+            progress,
+        })
+        .await
+    {
+        warn!("Failed to broadcast progress: {}", e);
     }
 }
 
@@ -197,14 +574,7 @@ async fn analyze_commits_with_ai(
 ) -> crate::error::Result<String> {
     if let Some(_token) = &config.gemini_token {
         // Update progress
-        if let Err(e) = event_tx
-            .broadcast(BackgroundEvent::ReleaseNotesProgress(
-                "Running AI analysis on commits...".to_string(),
-            ))
-            .await
-        {
-            warn!("Failed to broadcast AI progress: {}", e);
-        }
+        broadcast_progress(event_tx, "Running AI analysis on commits...", 0.3).await;
 
         // TODO: Implement proper Gemini service integration
         // For now, return a placeholder
@@ -217,27 +587,392 @@ async fn analyze_commits_with_ai(
     }
 }
 
-fn add_commit_section(release_notes: &mut String, title: &str, commits: &[&GitCommit]) {
-    if !commits.is_empty() {
-        release_notes.push_str(&format!("## {}\n\n", title));
-        for commit in commits {
-            let scope_str = if let Some(scope) = &commit.scope {
-                if !scope.is_empty() {
-                    format!("**{}**: ", scope)
+/// Release notes sections in display order, keyed by conventional commit type.
+/// Unknown types end up under chores.
+const TYPE_SECTIONS: [(&str, &str); 9] = [
+    ("feat", "✨ New Features"),
+    ("fix", "🐛 Bug Fixes"),
+    ("perf", "⚡ Performance Improvements"),
+    ("refactor", "♻️  Code Refactoring"),
+    ("docs", "📚 Documentation"),
+    ("test", "🧪 Tests"),
+    ("style", "💎 Style Changes"),
+    ("chore", "🔧 Chores"),
+    ("revert", "⏪ Reverts"),
+];
+
+/// Section title for commits without a scope.
+const GENERAL_SCOPE: &str = "general";
+
+/// What each commit line is prefixed with, depending on what the section already groups by.
+#[derive(Clone, Copy)]
+enum CommitLabel {
+    Scope,
+    Type,
+    None,
+}
+
+fn group_commits_by_type<'a>(commits: &[&'a GitCommit]) -> Vec<(&'static str, Vec<&'a GitCommit>)> {
+    TYPE_SECTIONS
+        .iter()
+        .map(|(commit_type, title)| {
+            let group = commits
+                .iter()
+                .filter(|commit| {
+                    let section = commit
+                        .commit_type
+                        .as_deref()
+                        .filter(|t| TYPE_SECTIONS.iter().any(|(known, _)| known == t))
+                        .unwrap_or("chore");
+                    section == *commit_type
+                })
+                .copied()
+                .collect();
+            (*title, group)
+        })
+        .collect()
+}
+
+/// Commits without a known conventional type; the type grouping files them under chores.
+pub fn non_conventional_commits(commits: &[GitCommit]) -> Vec<&GitCommit> {
+    commits
+        .iter()
+        .filter(|commit| match commit.commit_type.as_deref() {
+            Some(commit_type) => !TYPE_SECTIONS.iter().any(|(known, _)| *known == commit_type),
+            None => true,
+        })
+        .collect()
+}
+
+/// One-line summary of the commits outside the convention, for logs and the UI.
+pub fn non_conventional_warning(commits: &[&GitCommit]) -> String {
+    format!(
+        "{} commits no siguen Conventional Commits y se agruparon en Chores: {}",
+        commits.len(),
+        commits
+            .iter()
+            .map(|commit| &commit.hash[..commit.hash.len().min(8)])
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Whether `author` matches one of the bot name fragments.
+fn is_bot_author(author: &str, bots: &[String]) -> bool {
+    let author = author.to_lowercase();
+    bots.iter()
+        .any(|bot| !bot.trim().is_empty() && author.contains(&bot.trim().to_lowercase()))
+}
+
+/// Person responsible for the release: the configured one (`--responsible`,
+/// `RELEASE_RESPONSIBLE`), otherwise the author of most commits in the range.
+/// Bots and merge commits are not counted; ties go to the most recent author.
+pub fn release_responsible(config: &AppConfig, commits: &[GitCommit]) -> Option<String> {
+    if let Some(responsible) = config
+        .release_responsible
+        .as_deref()
+        .map(str::trim)
+        .filter(|responsible| !responsible.is_empty())
+    {
+        return Some(responsible.to_string());
+    }
+
+    let bots = config.release_bot_authors();
+    // Commits come newest first; the first author seen keeps the lowest rank
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for commit in commits {
+        if commit.subject.starts_with("Merge ") || is_bot_author(&commit.author, &bots) {
+            continue;
+        }
+        match counts
+            .iter_mut()
+            .find(|(author, _)| *author == commit.author)
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((commit.author.as_str(), 1)),
+        }
+    }
+    counts
+        .iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(author, _)| author.to_string())
+}
+
+fn add_non_conventional_section(release_notes: &mut String, commits: &[&GitCommit]) {
+    if commits.is_empty() {
+        return;
+    }
+    release_notes.push_str("## ⚠️  Commits fuera de Conventional Commits\n\n");
+    for commit in commits {
+        release_notes.push_str(&format!(
+            "- `{}` {} ({})\n",
+            &commit.hash[..commit.hash.len().min(8)],
+            commit.subject,
+            commit.author
+        ));
+    }
+    release_notes.push('\n');
+}
+
+static SECURITY_SEVERITY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^\W*(cr[ií]tic[ao]|critical|alta|high|media|medium|moderate|baja|low)\b\W*")
+        .expect("security severity pattern is valid")
+});
+
+/// Headings of the security summary, most severe first. Values without a
+/// recognized severity prefix go last.
+const SECURITY_SEVERITIES: [&str; 5] = ["Crítica", "Alta", "Media", "Baja", "Sin severidad"];
+
+/// Severity heading index and remaining text of a `Security` trailer, or
+/// `None` when it says there is nothing to report (`NA`, `ninguno`...).
+fn classify_security_note(value: &str) -> Option<(usize, String)> {
+    let value = value.trim();
+    let normalized = value.trim_end_matches('.').to_lowercase();
+    if matches!(
+        normalized.as_str(),
+        "" | "na" | "n/a" | "no" | "none" | "ninguno" | "ninguna" | "-"
+    ) {
+        return None;
+    }
+
+    let Some(captures) = SECURITY_SEVERITY_RE.captures(value) else {
+        return Some((SECURITY_SEVERITIES.len() - 1, value.to_string()));
+    };
+    let level = match captures[1].to_lowercase().as_str() {
+        "crítica" | "critica" | "crítico" | "critico" | "critical" => 0,
+        "alta" | "high" => 1,
+        "media" | "medium" | "moderate" => 2,
+        _ => 3,
+    };
+    let rest = value[captures.get(0).map_or(0, |m| m.end())..].trim();
+    Some((
+        level,
+        if rest.is_empty() { value } else { rest }.to_string(),
+    ))
+}
+
+/// "Resumen de Seguridad": every commit with a `Security` trailer, grouped by
+/// the severity it starts with (`Alta: ...`, `[low] ...`). Left out when no
+/// commit reports anything.
+fn add_security_summary_section(release_notes: &mut String, commits: &[GitCommit]) {
+    let mut by_severity: Vec<Vec<(&GitCommit, String)>> =
+        vec![Vec::new(); SECURITY_SEVERITIES.len()];
+    for commit in commits {
+        let Some(value) = commit.trailers.get("security") else {
+            continue;
+        };
+        if let Some((level, note)) = classify_security_note(value) {
+            by_severity[level].push((commit, note));
+        }
+    }
+    if by_severity.iter().all(Vec::is_empty) {
+        return;
+    }
+
+    release_notes.push_str("## 🔒 Resumen de Seguridad\n\n");
+    for (severity, notes) in SECURITY_SEVERITIES.iter().zip(&by_severity) {
+        if notes.is_empty() {
+            continue;
+        }
+        release_notes.push_str(&format!("### {}\n\n", severity));
+        for (commit, note) in notes {
+            release_notes.push_str(&format!(
+                "- `{}` {}: {}\n",
+                &commit.hash[..commit.hash.len().min(8)],
+                commit.description,
+                note.split_whitespace().collect::<Vec<_>>().join(" ")
+            ));
+        }
+        release_notes.push('\n');
+    }
+}
+
+/// Monday item IDs (digits) or JIRA keys (`PROJ-123`).
+fn is_task_id(value: &str) -> bool {
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+    match value.split_once('-') {
+        Some((project, number)) => {
+            (2..=10).contains(&project.len())
+                && project.chars().all(|c| c.is_ascii_uppercase())
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Group by scope: free-text scopes first (alphabetically, commits without
+/// scope last under "general"), then scopes made of task IDs like `123|456`.
+fn group_commits_by_scope<'a>(commits: &[&'a GitCommit]) -> Vec<(String, Vec<&'a GitCommit>)> {
+    let mut text_scopes: BTreeMap<String, Vec<&GitCommit>> = BTreeMap::new();
+    let mut task_scopes: BTreeMap<String, Vec<&GitCommit>> = BTreeMap::new();
+    let mut general = Vec::new();
+
+    for commit in commits {
+        let scope = commit.scope.as_deref().map(str::trim).unwrap_or_default();
+        // A shortened scope (`123…`) stands for every task in the commit's trailer
+        let task_ids: Vec<&str> = match scope.strip_suffix('…').filter(|first| is_task_id(first))
+        {
+            Some(first) => {
+                let trailer_ids: Vec<&str> = commit
+                    .monday_tasks
+                    .iter()
+                    .chain(&commit.jira_tasks)
+                    .map(String::as_str)
+                    .collect();
+                if trailer_ids.is_empty() {
+                    vec![first]
                 } else {
-                    String::new()
+                    trailer_ids
                 }
-            } else {
-                String::new()
+            }
+            None => scope
+                .split('|')
+                .map(str::trim)
+                .filter(|part| !part.is_empty())
+                .collect(),
+        };
+
+        if scope.is_empty() || scope == GENERAL_SCOPE {
+            general.push(*commit);
+        } else if task_ids.iter().all(|part| is_task_id(part)) {
+            task_scopes
+                .entry(task_ids.join(", "))
+                .or_default()
+                .push(*commit);
+        } else {
+            text_scopes
+                .entry(scope.to_string())
+                .or_default()
+                .push(*commit);
+        }
+    }
+
+    let mut groups: Vec<(String, Vec<&GitCommit>)> = text_scopes
+        .into_iter()
+        .map(|(scope, group)| (format!("📁 {}", scope), group))
+        .collect();
+    if !general.is_empty() {
+        groups.push((format!("📁 {}", GENERAL_SCOPE), general));
+    }
+    groups.extend(
+        task_scopes
+            .into_iter()
+            .map(|(tasks, group)| (format!("🔗 Tasks: {}", tasks), group)),
+    );
+    groups
+}
+
+/// Rough token count (~4 characters per token) used to log the size of the
+/// document sent to Gemini.
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Append the commit sections, writing bodies in `body_format`. Without one
+/// (compact mode) commit bodies and per-commit task references are left out:
+/// the task section already lists the tasks, and the bodies are what makes the
+/// document outgrow Gemini's context.
+fn add_commit_sections(
+    release_notes: &mut String,
+    commits: &[GitCommit],
+    grouping: GroupingStrategy,
+    trailers: &[CommitTrailer],
+    body_format: Option<BodyFormat>,
+) {
+    // Newest first, hash as tie-breaker, so reruns over the same range are identical
+    let mut commits: Vec<&GitCommit> = commits.iter().collect();
+    commits.sort_by(|a, b| {
+        b.timestamp
+            .cmp(&a.timestamp)
+            .then_with(|| a.hash.cmp(&b.hash))
+    });
+
+    match grouping {
+        GroupingStrategy::ByType => {
+            for (title, group) in group_commits_by_type(&commits) {
+                add_commit_section(
+                    release_notes,
+                    "##",
+                    title,
+                    &group,
+                    CommitLabel::Scope,
+                    trailers,
+                    body_format,
+                );
+            }
+        }
+        GroupingStrategy::ByScope => {
+            for (title, group) in group_commits_by_scope(&commits) {
+                add_commit_section(
+                    release_notes,
+                    "##",
+                    &title,
+                    &group,
+                    CommitLabel::Type,
+                    trailers,
+                    body_format,
+                );
+            }
+        }
+        GroupingStrategy::ByTypeThenScope => {
+            for (title, group) in group_commits_by_type(&commits) {
+                if group.is_empty() {
+                    continue;
+                }
+                release_notes.push_str(&format!("## {}\n\n", title));
+                for (scope_title, scope_group) in group_commits_by_scope(&group) {
+                    add_commit_section(
+                        release_notes,
+                        "###",
+                        &scope_title,
+                        &scope_group,
+                        CommitLabel::None,
+                        trailers,
+                        body_format,
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn add_commit_section(
+    release_notes: &mut String,
+    heading: &str,
+    title: &str,
+    commits: &[&GitCommit],
+    label: CommitLabel,
+    trailers: &[CommitTrailer],
+    body_format: Option<BodyFormat>,
+) {
+    if !commits.is_empty() {
+        release_notes.push_str(&format!("{} {}\n\n", heading, title));
+        for commit in commits {
+            let label_text = match label {
+                CommitLabel::Scope => commit.scope.as_deref(),
+                CommitLabel::Type => commit.commit_type.as_deref(),
+                CommitLabel::None => None,
+            };
+            let label_str = match label_text {
+                Some(text) if !text.is_empty() => format!("**{}**: ", text),
+                _ => String::new(),
             };
 
             release_notes.push_str(&format!(
                 "- {}{} ([{}])\n",
-                scope_str,
+                label_str,
                 commit.description,
                 &commit.hash[..8]
             ));
 
+            let Some(body_format) = body_format else {
+                continue;
+            };
+
             // Add task references if available
             if !commit.monday_tasks.is_empty() || !commit.jira_tasks.is_empty() {
                 let mut task_refs = Vec::new();
@@ -249,23 +984,133 @@ fn add_commit_section(release_notes: &mut String, title: &str, commits: &[&GitCo
                 }
             }
 
-            if !commit.body.trim().is_empty() && commit.body.len() > 50 {
+            // Recognized trailers get their own line, so leave them out of the
+            // body, as the template placeholders nobody filled in
+            let body = strip_template_placeholders(&body_without_trailers(&commit.body, trailers));
+            if !body.trim().is_empty() && body.len() > 50 {
                 // Add commit body if it's substantial
-                release_notes.push_str(&format!("  - {}\n", commit.body.trim()));
+                release_notes.push_str(&format!("  - {}\n", format_body(&body, body_format)));
+            }
+
+            for trailer in trailers {
+                if let Some(value) = commit.trailers.get(&trailer.field) {
+                    // Keep multi-line values inside the list item
+                    let value = value.lines().map(str::trim).collect::<Vec<_>>().join(" ");
+                    release_notes.push_str(&format!("  - **{}**: {}\n", trailer.key, value));
+                }
             }
         }
         release_notes.push('\n');
     }
 }
 
-#[instrument(skip(release_notes, commits, config))]
+/// Commit body as the text of a nested list item: on one line, or with its
+/// lines indented under the item so Markdown keeps its bullets and code blocks.
+fn format_body(body: &str, body_format: BodyFormat) -> String {
+    match body_format {
+        BodyFormat::Collapsed => body
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" | "),
+        BodyFormat::Preserved => body
+            .trim_matches('\n')
+            .lines()
+            .enumerate()
+            .map(|(i, line)| match (i, line.trim_end()) {
+                (0, line) => line.trim_start().to_string(),
+                (_, "") => String::new(),
+                (_, line) => format!("    {}", line),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Commit sections of the customer-facing variant: internal commit types
+/// (`RELEASE_NOTES_INTERNAL_TYPES`) become a single summary line instead of
+/// one line each, and commit bodies are left out.
+fn add_public_commit_sections(
+    release_notes: &mut String,
+    commits: &[GitCommit],
+    config: &AppConfig,
+) {
+    let internal_types = config.release_notes_internal_types();
+    // Commits outside the convention are filed under chores, so they follow them
+    let commit_type = |commit: &GitCommit| match commit.commit_type.as_deref() {
+        Some(t)
+            if TYPE_SECTIONS.iter().any(|(known, _)| *known == t)
+                || internal_types.iter().any(|internal| internal == t) =>
+        {
+            t.to_string()
+        }
+        _ => "chore".to_string(),
+    };
+    let (internal, public): (Vec<GitCommit>, Vec<GitCommit>) = commits
+        .iter()
+        .cloned()
+        .partition(|commit| internal_types.contains(&commit_type(commit)));
+
+    add_commit_sections(
+        release_notes,
+        &public,
+        config.release_notes_grouping,
+        &config.commit_trailers(),
+        None,
+    );
+
+    if !internal.is_empty() {
+        let present: Vec<&str> = internal_types
+            .iter()
+            .filter(|internal_type| {
+                internal
+                    .iter()
+                    .any(|commit| commit_type(commit) == **internal_type)
+            })
+            .map(String::as_str)
+            .collect();
+        release_notes.push_str(&format!(
+            "## 🔧 Cambios internos\n\n- {} cambios internos ({})\n\n",
+            internal.len(),
+            present.join("/")
+        ));
+    }
+}
+
+/// `body` without the blocks of the given trailers (the trailer line and the
+/// lines that follow it up to a blank line).
+fn body_without_trailers(body: &str, trailers: &[CommitTrailer]) -> String {
+    let mut in_trailer = false;
+    body.lines()
+        .filter(|line| {
+            let starts_trailer = trailers.iter().any(|trailer| {
+                line.split_at_checked(trailer.key.len())
+                    .is_some_and(|(key, rest)| {
+                        key.eq_ignore_ascii_case(&trailer.key) && rest.starts_with(':')
+                    })
+            });
+            if starts_trailer {
+                in_trailer = true;
+            } else if line.trim().is_empty() {
+                in_trailer = false;
+            }
+            !in_trailer
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[instrument(skip(release_notes, commits, config, event_tx))]
 async fn add_task_management_section(
     release_notes: &mut String,
     commits: &[GitCommit],
     config: &AppConfig,
+    event_tx: &Sender<BackgroundEvent>,
 ) {
-    let mut monday_tasks = std::collections::HashSet::new();
-    let mut jira_tasks = std::collections::HashSet::new();
+    // Sorted sets keep the task lists in the same order on every run
+    let mut monday_tasks = std::collections::BTreeSet::new();
+    let mut jira_tasks = std::collections::BTreeSet::new();
 
     // Collect unique task references
     for commit in commits {
@@ -280,11 +1125,58 @@ async fn add_task_management_section(
     if !monday_tasks.is_empty() || !jira_tasks.is_empty() {
         release_notes.push_str("## 📋 Related Tasks\n\n");
 
+        // Task phase spans 0.4..0.6 of the overall progress
+        let total_tasks = monday_tasks.len() + jira_tasks.len();
+        let mut processed_tasks = 0;
+
         if !monday_tasks.is_empty() && config.is_monday_configured() {
             release_notes.push_str("### Monday.com Tasks\n");
-            for task_id in &monday_tasks {
-                // TODO: Implement async Monday service integration
-                release_notes.push_str(&format!("- {}\n", task_id));
+            let task_ids: Vec<String> = monday_tasks.into_iter().collect();
+
+            // Resolve details concurrently; failed lookups fall back to the bare ID
+            let mut resolved = std::collections::HashMap::new();
+            // Offline the lookups would only time out, so only the IDs are listed
+            let client = if config.offline {
+                Err(SemanticReleaseError::connection_error(
+                    "Monday.com",
+                    std::io::Error::other("offline mode"),
+                ))
+            } else {
+                MondayClient::new(config)
+            };
+            match client {
+                Ok(client) => {
+                    let client =
+                        client.with_rate_limit_listener(rate_limit_progress(config, event_tx));
+                    let mut details =
+                        client.get_task_details(task_ids.clone(), config.monday_task_concurrency());
+                    while let Some((task_id, task)) = details.next().await {
+                        match task {
+                            Ok(task) => {
+                                resolved.insert(task_id, task);
+                            }
+                            Err(e) => {
+                                warn!(%task_id, error = %e, "Could not fetch Monday.com task details");
+                            }
+                        }
+                        processed_tasks += 1;
+                        broadcast_task_progress(event_tx, config, processed_tasks, total_tasks)
+                            .await;
+                    }
+                }
+                Err(e) => {
+                    warn!(error = %e, "Monday.com client unavailable, listing task IDs only");
+                    processed_tasks += task_ids.len();
+                    broadcast_task_progress(event_tx, config, processed_tasks, total_tasks).await;
+                }
+            }
+
+            for task_id in &task_ids {
+                match resolved.get(task_id) {
+                    Some(task) => release_notes
+                        .push_str(&format!("- [{}]({}) ({})\n", task.title, task.url, task_id)),
+                    None => release_notes.push_str(&format!("- {}\n", task_id)),
+                }
             }
             release_notes.push('\n');
         }
@@ -294,12 +1186,51 @@ async fn add_task_management_section(
             for task_key in &jira_tasks {
                 // TODO: Implement async JIRA service integration
                 release_notes.push_str(&format!("- {}\n", task_key));
+                processed_tasks += 1;
+                broadcast_task_progress(event_tx, config, processed_tasks, total_tasks).await;
             }
             release_notes.push('\n');
         }
     }
 }
 
+/// Rate limiter listener that tells the progress screen why task resolution
+/// stalled. Runs inside the client, so it can only `try_broadcast`; the bar
+/// stays at the start of the task phase until the next lookup completes.
+fn rate_limit_progress(
+    config: &AppConfig,
+    event_tx: &Sender<BackgroundEvent>,
+) -> crate::services::rate_limit::RateLimitListener {
+    let event_tx = event_tx.clone();
+    let waiting = crate::i18n::tr(config.language, "monday.rate_limited");
+    std::sync::Arc::new(move |wait: std::time::Duration| {
+        let _ = event_tx.try_broadcast(BackgroundEvent::ReleaseNotesProgress {
+            status: format!("{} {:.1}s...", waiting, wait.as_secs_f64()),
+            progress: 0.4,
+        });
+    })
+}
+
+async fn broadcast_task_progress(
+    event_tx: &Sender<BackgroundEvent>,
+    config: &AppConfig,
+    done: usize,
+    total: usize,
+) {
+    let progress = 0.4 + 0.2 * (done as f32 / total.max(1) as f32);
+    broadcast_progress(
+        event_tx,
+        &format!(
+            "{} ({}/{})",
+            config.tr("release_notes.task_progress"),
+            done,
+            total
+        ),
+        progress,
+    )
+    .await;
+}
+
 #[instrument(skip(event_tx, config, commits))]
 pub async fn generate_release_notes_task(
     event_tx: Sender<BackgroundEvent>,
@@ -309,71 +1240,136 @@ pub async fn generate_release_notes_task(
 ) -> crate::error::Result<()> {
     info!("Starting release notes generation task");
 
-    // Broadcast progress: preparation phase
+    let result = write_release_notes(&event_tx, &config, &commits, None).await?;
+
     if let Err(e) = event_tx
-        .broadcast(BackgroundEvent::ReleaseNotesProgress(
-            "Preparing commit data for analysis...".to_string(),
-        ))
+        .broadcast(BackgroundEvent::ReleaseNotesCompleted(result))
         .await
     {
-        warn!("Failed to broadcast progress: {}", e);
+        warn!("Failed to broadcast completion: {}", e);
+    }
+
+    info!("Release notes generation completed successfully");
+    Ok(())
+}
+
+/// Generate independent release notes for each configured package in a single operation.
+#[instrument(skip(event_tx, config, packages))]
+pub async fn generate_package_release_notes_task(
+    event_tx: Sender<BackgroundEvent>,
+    operation_id: String,
+    config: AppConfig,
+    packages: Vec<(ReleasePackage, Vec<GitCommit>)>,
+) -> crate::error::Result<()> {
+    info!(
+        package_count = packages.len(),
+        "Starting package release notes generation task"
+    );
+
+    let mut results = Vec::new();
+    for (package, commits) in &packages {
+        info!(package = %package.path, "Generating release notes for package");
+        let mut result = write_release_notes(&event_tx, &config, commits, Some(package)).await?;
+        result["package"] = serde_json::json!(package.path);
+        results.push(result);
     }
 
+    let message = format!(
+        "Release notes generated for {} packages: {}",
+        packages.len(),
+        packages
+            .iter()
+            .zip(&results)
+            .map(|((package, _), result)| match result["next_tag"].as_str() {
+                Some(tag) => format!("{} ({})", package.path, tag),
+                None => package.path.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    if let Err(e) = event_tx
+        .broadcast(BackgroundEvent::ReleaseNotesCompleted(serde_json::json!({
+            "packages": results,
+            "status": "completed",
+            "message": message
+        })))
+        .await
+    {
+        warn!("Failed to broadcast completion: {}", e);
+    }
+
+    info!("Package release notes generation completed successfully");
+    Ok(())
+}
+
+/// File name stem shared by every artifact of one run: `release-notes[-<package>]-<timestamp>`.
+fn release_notes_file_prefix(
+    package: Option<&ReleasePackage>,
+    timestamp: &impl std::fmt::Display,
+) -> String {
+    match package {
+        Some(package) => format!("release-notes-{}-{}", package.slug(), timestamp),
+        None => format!("release-notes-{}", timestamp),
+    }
+}
+
+/// Build, save and (optionally) AI-process the release notes for `commits`.
+/// Returns the completion payload without broadcasting it.
+async fn write_release_notes(
+    event_tx: &Sender<BackgroundEvent>,
+    config: &AppConfig,
+    commits: &[GitCommit],
+    package: Option<&ReleasePackage>,
+) -> crate::error::Result<serde_json::Value> {
+    // Broadcast progress: preparation phase
+    broadcast_progress(event_tx, "Preparing commit data for analysis...", 0.05).await;
+
+    // A package is released under its own tag, computed from its own history
+    let version_info = release_version_info(config, package);
+    let package_tag = package
+        .and_then(|_| version_info.as_ref())
+        .and_then(|info| info.next_tag.clone());
+
     let mut release_notes = String::new();
-    release_notes.push_str("# 🚀 Release Notes\n\n");
+    match (package, &package_tag) {
+        (Some(package), Some(tag)) => release_notes.push_str(&format!(
+            "# 🚀 Release Notes ({} {})\n\n",
+            package.path, tag
+        )),
+        (Some(package), None) => {
+            release_notes.push_str(&format!("# 🚀 Release Notes ({})\n\n", package.path))
+        }
+        (None, _) => release_notes.push_str("# 🚀 Release Notes\n\n"),
+    }
 
     if commits.is_empty() {
         let message = "No commits found for release notes generation.";
-        if let Err(e) = event_tx
-            .broadcast(BackgroundEvent::ReleaseNotesCompleted(
-                serde_json::json!({"message": message, "status": "completed"}),
-            ))
-            .await
-        {
-            warn!("Failed to broadcast completion: {}", e);
+        return Ok(serde_json::json!({"message": message, "status": "completed"}));
+    }
+
+    // Gemini fills the template's "Responsable despliegue" from this line
+    let responsible = release_responsible(config, commits);
+    match &responsible {
+        Some(responsible) => {
+            release_notes.push_str(&format!("**👤 Responsable:** {}\n\n", responsible))
         }
-        return Ok(());
+        None => warn!("No human author found to take as responsible for the release"),
     }
 
     // Broadcast progress: categorization phase
-    if let Err(e) = event_tx
-        .broadcast(BackgroundEvent::ReleaseNotesProgress(
-            "Categorizing commits by type...".to_string(),
-        ))
-        .await
-    {
-        warn!("Failed to broadcast progress: {}", e);
+    broadcast_progress(event_tx, "Categorizing commits by type...", 0.15).await;
+
+    let non_conventional = non_conventional_commits(commits);
+    let convention_warning =
+        (!non_conventional.is_empty()).then(|| non_conventional_warning(&non_conventional));
+    if let Some(warning) = &convention_warning {
+        warn!(count = non_conventional.len(), "{}", warning);
     }
 
-    // Group commits by type with better organization
-    let mut features = Vec::new();
-    let mut fixes = Vec::new();
-    let mut docs = Vec::new();
-    let mut style = Vec::new();
-    let mut refactor = Vec::new();
-    let mut performance = Vec::new();
-    let mut tests = Vec::new();
-    let mut chores = Vec::new();
-    let mut reverts = Vec::new();
     let mut breaking_changes = Vec::new();
-
-    for commit in &commits {
-        if !commit.breaking_changes.is_empty() {
-            breaking_changes.extend(commit.breaking_changes.iter().cloned());
-        }
-
-        match commit.commit_type.as_deref() {
-            Some("feat") => features.push(commit),
-            Some("fix") => fixes.push(commit),
-            Some("docs") => docs.push(commit),
-            Some("style") => style.push(commit),
-            Some("refactor") => refactor.push(commit),
-            Some("perf") => performance.push(commit),
-            Some("test") => tests.push(commit),
-            Some("chore") => chores.push(commit),
-            Some("revert") => reverts.push(commit),
-            _ => chores.push(commit), // Default fallback
-        }
+    for commit in commits {
+        breaking_changes.extend(commit.breaking_changes.iter().cloned());
     }
 
     // Breaking Changes Section (highest priority)
@@ -385,19 +1381,20 @@ pub async fn generate_release_notes_task(
         release_notes.push('\n');
     }
 
+    // Security notes get their own section, apart from the breaking changes
+    add_security_summary_section(&mut release_notes, commits);
+
     // Broadcast progress: AI enhancement phase
-    if let Err(e) = event_tx
-        .broadcast(BackgroundEvent::ReleaseNotesProgress(
-            "Enhancing release notes with AI analysis...".to_string(),
-        ))
-        .await
-    {
-        warn!("Failed to broadcast progress: {}", e);
-    }
+    broadcast_progress(
+        event_tx,
+        "Enhancing release notes with AI analysis...",
+        0.25,
+    )
+    .await;
 
     // Enhanced sections with AI analysis if available
     if config.gemini_token.is_some() {
-        match analyze_commits_with_ai(&config, &commits, &event_tx).await {
+        match analyze_commits_with_ai(config, commits, event_tx).await {
             Ok(ai_analysis) => {
                 release_notes.push_str("## 🤖 AI Summary\n\n");
                 release_notes.push_str(&ai_analysis);
@@ -411,49 +1408,86 @@ pub async fn generate_release_notes_task(
     }
 
     // Standard sections
-    add_commit_section(&mut release_notes, "✨ New Features", &features);
-    add_commit_section(&mut release_notes, "🐛 Bug Fixes", &fixes);
-    add_commit_section(
-        &mut release_notes,
-        "⚡ Performance Improvements",
-        &performance,
+    let trailers = config.commit_trailers();
+    let mut sections = String::new();
+    add_commit_sections(
+        &mut sections,
+        commits,
+        config.release_notes_grouping,
+        &trailers,
+        (!config.release_notes_compact).then_some(config.release_notes_body_format),
     );
-    add_commit_section(&mut release_notes, "♻️  Code Refactoring", &refactor);
-    add_commit_section(&mut release_notes, "📚 Documentation", &docs);
-    add_commit_section(&mut release_notes, "🧪 Tests", &tests);
-    add_commit_section(&mut release_notes, "💎 Style Changes", &style);
-    add_commit_section(&mut release_notes, "🔧 Chores", &chores);
-    add_commit_section(&mut release_notes, "⏪ Reverts", &reverts);
+    if config.release_notes_compact {
+        let mut full_sections = String::new();
+        add_commit_sections(
+            &mut full_sections,
+            commits,
+            config.release_notes_grouping,
+            &trailers,
+            Some(config.release_notes_body_format),
+        );
+        info!(
+            full_tokens = estimate_tokens(&full_sections),
+            compact_tokens = estimate_tokens(&sections),
+            "Compact mode shrank the commit sections"
+        );
+    }
+    // The customer-facing variant shares everything but the commit sections
+    let mut public_notes = config.release_notes_public().then(|| {
+        let mut public_notes = release_notes.clone();
+        add_public_commit_sections(&mut public_notes, commits, config);
+        public_notes
+    });
+    release_notes.push_str(&sections);
 
     // Broadcast progress: task management integration
-    if let Err(e) = event_tx
-        .broadcast(BackgroundEvent::ReleaseNotesProgress(
-            "Integrating task management data...".to_string(),
-        ))
-        .await
-    {
-        warn!("Failed to broadcast progress: {}", e);
+    broadcast_progress(event_tx, "Integrating task management data...", 0.4).await;
+
+    // Add task management integration, fetched once for both variants
+    let mut task_section = String::new();
+    add_task_management_section(&mut task_section, commits, config, event_tx).await;
+    release_notes.push_str(&task_section);
+    if let Some(public_notes) = &mut public_notes {
+        public_notes.push_str(&task_section);
     }
 
-    // Add task management integration
-    add_task_management_section(&mut release_notes, &commits, &config).await;
+    if config.release_notes_convention_warnings {
+        add_non_conventional_section(&mut release_notes, &non_conventional);
+    }
 
-    // Broadcast progress: saving files
-    if let Err(e) = event_tx
-        .broadcast(BackgroundEvent::ReleaseNotesProgress(
-            "Creating release-notes directory and saving files...".to_string(),
-        ))
-        .await
-    {
-        warn!("Failed to broadcast progress: {}", e);
+    // Link (#123) references and commit hashes to the GitHub/GitLab/Bitbucket repository
+    let remote = GitRepo::new()
+        .ok()
+        .and_then(|git_repo| git_repo.get_remote_repo(config.git_host()));
+    if let Some(remote) = &remote {
+        release_notes = link_pr_references(&release_notes, remote);
+        add_commit_link_definitions(&mut release_notes, commits, remote);
+        if let Some(public_notes) = &mut public_notes {
+            *public_notes = link_pr_references(public_notes, remote);
+            add_commit_link_definitions(public_notes, commits, remote);
+        }
     }
 
+    // Broadcast progress: saving files
+    broadcast_progress(
+        event_tx,
+        "Creating release-notes directory and saving files...",
+        0.65,
+    )
+    .await;
+
     // Create output directory
-    if let Err(e) = std::fs::create_dir_all("release-notes") {
-        warn!("Could not create release-notes directory: {}", e);
+    let output_dir = config.release_notes_dir();
+    if let Err(e) = std::fs::create_dir_all(&output_dir) {
+        warn!(
+            "Could not create release notes directory {}: {}",
+            output_dir.display(),
+            e
+        );
         if let Err(e) = event_tx
             .broadcast(BackgroundEvent::ReleaseNotesError(format!(
-                "Failed to create release-notes directory: {}",
+                "Failed to create release notes directory {}: {}",
+                output_dir.display(),
                 e
             )))
             .await
@@ -461,18 +1495,32 @@ pub async fn generate_release_notes_task(
             warn!("Failed to broadcast error: {}", e);
         }
         return Err(SemanticReleaseError::config_error(format!(
-            "Could not create release-notes directory: {}",
+            "Could not create release notes directory {}: {}",
+            output_dir.display(),
             e
         )));
     }
 
-    // Generate filenames
-    let date_str = chrono::Utc::now().format("%Y-%m-%d").to_string();
-    let script_filename = format!(
-        "release-notes/release-notes-{}_SCRIPT_WITH_ENTER_KEY.md",
-        date_str
-    );
-    let gemini_filename = format!("release-notes/release-notes-{}_GEMINI.md", date_str);
+    // All files of one run share the timestamp so they can be paired
+    let now = chrono::Utc::now();
+    let date_str = now.format("%Y-%m-%d").to_string();
+    let file_prefix = release_notes_file_prefix(package, &now.format("%Y-%m-%d_%H%M%S"));
+    let script_filename = output_dir
+        .join(format!("{}.md", file_prefix))
+        .display()
+        .to_string();
+    let gemini_filename = output_dir
+        .join(format!("{}_GEMINI.md", file_prefix))
+        .display()
+        .to_string();
+    let html_filename = output_dir
+        .join(format!("{}.html", file_prefix))
+        .display()
+        .to_string();
+    let public_filename = output_dir
+        .join(format!("{}_PUBLIC.md", file_prefix))
+        .display()
+        .to_string();
 
     // Save the basic release notes file
     if let Err(e) = std::fs::write(&script_filename, &release_notes) {
@@ -497,79 +1545,93 @@ pub async fn generate_release_notes_task(
 
     info!("Successfully saved release notes to: {}", script_filename);
 
-    // Try to process with Gemini if configured
-    if config.gemini_token.is_some() {
-        if let Err(e) = event_tx
-            .broadcast(BackgroundEvent::ReleaseNotesProgress(
-                "Processing release notes with Gemini AI...".to_string(),
-            ))
-            .await
-        {
-            warn!("Failed to broadcast progress: {}", e);
-        }
-
-        // Read the template file
-        let template_path = std::path::Path::new("scripts/plantilla.md");
-        let template_content = match std::fs::read_to_string(template_path) {
-            Ok(content) => content,
+    // The customer-facing variant is a convenience; the full document is what matters
+    let public_file = public_notes.and_then(|public_notes| {
+        match std::fs::write(&public_filename, public_notes) {
+            Ok(()) => {
+                info!(
+                    "Successfully saved public release notes to: {}",
+                    public_filename
+                );
+                Some(public_filename)
+            }
             Err(e) => {
-                warn!("Failed to read template file scripts/plantilla.md: {}", e);
-                if let Err(e) = event_tx
-                    .broadcast(BackgroundEvent::ReleaseNotesError(format!(
-                        "Failed to read template file: {}",
-                        e
-                    )))
-                    .await
-                {
-                    warn!("Failed to broadcast error: {}", e);
-                }
-                // Continue without Gemini processing if template can't be read
-                return Ok(());
+                warn!(
+                    "Failed to write public release notes file {}: {}",
+                    public_filename, e
+                );
+                None
             }
-        };
+        }
+    });
 
-        match crate::services::GeminiClient::new(&config) {
-            Ok(gemini_client) => {
-                // Combine release notes and template for Gemini processing
-                let combined_input = format!(
-                    "RELEASE NOTES TO PROCESS:\n{}\n\nTEMPLATE TO FOLLOW:\n{}",
-                    release_notes, template_content
-                );
+    // The HTML export uses the Gemini document when available
+    let mut final_notes = release_notes.clone();
+    let mut gemini_file = None;
 
-                match gemini_client
-                    .process_release_notes_document(&combined_input)
-                    .await
-                {
-                    Ok(gemini_response) => {
-                        // Save the Gemini-processed version
-                        if let Err(e) = std::fs::write(&gemini_filename, &gemini_response) {
-                            warn!("Failed to write Gemini file {}: {}", gemini_filename, e);
-                            // Don't fail the entire operation, just log the warning
-                        } else {
-                            info!(
-                                "Successfully saved Gemini-processed release notes to: {}",
-                                gemini_filename
-                            );
-                        }
-                    }
-                    Err(e) => {
-                        warn!("Gemini processing failed: {}", e);
-                        // Continue without Gemini processing
-                    }
+    // Try to process with Gemini if configured
+    let mut gemini_error = None;
+    let language = release_notes_language(config, commits);
+    if config.gemini_token.is_some() && !config.offline {
+        broadcast_progress(event_tx, "Processing release notes with Gemini AI...", 0.75).await;
+
+        match process_with_gemini(config, language, &release_notes, remote.as_ref()).await {
+            Ok(gemini_response) => {
+                // Save the Gemini-processed version
+                if let Err(e) = std::fs::write(&gemini_filename, &gemini_response) {
+                    warn!("Failed to write Gemini file {}: {}", gemini_filename, e);
+                    // Don't fail the entire operation, just log the warning
+                } else {
+                    info!(
+                        "Successfully saved Gemini-processed release notes to: {}",
+                        gemini_filename
+                    );
+                    gemini_file = Some(gemini_filename.clone());
                 }
+                final_notes = gemini_response;
             }
             Err(e) => {
-                warn!("Failed to create Gemini client: {}", e);
-                // Continue without Gemini processing
+                // The document is saved, so only this step needs to run again
+                warn!("Gemini processing failed: {}", e);
+                gemini_error = Some(e.to_string());
             }
         }
     }
 
+    broadcast_progress(event_tx, "Exporting release notes to HTML...", 0.9).await;
+
+    let html_title = match package {
+        Some(package) => format!("Release Notes {} ({})", date_str, package.path),
+        None => format!("Release Notes {}", date_str),
+    };
+    let html_file = match std::fs::write(
+        &html_filename,
+        render_release_notes_html(&final_notes, &html_title),
+    ) {
+        Ok(()) => {
+            info!(
+                "Successfully saved HTML release notes to: {}",
+                html_filename
+            );
+            Some(html_filename)
+        }
+        Err(e) => {
+            // The Markdown files are the primary output, so only warn
+            warn!("Failed to write HTML file {}: {}", html_filename, e);
+            None
+        }
+    };
+
     // Final broadcast: completion with file paths
-    let completion_message = if std::path::Path::new(&gemini_filename).exists() {
+    let mut completion_message = if let Some(gemini_file) = &gemini_file {
         format!(
             "Release notes generated successfully!\n\n📄 Basic release notes: {}\n🤖 AI-enhanced release notes: {}",
-            script_filename, gemini_filename
+            script_filename, gemini_file
+        )
+    } else if let Some(gemini_error) = &gemini_error {
+        format!(
+            "Release notes generated without AI!\n\n📄 Basic release notes: {}\n⚠️ Gemini failed: {}\n🔁 Retry only the AI step with 'g' on the release notes screen or `release-notes --from-document {}`",
+            script_filename, gemini_error, script_filename
         )
     } else {
         format!(
@@ -577,19 +1639,824 @@ pub async fn generate_release_notes_task(
             script_filename
         )
     };
+    if let Some(public_file) = &public_file {
+        completion_message.push_str(&format!("\n👥 Public release notes: {}", public_file));
+    }
+    if let Some(html_file) = &html_file {
+        completion_message.push_str(&format!("\n🌐 HTML release notes: {}", html_file));
+    }
+
+    let record = ReleaseNotesRecord {
+        generated_at: now,
+        prefix: file_prefix,
+        package: package.map(|package| package.path.clone()),
+        version: version_info.map(|info| info.next_version),
+        head_commit: commits[0].hash.clone(),
+        first_commit: commits[commits.len() - 1].hash.clone(),
+        commit_count: commits.len(),
+        files: [
+            Some(&script_filename),
+            gemini_file.as_ref(),
+            public_file.as_ref(),
+            html_file.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect(),
+        gemini: gemini_file.is_some(),
+        language: Some(language),
+    };
+    if let Err(e) = record_release_notes_generation(&output_dir, &record) {
+        // The notes are written; only the log entry is missing
+        warn!(error = %e, "Could not record the release notes generation");
+    }
 
-    if let Err(e) = event_tx.broadcast(BackgroundEvent::ReleaseNotesCompleted(
-        serde_json::json!({
-            "notes": release_notes,
-            "script_file": script_filename,
-            "gemini_file": if std::path::Path::new(&gemini_filename).exists() { Some(gemini_filename) } else { None },
-            "status": "completed",
-            "message": completion_message
+    Ok(serde_json::json!({
+        "notes": release_notes,
+        "script_file": script_filename,
+        "gemini_file": gemini_file,
+        "gemini_error": gemini_error,
+        "html_file": html_file,
+        "public_file": public_file,
+        "responsible": responsible,
+        "next_tag": package_tag,
+        "non_conventional": non_conventional
+            .iter()
+            .map(|commit| commit.hash.as_str())
+            .collect::<Vec<_>>(),
+        "warning": convention_warning,
+        "status": "completed",
+        "message": completion_message
+    }))
+}
+
+/// How many of the newest commits are looked at to detect the language.
+const LANGUAGE_SAMPLE_SIZE: usize = 50;
+
+/// Language Gemini writes the notes in: `AI_LANGUAGE` when set, otherwise the
+/// one most commits are written in, falling back to `APP_LANGUAGE` for mixed
+/// or too short histories.
+fn release_notes_language(config: &AppConfig, commits: &[GitCommit]) -> Language {
+    if let Some(language) = config.ai_language {
+        return language;
+    }
+    let sample = commits.iter().take(LANGUAGE_SAMPLE_SIZE).map(|commit| {
+        if commit.description.is_empty() {
+            commit.subject.as_str()
+        } else {
+            commit.description.as_str()
+        }
+    });
+    match detect_language(sample) {
+        Some(language) => {
+            info!(
+                language = language.as_str(),
+                "Detected the commits' language"
+            );
+            language
+        }
+        None => config.language,
+    }
+}
+
+/// Turn the structured document into the final notes with Gemini, following
+/// the release notes template. PR references in the answer are linked.
+async fn process_with_gemini(
+    config: &AppConfig,
+    language: Language,
+    release_notes: &str,
+    remote: Option<&RemoteRepo>,
+) -> crate::error::Result<String> {
+    let template_content = load_release_notes_template(config);
+    info!(
+        document_tokens = estimate_tokens(release_notes),
+        template_tokens = estimate_tokens(&template_content),
+        compact = config.release_notes_compact,
+        language = language.as_str(),
+        "Sending release notes document to Gemini"
+    );
+
+    let gemini_client = crate::services::GeminiClient::new(config)?.with_language(language);
+    // Combine release notes and template for Gemini processing
+    let combined_input = format!(
+        "RELEASE NOTES TO PROCESS:\n{}\n\nTEMPLATE TO FOLLOW:\n{}",
+        release_notes, template_content
+    );
+    let gemini_response = gemini_client
+        .process_release_notes_document(&combined_input)
+        .await?;
+
+    Ok(match remote {
+        Some(remote) => link_pr_references(&gemini_response, remote),
+        None => gemini_response,
+    })
+}
+
+/// Run only the Gemini step again on a structured document saved by an
+/// earlier generation (`<prefix>.md`), without fetching commits or Monday.com
+/// tasks. Writes `<prefix>_GEMINI.md` next to it and replaces `<prefix>.html`.
+/// Returns the completion payload without broadcasting it.
+pub async fn process_saved_release_notes(
+    event_tx: &Sender<BackgroundEvent>,
+    config: &AppConfig,
+    document: &std::path::Path,
+) -> crate::error::Result<serde_json::Value> {
+    let fail = |message: String| async move {
+        if let Err(e) = event_tx
+            .broadcast(BackgroundEvent::ReleaseNotesError(message.clone()))
+            .await
+        {
+            warn!("Failed to broadcast error: {}", e);
+        }
+        SemanticReleaseError::config_error(message)
+    };
+
+    let release_notes = match std::fs::read_to_string(document) {
+        Ok(release_notes) => release_notes,
+        Err(e) => {
+            return Err(fail(format!(
+                "Could not read release notes document {}: {}",
+                document.display(),
+                e
+            ))
+            .await)
+        }
+    };
+    let Some(prefix) = document
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+    else {
+        return Err(fail(format!(
+            "Not a release notes document: {}",
+            document.display()
+        ))
+        .await);
+    };
+    let output_dir = document
+        .parent()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+
+    // Same language as the first attempt; without a log entry there are no
+    // commits to detect it from
+    let previous = read_release_notes_log(&output_dir)
+        .into_iter()
+        .rev()
+        .find(|record| record.prefix == prefix);
+    let language = previous
+        .as_ref()
+        .and_then(|record| record.language)
+        .unwrap_or_else(|| config.ai_language());
+
+    broadcast_progress(event_tx, "Processing release notes with Gemini AI...", 0.3).await;
+    let remote = GitRepo::new()
+        .ok()
+        .and_then(|git_repo| git_repo.get_remote_repo(config.git_host()));
+    let gemini_response =
+        match process_with_gemini(config, language, &release_notes, remote.as_ref()).await {
+            Ok(gemini_response) => gemini_response,
+            Err(e) => return Err(fail(format!("Gemini processing failed: {}", e)).await),
+        };
+
+    let gemini_filename = output_dir
+        .join(format!("{}_GEMINI.md", prefix))
+        .display()
+        .to_string();
+    if let Err(e) = std::fs::write(&gemini_filename, &gemini_response) {
+        return Err(fail(format!(
+            "Failed to write Gemini file {}: {}",
+            gemini_filename, e
+        ))
+        .await);
+    }
+    info!(
+        "Successfully saved Gemini-processed release notes to: {}",
+        gemini_filename
+    );
+
+    broadcast_progress(event_tx, "Exporting release notes to HTML...", 0.9).await;
+
+    let html_title = match parse_artifact_prefix(&prefix) {
+        Some((Some(package), generated_at)) => format!(
+            "Release Notes {} ({})",
+            generated_at.format("%Y-%m-%d"),
+            package
+        ),
+        Some((None, generated_at)) => {
+            format!("Release Notes {}", generated_at.format("%Y-%m-%d"))
+        }
+        None => "Release Notes".to_string(),
+    };
+    let html_filename = output_dir
+        .join(format!("{}.html", prefix))
+        .display()
+        .to_string();
+    let html_file = match std::fs::write(
+        &html_filename,
+        render_release_notes_html(&gemini_response, &html_title),
+    ) {
+        Ok(()) => Some(html_filename),
+        Err(e) => {
+            warn!("Failed to write HTML file {}: {}", html_filename, e);
+            None
+        }
+    };
+
+    // Log the retry as a generation of the same commits, now with Gemini
+    if let Some(mut record) = previous {
+        record.generated_at = chrono::Utc::now();
+        record.gemini = true;
+        for file in [Some(&gemini_filename), html_file.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            if !record.files.contains(file) {
+                record.files.push(file.clone());
+            }
+        }
+        if let Err(e) = record_release_notes_generation(&output_dir, &record) {
+            warn!(error = %e, "Could not record the release notes generation");
+        }
+    }
+
+    let mut message = format!(
+        "Release notes processed with Gemini!\n\n📄 Document: {}\n🤖 AI-enhanced release notes: {}",
+        document.display(),
+        gemini_filename
+    );
+    if let Some(html_file) = &html_file {
+        message.push_str(&format!("\n🌐 HTML release notes: {}", html_file));
+    }
+
+    Ok(serde_json::json!({
+        "notes": gemini_response,
+        "script_file": document.display().to_string(),
+        "gemini_file": gemini_filename,
+        "html_file": html_file,
+        "status": "completed",
+        "message": message
+    }))
+}
+
+// =============================================================================
+// GENERATED ARTIFACTS
+// =============================================================================
+
+/// Files written by one release notes run, grouped by their shared
+/// `release-notes[-<package>]-<timestamp>` prefix.
+#[derive(Debug, Clone)]
+pub struct ReleaseNotesArtifact {
+    pub prefix: String,
+    /// Package slug, for monorepo runs
+    pub package: Option<String>,
+    pub generated_at: chrono::NaiveDateTime,
+    pub markdown: Option<PathBuf>,
+    pub gemini: Option<PathBuf>,
+    /// Customer-facing variant with the internal commits collapsed
+    pub public: Option<PathBuf>,
+    pub html: Option<PathBuf>,
+    /// Total size of the run's files, in bytes
+    pub size: u64,
+    /// First `x.y.z` version found in the notes' headings, if any
+    pub version: Option<String>,
+}
+
+impl ReleaseNotesArtifact {
+    /// A run that didn't finish: the HTML is written last, and the Markdown
+    /// document must exist and not be empty.
+    pub fn is_failed(&self) -> bool {
+        let markdown_empty = self
+            .markdown
+            .as_ref()
+            .and_then(|path| std::fs::metadata(path).ok())
+            .is_none_or(|metadata| metadata.len() == 0);
+        markdown_empty || self.html.is_none()
+    }
+
+    pub fn files(&self) -> Vec<&PathBuf> {
+        [&self.markdown, &self.gemini, &self.public, &self.html]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// File to open: the HTML export, else the Gemini notes, else the Markdown document
+    pub fn preferred_file(&self) -> Option<&PathBuf> {
+        self.html
+            .as_ref()
+            .or(self.gemini.as_ref())
+            .or(self.markdown.as_ref())
+    }
+}
+
+static ARTIFACT_VERSION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\bv?(\d+\.\d+\.\d+(?:-[0-9A-Za-z.]+)?)\b").expect("version pattern is valid")
+});
+
+/// Split `release-notes[-<package>]-<YYYY-MM-DD_HHMMSS>` into package and timestamp.
+fn parse_artifact_prefix(prefix: &str) -> Option<(Option<String>, chrono::NaiveDateTime)> {
+    let rest = prefix.strip_prefix("release-notes-")?;
+    let split = rest.len().checked_sub("YYYY-MM-DD_HHMMSS".len())?;
+    let (package, timestamp) = rest.split_at_checked(split)?;
+    let generated_at = chrono::NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%d_%H%M%S").ok()?;
+    let package = package.strip_suffix('-').map(str::to_string);
+    Some((package, generated_at))
+}
+
+fn artifact_version(path: &std::path::Path) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    content
+        .lines()
+        .filter(|line| line.starts_with('#'))
+        .take(5)
+        .find_map(|line| ARTIFACT_VERSION_RE.captures(line))
+        .map(|captures| captures[1].to_string())
+}
+
+/// Release notes runs found in `dir`, newest first. Files that don't follow
+/// the generated naming are ignored.
+pub fn scan_release_notes_artifacts(dir: &std::path::Path) -> Result<Vec<ReleaseNotesArtifact>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(SemanticReleaseError::config_error(format!(
+                "Could not read release notes directory {}: {}",
+                dir.display(),
+                e
+            )))
+        }
+    };
+
+    let mut runs: BTreeMap<String, ReleaseNotesArtifact> = BTreeMap::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let (prefix, kind) = if let Some(prefix) = name.strip_suffix("_GEMINI.md") {
+            (prefix, "gemini")
+        } else if let Some(prefix) = name.strip_suffix("_PUBLIC.md") {
+            (prefix, "public")
+        } else if let Some(prefix) = name.strip_suffix(".md") {
+            (prefix, "markdown")
+        } else if let Some(prefix) = name.strip_suffix(".html") {
+            (prefix, "html")
+        } else {
+            continue;
+        };
+        let Some((package, generated_at)) = parse_artifact_prefix(prefix) else {
+            continue;
+        };
+
+        let run = runs
+            .entry(prefix.to_string())
+            .or_insert_with(|| ReleaseNotesArtifact {
+                prefix: prefix.to_string(),
+                package,
+                generated_at,
+                markdown: None,
+                gemini: None,
+                public: None,
+                html: None,
+                size: 0,
+                version: None,
+            });
+        run.size += entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+        match kind {
+            "gemini" => run.gemini = Some(path),
+            "public" => run.public = Some(path),
+            "markdown" => run.markdown = Some(path),
+            _ => run.html = Some(path),
+        }
+    }
+
+    let mut artifacts: Vec<ReleaseNotesArtifact> = runs.into_values().collect();
+    for artifact in &mut artifacts {
+        artifact.version = artifact
+            .gemini
+            .as_deref()
+            .and_then(artifact_version)
+            .or_else(|| artifact.markdown.as_deref().and_then(artifact_version));
+    }
+    artifacts.sort_by_key(|artifact| std::cmp::Reverse(artifact.generated_at));
+    Ok(artifacts)
+}
+
+// =============================================================================
+// GENERATION LOG
+// =============================================================================
+
+/// Append-only log of the generations, one JSON object per line, kept next
+/// to the artifacts so it needs no setup and travels with them.
+const GENERATION_LOG_FILE: &str = "history.jsonl";
+
+/// One release notes generation, as recorded in the generation log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseNotesRecord {
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    /// Prefix shared by the run's files
+    pub prefix: String,
+    /// Package path, for monorepo runs
+    pub package: Option<String>,
+    /// Next version when the native strategy computed it
+    pub version: Option<String>,
+    /// Newest commit of the range
+    pub head_commit: String,
+    /// Oldest commit of the range
+    pub first_commit: String,
+    pub commit_count: usize,
+    pub files: Vec<String>,
+    /// Gemini produced the AI-enhanced notes
+    pub gemini: bool,
+    /// Language the notes were asked in; missing in older entries
+    #[serde(default)]
+    pub language: Option<Language>,
+}
+
+impl ReleaseNotesRecord {
+    /// `first..head` with short hashes
+    pub fn range(&self) -> String {
+        format!(
+            "{}..{}",
+            &self.first_commit[..self.first_commit.len().min(7)],
+            &self.head_commit[..self.head_commit.len().min(7)]
+        )
+    }
+
+    /// Every file of the run is still on disk
+    pub fn files_exist(&self) -> bool {
+        self.files
+            .iter()
+            .all(|file| std::path::Path::new(file).is_file())
+    }
+}
+
+fn record_release_notes_generation(
+    dir: &std::path::Path,
+    record: &ReleaseNotesRecord,
+) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let line = serde_json::to_string(record).map_err(std::io::Error::other)?;
+    let mut log = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(GENERATION_LOG_FILE))?;
+    writeln!(log, "{}", line)
+}
+
+/// Recorded generations in `dir`, oldest first. Lines that don't parse (e.g.
+/// cut by a crash) are skipped.
+pub fn read_release_notes_log(dir: &std::path::Path) -> Vec<ReleaseNotesRecord> {
+    let Ok(content) = std::fs::read_to_string(dir.join(GENERATION_LOG_FILE)) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                warn!(error = %e, "Skipping unreadable release notes log entry");
+                None
+            }
         })
-    )).await {
-        warn!("Failed to broadcast completion: {}", e);
+        .collect()
+}
+
+/// Latest recorded generation of exactly these commits whose files still
+/// exist. For monorepos every package must have one; the newest is returned.
+fn previous_generation(
+    config: &AppConfig,
+    commits: &ReleaseNotesCommits,
+) -> Option<ReleaseNotesRecord> {
+    let log = read_release_notes_log(&config.release_notes_dir());
+    let find = |package: Option<&str>, commits: &[GitCommit]| {
+        let head = &commits.first()?.hash;
+        log.iter()
+            .rev()
+            .find(|record| {
+                record.package.as_deref() == package
+                    && &record.head_commit == head
+                    && record.commit_count == commits.len()
+                    && record.files_exist()
+            })
+            .cloned()
+    };
+
+    match commits {
+        ReleaseNotesCommits::Repository(commits) => find(None, commits),
+        ReleaseNotesCommits::Packages(packages) => packages
+            .iter()
+            .map(|(package, commits)| find(Some(&package.path), commits))
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max_by_key(|record| record.generated_at),
     }
+}
 
-    info!("Release notes generation completed successfully");
-    Ok(())
+/// Document of the latest generation when Gemini didn't produce its notes
+/// and the document is still on disk, for retrying only the AI step.
+pub fn release_notes_retry_document(config: &AppConfig) -> Option<PathBuf> {
+    let log = read_release_notes_log(&config.release_notes_dir());
+    let latest = log.last().filter(|record| !record.gemini)?;
+    // The structured document is always the first file written
+    let document = PathBuf::from(latest.files.first()?);
+    document.is_file().then_some(document)
+}
+
+/// Next version of the notes' commits: the repository's, or the package's
+/// own from its tags. Only the native strategy is cheap enough to run here;
+/// packages always use it.
+fn release_version_info(
+    config: &AppConfig,
+    package: Option<&ReleasePackage>,
+) -> Option<VersionInfo> {
+    let options = match package {
+        Some(package) => config.package_version_options(package),
+        None if config.version_strategy == VersionStrategy::Native => config.version_options(),
+        None => return None,
+    };
+    match crate::git::repository::get_version_info(&options) {
+        Ok(info) => Some(info),
+        Err(e) => {
+            warn!(error = %e, "Could not compute the next version for the release notes");
+            None
+        }
+    }
+}
+
+// =============================================================================
+// PULL REQUEST AND ISSUE LINKS
+// =============================================================================
+
+static PR_REFERENCE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(^|[\s(\[{,;:])([#!])(\d+)\b").expect("PR reference pattern is valid")
+});
+
+/// Turn `#123` references into links to the repository's pull requests (on
+/// GitLab `#123` is an issue and `!123` a merge request; on Bitbucket `#123`
+/// is an issue). Headings, existing
+/// Markdown links, HTML entities and URL fragments are left alone.
+fn link_pr_references(markdown: &str, remote: &RemoteRepo) -> String {
+    markdown
+        .lines()
+        .map(|line| {
+            if line.trim_start().starts_with('#') {
+                return line.to_string();
+            }
+            PR_REFERENCE_RE
+                .replace_all(line, |caps: &regex::Captures| {
+                    let full = caps.get(0).unwrap();
+                    let number: u64 = match caps[3].parse() {
+                        Ok(number) => number,
+                        Err(_) => return full.as_str().to_string(),
+                    };
+                    // Already the text of a Markdown link: `[#123](...)`
+                    if &caps[1] == "[" && line[full.end()..].starts_with("](") {
+                        return full.as_str().to_string();
+                    }
+                    let url = match (&caps[2], remote.host) {
+                        ("#", GitHost::GitHub) => remote.pull_request_url(number),
+                        ("#", GitHost::GitLab) => remote.issue_url(number),
+                        ("!", GitHost::GitLab) => remote.pull_request_url(number),
+                        ("#", GitHost::Bitbucket) => remote.issue_url(number),
+                        _ => return full.as_str().to_string(),
+                    };
+                    format!("{}[{}{}]({})", &caps[1], &caps[2], number, url)
+                })
+                .into_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + if markdown.ends_with('\n') { "\n" } else { "" }
+}
+
+/// Define the `[abcd1234]` commit references of the document as links to the
+/// commits on the remote, so they resolve to `/commit/` (or `/-/commit/` on GitLab).
+fn add_commit_link_definitions(
+    release_notes: &mut String,
+    commits: &[GitCommit],
+    remote: &RemoteRepo,
+) {
+    let definitions: Vec<String> = commits
+        .iter()
+        .filter(|commit| commit.hash.len() >= 8)
+        .filter(|commit| release_notes.contains(&format!("[{}]", &commit.hash[..8])))
+        .map(|commit| {
+            format!(
+                "[{}]: {}",
+                &commit.hash[..8],
+                remote.commit_url(&commit.hash)
+            )
+        })
+        .collect();
+    if definitions.is_empty() {
+        return;
+    }
+
+    if !release_notes.ends_with('\n') {
+        release_notes.push('\n');
+    }
+    release_notes.push('\n');
+    release_notes.push_str(&definitions.join("\n"));
+    release_notes.push('\n');
+}
+
+// =============================================================================
+// HTML EXPORT
+// =============================================================================
+
+const HTML_BODY_STYLE: &str = "font-family: -apple-system, 'Segoe UI', Roboto, Helvetica, Arial, sans-serif; color: #24292f; line-height: 1.5; max-width: 960px; margin: 0 auto; padding: 24px;";
+const HTML_TABLE_STYLE: &str = "border-collapse: collapse; margin: 16px 0; width: 100%;";
+const HTML_HEADER_CELL_STYLE: &str = "border: 1px solid #d0d7de; padding: 6px 13px; background: #f6f8fa; font-weight: 600; text-align: left;";
+const HTML_CELL_STYLE: &str = "border: 1px solid #d0d7de; padding: 6px 13px; vertical-align: top;";
+const HTML_LINK_STYLE: &str = "color: #0969da;";
+const HTML_CODE_STYLE: &str = "font-family: SFMono-Regular, Consolas, monospace; background: #f6f8fa; padding: 2px 4px; border-radius: 4px;";
+
+fn html_heading_style(level: HeadingLevel) -> &'static str {
+    match level {
+        HeadingLevel::H1 => "font-size: 2em; border-bottom: 1px solid #d0d7de; padding-bottom: 0.3em; margin: 24px 0 16px;",
+        HeadingLevel::H2 => "font-size: 1.5em; border-bottom: 1px solid #d0d7de; padding-bottom: 0.3em; margin: 24px 0 16px;",
+        HeadingLevel::H3 => "font-size: 1.25em; margin: 24px 0 16px;",
+        _ => "font-size: 1em; margin: 24px 0 16px;",
+    }
+}
+
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::new();
+    // Writing into a String cannot fail
+    let _ = escape_html(&mut escaped, text);
+    escaped
+}
+
+fn html_link(url: &str, text: &str) -> String {
+    let mut href = String::new();
+    let _ = escape_href(&mut href, url);
+    format!(
+        "<a href=\"{}\" style=\"{}\">{}</a>",
+        href,
+        HTML_LINK_STYLE,
+        html_escape(text)
+    )
+}
+
+static BARE_URL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"https?://[^\s<>()\[\]"']+"#).expect("bare URL pattern is valid")
+});
+
+/// Split text so bare URLs (SupportBee, Monday, ...) become clickable links.
+fn autolink_text(text: CowStr<'_>) -> Vec<Event<'_>> {
+    if !BARE_URL_RE.is_match(&text) {
+        return vec![Event::Text(text)];
+    }
+
+    let mut events = Vec::new();
+    let mut last = 0;
+    for found in BARE_URL_RE.find_iter(&text) {
+        let url = found.as_str().trim_end_matches(['.', ',', ';', ':']);
+        let end = found.start() + url.len();
+        if found.start() > last {
+            events.push(Event::Text(text[last..found.start()].to_string().into()));
+        }
+        events.push(Event::Html(html_link(url, url).into()));
+        last = end;
+    }
+    if last < text.len() {
+        events.push(Event::Text(text[last..].to_string().into()));
+    }
+    events
+}
+
+/// Only these schemes become clickable links in the HTML export.
+fn is_safe_link(url: &str) -> bool {
+    let url = url.trim().to_ascii_lowercase();
+    ["http://", "https://", "mailto:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme))
+}
+
+/// Render release notes Markdown as a standalone HTML page. Styles are inline
+/// so the document survives being pasted into Confluence or an e-mail.
+pub fn render_release_notes_html(markdown: &str, title: &str) -> String {
+    let parser = Parser::new_ext(
+        markdown,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS,
+    );
+
+    let mut events = Vec::new();
+    let mut in_table_head = false;
+    let mut in_link = false;
+    let mut link_opened = false;
+    let mut in_code_block = false;
+
+    for event in parser {
+        let html = |markup: String| Event::Html(markup.into());
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                events.push(html(format!(
+                    "<{} style=\"{}\">",
+                    level,
+                    html_heading_style(level)
+                )));
+            }
+            Event::End(TagEnd::Heading(level)) => events.push(html(format!("</{}>\n", level))),
+            Event::Start(Tag::Table(_)) => {
+                events.push(html(format!("<table style=\"{}\">", HTML_TABLE_STYLE)));
+            }
+            Event::End(TagEnd::Table) => events.push(html("</tbody></table>\n".to_string())),
+            Event::Start(Tag::TableHead) => {
+                in_table_head = true;
+                events.push(html("<thead><tr>".to_string()));
+            }
+            Event::End(TagEnd::TableHead) => {
+                in_table_head = false;
+                events.push(html("</tr></thead><tbody>\n".to_string()));
+            }
+            Event::Start(Tag::TableRow) => events.push(html("<tr>".to_string())),
+            Event::End(TagEnd::TableRow) => events.push(html("</tr>\n".to_string())),
+            Event::Start(Tag::TableCell) => {
+                events.push(html(if in_table_head {
+                    format!("<th style=\"{}\">", HTML_HEADER_CELL_STYLE)
+                } else {
+                    format!("<td style=\"{}\">", HTML_CELL_STYLE)
+                }));
+            }
+            Event::End(TagEnd::TableCell) => {
+                events.push(html(
+                    if in_table_head { "</th>" } else { "</td>" }.to_string(),
+                ));
+            }
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                in_link = true;
+                // javascript:, data: and friends would run when clicked: keep just the text
+                link_opened = is_safe_link(&dest_url);
+                if link_opened {
+                    let mut href = String::new();
+                    let _ = escape_href(&mut href, &dest_url);
+                    events.push(html(format!(
+                        "<a href=\"{}\" style=\"{}\">",
+                        href, HTML_LINK_STYLE
+                    )));
+                }
+            }
+            Event::End(TagEnd::Link) => {
+                in_link = false;
+                if link_opened {
+                    events.push(html("</a>".to_string()));
+                }
+            }
+            Event::Start(Tag::CodeBlock(kind)) => {
+                in_code_block = true;
+                events.push(Event::Start(Tag::CodeBlock(kind)));
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                in_code_block = false;
+                events.push(Event::End(TagEnd::CodeBlock));
+            }
+            Event::Code(code) => events.push(html(format!(
+                "<code style=\"{}\">{}</code>",
+                HTML_CODE_STYLE,
+                html_escape(&code)
+            ))),
+            Event::Text(text) if !in_link && !in_code_block => events.extend(autolink_text(text)),
+            // Raw HTML comes from commit messages and task titles: show it, don't render it
+            Event::Html(raw) | Event::InlineHtml(raw) => events.push(Event::Text(raw)),
+            other => events.push(other),
+        }
+    }
+
+    let mut body = String::new();
+    pulldown_cmark::html::push_html(&mut body, events.into_iter());
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"es\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body style=\"{}\">\n{}</body>\n</html>\n",
+        html_escape(title),
+        HTML_BODY_STYLE,
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_release_notes_html_escapes_raw_html() {
+        let html = render_release_notes_html(
+            "- fix: <script>alert(1)</script> in the title\n\n<div onclick=\"x()\">block</div>\n\n\
+             [x](javascript:alert(1)) and [docs](https://example.com/docs)\n",
+            "Notes",
+        );
+
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<div onclick"));
+        assert!(html.contains("&lt;div onclick="));
+        assert!(!html.contains("javascript:"));
+        assert!(html.contains("<p>x and "));
+        assert!(html.contains("<a href=\"https://example.com/docs\""));
+    }
 }
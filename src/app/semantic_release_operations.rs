@@ -1,11 +1,16 @@
 use crate::error::Result;
-use std::process::{Command, Stdio};
-use std::sync::{Arc, Mutex};
-use std::thread;
+use async_broadcast::Sender;
+use tokio::process::Command;
+use tracing::warn;
 
 use crate::{
-    app::App,
-    types::{AppState, SemanticReleaseState},
+    app::{
+        background_operations::BackgroundEvent,
+        release_notes::{ensure_release_notes_template, resolve_release_notes_template_path},
+        App,
+    },
+    git::{repository::VersionInfoCache, GitHost, GitRepo},
+    types::{AppConfig, AppState, SemanticReleaseState, VersionInfo, VersionOptions},
     utils,
 };
 
@@ -21,32 +26,35 @@ pub trait SemanticReleaseOperations {
 impl SemanticReleaseOperations for App {
     async fn execute_semantic_release(&mut self, dry_run: bool) -> Result<()> {
         // Check if already processing
-        if matches!(self.current_state, AppState::Loading) || self.semantic_release_state.is_some()
+        if matches!(self.current_state, AppState::Loading)
+            || self.semantic_release_state.is_some()
+            || self.unavailable_offline("semantic-release")
         {
             return Ok(());
         }
 
+        // A release creates a tag without moving HEAD
+        if !dry_run {
+            self.version_info_cache.invalidate();
+        }
+
         // Set loading state immediately
         self.current_state = AppState::Loading;
         let action = if dry_run { "dry-run" } else { "release" };
         self.message = Some(format!("🚀 Ejecutando semantic-release {}...", action));
 
-        // Create shared state for the operation
-        let release_state = SemanticReleaseState {
-            status: Arc::new(Mutex::new(format!(
+        // Progress and the final result arrive as background events
+        if self
+            .background_task_manager
+            .start_semantic_release(dry_run)
+            .await?
+            .is_some()
+        {
+            self.semantic_release_state = Some(SemanticReleaseState::running(format!(
                 "📋 Preparando semantic-release {}...",
                 action
-            ))),
-            finished: Arc::new(Mutex::new(false)),
-            success: Arc::new(Mutex::new(true)),
-            result: Arc::new(Mutex::new(String::new())),
-        };
-
-        // Start the operation in a background thread
-        self.start_semantic_release_operation(release_state.clone(), dry_run);
-
-        // Store the state so the main loop can poll it
-        self.semantic_release_state = Some(release_state);
+            )));
+        }
 
         Ok(())
     }
@@ -92,21 +100,19 @@ impl SemanticReleaseOperations for App {
         self.current_state = AppState::Loading;
         self.message = Some("🔍 Analizando información de versión...".to_string());
 
-        // Create shared state for the operation
-        let release_state = SemanticReleaseState {
-            status: Arc::new(Mutex::new(
-                "🔍 Obteniendo información detallada de versión...".to_string(),
-            )),
-            finished: Arc::new(Mutex::new(false)),
-            success: Arc::new(Mutex::new(true)),
-            result: Arc::new(Mutex::new(String::new())),
-        };
-
-        // Start the operation in a background thread
-        self.start_version_info_operation(release_state.clone());
-
-        // Store the state so the main loop can poll it
-        self.semantic_release_state = Some(release_state);
+        if self
+            .background_task_manager
+            .start_version_info(
+                self.config.version_options(),
+                self.version_info_cache.clone(),
+            )
+            .await?
+            .is_some()
+        {
+            self.semantic_release_state = Some(SemanticReleaseState::running(
+                "🔍 Obteniendo información detallada de versión...",
+            ));
+        }
 
         Ok(())
     }
@@ -115,178 +121,42 @@ impl SemanticReleaseOperations for App {
         self.current_state = AppState::Loading;
         self.message = Some("🔧 Configurando GitHub Actions para semantic-release...".to_string());
 
-        // Create shared state for the operation
-        let release_state = SemanticReleaseState {
-            status: Arc::new(Mutex::new(
-                "🔧 Preparando configuración de GitHub Actions...".to_string(),
-            )),
-            finished: Arc::new(Mutex::new(false)),
-            success: Arc::new(Mutex::new(true)),
-            result: Arc::new(Mutex::new(String::new())),
-        };
-
-        // Start the operation in a background thread
-        self.start_github_setup_operation(release_state.clone());
-
-        // Store the state so the main loop can poll it
-        self.semantic_release_state = Some(release_state);
+        // The CI and the release plugin follow the provider of `origin` (GitHub if unknown)
+        let git_host = GitRepo::new()
+            .ok()
+            .and_then(|repo| repo.get_remote_repo(self.config.git_host()))
+            .map(|remote| remote.host)
+            .or(self.config.git_host())
+            .unwrap_or(GitHost::GitHub);
+
+        if self
+            .background_task_manager
+            .start_ci_setup(&self.config, git_host)
+            .await?
+            .is_some()
+        {
+            self.semantic_release_state = Some(SemanticReleaseState::running(
+                "🔧 Preparando configuración de GitHub Actions...",
+            ));
+        }
 
         Ok(())
     }
 }
 
 impl App {
-    pub fn start_semantic_release_operation(
-        &self,
-        release_state: SemanticReleaseState,
-        dry_run: bool,
-    ) {
-        // Clone state components for the thread
-        let status_clone = release_state.status.clone();
-        let finished_clone = release_state.finished.clone();
-        let success_clone = release_state.success.clone();
-        let result_clone = release_state.result.clone();
-
-        // Spawn the operation in a background thread
-        thread::spawn(move || {
-            // Update status: checking prerequisites
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "🔍 Verificando prerrequisitos de semantic-release...".to_string();
-            }
-
-            // Check if semantic-release is installed
-            let check_cmd = if cfg!(target_os = "windows") {
-                Command::new("cmd")
-                    .args(["/C", "npx semantic-release --version"])
-                    .output()
-            } else {
-                Command::new("npx")
-                    .args(["semantic-release", "--version"])
-                    .output()
-            };
-
-            match check_cmd {
-                Ok(output) => {
-                    if !output.status.success() {
-                        if let Ok(mut status) = status_clone.lock() {
-                            *status = "❌ semantic-release no está instalado".to_string();
-                        }
-                        if let Ok(mut success) = success_clone.lock() {
-                            *success = false;
-                        }
-                        if let Ok(mut finished) = finished_clone.lock() {
-                            *finished = true;
-                        }
-                        return;
-                    }
-                }
-                Err(e) => {
-                    utils::log_error("SEMANTIC-RELEASE", &e);
-                    if let Ok(mut status) = status_clone.lock() {
-                        *status = format!("❌ Error verificando semantic-release: {}", e);
-                    }
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                    if let Ok(mut finished) = finished_clone.lock() {
-                        *finished = true;
-                    }
-                    return;
-                }
-            }
-
-            // Update status: executing semantic-release
-            let action = if dry_run { "dry-run" } else { "release" };
-            if let Ok(mut status) = status_clone.lock() {
-                *status = format!("🚀 Ejecutando semantic-release {}...", action);
-            }
-
-            // Build command arguments
-            let mut args = vec!["semantic-release"];
-            if dry_run {
-                args.push("--dry-run");
-            }
-
-            // Execute semantic-release
-            let cmd_result = if cfg!(target_os = "windows") {
-                let mut cmd_args = vec!["/C", "npx"];
-                cmd_args.extend(args);
-                Command::new("cmd")
-                    .args(cmd_args)
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .output()
-            } else {
-                Command::new("npx")
-                    .args(args)
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .output()
-            };
-
-            match cmd_result {
-                Ok(output) => {
-                    let stdout = String::from_utf8_lossy(&output.stdout);
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-
-                    if output.status.success() {
-                        if let Ok(mut status) = status_clone.lock() {
-                            *status =
-                                format!("✅ Semantic-release {} completado exitosamente", action);
-                        }
-                        if let Ok(mut result) = result_clone.lock() {
-                            *result =
-                                format!("Salida:\n{}\n\nErrores/Advertencias:\n{}", stdout, stderr);
-                        }
-                        utils::log_success(
-                            "SEMANTIC-RELEASE",
-                            &format!("Semantic-release {} completed successfully", action),
-                        );
-                        utils::log_debug("SEMANTIC-RELEASE", &format!("Output: {}", stdout));
-                    } else {
-                        if let Ok(mut status) = status_clone.lock() {
-                            *status = format!("❌ Error en semantic-release {}", action);
-                        }
-                        if let Ok(mut result) = result_clone.lock() {
-                            *result = format!("Error:\n{}\n\nSalida:\n{}", stderr, stdout);
-                        }
-                        if let Ok(mut success) = success_clone.lock() {
-                            *success = false;
-                        }
-                        utils::log_error(
-                            "SEMANTIC-RELEASE",
-                            &format!("Semantic-release failed: {}", stderr),
-                        );
-                    }
-                }
-                Err(e) => {
-                    utils::log_error("SEMANTIC-RELEASE", &e);
-                    if let Ok(mut status) = status_clone.lock() {
-                        *status = format!("❌ Error ejecutando semantic-release: {}", e);
-                    }
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
-            }
-
-            // Mark as finished
-            if let Ok(mut finished) = finished_clone.lock() {
-                *finished = true;
-            }
-        });
-    }
-
     async fn get_last_release_info(&self) -> Result<String> {
         // Get the last git tag
         let output = if cfg!(target_os = "windows") {
             Command::new("cmd")
                 .args(["/C", "git describe --tags --abbrev=0"])
-                .output()?
+                .output()
+                .await?
         } else {
             Command::new("git")
                 .args(["describe", "--tags", "--abbrev=0"])
-                .output()?
+                .output()
+                .await?
         };
 
         if output.status.success() {
@@ -295,7 +165,8 @@ impl App {
             // Get commit count since last tag
             let commit_count_output = Command::new("git")
                 .args(["rev-list", "--count", &format!("{}..HEAD", last_tag)])
-                .output()?;
+                .output()
+                .await?;
 
             let commit_count_str = String::from_utf8_lossy(&commit_count_output.stdout);
             let commit_count = commit_count_str.trim();
@@ -352,7 +223,8 @@ impl App {
         // Check if we're in a git repository
         let git_check = Command::new("git")
             .args(["rev-parse", "--is-inside-work-tree"])
-            .output();
+            .output()
+            .await;
 
         match git_check {
             Ok(output) => {
@@ -369,338 +241,482 @@ impl App {
 
         Ok(config_info.join(", "))
     }
+}
 
-    pub fn start_version_info_operation(&self, release_state: SemanticReleaseState) {
-        use crate::git::repository::get_version_info;
+/// Broadcast the status line of the running semantic-release operation.
+async fn broadcast_status(event_tx: &Sender<BackgroundEvent>, status: impl Into<String>) {
+    if let Err(e) = event_tx
+        .broadcast(BackgroundEvent::SemanticReleaseProgress(status.into()))
+        .await
+    {
+        warn!("Failed to broadcast semantic-release progress: {}", e);
+    }
+}
 
-        // Clone state components for the thread
-        let status_clone = release_state.status.clone();
-        let finished_clone = release_state.finished.clone();
-        let success_clone = release_state.success.clone();
-        let result_clone = release_state.result.clone();
+/// Broadcast the final state. Failures are reported here, so the operation
+/// itself always completes.
+async fn broadcast_finished(event_tx: &Sender<BackgroundEvent>, state: SemanticReleaseState) {
+    if let Err(e) = event_tx
+        .broadcast(BackgroundEvent::SemanticReleaseFinished(Box::new(state)))
+        .await
+    {
+        warn!("Failed to broadcast semantic-release result: {}", e);
+    }
+}
 
-        // Spawn the operation in a background thread
-        thread::spawn(move || {
-            // Update status: analyzing version
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "📊 Analizando información de versión...".to_string();
-            }
+fn finished(success: bool, status: String, result: String) -> SemanticReleaseState {
+    SemanticReleaseState {
+        status,
+        finished: true,
+        success,
+        result,
+        version_info: None,
+    }
+}
 
-            match get_version_info() {
-                Ok(version_info) => {
-                    let mut result_text = String::new();
-
-                    // Current version section
-                    result_text.push_str("📦 INFORMACIÓN DE VERSIÓN\n");
-                    result_text.push_str("=".repeat(50).as_str());
-                    result_text.push_str("\n\n");
-
-                    if let Some(current) = &version_info.current_version {
-                        result_text.push_str(&format!("🏷️  Versión actual: {}\n", current));
-                    } else {
-                        result_text.push_str("🏷️  Versión actual: Sin versiones anteriores\n");
-                    }
-
-                    result_text.push_str(&format!(
-                        "🚀 Próxima versión: {}\n",
-                        version_info.next_version
-                    ));
-                    result_text.push_str(&format!(
-                        "📊 Tipo de release: {}\n",
-                        version_info.version_type
-                    ));
-                    result_text.push_str(&format!(
-                        "📈 Commits desde última versión: {}\n",
-                        version_info.commit_count
-                    ));
-
-                    if version_info.has_unreleased_changes {
-                        result_text.push_str("✅ Hay cambios para publicar\n");
-                    } else {
-                        result_text.push_str("⚠️  No hay cambios para publicar\n");
-                    }
-
-                    result_text.push('\n');
-                    result_text.push_str("🔍 ANÁLISIS DETALLADO\n");
-                    result_text.push_str("=".repeat(50).as_str());
-                    result_text.push_str("\n\n");
-                    result_text.push_str(&version_info.dry_run_output);
-
-                    if let Ok(mut status) = status_clone.lock() {
-                        *status = "✅ Análisis de versión completado".to_string();
-                    }
-                    if let Ok(mut result) = result_clone.lock() {
-                        *result = result_text;
-                    }
-                    utils::log_success("VERSION-INFO", "Version analysis completed successfully");
-                }
-                Err(e) => {
-                    utils::log_error("VERSION-INFO", &e);
-                    if let Ok(mut status) = status_clone.lock() {
-                        *status = format!("❌ Error analizando versión: {}", e);
-                    }
-                    if let Ok(mut result) = result_clone.lock() {
-                        *result = format!("Error: {}", e);
-                    }
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
-            }
+/// `npx <args>`, through `cmd /C` on Windows where npx is a batch file. The
+/// child is killed if the operation is cancelled.
+fn npx(args: &[&str]) -> Command {
+    let mut command = if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "npx"]);
+        command
+    } else {
+        Command::new("npx")
+    };
+    command.args(args).kill_on_drop(true);
+    command
+}
 
-            // Mark as finished
-            if let Ok(mut finished) = finished_clone.lock() {
-                *finished = true;
-            }
-        });
+/// Check that semantic-release is installed and run it (`--dry-run` when asked).
+pub async fn run_semantic_release_task(
+    event_tx: Sender<BackgroundEvent>,
+    dry_run: bool,
+) -> Result<()> {
+    broadcast_status(
+        &event_tx,
+        "🔍 Verificando prerrequisitos de semantic-release...",
+    )
+    .await;
+
+    let failure = match npx(&["semantic-release", "--version"]).output().await {
+        Ok(output) if output.status.success() => None,
+        Ok(_) => Some("❌ semantic-release no está instalado".to_string()),
+        Err(e) => {
+            utils::log_error("SEMANTIC-RELEASE", &e);
+            Some(format!("❌ Error verificando semantic-release: {}", e))
+        }
+    };
+    if let Some(status) = failure {
+        broadcast_finished(&event_tx, finished(false, status, String::new())).await;
+        return Ok(());
     }
 
-    pub fn start_github_setup_operation(&self, release_state: SemanticReleaseState) {
-        // Clone state components for the thread
-        let status_clone = release_state.status.clone();
-        let finished_clone = release_state.finished.clone();
-        let success_clone = release_state.success.clone();
-        let result_clone = release_state.result.clone();
+    let action = if dry_run { "dry-run" } else { "release" };
+    broadcast_status(
+        &event_tx,
+        format!("🚀 Ejecutando semantic-release {}...", action),
+    )
+    .await;
 
-        // Spawn the operation in a background thread
-        thread::spawn(move || {
-            let mut result_text = String::new();
+    let mut args = vec!["semantic-release"];
+    if dry_run {
+        args.push("--dry-run");
+    }
 
-            // Update status: setting up GitHub Actions
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "🔧 Configurando GitHub Actions para semantic-release...".to_string();
+    let state = match npx(&args).output().await {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+
+            if output.status.success() {
+                utils::log_success(
+                    "SEMANTIC-RELEASE",
+                    &format!("Semantic-release {} completed successfully", action),
+                );
+                utils::log_debug("SEMANTIC-RELEASE", &format!("Output: {}", stdout));
+                finished(
+                    true,
+                    format!("✅ Semantic-release {} completado exitosamente", action),
+                    format!("Salida:\n{}\n\nErrores/Advertencias:\n{}", stdout, stderr),
+                )
+            } else {
+                utils::log_error(
+                    "SEMANTIC-RELEASE",
+                    &format!("Semantic-release failed: {}", stderr),
+                );
+                finished(
+                    false,
+                    format!("❌ Error en semantic-release {}", action),
+                    format!("Error:\n{}\n\nSalida:\n{}", stderr, stdout),
+                )
             }
+        }
+        Err(e) => {
+            utils::log_error("SEMANTIC-RELEASE", &e);
+            finished(
+                false,
+                format!("❌ Error ejecutando semantic-release: {}", e),
+                String::new(),
+            )
+        }
+    };
 
-            result_text.push_str("🚀 CONFIGURACIÓN DE GITHUB ACTIONS SEMANTIC-RELEASE\n");
-            result_text.push_str("=".repeat(60).as_str());
-            result_text.push_str("\n\n");
-
-            // Step 1: Check if already configured
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "🔍 Verificando configuración existente...".to_string();
-            }
+    broadcast_finished(&event_tx, state).await;
+    Ok(())
+}
 
-            let mut files_created = Vec::new();
-            let mut files_skipped = Vec::new();
+/// Compute (or reuse) the next version analysis for the results panel.
+pub async fn run_version_info_task(
+    event_tx: Sender<BackgroundEvent>,
+    version_options: VersionOptions,
+    version_info_cache: VersionInfoCache,
+) -> Result<()> {
+    broadcast_status(&event_tx, "📊 Analizando información de versión...").await;
+
+    // The analysis reads the repository and may run semantic-release synchronously
+    let computed = match tokio::task::spawn_blocking(move || {
+        version_info_cache.get_or_compute(&version_options)
+    })
+    .await
+    {
+        Ok(computed) => computed.map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
 
-            // Step 2: Setup package.json
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "📦 Configurando package.json...".to_string();
+    let state = match computed {
+        Ok(version_info) => {
+            utils::log_success("VERSION-INFO", "Version analysis completed successfully");
+            SemanticReleaseState {
+                version_info: Some(version_info.clone()),
+                ..finished(
+                    true,
+                    "✅ Análisis de versión completado".to_string(),
+                    version_info_report(&version_info),
+                )
             }
+        }
+        Err(e) => {
+            utils::log_error("VERSION-INFO", &e);
+            finished(
+                false,
+                format!("❌ Error analizando versión: {}", e),
+                format!("Error: {}", e),
+            )
+        }
+    };
 
-            match setup_package_json() {
-                Ok(created) => {
-                    if created {
-                        files_created.push("package.json");
-                        result_text.push_str(
-                            "✅ package.json creado con dependencias de semantic-release\n",
-                        );
-                    } else {
-                        files_skipped.push("package.json");
-                        result_text.push_str("⚠️  package.json ya existe - no modificado\n");
-                    }
-                }
-                Err(e) => {
-                    result_text.push_str(&format!("❌ Error configurando package.json: {}\n", e));
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
-            }
+    broadcast_finished(&event_tx, state).await;
+    Ok(())
+}
 
-            // Step 3: Setup .releaserc.json
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "⚙️ Configurando .releaserc.json...".to_string();
-            }
+/// Plain text version of the analysis, kept as the result of the operation.
+fn version_info_report(version_info: &VersionInfo) -> String {
+    let mut result_text = String::new();
 
-            match setup_releaserc() {
-                Ok(created) => {
-                    if created {
-                        files_created.push(".releaserc.json");
-                        result_text.push_str(
-                            "✅ .releaserc.json creado con configuración de semantic-release\n",
-                        );
-                    } else {
-                        files_skipped.push(".releaserc.json");
-                        result_text.push_str("⚠️  .releaserc.json ya existe - no modificado\n");
-                    }
-                }
-                Err(e) => {
-                    result_text
-                        .push_str(&format!("❌ Error configurando .releaserc.json: {}\n", e));
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
-            }
+    // Current version section
+    result_text.push_str("📦 INFORMACIÓN DE VERSIÓN\n");
+    result_text.push_str("=".repeat(50).as_str());
+    result_text.push_str("\n\n");
 
-            // Step 4: Setup GitHub Actions workflow
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "🔄 Configurando GitHub Actions workflow...".to_string();
-            }
+    if let Some(current) = &version_info.current_version {
+        result_text.push_str(&format!("🏷️  Versión actual: {}\n", current));
+    } else {
+        result_text.push_str("🏷️  Versión actual: Sin versiones anteriores\n");
+    }
 
-            match setup_github_workflow() {
-                Ok(created) => {
-                    if created {
-                        files_created.push(".github/workflows/release.yml");
-                        result_text.push_str(
-                            "✅ GitHub Actions workflow creado en .github/workflows/release.yml\n",
-                        );
-                    } else {
-                        files_skipped.push(".github/workflows/release.yml");
-                        result_text
-                            .push_str("⚠️  GitHub Actions workflow ya existe - no modificado\n");
-                    }
-                }
-                Err(e) => {
-                    result_text
-                        .push_str(&format!("❌ Error configurando GitHub workflow: {}\n", e));
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
-            }
+    result_text.push_str(&format!(
+        "🚀 Próxima versión: {}\n",
+        version_info.next_version
+    ));
+    if let Some(channel) = &version_info.channel {
+        result_text.push_str(&format!("🧪 Canal: {}\n", channel));
+    }
+    result_text.push_str(&format!(
+        "📊 Tipo de release: {}\n",
+        version_info.version_type
+    ));
+    result_text.push_str(&format!(
+        "📈 Commits desde última versión: {}\n",
+        version_info.commit_count
+    ));
+
+    if version_info.has_unreleased_changes {
+        result_text.push_str("✅ Hay cambios para publicar\n");
+    } else {
+        result_text.push_str("⚠️  No hay cambios para publicar\n");
+    }
 
-            // Step 5: Setup package-lock.json
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "📦 Configurando package-lock.json...".to_string();
-            }
+    result_text.push('\n');
+    result_text.push_str("🔍 ANÁLISIS DETALLADO\n");
+    result_text.push_str("=".repeat(50).as_str());
+    result_text.push_str("\n\n");
+    result_text.push_str(&version_info.dry_run_output);
+    result_text
+}
 
-            match setup_package_lock() {
-                Ok(created) => {
-                    if created {
-                        files_created.push("package-lock.json");
-                        result_text
-                            .push_str("✅ package-lock.json creado para caché de dependencias\n");
-                    } else {
-                        files_skipped.push("package-lock.json");
-                        result_text.push_str("⚠️  package-lock.json ya existe - no modificado\n");
-                    }
-                }
-                Err(e) => {
-                    result_text
-                        .push_str(&format!("❌ Error configurando package-lock.json: {}\n", e));
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
+/// Create the semantic-release files and the CI pipeline for `git_host`,
+/// leaving existing files untouched.
+pub async fn run_ci_setup_task(
+    event_tx: Sender<BackgroundEvent>,
+    config: AppConfig,
+    git_host: GitHost,
+) -> Result<()> {
+    let ci_name = ci_name(git_host);
+    let mut result_text = String::new();
+    let mut success = true;
+
+    // Update status: setting up the CI
+    broadcast_status(
+        &event_tx,
+        format!("🔧 Configurando {} para semantic-release...", ci_name),
+    )
+    .await;
+
+    result_text.push_str(&format!(
+        "🚀 CONFIGURACIÓN DE {} SEMANTIC-RELEASE\n",
+        ci_name.to_uppercase()
+    ));
+    result_text.push_str("=".repeat(60).as_str());
+    result_text.push_str("\n\n");
+
+    // Step 1: Check if already configured
+    broadcast_status(&event_tx, "🔍 Verificando configuración existente...").await;
+
+    let mut files_created = Vec::new();
+    let mut files_skipped = Vec::new();
+
+    // Step 2: Setup package.json
+    broadcast_status(&event_tx, "📦 Configurando package.json...").await;
+
+    match setup_package_json(git_host) {
+        Ok(created) => {
+            if created {
+                files_created.push("package.json");
+                result_text
+                    .push_str("✅ package.json creado con dependencias de semantic-release\n");
+            } else {
+                files_skipped.push("package.json");
+                result_text.push_str("⚠️  package.json ya existe - no modificado\n");
             }
+        }
+        Err(e) => {
+            result_text.push_str(&format!("❌ Error configurando package.json: {}\n", e));
+            success = false;
+        }
+    }
 
-            // Step 6: Setup Node.js .gitignore
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "📁 Configurando .gitignore para Node.js...".to_string();
-            }
+    // Step 3: Setup .releaserc.json
+    broadcast_status(&event_tx, "⚙️ Configurando .releaserc.json...").await;
 
-            match setup_nodejs_gitignore() {
-                Ok(created) => {
-                    if created {
-                        files_created.push(".gitignore");
-                        result_text.push_str("✅ .gitignore configurado para proyecto Node.js\n");
-                    } else {
-                        files_skipped.push(".gitignore");
-                        result_text.push_str("⚠️  .gitignore ya existe con reglas Node.js\n");
-                    }
-                }
-                Err(e) => {
-                    result_text.push_str(&format!("❌ Error configurando .gitignore: {}\n", e));
-                    if let Ok(mut success) = success_clone.lock() {
-                        *success = false;
-                    }
-                }
+    match setup_releaserc(git_host) {
+        Ok(created) => {
+            if created {
+                files_created.push(".releaserc.json");
+                result_text
+                    .push_str("✅ .releaserc.json creado con configuración de semantic-release\n");
+            } else {
+                files_skipped.push(".releaserc.json");
+                result_text.push_str("⚠️  .releaserc.json ya existe - no modificado\n");
             }
+        }
+        Err(e) => {
+            result_text.push_str(&format!("❌ Error configurando .releaserc.json: {}\n", e));
+            success = false;
+        }
+    }
 
-            // Step 7: Ensure plantilla template exists
-            if let Ok(mut status) = status_clone.lock() {
-                *status = "📄 Verificando plantilla de release notes...".to_string();
+    // Step 4: Setup the CI pipeline
+    broadcast_status(
+        &event_tx,
+        format!("🔄 Configurando pipeline de {}...", ci_name),
+    )
+    .await;
+
+    match ci_config_path(git_host) {
+        Some(path) => match setup_ci_config(git_host) {
+            Ok(true) => {
+                files_created.push(path);
+                result_text.push_str(&format!("✅ Pipeline de {} creado en {}\n", ci_name, path));
             }
-
-            match ensure_plantilla_template_exists() {
-                Ok(_) => {
-                    result_text.push_str("✅ Plantilla de release notes verificada\n");
-                }
-                Err(e) => {
-                    result_text.push_str(&format!("❌ Error configurando plantilla: {}\n", e));
-                }
+            Ok(false) => {
+                files_skipped.push(path);
+                result_text.push_str(&format!(
+                    "⚠️  Pipeline de {} ya existe - no modificado\n",
+                    ci_name
+                ));
+            }
+            Err(e) => {
+                result_text.push_str(&format!(
+                    "❌ Error configurando pipeline de {}: {}\n",
+                    ci_name, e
+                ));
+                success = false;
             }
+        },
+        None => {
+            result_text.push_str(&format!(
+                "⚠️  No se genera pipeline para {}: configúralo a mano con `npx semantic-release`\n",
+                ci_name
+            ));
+        }
+    }
 
-            // Summary
-            result_text.push('\n');
-            result_text.push_str("📋 RESUMEN DE CONFIGURACIÓN\n");
-            result_text.push_str("=".repeat(30).as_str());
-            result_text.push_str("\n\n");
+    // Step 5: Setup package-lock.json
+    broadcast_status(&event_tx, "📦 Configurando package-lock.json...").await;
 
-            if !files_created.is_empty() {
-                result_text.push_str("✅ Archivos creados:\n");
-                for file in &files_created {
-                    result_text.push_str(&format!("   • {}\n", file));
-                }
-                result_text.push('\n');
+    match setup_package_lock(git_host).await {
+        Ok(created) => {
+            if created {
+                files_created.push("package-lock.json");
+                result_text.push_str("✅ package-lock.json creado para caché de dependencias\n");
+            } else {
+                files_skipped.push("package-lock.json");
+                result_text.push_str("⚠️  package-lock.json ya existe - no modificado\n");
             }
+        }
+        Err(e) => {
+            result_text.push_str(&format!("❌ Error configurando package-lock.json: {}\n", e));
+            success = false;
+        }
+    }
 
-            if !files_skipped.is_empty() {
-                result_text.push_str("⚠️  Archivos ya existentes (no modificados):\n");
-                for file in &files_skipped {
-                    result_text.push_str(&format!("   • {}\n", file));
-                }
-                result_text.push('\n');
-            }
+    // Step 6: Setup Node.js .gitignore
+    broadcast_status(&event_tx, "📁 Configurando .gitignore para Node.js...").await;
 
-            // Next steps
-            result_text.push_str("🚀 PRÓXIMOS PASOS\n");
-            result_text.push_str("=".repeat(20).as_str());
-            result_text.push_str("\n\n");
-            result_text.push_str("1. 🔑 Configurar secrets en GitHub:\n");
-            result_text.push_str("   • GITHUB_TOKEN: Personal access token con permisos 'repo'\n");
-            result_text.push_str("   • Ir a Settings > Secrets and variables > Actions\n\n");
-            result_text.push_str("2. 📦 Verificar dependencias (opcional):\n");
-            result_text.push_str("   • Si tienes npm instalado: npm install\n");
-            result_text
-                .push_str("   • Esto actualizará package-lock.json con versiones exactas\n\n");
-            result_text.push_str("3. 📝 Usar commits convencionales:\n");
-            result_text.push_str("   • feat: nueva funcionalidad (minor version)\n");
-            result_text.push_str("   • fix: corrección de bug (patch version)\n");
-            result_text.push_str("   • feat!: breaking change (major version)\n\n");
-            result_text.push_str("4. 🚢 Hacer push a main para ejecutar el primer release:\n");
-            result_text.push_str("   • git add .\n");
-            result_text.push_str(
-                "   • git commit -m \"feat: setup semantic-release with GitHub Actions\"\n",
-            );
-            result_text.push_str("   • git push origin main\n\n");
-            result_text.push_str("💡 NOTA: Los scripts de test y build son placeholders.\n");
-            result_text
-                .push_str("   Personaliza package.json según las necesidades de tu proyecto.\n\n");
-
-            // Update final status
-            let has_errors = files_created.is_empty() && files_skipped.is_empty();
-            if has_errors {
-                if let Ok(mut status) = status_clone.lock() {
-                    *status = "❌ Error configurando GitHub Actions".to_string();
-                }
-                if let Ok(mut success) = success_clone.lock() {
-                    *success = false;
-                }
+    match setup_nodejs_gitignore() {
+        Ok(created) => {
+            if created {
+                files_created.push(".gitignore");
+                result_text.push_str("✅ .gitignore configurado para proyecto Node.js\n");
             } else {
-                if let Ok(mut status) = status_clone.lock() {
-                    *status = "✅ GitHub Actions configurado exitosamente".to_string();
-                }
-                utils::log_success("GITHUB-SETUP", "GitHub Actions configured successfully");
+                files_skipped.push(".gitignore");
+                result_text.push_str("⚠️  .gitignore ya existe con reglas Node.js\n");
             }
+        }
+        Err(e) => {
+            result_text.push_str(&format!("❌ Error configurando .gitignore: {}\n", e));
+            success = false;
+        }
+    }
 
-            // Store result
-            if let Ok(mut result) = result_clone.lock() {
-                *result = result_text;
-            }
+    // Step 7: Ensure the release notes template exists
+    broadcast_status(&event_tx, "📄 Verificando plantilla de release notes...").await;
 
-            // Mark as finished
-            if let Ok(mut finished) = finished_clone.lock() {
-                *finished = true;
-            }
-        });
+    let template_path = resolve_release_notes_template_path(&config);
+    match ensure_release_notes_template(&config) {
+        Ok(true) => {
+            result_text.push_str(&format!(
+                "✅ Plantilla de release notes creada en {}\n",
+                template_path.display()
+            ));
+        }
+        Ok(false) => {
+            result_text.push_str(&format!(
+                "✅ Plantilla de release notes verificada ({})\n",
+                template_path.display()
+            ));
+        }
+        Err(e) => {
+            result_text.push_str(&format!("❌ Error configurando plantilla: {}\n", e));
+        }
+    }
+
+    // Summary
+    result_text.push('\n');
+    result_text.push_str("📋 RESUMEN DE CONFIGURACIÓN\n");
+    result_text.push_str("=".repeat(30).as_str());
+    result_text.push_str("\n\n");
+
+    if !files_created.is_empty() {
+        result_text.push_str("✅ Archivos creados:\n");
+        for file in &files_created {
+            result_text.push_str(&format!("   • {}\n", file));
+        }
+        result_text.push('\n');
+    }
+
+    if !files_skipped.is_empty() {
+        result_text.push_str("⚠️  Archivos ya existentes (no modificados):\n");
+        for file in &files_skipped {
+            result_text.push_str(&format!("   • {}\n", file));
+        }
+        result_text.push('\n');
+    }
+
+    // Next steps
+    result_text.push_str("🚀 PRÓXIMOS PASOS\n");
+    result_text.push_str("=".repeat(20).as_str());
+    result_text.push_str("\n\n");
+    if git_host == GitHost::GitLab {
+        result_text.push_str("1. 🔑 Configurar variables de CI/CD en GitLab:\n");
+        result_text.push_str(
+            "   • GITLAB_TOKEN: Project access token con scopes 'api' y 'write_repository'\n",
+        );
+        result_text.push_str("   • Ir a Settings > CI/CD > Variables (marcada como masked)\n\n");
+    } else {
+        result_text.push_str("1. 🔑 Configurar secrets en GitHub:\n");
+        result_text.push_str("   • GITHUB_TOKEN: Personal access token con permisos 'repo'\n");
+        result_text.push_str("   • Ir a Settings > Secrets and variables > Actions\n\n");
+    }
+    result_text.push_str("2. 📦 Verificar dependencias (opcional):\n");
+    result_text.push_str("   • Si tienes npm instalado: npm install\n");
+    result_text.push_str("   • Esto actualizará package-lock.json con versiones exactas\n\n");
+    result_text.push_str("3. 📝 Usar commits convencionales:\n");
+    result_text.push_str("   • feat: nueva funcionalidad (minor version)\n");
+    result_text.push_str("   • fix: corrección de bug (patch version)\n");
+    result_text.push_str("   • feat!: breaking change (major version)\n\n");
+    result_text.push_str("4. 🚢 Hacer push a main para ejecutar el primer release:\n");
+    result_text.push_str("   • git add .\n");
+    result_text.push_str(&format!(
+        "   • git commit -m \"feat: setup semantic-release with {}\"\n",
+        ci_name
+    ));
+    result_text.push_str("   • git push origin main\n\n");
+    result_text.push_str("💡 NOTA: Los scripts de test y build son placeholders.\n");
+    result_text.push_str("   Personaliza package.json según las necesidades de tu proyecto.\n\n");
+
+    // Update final status
+    let has_errors = files_created.is_empty() && files_skipped.is_empty();
+    let status = if has_errors {
+        success = false;
+        format!("❌ Error configurando {}", ci_name)
+    } else {
+        utils::log_success("CI-SETUP", &format!("{} configured successfully", ci_name));
+        format!("✅ {} configurado exitosamente", ci_name)
+    };
+
+    broadcast_finished(&event_tx, finished(success, status, result_text)).await;
+    Ok(())
+}
+
+/// Name of the CI service set up for `host`
+fn ci_name(host: GitHost) -> &'static str {
+    match host {
+        GitHost::GitHub => "GitHub Actions",
+        GitHost::GitLab => "GitLab CI",
+        GitHost::Bitbucket => "Bitbucket Pipelines",
     }
 }
 
-fn setup_package_json() -> Result<bool> {
-    use crate::git::repository::GitRepo;
+/// `devDependencies` line of the semantic-release plugin that publishes the
+/// release on `host`; Bitbucket has no official one.
+fn release_plugin_dependency(host: GitHost, indent: usize) -> String {
+    let (plugin, version) = match host {
+        GitHost::GitHub => ("@semantic-release/github", "^9.2.6"),
+        GitHost::GitLab => ("@semantic-release/gitlab", "^13.2.1"),
+        GitHost::Bitbucket => return String::new(),
+    };
+    format!(
+        "{:indent$}\"{}\": \"{}\",\n",
+        "",
+        plugin,
+        version,
+        indent = indent
+    )
+}
+
+fn setup_package_json(git_host: GitHost) -> Result<bool> {
     use std::fs;
     use std::path::Path;
 
@@ -749,20 +765,46 @@ fn setup_package_json() -> Result<bool> {
     "@semantic-release/changelog": "^6.0.3",
     "@semantic-release/commit-analyzer": "^11.1.0",
     "@semantic-release/git": "^10.0.1",
-    "@semantic-release/github": "^9.2.6",
-    "@semantic-release/release-notes-generator": "^12.1.0",
+{}    "@semantic-release/release-notes-generator": "^12.1.0",
     "semantic-release": "^22.0.12"
   }}
 }}
 "#,
-        repo_url
+        repo_url,
+        release_plugin_dependency(git_host, 4)
     );
 
     fs::write(package_path, package_json)?;
     Ok(true)
 }
 
-fn setup_releaserc() -> Result<bool> {
+/// `.releaserc.json` entry publishing the release on GitHub
+const GITHUB_RELEASE_PLUGIN: &str = r#"    ["@semantic-release/github", {
+      "assets": [
+        {
+          "path": "dist/**/*",
+          "label": "Distribution files"
+        }
+      ],
+      "successComment": "🎉 This release is now available in [version ${nextRelease.version}](${releases.find(release => release.name === 'GitHub release').url}) 🎉",
+      "failComment": "This release from branch `${branch.name}` has failed due to the following errors:\n- ${errors.map(err => err.message).join('\\n- ')}",
+      "labels": ["released"],
+      "assignees": ["@semantic-release/github"]
+    }],
+"#;
+
+/// `.releaserc.json` entry publishing the release on GitLab
+const GITLAB_RELEASE_PLUGIN: &str = r#"    ["@semantic-release/gitlab", {
+      "assets": [
+        {
+          "path": "dist/**/*",
+          "label": "Distribution files"
+        }
+      ]
+    }],
+"#;
+
+fn setup_releaserc(git_host: GitHost) -> Result<bool> {
     use std::fs;
     use std::path::Path;
 
@@ -773,38 +815,51 @@ fn setup_releaserc() -> Result<bool> {
         return Ok(false);
     }
 
-    let releaserc_json = r#"{
+    let publish_plugin = match git_host {
+        GitHost::GitHub => GITHUB_RELEASE_PLUGIN,
+        GitHost::GitLab => GITLAB_RELEASE_PLUGIN,
+        GitHost::Bitbucket => "",
+    };
+    let releaserc_json = format!(
+        r#"{{
   "branches": ["main"],
   "plugins": [
     "@semantic-release/commit-analyzer",
     "@semantic-release/release-notes-generator",
-    ["@semantic-release/changelog", {
+    ["@semantic-release/changelog", {{
       "changelogFile": "CHANGELOG.md"
-    }],
-    ["@semantic-release/github", {
-      "assets": [
-        {
-          "path": "dist/**/*",
-          "label": "Distribution files"
-        }
-      ],
-      "successComment": "🎉 This release is now available in [version ${nextRelease.version}](${releases.find(release => release.name === 'GitHub release').url}) 🎉",
-      "failComment": "This release from branch `${branch.name}` has failed due to the following errors:\n- ${errors.map(err => err.message).join('\\n- ')}",
-      "labels": ["released"],
-      "assignees": ["@semantic-release/github"]
-    }],
-    ["@semantic-release/git", {
+    }}],
+{}    ["@semantic-release/git", {{
       "assets": ["package.json", "package-lock.json", "CHANGELOG.md"],
-      "message": "chore(release): ${nextRelease.version} [skip ci]\n\n${nextRelease.notes}"
-    }]
+      "message": "chore(release): ${{nextRelease.version}} [skip ci]\n\n${{nextRelease.notes}}"
+    }}]
   ]
-}
-"#;
+}}
+"#,
+        publish_plugin
+    );
 
     fs::write(releaserc_path, releaserc_json)?;
     Ok(true)
 }
 
+/// Pipeline file generated for `host`; Bitbucket Pipelines are not generated
+fn ci_config_path(host: GitHost) -> Option<&'static str> {
+    match host {
+        GitHost::GitHub => Some(".github/workflows/release.yml"),
+        GitHost::GitLab => Some(".gitlab-ci.yml"),
+        GitHost::Bitbucket => None,
+    }
+}
+
+fn setup_ci_config(host: GitHost) -> Result<bool> {
+    match host {
+        GitHost::GitHub => setup_github_workflow(),
+        GitHost::GitLab => setup_gitlab_ci(),
+        GitHost::Bitbucket => Ok(false),
+    }
+}
+
 fn setup_github_workflow() -> Result<bool> {
     use std::fs;
     use std::path::Path;
@@ -920,9 +975,68 @@ jobs:
     Ok(true)
 }
 
-fn setup_package_lock() -> Result<bool> {
+/// GitLab CI equivalent of the GitHub Actions workflow: test on merge requests
+/// and on main, release on pushes to main.
+fn setup_gitlab_ci() -> Result<bool> {
+    use std::fs;
+    use std::path::Path;
+
+    let pipeline_path = Path::new(".gitlab-ci.yml");
+
+    // If the pipeline already exists, don't modify it
+    if pipeline_path.exists() {
+        return Ok(false);
+    }
+
+    let pipeline_yml = r#"stages:
+  - test
+  - release
+
+variables:
+  # semantic-release needs the full history and tags
+  GIT_DEPTH: 0
+
+default:
+  image: node:lts
+  cache:
+    key:
+      files:
+        - package-lock.json
+    paths:
+      - .npm/
+  before_script:
+    - |
+      if [ -f package-lock.json ]; then
+        npm ci --cache .npm --prefer-offline
+      else
+        npm install --cache .npm
+      fi
+
+test:
+  stage: test
+  script:
+    - npm test
+    - npm run build
+  rules:
+    - if: $CI_PIPELINE_SOURCE == "merge_request_event"
+    - if: $CI_COMMIT_BRANCH == "main"
+
+release:
+  stage: release
+  script:
+    - npm run build
+    # GITLAB_TOKEN (and NPM_TOKEN if publishing) come from Settings > CI/CD > Variables
+    - npx semantic-release
+  rules:
+    - if: $CI_COMMIT_BRANCH == "main" && $CI_PIPELINE_SOURCE == "push"
+"#;
+
+    fs::write(pipeline_path, pipeline_yml)?;
+    Ok(true)
+}
+
+async fn setup_package_lock(git_host: GitHost) -> Result<bool> {
     use std::path::Path;
-    use std::process::Command;
 
     let package_lock_path = Path::new("package-lock.json");
     let package_json_path = Path::new("package.json");
@@ -941,11 +1055,15 @@ fn setup_package_lock() -> Result<bool> {
     let output = if cfg!(target_os = "windows") {
         Command::new("cmd")
             .args(["/C", "npm install --package-lock-only"])
+            .kill_on_drop(true)
             .output()
+            .await
     } else {
         Command::new("npm")
             .args(["install", "--package-lock-only"])
+            .kill_on_drop(true)
             .output()
+            .await
     };
 
     match output {
@@ -956,46 +1074,48 @@ fn setup_package_lock() -> Result<bool> {
                     Ok(true)
                 } else {
                     // If npm install failed, create a minimal package-lock.json
-                    create_minimal_package_lock()?;
+                    create_minimal_package_lock(git_host)?;
                     Ok(true)
                 }
             } else {
                 // If npm install failed, create a minimal package-lock.json
-                create_minimal_package_lock()?;
+                create_minimal_package_lock(git_host)?;
                 Ok(true)
             }
         }
         Err(_) => {
             // If npm is not available, create a minimal package-lock.json
-            create_minimal_package_lock()?;
+            create_minimal_package_lock(git_host)?;
             Ok(true)
         }
     }
 }
 
-fn create_minimal_package_lock() -> Result<()> {
-    let package_lock_json = r#"{
+fn create_minimal_package_lock(git_host: GitHost) -> Result<()> {
+    let package_lock_json = format!(
+        r#"{{
   "name": "semantic-release-project",
   "version": "1.0.0",
   "lockfileVersion": 3,
   "requires": true,
-  "packages": {
-    "": {
+  "packages": {{
+    "": {{
       "name": "semantic-release-project",
       "version": "1.0.0",
       "license": "MIT",
-      "devDependencies": {
+      "devDependencies": {{
         "@semantic-release/changelog": "^6.0.3",
         "@semantic-release/commit-analyzer": "^11.1.0",
         "@semantic-release/git": "^10.0.1",
-        "@semantic-release/github": "^9.2.6",
-        "@semantic-release/release-notes-generator": "^12.1.0",
+{}        "@semantic-release/release-notes-generator": "^12.1.0",
         "semantic-release": "^22.0.12"
-      }
-    }
-  }
-}
-"#;
+      }}
+    }}
+  }}
+}}
+"#,
+        release_plugin_dependency(git_host, 8)
+    );
 
     std::fs::write("package-lock.json", package_lock_json)?;
     Ok(())
@@ -1122,8 +1242,3 @@ fn ensure_nodejs_gitignore_rules() -> Result<bool> {
         Ok(false)
     }
 }
-
-fn ensure_plantilla_template_exists() -> Result<()> {
-    // Implementation of ensure_plantilla_template_exists function
-    Ok(())
-}
@@ -1,18 +1,21 @@
 use crate::error::Result;
+use futures::StreamExt;
 use std::fs::OpenOptions;
 use std::io::Write;
-use tracing::{debug, error, instrument};
+use std::sync::Arc;
+use tracing::{debug, error, instrument, warn};
 
 use crate::{
     app::App,
     services::{jira::JiraClient, monday::MondayClient},
-    types::{JiraTask, MondayTask},
+    types::{AppConfig, JiraTask, MondayTask, TaskIdStatus, TaskSystem},
 };
 
 #[allow(async_fn_in_trait)]
 pub trait TaskOperations {
     async fn search_monday_tasks(&self, query: &str) -> Result<Vec<MondayTask>>;
     async fn search_jira_tasks(&self, query: &str) -> Result<Vec<JiraTask>>;
+    async fn check_monday_connection(&self) -> Result<String>;
     fn update_task_selection(&mut self);
 }
 
@@ -21,6 +24,35 @@ impl TaskOperations for App {
     async fn search_monday_tasks(&self, query: &str) -> Result<Vec<MondayTask>> {
         debug!("Starting Monday.com task search");
 
+        if let Some(item_ids) = monday_item_ids(query) {
+            debug!(
+                count = item_ids.len(),
+                "Query is a list of item IDs, fetching them directly"
+            );
+            let client = monday_search_client(self)?;
+            let mut details =
+                client.get_task_details(item_ids.clone(), self.config.monday_task_concurrency());
+
+            let mut tasks = Vec::new();
+            let mut first_error = None;
+            while let Some((item_id, task)) = details.next().await {
+                match task {
+                    Ok(task) => tasks.push(task),
+                    Err(e) => {
+                        warn!(%item_id, error = %e, "Could not fetch Monday.com item");
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+
+            // Nothing found because of an error (e.g. expired credentials): report it
+            if let (true, Some(e)) = (tasks.is_empty(), first_error) {
+                return Err(e);
+            }
+            tasks.sort_by_key(|task| item_ids.iter().position(|id| *id == task.id));
+            return Ok(tasks);
+        }
+
         // Write debug to file
         let mut debug_file = OpenOptions::new()
             .create(true)
@@ -35,7 +67,7 @@ impl TaskOperations for App {
         )
         .ok();
 
-        let client = MondayClient::new(&self.config)?;
+        let client = monday_search_client(self)?;
         debug!("Monday.com client created successfully");
         writeln!(debug_file, "DEBUG: MondayClient created successfully").ok();
 
@@ -66,6 +98,16 @@ impl TaskOperations for App {
     async fn search_jira_tasks(&self, query: &str) -> Result<Vec<JiraTask>> {
         debug!("Starting JIRA task search");
 
+        if let Some(keys) = jira_issue_keys(query) {
+            debug!(
+                count = keys.len(),
+                "Query is a list of issue keys, fetching them directly"
+            );
+            let mut tasks = JiraClient::new(&self.config)?.get_issues(&keys).await?;
+            tasks.sort_by_key(|task| keys.iter().position(|key| *key == task.key));
+            return Ok(tasks);
+        }
+
         // Write debug to file
         let mut debug_file = OpenOptions::new()
             .create(true)
@@ -112,6 +154,12 @@ impl TaskOperations for App {
         result
     }
 
+    #[instrument(skip(self))]
+    async fn check_monday_connection(&self) -> Result<String> {
+        let client = MondayClient::new(&self.config)?;
+        client.test_connection().await
+    }
+
     #[instrument(skip(self))]
     fn update_task_selection(&mut self) {
         use crate::types::TaskLike;
@@ -132,11 +180,7 @@ impl TaskOperations for App {
                     .iter()
                     .map(|t| t.get_id().to_string())
                     .collect();
-                self.commit_form.scope = if task_ids.is_empty() {
-                    String::new()
-                } else {
-                    task_ids.join("|")
-                };
+                self.set_task_scope(&task_ids);
                 debug!("Updated scope with {} Monday task IDs", task_ids.len());
             }
             crate::types::TaskSystem::Jira => {
@@ -153,11 +197,7 @@ impl TaskOperations for App {
                     .iter()
                     .map(|t| t.key.clone())
                     .collect();
-                self.commit_form.scope = if task_ids.is_empty() {
-                    String::new()
-                } else {
-                    task_ids.join("|")
-                };
+                self.set_task_scope(&task_ids);
                 debug!("Updated scope with {} JIRA task IDs", task_ids.len());
             }
             crate::types::TaskSystem::None => {
@@ -170,3 +210,230 @@ impl TaskOperations for App {
         }
     }
 }
+
+impl App {
+    /// Put the selected task IDs in the scope, warning when the result is too
+    /// long for a readable header (or shortening it with `COMPACT_TASK_SCOPE`).
+    fn set_task_scope(&mut self, task_ids: &[String]) {
+        let max_length = self.config.task_scope_max_length();
+        let scope = task_ids.join("|");
+        if scope.chars().count() <= max_length {
+            self.commit_form.scope = scope;
+            return;
+        }
+
+        if self.config.compact_task_scope {
+            self.commit_form.scope = compact_task_scope(task_ids);
+            self.message = Some(format!(
+                "ℹ️ Scope resumido a '{}': las {} tareas van en el trailer del commit",
+                self.commit_form.scope,
+                task_ids.len()
+            ));
+        } else {
+            warn!(
+                length = scope.chars().count(),
+                max_length, "Task scope exceeds the configured length"
+            );
+            self.message = Some(format!(
+                "⚠️ El scope con {} tareas ocupa {} caracteres (máx. {}). Usa COMPACT_TASK_SCOPE=true para dejarlas solo en el trailer",
+                task_ids.len(),
+                scope.chars().count(),
+                max_length
+            ));
+            self.commit_form.scope = scope;
+        }
+    }
+}
+
+impl App {
+    /// With `VALIDATE_TASK_IDS`, check in the background that the task IDs
+    /// typed in the scope exist. Selected tasks came from the task system, so
+    /// only the ones written by hand are checked.
+    pub(crate) fn check_scope_task_ids(&mut self) {
+        if !self.config.validate_task_ids || self.config.offline {
+            return;
+        }
+        let selected: Vec<&str> = self
+            .commit_form
+            .selected_monday_tasks
+            .iter()
+            .map(|task| task.id.as_str())
+            .chain(
+                self.commit_form
+                    .selected_jira_tasks
+                    .iter()
+                    .map(|task| task.key.as_str()),
+            )
+            .collect();
+        let ids: Vec<String> =
+            scope_task_ids(&self.commit_form.scope, &self.config.get_task_system())
+                .into_iter()
+                .filter(|id| !selected.contains(&id.as_str()))
+                .collect();
+        if ids.is_empty() {
+            return;
+        }
+        debug!(count = ids.len(), "Checking scope task IDs");
+        self.background_task_manager
+            .start_task_id_check(&self.config, ids);
+    }
+}
+
+/// Summary scope for many tasks: the first ID followed by "…".
+fn compact_task_scope(task_ids: &[String]) -> String {
+    match task_ids.first() {
+        Some(first) => format!("{}…", first),
+        None => String::new(),
+    }
+}
+
+// =============================================================================
+// LOOKUP BY ID
+// =============================================================================
+
+/// Words of a query separated by spaces or commas, as pasted from a ticket.
+fn query_tokens(query: &str) -> Vec<&str> {
+    query
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+/// Item IDs when the query is only numeric Monday.com IDs, in the order given.
+pub fn monday_item_ids(query: &str) -> Option<Vec<String>> {
+    let tokens = query_tokens(query);
+    if tokens.is_empty() || !tokens.iter().all(|t| t.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let mut ids: Vec<String> = Vec::new();
+    for token in tokens {
+        if !ids.iter().any(|id| id == token) {
+            ids.push(token.to_string());
+        }
+    }
+    Some(ids)
+}
+
+/// Issue keys (`PROJ-123`, any case) when the query is only JIRA keys, uppercased.
+pub fn jira_issue_keys(query: &str) -> Option<Vec<String>> {
+    let tokens = query_tokens(query);
+    if tokens.is_empty() || !tokens.iter().all(|token| is_jira_key(token)) {
+        return None;
+    }
+    let mut keys: Vec<String> = Vec::new();
+    for token in tokens {
+        let key = token.to_uppercase();
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    Some(keys)
+}
+
+/// `PROJ-123`, in any case.
+fn is_jira_key(token: &str) -> bool {
+    token.split_once('-').is_some_and(|(project, number)| {
+        project.starts_with(|c: char| c.is_ascii_alphabetic())
+            && project
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+// =============================================================================
+// SCOPE TASK ID VALIDATION
+// =============================================================================
+
+/// Task IDs in a scope (`1234|5678`, `PROJ-1, PROJ-2`), skipping any other
+/// word. JIRA keys are uppercased; nothing is returned without a task system.
+pub fn scope_task_ids(scope: &str, task_system: &TaskSystem) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for token in scope
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '|')
+        .filter(|token| !token.is_empty())
+    {
+        let id = match task_system {
+            TaskSystem::Monday if token.chars().all(|c| c.is_ascii_digit()) => token.to_string(),
+            TaskSystem::Jira if is_jira_key(token) => token.to_uppercase(),
+            _ => continue,
+        };
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// Monday.com client for the task search, adding up the time it waits on the
+/// rate limiter so the search can report it (`App::monday_rate_limit_wait`).
+fn monday_search_client(app: &App) -> Result<MondayClient> {
+    let waited = app.monday_rate_limit_wait.clone();
+    Ok(
+        MondayClient::new(&app.config)?.with_rate_limit_listener(Arc::new(move |wait| {
+            if let Ok(mut waited) = waited.lock() {
+                *waited += wait;
+            }
+        })),
+    )
+}
+
+/// Ask the configured task system whether each ID exists, one request per ID.
+pub async fn check_task_ids(config: &AppConfig, ids: &[String]) -> Vec<(String, TaskIdStatus)> {
+    let mut results = Vec::new();
+    match config.get_task_system() {
+        TaskSystem::Monday => {
+            let client = match MondayClient::new(config) {
+                Ok(client) => client,
+                Err(e) => {
+                    return ids
+                        .iter()
+                        .map(|id| (id.clone(), TaskIdStatus::Failed(e.to_string())))
+                        .collect()
+                }
+            };
+            for id in ids {
+                results.push((id.clone(), client.check_task_exists(id).await));
+            }
+        }
+        TaskSystem::Jira => {
+            let client = match JiraClient::new(config) {
+                Ok(client) => client,
+                Err(e) => {
+                    return ids
+                        .iter()
+                        .map(|id| (id.clone(), TaskIdStatus::Failed(e.to_string())))
+                        .collect()
+                }
+            };
+            for id in ids {
+                results.push((id.clone(), client.check_issue_exists(id).await));
+            }
+        }
+        TaskSystem::None => {}
+    }
+    results
+}
+
+/// One line per ID that couldn't be confirmed, e.g. `1234: no existe`.
+/// Empty when every ID was found.
+pub fn task_id_problems(config: &AppConfig, results: &[(String, TaskIdStatus)]) -> Vec<String> {
+    results
+        .iter()
+        .filter_map(|(id, status)| {
+            let problem = match status {
+                TaskIdStatus::Found => return None,
+                TaskIdStatus::NotFound => config.tr("task_check.not_found").to_string(),
+                TaskIdStatus::Forbidden => config.tr("task_check.forbidden").to_string(),
+                TaskIdStatus::NetworkError(error) => {
+                    format!("{} ({})", config.tr("task_check.network_error"), error)
+                }
+                TaskIdStatus::Failed(error) => {
+                    format!("{} ({})", config.tr("task_check.failed"), error)
+                }
+            };
+            Some(format!("{}: {}", id, problem))
+        })
+        .collect()
+}
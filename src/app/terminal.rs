@@ -0,0 +1,89 @@
+use crossterm::{
+    cursor::Show,
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::backtrace::Backtrace;
+use std::io;
+use tracing::{error, warn};
+
+/// Raw mode and the alternate screen, active while the guard lives. Dropping
+/// it restores the terminal, so an early `?` return leaves a usable shell too.
+pub struct TerminalGuard {
+    _private: (),
+}
+
+impl TerminalGuard {
+    pub fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        if let Err(e) = execute!(io::stdout(), EnterAlternateScreen) {
+            let _ = disable_raw_mode();
+            return Err(e);
+        }
+        Ok(Self { _private: () })
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+/// Leave raw mode and the alternate screen and show the cursor again. Safe to
+/// call more than once; errors are ignored, there is nothing left to do then.
+pub fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, Show);
+}
+
+/// Make panics readable while the TUI is up. A panic on the main thread (the
+/// one that renders and handles keys) restores the terminal before printing
+/// the message and a backtrace, instead of writing them into the alternate
+/// screen in raw mode where they are lost. Panics in background tasks leave the
+/// TUI running and only go to the log.
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let backtrace = Backtrace::force_capture();
+        if std::thread::current().name() != Some("main") {
+            error!(panic = %info, %backtrace, "Background task panicked");
+            return;
+        }
+
+        restore_terminal();
+        error!(panic = %info, %backtrace, "Application panicked");
+        eprintln!("{}\n\n{}", info, backtrace);
+    }));
+}
+
+/// Restore the terminal and exit when the process is interrupted (SIGINT, or
+/// SIGTERM on Unix). In raw mode Ctrl+C reaches the app as a key, so this
+/// covers `kill` and the like, which would otherwise leave the shell in raw
+/// mode on the alternate screen.
+pub fn exit_on_signal() {
+    tokio::spawn(async {
+        let exit_code = termination_signal().await;
+        warn!(exit_code, "Interrupted by a signal, restoring the terminal");
+        restore_terminal();
+        std::process::exit(exit_code);
+    });
+}
+
+/// Wait for an interrupting signal and return the conventional exit code for it.
+async fn termination_signal() -> i32 {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        if let Ok(mut terminate) = signal(SignalKind::terminate()) {
+            tokio::select! {
+                Ok(()) = tokio::signal::ctrl_c() => return 130,
+                _ = terminate.recv() => return 143,
+            }
+        }
+    }
+    if tokio::signal::ctrl_c().await.is_err() {
+        // No signal handling available: never report an interruption
+        std::future::pending::<()>().await;
+    }
+    130
+}
@@ -1,81 +1,512 @@
 use dialoguer::{Input, Password, Select};
 use dirs::home_dir;
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
     error::{Result, SemanticReleaseError},
-    types::AppConfig,
+    git::GitHost,
+    i18n::Language,
+    types::{
+        AppConfig, BodyFormat, CommitTrailer, CommitType, ConfigWarning, ConfigWarningSeverity,
+        GroupingStrategy, ReleasePackage, SecretScanMode, VersionStrategy,
+    },
+    ui::keybindings::Keymap,
 };
 
+// =============================================================================
+// CONFIGURATION PROFILES
+// =============================================================================
+
+/// Name shown for the configuration stored in the plain `.env` file
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Setting in a profile file listing the remote URL fragments that select it
+const PROFILE_REMOTE_KEY: &str = "PROFILE_REMOTE";
+
+static ACTIVE_PROFILE: OnceLock<Option<String>> = OnceLock::new();
+
+/// Select the profile used for the rest of the process (`--profile <name>`).
+/// Must be called before the configuration is loaded for the first time.
+/// The name becomes part of a file name, so path separators and `..` are rejected.
+pub fn set_active_profile(profile: &str) -> Result<()> {
+    let name = profile.trim();
+    if name.contains(['/', '\\']) || name.contains("..") {
+        return Err(SemanticReleaseError::config_error(format!(
+            "Invalid profile name '{}': it can't contain '/', '\\' or '..'",
+            name
+        )));
+    }
+
+    let profile = Some(name.to_string()).filter(|name| !name.is_empty() && name != DEFAULT_PROFILE);
+    if ACTIVE_PROFILE.set(profile).is_err() {
+        warn!("Active profile was already selected, ignoring --profile");
+    }
+    Ok(())
+}
+
+/// Profile in use: the one given with `--profile`, otherwise the one whose
+/// `PROFILE_REMOTE` matches the repository remote, otherwise the default `.env`.
+pub fn active_profile() -> Option<&'static str> {
+    ACTIVE_PROFILE
+        .get_or_init(detect_profile_from_remote)
+        .as_deref()
+}
+
+/// Env file holding a profile's settings: `.env` for the default profile, `.env.<name>` otherwise.
+fn profile_file_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(name) => format!(".env.{}", name),
+        None => ".env".to_string(),
+    }
+}
+
+fn detect_profile_from_remote() -> Option<String> {
+    let output = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let remote = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if remote.is_empty() {
+        return None;
+    }
+
+    let mut dirs = vec![PathBuf::from(".")];
+    dirs.extend(home_dir());
+
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let Some(profile) = file_name.strip_prefix(".env.") else {
+                continue;
+            };
+            if profile.is_empty() || profile.ends_with("local") {
+                continue;
+            }
+
+            let Ok(content) = fs::read_to_string(entry.path()) else {
+                continue;
+            };
+            let matches_remote = content
+                .lines()
+                .filter_map(|line| line.strip_prefix(&format!("{}=", PROFILE_REMOTE_KEY)))
+                .flat_map(|patterns| patterns.trim_matches('"').split(','))
+                .map(str::trim)
+                .any(|pattern| !pattern.is_empty() && remote.contains(pattern));
+
+            if matches_remote {
+                info!(profile = profile, remote = %remote, "Selected configuration profile from git remote");
+                return Some(profile.to_string());
+            }
+        }
+    }
+
+    None
+}
+
+// =============================================================================
+// EXPLICIT CONFIGURATION FILE
+// =============================================================================
+
+static EXPLICIT_CONFIG_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Read settings from `path` before any discovered file (`--config <path>`).
+/// The file must exist and parse: a wrong path fails here instead of leaving
+/// every integration looking unconfigured. Call before loading the configuration.
+pub fn set_config_file(path: &Path) -> Result<()> {
+    if !path.is_file() {
+        return Err(SemanticReleaseError::config_error(format!(
+            "Config file {} does not exist",
+            path.display()
+        )));
+    }
+    read_settings_file(path)?;
+
+    if EXPLICIT_CONFIG_FILE.set(path.to_path_buf()).is_err() {
+        warn!("Config file was already selected, ignoring --config");
+    }
+    Ok(())
+}
+
+/// File given with `--config`, if any
+pub fn explicit_config_file() -> Option<&'static Path> {
+    EXPLICIT_CONFIG_FILE.get().map(PathBuf::as_path)
+}
+
+static OFFLINE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Disable the features that need the network for the rest of the process
+/// (`--offline`). Explicit instead of detected, so startup has no extra latency.
+/// Call before loading the configuration.
+pub fn set_offline_mode() {
+    OFFLINE_MODE.store(true, Ordering::Relaxed);
+}
+
+static NO_VERIFY_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Skip the repository's commit hooks for the rest of the process
+/// (`--no-verify`). Call before loading the configuration.
+pub fn set_no_verify_mode() {
+    NO_VERIFY_MODE.store(true, Ordering::Relaxed);
+}
+
+// =============================================================================
+// CONFIGURATION SOURCES
+// =============================================================================
+
+/// Where a setting can come from, from highest to lowest precedence.
+/// Each source overrides individual settings, not the whole configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigSource {
+    /// Real environment variables of the process (e.g. CI secrets)
+    ProcessEnv,
+    /// File given with `--config`
+    ExplicitFile,
+    /// Profile `.env` file in the current directory (the repository)
+    LocalEnvFile,
+    /// `~/.config/semantic-release-tui/config` (or `config.<profile>`)
+    UserConfigFile,
+    /// Profile `.env` file in the home directory, kept for older setups
+    HomeEnvFile,
+}
+
+impl ConfigSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::ProcessEnv => "process env",
+            Self::ExplicitFile => "--config file",
+            Self::LocalEnvFile => "local .env",
+            Self::UserConfigFile => "user config",
+            Self::HomeEnvFile => "home .env",
+        }
+    }
+}
+
+/// Directory for per-user settings shared by every repository
+fn user_config_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join(".config").join("semantic-release-tui"))
+}
+
+/// File where past task searches are kept between sessions
+pub fn search_history_path() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("search_history.json"))
+}
+
+/// Settings files of the active profile, in precedence order
 #[instrument]
 pub fn get_env_paths() -> Vec<PathBuf> {
-    let mut paths = Vec::new();
+    config_file_layers()
+        .into_iter()
+        .map(|(_, path)| path)
+        .collect()
+}
 
-    // First priority: .env in current directory (same as original project)
-    paths.push(PathBuf::from(".env"));
+fn config_file_layers() -> Vec<(ConfigSource, PathBuf)> {
+    let profile = active_profile();
+    let env_file = profile_file_name(profile);
+    let mut layers: Vec<(ConfigSource, PathBuf)> = explicit_config_file()
+        .map(|path| (ConfigSource::ExplicitFile, path.to_path_buf()))
+        .into_iter()
+        .collect();
+    layers.push((ConfigSource::LocalEnvFile, PathBuf::from(&env_file)));
+
+    if let Some(dir) = user_config_dir() {
+        let file = match profile {
+            Some(name) => format!("config.{}", name),
+            None => "config".to_string(),
+        };
+        layers.push((ConfigSource::UserConfigFile, dir.join(file)));
+    }
 
-    // Second priority: .env in home directory for global config
     if let Some(home) = home_dir() {
-        paths.push(home.join(".env"));
+        layers.push((ConfigSource::HomeEnvFile, home.join(&env_file)));
     }
 
-    debug!(path_count = paths.len(), "Generated environment file paths");
-    paths
+    debug!(
+        path_count = layers.len(),
+        "Generated configuration file paths"
+    );
+    layers
 }
 
-#[instrument]
-pub fn load_config() -> Result<AppConfig> {
-    info!("Loading application configuration");
+/// Layered view over the process environment and the settings files.
+/// Empty values are treated as unset so they don't hide lower layers.
+struct ConfigSources {
+    files: Vec<(ConfigSource, PathBuf, BTreeMap<String, String>)>,
+}
 
-    let env_paths = get_env_paths();
+impl ConfigSources {
+    fn load() -> Result<Self> {
+        let mut files = Vec::new();
+        for (source, path) in config_file_layers() {
+            if !path.exists() {
+                if source == ConfigSource::ExplicitFile {
+                    return Err(SemanticReleaseError::config_error(format!(
+                        "Config file {} does not exist",
+                        path.display()
+                    )));
+                }
+                continue;
+            }
+            info!(config_file = %path.display(), source = source.as_str(), "Found configuration file");
+            files.push((source, path.clone(), read_settings_file(&path)?));
+        }
+        Ok(Self { files })
+    }
 
-    // Try to load from each .env file in order of priority
-    for env_path in &env_paths {
-        if env_path.exists() {
-            info!(config_file = %env_path.display(), "Found configuration file");
-            return load_config_from_env(env_path);
+    fn get(&self, key: &str) -> Option<String> {
+        if let Some(value) = env::var(key).ok().filter(|value| !value.is_empty()) {
+            debug!(
+                key = key,
+                source = ConfigSource::ProcessEnv.as_str(),
+                "Resolved setting"
+            );
+            return Some(value);
+        }
+
+        for (source, path, values) in &self.files {
+            if let Some(value) = values.get(key).filter(|value| !value.is_empty()) {
+                debug!(key = key, source = source.as_str(), file = %path.display(), "Resolved setting");
+                return Some(value.clone());
+            }
         }
+
+        None
     }
 
-    warn!("No configuration file found, loading from environment variables");
-    // If no .env file exists, try to load from environment variables
-    load_config_from_env_vars()
+    /// Layer the value of `key` is taken from, if any
+    fn source_of(&self, key: &str) -> Option<ConfigSource> {
+        if env::var(key).is_ok_and(|value| !value.is_empty()) {
+            return Some(ConfigSource::ProcessEnv);
+        }
+        self.files
+            .iter()
+            .find(|(_, _, values)| values.get(key).is_some_and(|value| !value.is_empty()))
+            .map(|(source, _, _)| *source)
+    }
 }
 
-#[instrument(skip(env_path), fields(config_file = %env_path.display()))]
-fn load_config_from_env(env_path: &Path) -> Result<AppConfig> {
-    debug!("Loading configuration from file");
+/// Parse a settings file: TOML when it has a `.toml` extension, dotenv otherwise.
+fn read_settings_file(path: &Path) -> Result<BTreeMap<String, String>> {
+    if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"))
+    {
+        read_toml_file(path)
+    } else {
+        read_env_file(path)
+    }
+}
 
-    dotenv::from_path(env_path).map_err(|e| {
-        error!(config_file = %env_path.display(), error = %e, "Failed to load environment file");
+/// Parse a TOML file of top-level settings named like the environment
+/// variables (`GEMINI_TOKEN = "..."`, case-insensitive). Arrays become
+/// comma-separated lists, as the env settings expect.
+fn read_toml_file(path: &Path) -> Result<BTreeMap<String, String>> {
+    let content = fs::read_to_string(path).map_err(|e| {
+        SemanticReleaseError::config_error_with_source(
+            format!("Failed to read config file {}", path.display()),
+            e,
+        )
+    })?;
+    let table: toml::Table = content.parse().map_err(|e: toml::de::Error| {
+        error!(config_file = %path.display(), error = %e, "Failed to parse TOML config file");
+        SemanticReleaseError::config_error(format!(
+            "Failed to parse config file {}: {}",
+            path.display(),
+            e.message()
+        ))
+    })?;
+
+    fn setting_value(value: &toml::Value) -> Option<String> {
+        match value {
+            toml::Value::String(text) => Some(text.clone()),
+            toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+                Some(value.to_string())
+            }
+            toml::Value::Array(items) => Some(
+                items
+                    .iter()
+                    .filter_map(setting_value)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            toml::Value::Datetime(_) | toml::Value::Table(_) => None,
+        }
+    }
+
+    Ok(table
+        .iter()
+        .filter_map(|(key, value)| {
+            let setting = setting_value(value);
+            if setting.is_none() {
+                warn!(config_file = %path.display(), key = %key, "Ignoring config setting that is not a plain value");
+            }
+            Some((key.to_uppercase(), setting?))
+        })
+        .collect())
+}
+
+/// Parse a dotenv-style file without touching the process environment.
+// `from_path_iter` is deprecated in favour of loading into the process env, which
+// would make file values indistinguishable from real environment variables.
+#[allow(deprecated)]
+fn read_env_file(path: &Path) -> Result<BTreeMap<String, String>> {
+    let load_error = |e: dotenv::Error| {
+        error!(config_file = %path.display(), error = %e, "Failed to load environment file");
         SemanticReleaseError::config_error(format!(
             "Failed to load config file {}: {}",
-            env_path.display(),
+            path.display(),
             e
         ))
-    })?; // Load .env file into environment
+    };
 
-    load_config_from_env_vars()
+    dotenv::from_path_iter(path)
+        .map_err(load_error)?
+        .map(|entry| entry.map_err(load_error))
+        .collect()
 }
 
+/// Load the configuration with this precedence, per setting:
+/// process env > `--config` file > local `.env` > `~/.config/semantic-release-tui/config` > `~/.env` > defaults.
 #[instrument]
-fn load_config_from_env_vars() -> Result<AppConfig> {
-    debug!("Loading configuration from environment variables");
+pub fn load_config() -> Result<AppConfig> {
+    info!("Loading application configuration");
+
+    let sources = ConfigSources::load()?;
+    if sources.files.is_empty() {
+        warn!("No configuration file found, loading from environment variables");
+    }
+
+    load_config_from_sources(&sources)
+}
+
+#[instrument(skip(sources))]
+fn load_config_from_sources(sources: &ConfigSources) -> Result<AppConfig> {
+    debug!("Resolving configuration settings");
 
     let config = AppConfig {
-        monday_api_key: env::var("MONDAY_API_KEY").ok(),
-        monday_account_slug: env::var("ACCOUNT_SLUG").ok(),
-        monday_board_id: env::var("MONDAY_BOARD_ID").ok(),
-        monday_url_template: env::var("MONDAY_URL_TEMPLATE").ok(),
-        jira_url: env::var("JIRA_URL").ok(),
-        jira_username: env::var("JIRA_USERNAME").ok(),
-        jira_api_token: env::var("JIRA_API_TOKEN").ok(),
-        jira_project_key: env::var("JIRA_PROJECT_KEY").ok(),
-        gemini_token: env::var("GEMINI_TOKEN").ok(),
+        monday_api_key: load_secret(sources, "MONDAY_API_KEY"),
+        monday_account_slug: sources.get("ACCOUNT_SLUG"),
+        monday_board_id: sources.get("MONDAY_BOARD_ID"),
+        monday_board_ids: sources
+            .get("MONDAY_BOARD_IDS")
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        monday_url_template: sources.get("MONDAY_URL_TEMPLATE"),
+        monday_rate_limit_per_minute: sources
+            .get("MONDAY_RATE_LIMIT_PER_MINUTE")
+            .and_then(|value| value.trim().parse().ok()),
+        monday_task_concurrency: sources
+            .get("MONDAY_TASK_CONCURRENCY")
+            .and_then(|value| value.trim().parse().ok()),
+        monday_post_commit_update: parse_env_bool(sources, "MONDAY_POST_COMMIT_UPDATE")
+            .unwrap_or(false),
+        jira_url: sources.get("JIRA_URL"),
+        jira_username: sources.get("JIRA_USERNAME"),
+        jira_api_token: load_secret(sources, "JIRA_API_TOKEN"),
+        jira_project_key: sources.get("JIRA_PROJECT_KEY"),
+        gemini_token: load_secret(sources, "GEMINI_TOKEN"),
+        release_notes_template_path: sources.get("RELEASE_NOTES_TEMPLATE"),
+        release_notes_dir: sources.get("RELEASE_NOTES_DIR"),
+        release_notes_strict: parse_env_bool(sources, "RELEASE_NOTES_STRICT").unwrap_or(false),
+        release_notes_compact: parse_env_bool(sources, "RELEASE_NOTES_COMPACT").unwrap_or(false),
+        release_notes_convention_warnings: parse_env_bool(
+            sources,
+            "RELEASE_NOTES_CONVENTION_WARNINGS",
+        )
+        .unwrap_or(false),
+        release_notes_public: parse_env_bool(sources, "RELEASE_NOTES_PUBLIC"),
+        release_notes_internal_types: sources
+            .get("RELEASE_NOTES_INTERNAL_TYPES")
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|commit_type| commit_type.trim().to_lowercase())
+                    .filter(|commit_type| !commit_type.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        release_responsible: sources.get("RELEASE_RESPONSIBLE"),
+        release_bot_authors: sources
+            .get("RELEASE_BOT_AUTHORS")
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|author| !author.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        commit_trailers: sources
+            .get("COMMIT_TRAILERS")
+            .map(|value| value.split(',').filter_map(CommitTrailer::parse).collect())
+            .unwrap_or_default(),
+        release_packages: sources
+            .get("RELEASE_PACKAGES")
+            .map(|value| value.split(',').filter_map(ReleasePackage::parse).collect())
+            .unwrap_or_default(),
+        http_proxy: sources
+            .get("HTTP_PROXY")
+            .or_else(|| sources.get("http_proxy")),
+        https_proxy: sources
+            .get("HTTPS_PROXY")
+            .or_else(|| sources.get("https_proxy")),
+        no_proxy: sources.get("NO_PROXY").or_else(|| sources.get("no_proxy")),
+        http_timeout_seconds: parse_env_u64(sources, "HTTP_TIMEOUT_SECONDS"),
+        ai_timeout_seconds: parse_env_u64(sources, "AI_TIMEOUT_SECONDS"),
+        release_notes_timeout_seconds: parse_env_u64(sources, "RELEASE_NOTES_TIMEOUT_SECONDS"),
+        profile: active_profile().map(str::to_string),
+        offline: OFFLINE_MODE.load(Ordering::Relaxed),
+        no_verify: NO_VERIFY_MODE.load(Ordering::Relaxed),
+        release_notes_grouping: parse_grouping_strategy(sources),
+        release_notes_body_format: parse_body_format(sources),
+        version_strategy: parse_version_strategy(sources),
+        release_channel: sources.get("RELEASE_CHANNEL"),
+        git_host: parse_git_host(sources),
+        use_gitmoji: parse_env_bool(sources, "USE_GITMOJI").unwrap_or(false),
+        commit_preview_wrap: parse_env_bool(sources, "COMMIT_PREVIEW_WRAP").unwrap_or(false),
+        task_scope_max_length: sources
+            .get("TASK_SCOPE_MAX_LENGTH")
+            .and_then(|value| value.trim().parse().ok()),
+        compact_task_scope: parse_env_bool(sources, "COMPACT_TASK_SCOPE").unwrap_or(false),
+        validate_task_ids: parse_env_bool(sources, "VALIDATE_TASK_IDS").unwrap_or(false),
+        trivial_commit_types: sources.get("TRIVIAL_COMMIT_TYPES").map(|value| {
+            value
+                .split(',')
+                .map(|commit_type| commit_type.trim().to_lowercase())
+                .filter(|commit_type| !commit_type.is_empty() && commit_type != "none")
+                .collect()
+        }),
+        secret_scan: parse_secret_scan_mode(sources),
+        secret_patterns: parse_pattern_list(sources, "SECRET_PATTERNS"),
+        secret_allowlist: parse_pattern_list(sources, "SECRET_ALLOWLIST"),
+        gitmoji_overrides: sources
+            .get("GITMOJI_MAP")
+            .map(|value| parse_gitmoji_map(&value))
+            .unwrap_or_default(),
+        commit_templates: parse_commit_templates(sources),
+        language: parse_language(sources, "APP_LANGUAGE").unwrap_or_default(),
+        ai_language: parse_language(sources, "AI_LANGUAGE"),
+        ai_inline_suggestions: parse_env_bool(sources, "AI_INLINE_SUGGESTIONS").unwrap_or(false),
+        keybindings: sources.get("KEYBINDINGS"),
     };
 
     debug!(
@@ -88,20 +519,555 @@ fn load_config_from_env_vars() -> Result<AppConfig> {
     Ok(config)
 }
 
+// =============================================================================
+// SYSTEM KEYRING STORAGE
+// =============================================================================
+
+const KEYRING_SERVICE: &str = "semantic-release-tui";
+
+/// Settings that hold credentials and may live in the system keyring
+const SECRET_KEYS: [&str; 3] = ["MONDAY_API_KEY", "JIRA_API_TOKEN", "GEMINI_TOKEN"];
+
+/// Read a credential from the environment or settings files, falling back to the system keyring.
+/// Values in .env win so a key entered without the keyring is never shadowed.
+fn load_secret(sources: &ConfigSources, key: &str) -> Option<String> {
+    if let Some(secret) = sources.get(key) {
+        return Some(secret);
+    }
+
+    match keyring_get(key) {
+        Ok(secret) => {
+            if secret.is_some() {
+                debug!(key = key, "Loaded credential from system keyring");
+            }
+            secret
+        }
+        Err(e) => {
+            debug!(key = key, error = %e, "System keyring unavailable");
+            None
+        }
+    }
+}
+
+/// Keyring entry name for a credential; profiles get their own entries.
+fn keyring_user(key: &str) -> String {
+    match active_profile() {
+        Some(profile) => format!("{}:{}", profile, key),
+        None => key.to_string(),
+    }
+}
+
+fn keyring_get(key: &str) -> std::result::Result<Option<String>, keyring::Error> {
+    match keyring::Entry::new(KEYRING_SERVICE, &keyring_user(key))?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn keyring_set(key: &str, secret: &str) -> std::result::Result<(), keyring::Error> {
+    keyring::Entry::new(KEYRING_SERVICE, &keyring_user(key))?.set_password(secret)
+}
+
+fn keyring_delete(key: &str) -> std::result::Result<(), keyring::Error> {
+    match keyring::Entry::new(KEYRING_SERVICE, &keyring_user(key))?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether a system keyring (Keychain, Credential Manager, Secret Service) can be used.
+pub fn is_keyring_available() -> bool {
+    match keyring_get("availability-check") {
+        Ok(_) => true,
+        Err(e) => {
+            debug!(error = %e, "System keyring is not available");
+            false
+        }
+    }
+}
+
+fn secret_value<'a>(config: &'a AppConfig, key: &str) -> Option<&'a String> {
+    match key {
+        "MONDAY_API_KEY" => config.monday_api_key.as_ref(),
+        "JIRA_API_TOKEN" => config.jira_api_token.as_ref(),
+        "GEMINI_TOKEN" => config.gemini_token.as_ref(),
+        _ => None,
+    }
+}
+
+/// Save the configuration keeping credentials in the system keyring. Any
+/// credential the keyring cannot store is written to `.env` instead, with a warning.
+#[instrument(skip(config))]
+pub fn save_config_with_keyring(config: &AppConfig) -> Result<()> {
+    info!("Saving configuration with credentials in the system keyring");
+
+    let sources = ConfigSources::load()?;
+    let loaded = load_config_from_sources(&sources)?;
+    save_settings_with_keyring(config, &settings_to_save(config, &loaded, &sources))
+}
+
+/// Store the `changed` credentials in the keyring and save the rest of the
+/// settings. Credentials taken unchanged from another layer are left there.
+fn save_settings_with_keyring(config: &AppConfig, changed: &BTreeSet<String>) -> Result<()> {
+    let mut env_config = config.clone();
+    for key in SECRET_KEYS {
+        let Some(secret) = secret_value(config, key).filter(|_| changed.contains(key)) else {
+            continue;
+        };
+
+        match keyring_set(key, secret) {
+            Ok(()) => {
+                info!(key = key, "Stored credential in system keyring");
+                match key {
+                    "MONDAY_API_KEY" => env_config.monday_api_key = None,
+                    "JIRA_API_TOKEN" => env_config.jira_api_token = None,
+                    _ => env_config.gemini_token = None,
+                }
+            }
+            Err(e) => {
+                warn!(key = key, error = %e, "Could not store credential in keyring, keeping it in .env");
+                println!(
+                    "⚠️  Could not store {} in the system keyring ({}); saving it to .env in plain text",
+                    key, e
+                );
+            }
+        }
+    }
+
+    let env_path = PathBuf::from(profile_file_name(active_profile()));
+    save_config_to_env(&env_path, &env_config, changed)
+}
+
+/// Save the configuration edited on the TUI's configuration screen, keeping
+/// credentials where they already are: in the system keyring when it's
+/// available and `.env` holds none in plain text, in `.env` otherwise.
+/// Credentials cleared on the screen are removed from the keyring too, so they
+/// don't come back on the next load. Only the `edited` settings and the ones
+/// already in `.env` are written to it.
+#[instrument(skip(config, edited))]
+pub fn save_config_keeping_storage(config: &AppConfig, edited: &BTreeSet<&str>) -> Result<()> {
+    let changed: BTreeSet<String> = edited.iter().map(|key| key.to_string()).collect();
+    let env_path = PathBuf::from(profile_file_name(active_profile()));
+    if !is_keyring_available() || !plaintext_secrets_in_env(&env_path).is_empty() {
+        return save_config_to_env(&env_path, config, &changed);
+    }
+
+    for key in SECRET_KEYS {
+        if secret_value(config, key).is_none() {
+            if let Err(e) = keyring_delete(key) {
+                warn!(key = key, error = %e, "Could not remove credential from keyring");
+            }
+        }
+    }
+    save_settings_with_keyring(config, &changed)
+}
+
+/// Move the `keys` stored in plain text in `env_path` to the system keyring,
+/// removing their lines. The rest of the file is left as it is; a credential
+/// the keyring can't store stays there.
+#[instrument(fields(config_file = %env_path.display()))]
+fn move_secrets_to_keyring(env_path: &Path, keys: &[&str]) -> Result<()> {
+    let values = read_env_file(env_path)?;
+    let content = fs::read_to_string(env_path).map_err(|e| {
+        SemanticReleaseError::config_error_with_source(
+            format!("Failed to read config file {}", env_path.display()),
+            e,
+        )
+    })?;
+
+    let mut moved = Vec::new();
+    for key in keys {
+        let Some(secret) = values.get(*key).filter(|secret| !secret.is_empty()) else {
+            continue;
+        };
+        match keyring_set(key, secret) {
+            Ok(()) => {
+                info!(key = key, "Moved credential to system keyring");
+                moved.push(format!("{}=", key));
+            }
+            Err(e) => {
+                warn!(key = key, error = %e, "Could not store credential in keyring, keeping it in .env");
+                println!(
+                    "⚠️  Could not store {} in the system keyring ({}); it stays in .env",
+                    key, e
+                );
+            }
+        }
+    }
+    if moved.is_empty() {
+        return Ok(());
+    }
+
+    let kept: String = content
+        .split_inclusive('\n')
+        .filter(|line| !moved.iter().any(|prefix| line.starts_with(prefix.as_str())))
+        .collect();
+    fs::write(env_path, kept).map_err(|e| {
+        error!(config_file = %env_path.display(), error = %e, "Failed to write config file");
+        SemanticReleaseError::config_error(format!(
+            "Failed to write config file {}: {}",
+            env_path.display(),
+            e
+        ))
+    })?;
+
+    println!("🔐 Credentials moved to the system keyring");
+    Ok(())
+}
+
+/// Credentials currently stored in plain text in the `.env` file.
+fn plaintext_secrets_in_env(env_path: &Path) -> Vec<&'static str> {
+    let Ok(content) = fs::read_to_string(env_path) else {
+        return Vec::new();
+    };
+
+    SECRET_KEYS
+        .into_iter()
+        .filter(|key| {
+            content
+                .lines()
+                .any(|line| line.starts_with(&format!("{}=", key)) && line.len() > key.len() + 1)
+        })
+        .collect()
+}
+
+fn parse_env_bool(sources: &ConfigSources, key: &str) -> Option<bool> {
+    let value = sources.get(key)?;
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" | "yes" => Some(true),
+        "false" | "0" | "no" => Some(false),
+        _ => {
+            warn!(key = key, value = %value, "Ignoring invalid boolean setting, expected true or false");
+            None
+        }
+    }
+}
+
+fn parse_env_u64(sources: &ConfigSources, key: &str) -> Option<u64> {
+    let value = sources.get(key)?;
+    match value.trim().parse() {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            warn!(key = key, value = %value, error = %e, "Ignoring invalid numeric setting");
+            None
+        }
+    }
+}
+
+fn parse_grouping_strategy(sources: &ConfigSources) -> GroupingStrategy {
+    let Some(value) = sources.get("RELEASE_NOTES_GROUPING") else {
+        return GroupingStrategy::default();
+    };
+    GroupingStrategy::parse(&value).unwrap_or_else(|| {
+        warn!(value = %value, "Ignoring invalid RELEASE_NOTES_GROUPING, expected type, scope or type-scope");
+        GroupingStrategy::default()
+    })
+}
+
+fn parse_body_format(sources: &ConfigSources) -> BodyFormat {
+    let Some(value) = sources.get("RELEASE_NOTES_BODY_FORMAT") else {
+        return BodyFormat::default();
+    };
+    BodyFormat::parse(&value).unwrap_or_else(|| {
+        warn!(value = %value, "Ignoring invalid RELEASE_NOTES_BODY_FORMAT, expected collapsed or preserved");
+        BodyFormat::default()
+    })
+}
+
+fn parse_secret_scan_mode(sources: &ConfigSources) -> SecretScanMode {
+    let Some(value) = sources.get("SECRET_SCAN") else {
+        return SecretScanMode::default();
+    };
+    SecretScanMode::parse(&value).unwrap_or_else(|| {
+        warn!(value = %value, "Ignoring invalid SECRET_SCAN, expected confirm, block or off");
+        SecretScanMode::default()
+    })
+}
+
+/// Regular expressions separated by `;`, since commas are common inside them.
+fn parse_pattern_list(sources: &ConfigSources, key: &str) -> Vec<String> {
+    sources
+        .get(key)
+        .map(|value| {
+            value
+                .split(';')
+                .map(str::trim)
+                .filter(|pattern| !pattern.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_version_strategy(sources: &ConfigSources) -> VersionStrategy {
+    let Some(value) = sources.get("VERSION_STRATEGY") else {
+        return VersionStrategy::default();
+    };
+    VersionStrategy::parse(&value).unwrap_or_else(|| {
+        warn!(value = %value, "Ignoring invalid VERSION_STRATEGY, expected native or semantic-release");
+        VersionStrategy::default()
+    })
+}
+
+fn parse_git_host(sources: &ConfigSources) -> Option<String> {
+    let value = sources.get("GIT_HOST")?;
+    match GitHost::parse(&value) {
+        Some(host) => Some(host.as_str().to_string()),
+        None => {
+            warn!(value = %value, "Ignoring invalid GIT_HOST, expected github, gitlab or bitbucket");
+            None
+        }
+    }
+}
+
+fn parse_language(sources: &ConfigSources, key: &str) -> Option<Language> {
+    let value = sources.get(key)?;
+    let language = Language::parse(&value);
+    if language.is_none() {
+        warn!(key, value = %value, "Ignoring invalid language, expected es or en");
+    }
+    language
+}
+
+/// Parse `GITMOJI_MAP` entries like `feat=🎉,fix=🚑️`, ignoring unknown types.
+fn parse_gitmoji_map(value: &str) -> BTreeMap<String, String> {
+    value
+        .split(',')
+        .filter_map(|entry| {
+            let (commit_type, emoji) = entry.split_once('=')?;
+            let commit_type = CommitType::parse(commit_type)?;
+            let emoji = emoji.trim();
+            (!emoji.is_empty()).then(|| (commit_type.as_str().to_string(), emoji.to_string()))
+        })
+        .collect()
+}
+
+/// `COMMIT_TEMPLATE_<TYPE>` settings, keyed by type. A literal `\n` in the
+/// value is a line break, so templates fit on one line of the environment.
+fn parse_commit_templates(sources: &ConfigSources) -> BTreeMap<String, String> {
+    CommitType::all()
+        .iter()
+        .filter_map(|commit_type| {
+            let value = sources.get(&commit_template_key(commit_type))?;
+            let template = value.replace("\\n", "\n").replace("\r\n", "\n");
+            (!template.trim().is_empty()).then(|| (commit_type.as_str().to_string(), template))
+        })
+        .collect()
+}
+
+fn commit_template_key(commit_type: &CommitType) -> String {
+    format!("COMMIT_TEMPLATE_{}", commit_type.as_str().to_uppercase())
+}
+
+/// Check the loaded configuration for half-configured integrations and
+/// suspicious values.
+pub fn validate_config(config: &AppConfig) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+    let mut warn = |severity, setting, message: &str| {
+        warnings.push(ConfigWarning {
+            severity,
+            setting,
+            message: message.to_string(),
+        })
+    };
+
+    // Monday.com
+    match (&config.monday_api_key, &config.monday_account_slug) {
+        (Some(_), None) => warn(
+            ConfigWarningSeverity::Incomplete,
+            "ACCOUNT_SLUG",
+            "Monday.com API key is set but the account slug is missing",
+        ),
+        (None, Some(_)) => warn(
+            ConfigWarningSeverity::Incomplete,
+            "MONDAY_API_KEY",
+            "Monday.com account slug is set but the API key is missing",
+        ),
+        _ => {}
+    }
+    if config
+        .monday_board_id
+        .as_deref()
+        .is_some_and(|id| id.trim().parse::<u64>().is_err())
+    {
+        warn(
+            ConfigWarningSeverity::Suspicious,
+            "MONDAY_BOARD_ID",
+            "Monday.com board ID should be numeric",
+        );
+    }
+
+    // JIRA
+    let jira_fields = [
+        ("JIRA_URL", config.jira_url.is_some()),
+        ("JIRA_USERNAME", config.jira_username.is_some()),
+        ("JIRA_API_TOKEN", config.jira_api_token.is_some()),
+    ];
+    if jira_fields.iter().any(|(_, set)| *set) {
+        for (setting, _) in jira_fields.iter().filter(|(_, set)| !*set) {
+            warn(
+                ConfigWarningSeverity::Incomplete,
+                setting,
+                "JIRA is partially configured: URL, username and API token are all required",
+            );
+        }
+    }
+    if let Some(url) = &config.jira_url {
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            warn(
+                ConfigWarningSeverity::Suspicious,
+                "JIRA_URL",
+                "JIRA URL should start with https://",
+            );
+        } else if url.ends_with('/') {
+            warn(
+                ConfigWarningSeverity::Suspicious,
+                "JIRA_URL",
+                "JIRA URL should not end with a slash",
+            );
+        }
+    }
+
+    if config.is_monday_configured() && config.is_jira_configured() {
+        warn(
+            ConfigWarningSeverity::Suspicious,
+            "JIRA_URL",
+            "Both Monday.com and JIRA are configured; only Monday.com will be used",
+        );
+    }
+
+    // Gemini API keys look like `AIza` followed by 35 URL-safe characters
+    if let Some(token) = &config.gemini_token {
+        let looks_valid = token.len() == 39
+            && token.starts_with("AIza")
+            && token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !looks_valid {
+            warn(
+                ConfigWarningSeverity::Suspicious,
+                "GEMINI_TOKEN",
+                "Gemini token does not look like a Google API key (AIza...)",
+            );
+        }
+    }
+
+    for (setting, value) in [
+        ("HTTP_PROXY", &config.http_proxy),
+        ("HTTPS_PROXY", &config.https_proxy),
+    ] {
+        if value
+            .as_deref()
+            .is_some_and(|url| reqwest::Url::parse(url).is_err())
+        {
+            warn(
+                ConfigWarningSeverity::Suspicious,
+                setting,
+                "Proxy URL is not valid (expected http://[user:password@]host:port)",
+            );
+        }
+    }
+
+    for (setting, value) in [
+        ("HTTP_TIMEOUT_SECONDS", config.http_timeout_seconds),
+        ("AI_TIMEOUT_SECONDS", config.ai_timeout_seconds),
+        (
+            "RELEASE_NOTES_TIMEOUT_SECONDS",
+            config.release_notes_timeout_seconds,
+        ),
+    ] {
+        if value == Some(0) {
+            warn(
+                ConfigWarningSeverity::Suspicious,
+                setting,
+                "A timeout of 0 seconds makes every request fail",
+            );
+        }
+    }
+
+    for commit_type in config.trivial_commit_types.iter().flatten() {
+        if CommitType::parse(commit_type).is_none() {
+            warn(
+                ConfigWarningSeverity::Suspicious,
+                "TRIVIAL_COMMIT_TYPES",
+                &format!("Unknown commit type '{}'", commit_type),
+            );
+        }
+    }
+
+    for (setting, patterns) in [
+        ("SECRET_PATTERNS", &config.secret_patterns),
+        ("SECRET_ALLOWLIST", &config.secret_allowlist),
+    ] {
+        for pattern in patterns {
+            if let Err(e) = regex::Regex::new(pattern) {
+                warn(
+                    ConfigWarningSeverity::Suspicious,
+                    setting,
+                    &format!(
+                        "Invalid regular expression '{}', it is ignored: {}",
+                        pattern, e
+                    ),
+                );
+            }
+        }
+    }
+
+    // Remapped keys: unknown names and keys bound to two actions
+    let (_, keymap_problems) = Keymap::from_config(config.keybindings.as_deref(), config.language);
+    for problem in &keymap_problems {
+        warn(ConfigWarningSeverity::Suspicious, "KEYBINDINGS", problem);
+    }
+
+    warnings
+}
+
 #[instrument(skip(config))]
 pub fn save_config(config: &AppConfig) -> Result<()> {
     info!("Saving application configuration");
 
-    // Save to .env in current directory (same as original project)
-    let env_path = PathBuf::from(".env");
-    save_config_to_env(&env_path, config)
+    let sources = ConfigSources::load()?;
+    let loaded = load_config_from_sources(&sources)?;
+    let changed = settings_to_save(config, &loaded, &sources);
+
+    // Save to the active profile's file in current directory (same as original project)
+    let env_path = PathBuf::from(profile_file_name(active_profile()));
+    save_config_to_env(&env_path, config, &changed)
+}
+
+/// Settings of `config` that a save writes to the local `.env`: the ones that
+/// changed from the `loaded` configuration and the ones already taken from that
+/// file. Values resolved unchanged from the process environment or another
+/// settings file stay in their layer, so later changes there keep taking effect.
+fn settings_to_save(
+    config: &AppConfig,
+    loaded: &AppConfig,
+    sources: &ConfigSources,
+) -> BTreeSet<String> {
+    let loaded = config_settings(loaded);
+    config_settings(config)
+        .into_iter()
+        .filter(|setting| {
+            !loaded.contains(setting)
+                || sources.source_of(&setting.0) == Some(ConfigSource::LocalEnvFile)
+        })
+        .map(|(key, _)| key)
+        .collect()
 }
 
-#[instrument(skip(config), fields(config_file = %env_path.display()))]
-fn save_config_to_env(env_path: &Path, config: &AppConfig) -> Result<()> {
+/// Rewrite the settings already in `env_path` and add the `changed` ones.
+/// Lines of other settings and comments are kept as they are.
+#[instrument(skip(config, changed), fields(config_file = %env_path.display()))]
+fn save_config_to_env(
+    env_path: &Path,
+    config: &AppConfig,
+    changed: &BTreeSet<String>,
+) -> Result<()> {
     debug!("Saving configuration to file");
 
     let mut env_content = String::new();
+    let mut keys_in_file = BTreeSet::new();
 
     // Load existing .env content if it exists
     if env_path.exists() {
@@ -111,18 +1077,62 @@ fn save_config_to_env(env_path: &Path, config: &AppConfig) -> Result<()> {
         })?;
 
         let mut lines: Vec<String> = existing_content.lines().map(|s| s.to_string()).collect();
+        keys_in_file.extend(
+            lines
+                .iter()
+                .filter_map(|line| line.split_once('='))
+                .map(|(key, _)| key.trim().to_string()),
+        );
 
         // Remove existing keys that we're about to set
         lines.retain(|line| {
             !line.starts_with("MONDAY_API_KEY=")
                 && !line.starts_with("ACCOUNT_SLUG=")
                 && !line.starts_with("MONDAY_BOARD_ID=")
+                && !line.starts_with("MONDAY_BOARD_IDS=")
                 && !line.starts_with("MONDAY_URL_TEMPLATE=")
+                && !line.starts_with("MONDAY_RATE_LIMIT_PER_MINUTE=")
+                && !line.starts_with("MONDAY_TASK_CONCURRENCY=")
+                && !line.starts_with("MONDAY_POST_COMMIT_UPDATE=")
                 && !line.starts_with("JIRA_URL=")
                 && !line.starts_with("JIRA_USERNAME=")
                 && !line.starts_with("JIRA_API_TOKEN=")
                 && !line.starts_with("JIRA_PROJECT_KEY=")
                 && !line.starts_with("GEMINI_TOKEN=")
+                && !line.starts_with("RELEASE_NOTES_TEMPLATE=")
+                && !line.starts_with("RELEASE_NOTES_DIR=")
+                && !line.starts_with("RELEASE_NOTES_STRICT=")
+                && !line.starts_with("RELEASE_NOTES_COMPACT=")
+                && !line.starts_with("RELEASE_NOTES_CONVENTION_WARNINGS=")
+                && !line.starts_with("RELEASE_NOTES_PUBLIC=")
+                && !line.starts_with("RELEASE_NOTES_INTERNAL_TYPES=")
+                && !line.starts_with("RELEASE_RESPONSIBLE=")
+                && !line.starts_with("RELEASE_BOT_AUTHORS=")
+                && !line.starts_with("RELEASE_PACKAGES=")
+                && !line.starts_with("COMMIT_TRAILERS=")
+                && !line.starts_with("RELEASE_NOTES_GROUPING=")
+                && !line.starts_with("RELEASE_NOTES_BODY_FORMAT=")
+                && !line.starts_with("VERSION_STRATEGY=")
+                && !line.starts_with("RELEASE_CHANNEL=")
+                && !line.starts_with("GIT_HOST=")
+                && !line.starts_with("USE_GITMOJI=")
+                && !line.starts_with("GITMOJI_MAP=")
+                && !line.starts_with("COMMIT_TEMPLATE_")
+                && !line.starts_with("COMMIT_PREVIEW_WRAP=")
+                && !line.starts_with("TASK_SCOPE_MAX_LENGTH=")
+                && !line.starts_with("COMPACT_TASK_SCOPE=")
+                && !line.starts_with("VALIDATE_TASK_IDS=")
+                && !line.starts_with("TRIVIAL_COMMIT_TYPES=")
+                && !line.starts_with("SECRET_SCAN=")
+                && !line.starts_with("SECRET_PATTERNS=")
+                && !line.starts_with("SECRET_ALLOWLIST=")
+                && !line.starts_with("APP_LANGUAGE=")
+                && !line.starts_with("AI_LANGUAGE=")
+                && !line.starts_with("AI_INLINE_SUGGESTIONS=")
+                && !line.starts_with("KEYBINDINGS=")
+                && !line.starts_with("HTTP_TIMEOUT_SECONDS=")
+                && !line.starts_with("AI_TIMEOUT_SECONDS=")
+                && !line.starts_with("RELEASE_NOTES_TIMEOUT_SECONDS=")
         });
 
         env_content = lines.join("\n");
@@ -131,7 +1141,30 @@ fn save_config_to_env(env_path: &Path, config: &AppConfig) -> Result<()> {
         }
     }
 
-    // Add our configuration
+    for (key, value) in config_settings(config) {
+        if keys_in_file.contains(&key) || changed.contains(&key) {
+            env_content.push_str(&format!("{}={}\n", key, value));
+        }
+    }
+
+    fs::write(env_path, env_content).map_err(|e| {
+        error!(config_file = %env_path.display(), error = %e, "Failed to write config file");
+        SemanticReleaseError::config_error(format!(
+            "Failed to write config file {}: {}",
+            env_path.display(),
+            e
+        ))
+    })?;
+
+    info!(config_file = %env_path.display(), "Configuration saved successfully");
+    Ok(())
+}
+
+/// Every setting of `config` that is set, as `(KEY, value)` in the order they
+/// are written to `.env`. Values equal to the defaults are left out.
+fn config_settings(config: &AppConfig) -> Vec<(String, String)> {
+    let mut env_content = String::new();
+
     if let Some(api_key) = &config.monday_api_key {
         env_content.push_str(&format!("MONDAY_API_KEY={}\n", api_key));
     }
@@ -144,10 +1177,29 @@ fn save_config_to_env(env_path: &Path, config: &AppConfig) -> Result<()> {
         env_content.push_str(&format!("MONDAY_BOARD_ID={}\n", board_id));
     }
 
+    if !config.monday_board_ids.is_empty() {
+        env_content.push_str(&format!(
+            "MONDAY_BOARD_IDS={}\n",
+            config.monday_board_ids.join(",")
+        ));
+    }
+
     if let Some(url_template) = &config.monday_url_template {
         env_content.push_str(&format!("MONDAY_URL_TEMPLATE={}\n", url_template));
     }
 
+    if let Some(rate_limit) = config.monday_rate_limit_per_minute {
+        env_content.push_str(&format!("MONDAY_RATE_LIMIT_PER_MINUTE={}\n", rate_limit));
+    }
+
+    if let Some(concurrency) = config.monday_task_concurrency {
+        env_content.push_str(&format!("MONDAY_TASK_CONCURRENCY={}\n", concurrency));
+    }
+
+    if config.monday_post_commit_update {
+        env_content.push_str("MONDAY_POST_COMMIT_UPDATE=true\n");
+    }
+
     if let Some(jira_url) = &config.jira_url {
         env_content.push_str(&format!("JIRA_URL={}\n", jira_url));
     }
@@ -168,17 +1220,201 @@ fn save_config_to_env(env_path: &Path, config: &AppConfig) -> Result<()> {
         env_content.push_str(&format!("GEMINI_TOKEN={}\n", gemini_token));
     }
 
-    fs::write(env_path, env_content).map_err(|e| {
-        error!(config_file = %env_path.display(), error = %e, "Failed to write config file");
-        SemanticReleaseError::config_error(format!(
-            "Failed to write config file {}: {}",
-            env_path.display(),
-            e
-        ))
-    })?;
+    if let Some(template_path) = &config.release_notes_template_path {
+        env_content.push_str(&format!("RELEASE_NOTES_TEMPLATE={}\n", template_path));
+    }
 
-    info!(config_file = %env_path.display(), "Configuration saved successfully");
-    Ok(())
+    if let Some(output_dir) = &config.release_notes_dir {
+        env_content.push_str(&format!("RELEASE_NOTES_DIR={}\n", output_dir));
+    }
+
+    if config.release_notes_strict {
+        env_content.push_str("RELEASE_NOTES_STRICT=true\n");
+    }
+
+    if config.release_notes_compact {
+        env_content.push_str("RELEASE_NOTES_COMPACT=true\n");
+    }
+
+    if config.release_notes_convention_warnings {
+        env_content.push_str("RELEASE_NOTES_CONVENTION_WARNINGS=true\n");
+    }
+
+    if let Some(public) = config.release_notes_public {
+        env_content.push_str(&format!("RELEASE_NOTES_PUBLIC={}\n", public));
+    }
+
+    if !config.release_notes_internal_types.is_empty() {
+        env_content.push_str(&format!(
+            "RELEASE_NOTES_INTERNAL_TYPES={}\n",
+            config.release_notes_internal_types.join(",")
+        ));
+    }
+
+    if let Some(responsible) = &config.release_responsible {
+        env_content.push_str(&format!("RELEASE_RESPONSIBLE={}\n", responsible));
+    }
+
+    if !config.release_bot_authors.is_empty() {
+        env_content.push_str(&format!(
+            "RELEASE_BOT_AUTHORS={}\n",
+            config.release_bot_authors.join(",")
+        ));
+    }
+
+    if !config.release_packages.is_empty() {
+        let packages: Vec<String> = config
+            .release_packages
+            .iter()
+            .map(|package| format!("{}={}", package.path, package.tag_prefix))
+            .collect();
+        env_content.push_str(&format!("RELEASE_PACKAGES={}\n", packages.join(",")));
+    }
+
+    if !config.commit_trailers.is_empty() {
+        let trailers: Vec<String> = config
+            .commit_trailers
+            .iter()
+            .map(|trailer| format!("{}={}", trailer.key, trailer.field))
+            .collect();
+        env_content.push_str(&format!("COMMIT_TRAILERS={}\n", trailers.join(",")));
+    }
+
+    if config.release_notes_grouping != GroupingStrategy::default() {
+        env_content.push_str(&format!(
+            "RELEASE_NOTES_GROUPING={}\n",
+            config.release_notes_grouping.as_str()
+        ));
+    }
+
+    if config.release_notes_body_format != BodyFormat::default() {
+        env_content.push_str(&format!(
+            "RELEASE_NOTES_BODY_FORMAT={}\n",
+            config.release_notes_body_format.as_str()
+        ));
+    }
+
+    if config.version_strategy != VersionStrategy::default() {
+        env_content.push_str(&format!(
+            "VERSION_STRATEGY={}\n",
+            config.version_strategy.as_str()
+        ));
+    }
+
+    if let Some(channel) = &config.release_channel {
+        env_content.push_str(&format!("RELEASE_CHANNEL={}\n", channel));
+    }
+
+    if let Some(git_host) = &config.git_host {
+        env_content.push_str(&format!("GIT_HOST={}\n", git_host));
+    }
+
+    if config.use_gitmoji {
+        env_content.push_str("USE_GITMOJI=true\n");
+    }
+
+    if config.commit_preview_wrap {
+        env_content.push_str("COMMIT_PREVIEW_WRAP=true\n");
+    }
+
+    if let Some(max_length) = config.task_scope_max_length {
+        env_content.push_str(&format!("TASK_SCOPE_MAX_LENGTH={}\n", max_length));
+    }
+
+    if config.compact_task_scope {
+        env_content.push_str("COMPACT_TASK_SCOPE=true\n");
+    }
+
+    if config.validate_task_ids {
+        env_content.push_str("VALIDATE_TASK_IDS=true\n");
+    }
+
+    if let Some(types) = &config.trivial_commit_types {
+        let types = if types.is_empty() {
+            "none".to_string()
+        } else {
+            types.join(",")
+        };
+        env_content.push_str(&format!("TRIVIAL_COMMIT_TYPES={}\n", types));
+    }
+
+    if config.secret_scan != SecretScanMode::default() {
+        env_content.push_str(&format!("SECRET_SCAN={}\n", config.secret_scan.as_str()));
+    }
+
+    if !config.secret_patterns.is_empty() {
+        env_content.push_str(&format!(
+            "SECRET_PATTERNS={}\n",
+            config.secret_patterns.join(";")
+        ));
+    }
+
+    if !config.secret_allowlist.is_empty() {
+        env_content.push_str(&format!(
+            "SECRET_ALLOWLIST={}\n",
+            config.secret_allowlist.join(";")
+        ));
+    }
+
+    if config.language != Language::default() {
+        env_content.push_str(&format!("APP_LANGUAGE={}\n", config.language.as_str()));
+    }
+
+    if let Some(ai_language) = config.ai_language {
+        env_content.push_str(&format!("AI_LANGUAGE={}\n", ai_language.as_str()));
+    }
+
+    if config.ai_inline_suggestions {
+        env_content.push_str("AI_INLINE_SUGGESTIONS=true\n");
+    }
+
+    if let Some(keybindings) = &config.keybindings {
+        env_content.push_str(&format!("KEYBINDINGS={}\n", keybindings));
+    }
+
+    if !config.gitmoji_overrides.is_empty() {
+        let entries: Vec<String> = config
+            .gitmoji_overrides
+            .iter()
+            .map(|(commit_type, emoji)| format!("{}={}", commit_type, emoji))
+            .collect();
+        env_content.push_str(&format!("GITMOJI_MAP={}\n", entries.join(",")));
+    }
+
+    for commit_type in CommitType::all() {
+        if let Some(template) = config.commit_template(&commit_type) {
+            // Quoted, so the spaces survive and `\n` is read back as a line break
+            let escaped = template
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('$', "\\$")
+                .replace('\n', "\\n");
+            env_content.push_str(&format!(
+                "{}=\"{}\"\n",
+                commit_template_key(&commit_type),
+                escaped
+            ));
+        }
+    }
+
+    for (key, value) in [
+        ("HTTP_TIMEOUT_SECONDS", config.http_timeout_seconds),
+        ("AI_TIMEOUT_SECONDS", config.ai_timeout_seconds),
+        (
+            "RELEASE_NOTES_TIMEOUT_SECONDS",
+            config.release_notes_timeout_seconds,
+        ),
+    ] {
+        if let Some(value) = value {
+            env_content.push_str(&format!("{}={}\n", key, value));
+        }
+    }
+
+    env_content
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
 }
 
 #[instrument]
@@ -189,6 +1425,34 @@ pub async fn run_config() -> Result<()> {
     println!("=====================================");
 
     let current_config = load_config().unwrap_or_default();
+    let keyring_available = is_keyring_available();
+
+    // Offer to move plain-text credentials from .env to the system keyring
+    let plaintext_secrets =
+        plaintext_secrets_in_env(Path::new(&profile_file_name(active_profile())));
+    if !plaintext_secrets.is_empty() {
+        if keyring_available {
+            let migrate: bool = dialoguer::Confirm::new()
+                .with_prompt(format!(
+                    "Found plain-text credentials in .env ({}). Move them to the system keyring?",
+                    plaintext_secrets.join(", ")
+                ))
+                .default(true)
+                .interact()?;
+
+            if migrate {
+                move_secrets_to_keyring(
+                    Path::new(&profile_file_name(active_profile())),
+                    &plaintext_secrets,
+                )?;
+            }
+        } else {
+            warn!("System keyring unavailable, credentials stay in .env");
+            println!(
+                "⚠️  No system keyring available (e.g. headless Linux without Secret Service); credentials stay in .env"
+            );
+        }
+    }
 
     // Determine which task system to configure
     let task_system_options = vec!["Monday.com", "JIRA"];
@@ -212,7 +1476,19 @@ pub async fn run_config() -> Result<()> {
             SemanticReleaseError::config_error(format!("Failed to get user input: {}", e))
         })?;
 
-    let mut config = AppConfig::default();
+    // Credentials are re-entered below; keep every other setting as it was
+    let mut config = AppConfig {
+        monday_api_key: None,
+        monday_account_slug: None,
+        monday_board_id: None,
+        monday_url_template: None,
+        jira_url: None,
+        jira_username: None,
+        jira_api_token: None,
+        jira_project_key: None,
+        gemini_token: None,
+        ..current_config.clone()
+    };
 
     match selection {
         0 => {
@@ -396,15 +1672,25 @@ pub async fn run_config() -> Result<()> {
 
     config.gemini_token = gemini_token;
 
-    save_config(&config)?;
+    let use_keyring = keyring_available
+        && dialoguer::Confirm::new()
+            .with_prompt("Store API keys in the system keyring instead of .env?")
+            .default(true)
+            .interact()?;
+
+    if use_keyring {
+        save_config_with_keyring(&config)?;
+    } else {
+        save_config(&config)?;
+    }
 
     println!("✅ Configuration saved successfully!");
 
     // Ensure .env is in .gitignore to prevent committing sensitive data
     ensure_env_in_gitignore()?;
 
-    // Check and create plantilla.md file if it doesn't exist
-    ensure_plantilla_template_exists()?;
+    // Check and create the release notes template if it doesn't exist
+    ensure_release_notes_template_exists(&config)?;
 
     // Test connections based on chosen system
     match config.get_task_system() {
@@ -442,11 +1728,12 @@ async fn test_monday_connection(config: &AppConfig) -> Result<String> {
     info!("Testing Monday.com connection");
 
     if let Some(api_key) = &config.monday_api_key {
-        let client = reqwest::Client::new();
+        let client = crate::services::shared_http_client(config)?;
         let query = r#"{"query": "query { me { name email } }"}"#;
 
         let response = client
             .post("https://api.monday.com/v2")
+            .timeout(config.http_timeout())
             .header("Authorization", api_key)
             .header("Content-Type", "application/json")
             .header("API-Version", "2024-10")
@@ -768,7 +2055,13 @@ fn ensure_env_in_gitignore() -> Result<()> {
 
     // Check if .env is already in .gitignore
     let lines: Vec<&str> = gitignore_content.lines().collect();
-    let env_patterns = [".env", "*.env", ".env*"];
+    let profile = active_profile();
+    let profile_file = profile_file_name(profile);
+    let env_patterns = match profile {
+        // Profile files are only covered by their own rule or a wildcard
+        Some(_) => vec![profile_file.as_str(), ".env.*", ".env*"],
+        None => vec![".env", "*.env", ".env*"],
+    };
 
     let has_env_rule = lines.iter().any(|line| {
         let trimmed = line.trim();
@@ -793,6 +2086,9 @@ fn ensure_env_in_gitignore() -> Result<()> {
         gitignore_content.push_str(".env\n");
         gitignore_content.push_str(".env.local\n");
         gitignore_content.push_str(".env.*.local\n");
+        if profile.is_some() {
+            gitignore_content.push_str(".env.*\n");
+        }
 
         // Write the updated .gitignore
         fs::write(gitignore_path, gitignore_content).map_err(|e| {
@@ -810,192 +2106,118 @@ fn ensure_env_in_gitignore() -> Result<()> {
     Ok(())
 }
 
-#[instrument]
-fn ensure_plantilla_template_exists() -> Result<()> {
-    debug!("Ensuring plantilla template file exists");
-
-    let plantilla_path = PathBuf::from("scripts/plantilla.md");
-
-    // Check if the file already exists
-    if plantilla_path.exists() {
-        debug!(plantilla_path = %plantilla_path.display(), "Plantilla template file already exists");
-        return Ok(());
-    }
-
-    info!("Creating scripts/plantilla.md template file");
-    println!("📄 Creating scripts/plantilla.md template file...");
-
-    // Create the scripts directory if it doesn't exist
-    if let Some(parent) = plantilla_path.parent() {
-        fs::create_dir_all(parent).map_err(|e| {
-            error!(parent_dir = %parent.display(), error = %e, "Failed to create scripts directory");
-            SemanticReleaseError::config_error(format!("Failed to create scripts directory: {}", e))
-        })?;
+#[instrument(skip(config))]
+fn ensure_release_notes_template_exists(config: &AppConfig) -> Result<()> {
+    let template_path = crate::app::release_notes::resolve_release_notes_template_path(config);
+    if crate::app::release_notes::ensure_release_notes_template(config)? {
+        println!(
+            "✅ Created release notes template at {}",
+            template_path.display()
+        );
+    } else {
+        debug!(template_path = %template_path.display(), "Release notes template already exists");
     }
 
-    // Template content
-    let template_content = r#"# Actualización Teixo versión --VERSION-A-CAMBIAR--
-
-# **Información para N1**
-
-Tickets que se solucionan en esta actualización
-
-| Desarrollo | IDTarea | Support Bee |
-| ----- | ----- | ----- |
-Aquí añadirás todos los tickets de supportbee que se hayan resuelto en esta actualización. Son todos los que veas en el documento de entrada.
-
-
-# **Información técnica**
-
-### Responsable despliegue
-
-Aquí el nombre de la persona que lanzó la petición 
-
-### Etiquetas
-
-### Migraciones lentas
-
-| IDTarea | Fichero | Tiempos |
-| ----- | ----- | ----- |
-|  |  |  |
-
-AQUI TIENES QUE METER TODAS LAS MIGRACIONES LENTAS QUE SE HAYAN REALIZADO Y QUE VEAS EN LOS COMMITS
-
-### Partes a ejecutar
-
-| IDTarea | Enlace a Script |
-| ----- | ----- |
-| m8392481017 | https://redmine.teimas.com/issues/35728 |
-
-AQUI TIENES QUE METER TODOS LOS PARTES QUE SE HAYAN REALIZADO Y QUE VEAS EN LOS COMMITS
-
-## 
-
-## **Cambios para entorno de producción**
-
-## **Correcciones**
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "1. BUG".
-
-## **N2** 
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "N2".
-
-## **Novedades**
-
-### Relacionado con tramitadores
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "2. TRAMITADORES".  
-
-### Desarrollos pagados por cliente
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "3. CLIENTE - PAGADO". 
-
-### Pequeños evolutivos
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "4. EVOLUTIVO". 
-
-### Proyectos especiales
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "PE". 
-
-## **QA - Cobertura de test automáticos**
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "QA".
-
-## **APS**
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "APS". 
-
-## **SYS y otros**
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "SYS". 
-
-## **Desarrollos que afectan a la seguridad**
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "SEC". 
-
-# **Validación en Sandbox**
-
-AQUI TIENES QUE METER TODAS LAS TAREAS DE MONDAY QUE TENGAN LA LABEL "SEC". 
-
-## **Para paso a entorno de producción**
-
-### Correcciones
-
-| Ref. | Resp. | Comprobación | Quién N1 | N1 ok? | Quien QA? | QA ok? |
-| ----- | ----- | ----- | ----- | ----- | ----- | ----- |
-
-### Novedades. En relación con las tramitaciones
-
-| Ref. | Resp. | Comprobación | Quién N1 | N1 ok? | Quien QA? | QA ok? |
-| ----- | ----- | ----- | ----- | ----- | ----- | ----- |
-|  |  | NA |  |  |  |  |
-
-### Novedades. Desarrollos pagados por cliente
-
-| Ref. | Resp. | Comprobación | Quién N1 | N1 ok? | Quien QA? | QA ok? |
-| ----- | ----- | ----- | ----- | ----- | ----- | ----- |
-|  |  | NA |  |  |  |  |
-
-### Novedades. Pequeños evolutivos
-
-| Ref. | Resp. | Comprobación | Quién N1 | N1 ok? | Quien QA? | QA ok? |
-| ----- | ----- | ----- | ----- | ----- | ----- | ----- |
-
-### Novedades. Proyectos especiales
-
-| Ref. | Resp. | Comprobación | Quién N1 | N1 ok? | Quien QA? | QA ok? |
-| ----- | ----- | ----- | ----- | ----- | ----- | ----- |
-|  |  | NA |  |  |  |  |
-
-   
-QA y APS
-
-| Ref. | Resp. | Comprobación | Quién N1 | N1 ok? | Quien QA? | QA ok? |
-| ----- | ----- | ----- | ----- | ----- | ----- | ----- |
-|  |  | NA |  |  |  |  |
-
-   
-
-#  **Pruebas**
-
-Aquí vendrán todos los tests que están marcados en CADA UNO DE LOS COMMITS. No dejes ninguno fuera. Todos y cada uno de ellos.
-
-# **Referencia commits**
-
-Aquí irán absolutamente TODOS los commits que recibas. No dejes ninguno. Exactamente como tienes en el documento de entrada. El commit tiene que ser verboso, es decir, con toda la información posible. Incluye fechas, nombre de la persona, email, etc.
-
-Utiliza esta plantilla:
-
----
-
-### feat(8851673176|8872179232|8838736619): Improvements [
-5f0c72]
+    Ok(())
+}
 
-feat(8851673176|8872179232|8838736619): Improvements | Improvements with new lines | Test Details: | - Test 1 | - Test 2 | - Test 3 | Security: NA | Refs: 8851673176|8872179232|8838736619 | MONDAY TASKS: | - [PE.25.002] VERIFACTU. Bloque 1. Análisis series para facturas rectificativas [A] (ID: 8851673176, URL: https://teimas.monday.com/boards/1013914950/pulses/8851673176) | - [PE.25.002] VERIFACTU. Bloque 1. Creación de registros de facturación [E1] [IV] (ID: 8872179232, URL: https://teimas.monday.com/boards/1013914950/pulses/8872179232) | - [PE.25.002] VERIFACTU. Bloque 1. Modelo registros de eventos [E3] [III] (ID: 8838736619, URL: https://teimas.monday.com/boards/1013914950/pulses/8838736619)
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-**Pruebas**:
-- Test 1
-- Test 2
-- Test 3
+    /// Directory of its own for each test's settings files
+    fn settings_dir() -> PathBuf {
+        let dir = env::temp_dir().join(format!("semantic-release-tui-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
 
-**Tareas relacionadas**:
-- [PE.25.002] VERIFACTU. Bloque 1. Análisis series para facturas rectificativas [A] (ID: 8851673176, Estado: active)
-- [PE.25.002] VERIFACTU. Bloque 1. Creación de registros de facturación [E1] [IV] (ID: 8872179232, Estado: active)
-- [PE.25.002] VERIFACTU. Bloque 1. Modelo registros de eventos [E3] [III] (ID: 8838736619, Estado: active)
+    #[test]
+    fn test_save_config_keeps_user_settings_out_of_the_local_file() {
+        let dir = settings_dir();
+        let local = dir.join(".env");
+        let user = dir.join("config");
+        fs::write(&local, "# Monday.com\nACCOUNT_SLUG=acme\n").unwrap();
+        fs::write(
+            &user,
+            "JIRA_URL=https://acme.atlassian.net\nRELEASE_NOTES_DIR=notes\n",
+        )
+        .unwrap();
+        let sources = ConfigSources {
+            files: vec![
+                (
+                    ConfigSource::LocalEnvFile,
+                    local.clone(),
+                    read_env_file(&local).unwrap(),
+                ),
+                (
+                    ConfigSource::UserConfigFile,
+                    user.clone(),
+                    read_env_file(&user).unwrap(),
+                ),
+            ],
+        };
+        let loaded = AppConfig {
+            monday_account_slug: sources.get("ACCOUNT_SLUG"),
+            jira_url: sources.get("JIRA_URL"),
+            release_notes_dir: sources.get("RELEASE_NOTES_DIR"),
+            ..AppConfig::default()
+        };
+
+        let mut config = loaded.clone();
+        config.monday_board_id = Some("42".to_string());
+        config.release_notes_dir = Some("docs/releases".to_string());
+        save_config_to_env(
+            &local,
+            &config,
+            &settings_to_save(&config, &loaded, &sources),
+        )
+        .unwrap();
+        let saved = fs::read_to_string(&local).unwrap();
+        fs::remove_dir_all(&dir).ok();
+
+        // Kept and edited settings are written, unchanged user settings are not
+        assert!(saved.contains("# Monday.com\n"));
+        assert!(saved.contains("ACCOUNT_SLUG=acme\n"));
+        assert!(saved.contains("MONDAY_BOARD_ID=42\n"));
+        assert!(saved.contains("RELEASE_NOTES_DIR=docs/releases\n"));
+        assert!(!saved.contains("JIRA_URL"));
+    }
 
----
-"#;
+    #[test]
+    fn test_save_config_removes_cleared_settings_from_the_local_file() {
+        let dir = settings_dir();
+        let local = dir.join(".env");
+        fs::write(&local, "MONDAY_BOARD_ID=42\nCUSTOM_SETTING=kept\n").unwrap();
 
-    // Write the file
-    fs::write(&plantilla_path, template_content).map_err(|e| {
-        error!(plantilla_path = %plantilla_path.display(), error = %e, "Failed to write plantilla template file");
-        SemanticReleaseError::config_error(format!("Failed to write plantilla template: {}", e))
-    })?;
+        save_config_to_env(&local, &AppConfig::default(), &BTreeSet::new()).unwrap();
+        let saved = fs::read_to_string(&local).unwrap();
+        fs::remove_dir_all(&dir).ok();
 
-    info!(plantilla_path = %plantilla_path.display(), "Plantilla template file created successfully");
-    println!("✅ Created scripts/plantilla.md template file");
+        assert_eq!(saved, "CUSTOM_SETTING=kept\n");
+    }
 
-    Ok(())
+    #[test]
+    fn test_parse_env_bool_values() {
+        let values = [
+            ("USE_GITMOJI", "Yes"),
+            ("COMPACT_TASK_SCOPE", "0"),
+            ("VALIDATE_TASK_IDS", "sí"),
+        ];
+        let sources = ConfigSources {
+            files: vec![(
+                ConfigSource::LocalEnvFile,
+                PathBuf::from(".env"),
+                values
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            )],
+        };
+
+        assert_eq!(parse_env_bool(&sources, "USE_GITMOJI"), Some(true));
+        assert_eq!(parse_env_bool(&sources, "COMPACT_TASK_SCOPE"), Some(false));
+        assert_eq!(parse_env_bool(&sources, "VALIDATE_TASK_IDS"), None);
+        assert_eq!(parse_env_bool(&sources, "RELEASE_NOTES_PUBLIC"), None);
+    }
 }
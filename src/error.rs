@@ -34,6 +34,13 @@ pub enum SemanticReleaseError {
     )]
     MondayError(#[source] Box<dyn std::error::Error + Send + Sync>),
 
+    #[error("Monday.com credentials are invalid or expired (HTTP {status})")]
+    #[diagnostic(
+        code(semantic_release::monday_auth_error),
+        help("Update MONDAY_API_KEY from the Config screen or with `semantic-release-tui config`")
+    )]
+    MondayAuthError { status: u16 },
+
     #[error("JIRA API error")]
     #[diagnostic(
         code(semantic_release::jira_error),
@@ -81,6 +88,31 @@ pub enum SemanticReleaseError {
     #[diagnostic(code(semantic_release::http_error))]
     HttpError(#[from] reqwest::Error),
 
+    #[error("Could not connect to {service} (behind a proxy? set HTTP_PROXY/HTTPS_PROXY)")]
+    #[diagnostic(
+        code(semantic_release::connection_error),
+        help("Check your network connection. Behind a corporate proxy, set HTTP_PROXY/HTTPS_PROXY (and NO_PROXY for internal hosts) in the environment or the .env file")
+    )]
+    ConnectionError {
+        service: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    #[error("{service} request timed out after {seconds}s")]
+    #[diagnostic(
+        code(semantic_release::timeout_error),
+        help("The service took too long to respond. Try again or raise the timeout (HTTP_TIMEOUT_SECONDS, AI_TIMEOUT_SECONDS, RELEASE_NOTES_TIMEOUT_SECONDS)")
+    )]
+    TimeoutError { service: String, seconds: u64 },
+
+    #[error("Invalid input: {message}")]
+    #[diagnostic(
+        code(semantic_release::validation_error),
+        help("Check the command-line flags, e.g. `semantic-release-tui commit --help`")
+    )]
+    ValidationError { message: String },
+
     #[error("User interaction failed")]
     #[diagnostic(code(semantic_release::user_interaction_error))]
     UserInteractionError(#[from] dialoguer::Error),
@@ -129,6 +161,16 @@ impl SemanticReleaseError {
         Self::MondayError(Box::new(source))
     }
 
+    /// Whether this is a Monday.com authentication failure (expired or revoked token)
+    pub fn is_monday_auth_error(&self) -> bool {
+        matches!(self, Self::MondayAuthError { .. })
+    }
+
+    /// Whether a request gave up after its timeout
+    pub fn is_timeout_error(&self) -> bool {
+        matches!(self, Self::TimeoutError { .. })
+    }
+
     /// Create an AI service error
     pub fn ai_error(
         provider: impl Into<String>,
@@ -140,6 +182,25 @@ impl SemanticReleaseError {
         }
     }
 
+    /// Create a timeout error
+    pub fn timeout_error(service: impl Into<String>, timeout: std::time::Duration) -> Self {
+        Self::TimeoutError {
+            service: service.into(),
+            seconds: timeout.as_secs(),
+        }
+    }
+
+    /// Create a connection error for `service` (DNS, refused connection, proxy...)
+    pub fn connection_error(
+        service: impl Into<String>,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self::ConnectionError {
+            service: service.into(),
+            source: Box::new(source),
+        }
+    }
+
     /// Create a command error
     pub fn command_error(
         command: impl Into<String>,
@@ -153,6 +214,13 @@ impl SemanticReleaseError {
         }
     }
 
+    /// Create an invalid input error
+    pub fn validation_error(message: impl Into<String>) -> Self {
+        Self::ValidationError {
+            message: message.into(),
+        }
+    }
+
     /// Create a release operation error
     pub fn release_error(operation: impl Into<String>) -> Self {
         Self::ReleaseError {
@@ -1,3 +1,5 @@
 pub mod repository;
+pub mod secrets;
 
 pub use repository::*;
+pub use secrets::{secret_findings_summary, SecretScanner};
@@ -0,0 +1,451 @@
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+// =============================================================================
+// LANGUAGES
+// =============================================================================
+
+/// Language of user-facing messages (`APP_LANGUAGE`) or of the text Gemini is
+/// asked to write (`AI_LANGUAGE`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    Es,
+    En,
+}
+
+impl Language {
+    /// Parse `es`/`en`, also accepting locales (`en_US`, `es-ES`) and full names.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_lowercase();
+        let code = value.split(['-', '_', '.']).next().unwrap_or_default();
+        match code {
+            "es" | "spanish" | "español" | "espanol" => Some(Language::Es),
+            "en" | "english" | "inglés" | "ingles" => Some(Language::En),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::Es => "es",
+            Language::En => "en",
+        }
+    }
+
+    /// Name used inside the (Spanish) commit analysis prompt.
+    pub fn prompt_name(&self) -> &'static str {
+        match self {
+            Language::Es => "español",
+            Language::En => "inglés",
+        }
+    }
+
+    /// English name, for prompts written in English.
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Language::Es => "Spanish",
+            Language::En => "English",
+        }
+    }
+}
+
+/// Words that only show up in one of the languages, common in commit messages.
+const SPANISH_WORDS: &[&str] = &[
+    "de",
+    "la",
+    "el",
+    "en",
+    "los",
+    "las",
+    "del",
+    "para",
+    "con",
+    "por",
+    "una",
+    "que",
+    "se",
+    "al",
+    "y",
+    "añade",
+    "añadir",
+    "agrega",
+    "corrige",
+    "corregir",
+    "arregla",
+    "actualiza",
+    "elimina",
+    "mejora",
+    "cambia",
+    "nuevo",
+    "nueva",
+    "pantalla",
+    "cuando",
+    "sin",
+];
+const ENGLISH_WORDS: &[&str] = &[
+    "the", "of", "to", "for", "with", "in", "and", "on", "from", "when", "add", "adds", "added",
+    "fix", "fixes", "fixed", "update", "updates", "remove", "removes", "improve", "change", "new",
+    "bug", "screen", "without", "use",
+];
+
+/// Predominant language of `texts` (e.g. commit subjects): each text votes
+/// for the language with more of its typical words, accents counting as
+/// Spanish. `None` when too few texts vote or neither language clearly wins.
+pub fn detect_language<'a>(texts: impl IntoIterator<Item = &'a str>) -> Option<Language> {
+    let (mut spanish, mut english) = (0usize, 0usize);
+    for text in texts {
+        let (mut es, mut en) = (0usize, 0usize);
+        for word in text
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|word| !word.is_empty())
+        {
+            let word = word.to_lowercase();
+            if SPANISH_WORDS.contains(&word.as_str())
+                || word.contains(['á', 'é', 'í', 'ó', 'ú', 'ñ'])
+            {
+                es += 1;
+            } else if ENGLISH_WORDS.contains(&word.as_str()) {
+                en += 1;
+            }
+        }
+        match es.cmp(&en) {
+            std::cmp::Ordering::Greater => spanish += 1,
+            std::cmp::Ordering::Less => english += 1,
+            std::cmp::Ordering::Equal => {}
+        }
+    }
+
+    // A handful of votes or a near tie says nothing about the repo
+    let votes = spanish + english;
+    if votes < 3 {
+        return None;
+    }
+    debug!(spanish, english, "Detected commit languages");
+    if spanish * 3 >= votes * 2 {
+        Some(Language::Es)
+    } else if english * 3 >= votes * 2 {
+        Some(Language::En)
+    } else {
+        None
+    }
+}
+
+// =============================================================================
+// MESSAGE LOOKUP
+// =============================================================================
+
+/// User-facing messages by key: `(key, Spanish, English)`.
+const MESSAGES: &[(&str, &str, &str)] = &[
+    // Status bar
+    ("status.ready", "Listo", "Ready"),
+    ("status.title", "Estado", "Status"),
+    ("status.processing", "Procesando...", "Processing..."),
+    ("status.loading_title", "⏳ Cargando", "⏳ Loading"),
+    (
+        "status.error_title",
+        "⚠️ ERROR - Presiona cualquier tecla para continuar",
+        "⚠️ ERROR - Press any key to continue",
+    ),
+    (
+        "status.confirm_stage_all",
+        "Presiona 'y' para añadir todos los cambios (git add -A), 'n' para cancelar",
+        "Press 'y' to stage all changes (git add -A), 'n' to cancel",
+    ),
+    ("status.confirm_title", "❓ Confirmación requerida", "❓ Confirmation Required"),
+    (
+        "status.confirm_merge",
+        "Hay un merge en curso. Presiona 'y' para hacer el commit, 'n' para cancelar",
+        "A merge is in progress. Press 'y' to commit it, 'n' to cancel",
+    ),
+    ("status.merge_title", "⚠️ Merge en curso", "⚠️ Merge in progress"),
+    ("status.selected_tasks", "Tareas", "Tasks"),
+    // Secret detection before committing
+    ("secrets.title", "🔐 Posibles secretos", "🔐 Possible secrets"),
+    (
+        "secrets.found",
+        "🔐 Los cambios preparados parecen contener secretos:",
+        "🔐 The staged changes seem to contain secrets:",
+    ),
+    (
+        "secrets.confirm",
+        "Presiona 'y' para hacer el commit igualmente, 'n' para cancelar",
+        "Press 'y' to commit anyway, 'n' to cancel",
+    ),
+    (
+        "secrets.blocked",
+        "Commit bloqueado (SECRET_SCAN=block). Quita los secretos o añádelos a SECRET_ALLOWLIST:",
+        "Commit blocked (SECRET_SCAN=block). Remove the secrets or add them to SECRET_ALLOWLIST:",
+    ),
+    (
+        "secrets.cancelled",
+        "Commit cancelado. Los cambios siguen preparados",
+        "Commit cancelled. The changes are still staged",
+    ),
+    (
+        "secrets.cli_refused",
+        "Commit no creado: revisa los cambios o repite con --allow-secrets",
+        "Commit not created: review the changes or run again with --allow-secrets",
+    ),
+    (
+        "monday.auth_failed",
+        "Credenciales de Monday inválidas o expiradas. Presiona 'c' para ir a Config o cualquier otra tecla para continuar",
+        "Monday credentials are invalid or expired. Press 'c' to go to Config or any other key to continue",
+    ),
+    (
+        "commit.missing_type",
+        "Falta el tipo de commit: elige uno antes de confirmar",
+        "The commit type is missing: pick one before committing",
+    ),
+    (
+        "commit.missing_title",
+        "Falta el título del commit",
+        "The commit title is missing",
+    ),
+    (
+        "monday.rate_limited",
+        "⏳ Límite de peticiones de Monday.com alcanzado, esperando",
+        "⏳ Monday.com rate limit reached, waiting",
+    ),
+    (
+        "monday.rate_limited_waited",
+        "⏳ esperado por el límite de Monday.com:",
+        "⏳ waited on the Monday.com rate limit:",
+    ),
+    (
+        "offline.unavailable",
+        "📴 No disponible en modo offline (--offline):",
+        "📴 Not available in offline mode (--offline):",
+    ),
+    // Background operations
+    ("operation.cancelled", "⛔ Cancelado", "⛔ Cancelled"),
+    (
+        "analysis.completed",
+        "✅ Análisis completado - Formulario poblado automáticamente",
+        "✅ Analysis completed - form filled in automatically",
+    ),
+    (
+        "analysis.suggestions_ready",
+        "✅ Análisis completado - Tab acepta la sugerencia del campo, Ctrl+→ palabra a palabra",
+        "✅ Analysis completed - Tab accepts the field's suggestion, Ctrl+→ word by word",
+    ),
+    ("analysis.error", "Error en análisis", "Analysis error"),
+    (
+        "analysis.kept_fields",
+        "✅ Análisis completado - Se conservó lo escrito a mano en:",
+        "✅ Analysis completed - Kept your own text in:",
+    ),
+    (
+        "analysis.reverted",
+        "↩️ Formulario restaurado a como estaba antes del análisis",
+        "↩️ Form restored to how it was before the analysis",
+    ),
+    (
+        "analysis.nothing_to_revert",
+        "No hay ningún análisis con IA que revertir",
+        "There is no AI analysis to revert",
+    ),
+    (
+        "analysis.nothing_to_show",
+        "Todavía no se ha ejecutado ningún análisis con IA",
+        "No AI analysis has run yet",
+    ),
+    (
+        "analysis.already_running",
+        "⏳ Ya hay un análisis con IA en curso",
+        "⏳ An AI analysis is already running",
+    ),
+    (
+        "analysis.field_completed",
+        "✅ Análisis regenerado:",
+        "✅ Analysis regenerated:",
+    ),
+    (
+        "analysis.field_nothing_found",
+        " (no se encontró nada, el campo queda vacío)",
+        " (nothing found, the field is left empty)",
+    ),
+    (
+        "analysis.fallback_description",
+        "Se realizaron cambios en el código del proyecto. No se pudo generar un análisis detallado automáticamente.",
+        "Code changes were made to the project. A detailed analysis could not be generated automatically.",
+    ),
+    // Scope task ID validation
+    (
+        "task_check.not_found",
+        "no existe",
+        "doesn't exist",
+    ),
+    (
+        "task_check.forbidden",
+        "sin permisos para verla",
+        "no permission to see it",
+    ),
+    (
+        "task_check.network_error",
+        "error de red, no se pudo comprobar",
+        "network error, couldn't check it",
+    ),
+    ("task_check.failed", "no se pudo comprobar", "couldn't check it"),
+    (
+        "task_check.problems",
+        "⚠️ Tareas del scope sin confirmar:",
+        "⚠️ Scope tasks that couldn't be confirmed:",
+    ),
+    (
+        "task_check.all_found",
+        "✅ Las tareas del scope existen:",
+        "✅ The scope tasks exist:",
+    ),
+    // Staging selector
+    (
+        "stage.applied",
+        "✅ Index actualizado (añadidos/quitados):",
+        "✅ Index updated (staged/unstaged):",
+    ),
+    (
+        "stage.unchanged",
+        "La selección no cambia el index",
+        "The selection leaves the index as it was",
+    ),
+    ("stage.error", "Error actualizando el index", "Could not update the index"),
+    // Configuration screen
+    (
+        "config.saved",
+        "✅ Configuración guardada",
+        "✅ Configuration saved",
+    ),
+    (
+        "config.not_saved",
+        "No se guardó la configuración",
+        "The configuration was not saved",
+    ),
+    (
+        "config.missing_credentials",
+        "faltan las credenciales del sistema de tareas elegido",
+        "the chosen task system is missing its credentials",
+    ),
+    (
+        "config.save_error",
+        "Error guardando la configuración",
+        "Could not save the configuration",
+    ),
+    (
+        "config.unsaved",
+        "⚠️ Hay cambios sin guardar: 's' para guardar, 'q' de nuevo para descartarlos",
+        "⚠️ There are unsaved changes: 's' to save, 'q' again to discard them",
+    ),
+    // Remapped keys (KEYBINDINGS)
+    (
+        "keybindings.expected_entry",
+        "se esperaba pantalla.acción=teclas",
+        "expected screen.action=keys",
+    ),
+    (
+        "keybindings.unknown_screen",
+        "pantalla desconocida",
+        "unknown screen",
+    ),
+    (
+        "keybindings.unknown_action",
+        "acción desconocida",
+        "unknown action",
+    ),
+    ("keybindings.unknown_key", "tecla desconocida", "unknown key"),
+    (
+        "keybindings.not_on_screen",
+        "no existe en esa pantalla",
+        "is not on that screen",
+    ),
+    (
+        "keybindings.bound_to",
+        "está asignada a",
+        "is bound to",
+    ),
+    ("keybindings.and", "y a", "and"),
+    // Release notes
+    (
+        "release_notes.started",
+        "🚀 Iniciando generación de notas de versión...",
+        "🚀 Starting release notes generation...",
+    ),
+    (
+        "release_notes.completed",
+        "✅ Notas de versión generadas exitosamente",
+        "✅ Release notes generated successfully",
+    ),
+    ("release_notes.generation_error", "Error en generación", "Generation error"),
+    (
+        "release_notes.already_running",
+        "⏳ Ya hay una generación de notas de versión en curso",
+        "⏳ Release notes generation is already running",
+    ),
+    (
+        "release_notes.start_error",
+        "Error iniciando generación",
+        "Could not start generation",
+    ),
+    (
+        "release_notes.already_generated",
+        "ℹ️ Estas notas ya se generaron el",
+        "ℹ️ These notes were already generated on",
+    ),
+    (
+        "release_notes.regenerate_hint",
+        "Vuelve a pulsar para regenerarlas",
+        "Press again to regenerate them",
+    ),
+    (
+        "release_notes.gemini_failed",
+        "⚠️ Gemini falló, solo se guardó el documento estructurado. 'g' en la pantalla de notas reintenta solo la IA:",
+        "⚠️ Gemini failed, only the structured document was saved. 'g' on the release notes screen retries only the AI step:",
+    ),
+    (
+        "release_notes.retrying_gemini",
+        "🤖 Reintentando solo Gemini sobre",
+        "🤖 Retrying only Gemini on",
+    ),
+    (
+        "release_notes.nothing_to_retry",
+        "La última generación ya tiene notas de Gemini (o su documento ya no existe)",
+        "The latest generation already has Gemini notes (or its document is gone)",
+    ),
+    (
+        "release_notes.task_progress",
+        "Integrando las tareas relacionadas...",
+        "Integrating task management data...",
+    ),
+    (
+        "release_notes.no_gemini",
+        "GEMINI_TOKEN no está configurado",
+        "GEMINI_TOKEN is not configured",
+    ),
+    (
+        "release_notes.npm_running",
+        "⚙️ Ejecutando npm run release-notes...",
+        "⚙️ Running npm run release-notes...",
+    ),
+    (
+        "release_notes.use_internal",
+        "Presiona 'i' para usar la generación interna.",
+        "Press 'i' to use the built-in generation.",
+    ),
+];
+
+/// Message for `key` in `language`. Unknown keys are returned as-is so a
+/// missing translation shows up in the UI instead of an empty string.
+pub fn tr(language: Language, key: &'static str) -> &'static str {
+    match MESSAGES
+        .iter()
+        .find(|(message_key, _, _)| *message_key == key)
+    {
+        Some((_, es, en)) => match language {
+            Language::Es => es,
+            Language::En => en,
+        },
+        None => {
+            debug!(key, "Missing translation");
+            key
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub mod app;
 pub mod config;
 pub mod error;
 pub mod git;
+pub mod i18n;
 pub mod observability;
 pub mod services;
 pub mod types;
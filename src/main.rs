@@ -1,17 +1,21 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
 use semantic_release_tui::observability::log_user_message;
+use std::io::IsTerminal;
 use tracing::{error, info};
 
 mod app;
 mod config;
 mod error;
 mod git;
+mod i18n;
 mod observability;
 mod services;
 mod types;
 mod ui;
 mod utils;
 
+use app::cli_operations::{ArtifactsAction, CommitFlags, HistoryFormat, HistoryOptions};
 use app::App;
 use types::AppScreen;
 
@@ -34,9 +38,26 @@ struct Cli {
     #[arg(long, global = true)]
     autocommit: bool,
 
-    /// Enable development mode with hierarchical logging
+    /// Enable development mode (hierarchical logging, F12 toggles render stats)
     #[arg(long, global = true)]
     dev: bool,
+
+    /// Configuration profile to use (reads `.env.<profile>` instead of `.env`)
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Read settings from this file (dotenv or `.toml`) before any discovered one
+    #[arg(long = "config", global = true, value_name = "PATH")]
+    config_file: Option<std::path::PathBuf>,
+
+    /// Work without network: task search, AI analysis, npm release-notes and
+    /// semantic-release are disabled; manual commits keep working
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// Skip the repository's pre-commit and commit-msg hooks when committing
+    #[arg(long, global = true)]
+    no_verify: bool,
 }
 
 #[derive(Subcommand)]
@@ -46,15 +67,122 @@ enum Commands {
     /// Configure API keys and settings
     Config,
     /// Create a commit with Monday.com integration
-    Commit,
+    Commit {
+        /// Create the commit from the flags below without opening the TUI
+        #[arg(long)]
+        no_tui: bool,
+        /// Print the commit message instead of committing (implies --no-tui)
+        #[arg(long)]
+        dry_run: bool,
+        /// Commit type (feat, fix, docs, style, refactor, perf, test, chore, revert)
+        #[arg(long = "type", value_name = "TYPE")]
+        commit_type: Option<String>,
+        /// Commit scope (defaults to the --task IDs joined with '|')
+        #[arg(long)]
+        scope: Option<String>,
+        /// Commit title
+        #[arg(long)]
+        title: Option<String>,
+        /// Detailed description
+        #[arg(long)]
+        description: Option<String>,
+        /// Breaking change description
+        #[arg(long)]
+        breaking: Option<String>,
+        /// Test details
+        #[arg(long)]
+        test_details: Option<String>,
+        /// Security analysis
+        #[arg(long)]
+        security: Option<String>,
+        /// Related task ID (repeatable)
+        #[arg(long = "task", value_name = "ID")]
+        tasks: Vec<String>,
+        /// Commit even if the staged changes look like they contain secrets (SECRET_SCAN=confirm)
+        #[arg(long)]
+        allow_secrets: bool,
+    },
     /// Generate release notes with AI
-    ReleaseNotes,
+    ReleaseNotes {
+        /// Directory for the generated files (overrides RELEASE_NOTES_DIR)
+        #[arg(long, value_name = "DIR")]
+        output_dir: Option<String>,
+        /// Fail if the release notes template is missing (also RELEASE_NOTES_STRICT=true)
+        #[arg(long)]
+        strict: bool,
+        /// Use commits since a duration or date (7d, 2w, 2024-01-01) instead of the last tag
+        #[arg(long, value_name = "WHEN", value_parser = parse_since_arg)]
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        /// Only use commits made up to a duration ago or a date (7d, 2024-02-01)
+        #[arg(long, value_name = "WHEN", value_parser = parse_since_arg)]
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        /// Only use commits whose author name or email contains this text
+        #[arg(long, value_name = "NAME")]
+        author: Option<String>,
+        /// Fail if more than N commits don't follow Conventional Commits
+        #[arg(long, value_name = "N")]
+        max_non_conventional: Option<usize>,
+        /// Generate the files without the TUI, printing progress to stdout
+        #[arg(long)]
+        ci: bool,
+        /// Person responsible for the release (overrides RELEASE_RESPONSIBLE)
+        #[arg(long, value_name = "NAME")]
+        responsible: Option<String>,
+        /// Only run the Gemini step on a document saved by an earlier run
+        /// (`release-notes-<timestamp>.md`), without fetching commits or tasks
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["since", "until", "author", "max_non_conventional"])]
+        from_document: Option<std::path::PathBuf>,
+    },
+    /// List, open or delete the generated release notes
+    Artifacts {
+        #[command(subcommand)]
+        artifacts_command: Option<ArtifactsCommands>,
+    },
     /// Search Monday.com tasks
     Search { query: Option<String> },
     /// Setup git commit template for consistent commit messages
     SetupTemplate,
-    /// Get detailed version information using semantic-release
-    VersionInfo,
+    /// Get detailed version information (next version, release type, commits)
+    VersionInfo {
+        /// Pre-release channel, e.g. `beta` for 1.2.0-beta.1 (overrides RELEASE_CHANNEL)
+        #[arg(long, value_name = "NAME")]
+        channel: Option<String>,
+        /// Build metadata appended as +META; `sha` uses the short HEAD hash
+        #[arg(long, value_name = "META")]
+        build: Option<String>,
+        /// Print only the version information as JSON to stdout (errors go to stderr)
+        #[arg(long)]
+        json: bool,
+        /// Version one of the RELEASE_PACKAGES (its path) from its own tags
+        #[arg(long, value_name = "PATH")]
+        package: Option<String>,
+    },
+    /// List recent commits with their type, scope and linked tasks
+    History {
+        /// Maximum number of commits to list
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+        /// Only list commits after this tag/revision, duration or date (v1.2.0, 7d, 2024-01-01)
+        #[arg(long, value_name = "REV|WHEN")]
+        since: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = HistoryFormat::Table)]
+        format: HistoryFormat,
+    },
+    /// Check that the commits since the last tag parse as conventional commits
+    /// (exit code 1 if any doesn't), e.g. in CI before tagging
+    Verify {
+        /// Check the commits after this tag/revision, duration or date instead of the last tag
+        #[arg(long, value_name = "REV|WHEN")]
+        since: Option<String>,
+    },
+    /// Check the whole configuration and report problems
+    Doctor,
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for
+        shell: Shell,
+    },
     /// Debug mode - show detailed error information
     Debug {
         #[command(subcommand)]
@@ -62,6 +190,30 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum ArtifactsCommands {
+    /// List the generated runs: date, version, size, Gemini notes and status
+    List,
+    /// Show the generation log: every run with its commit range, even deleted ones
+    History,
+    /// Open a run (its name, one of its files or `latest`) with the system viewer
+    Open { run: String },
+    /// Delete every file of a run
+    Delete { run: String },
+    /// Delete old and/or failed runs
+    Clean {
+        /// Delete runs generated more than DAYS days ago
+        #[arg(long, value_name = "DAYS")]
+        older_than: Option<u64>,
+        /// Delete runs that didn't finish (no HTML export or empty Markdown)
+        #[arg(long)]
+        failed: bool,
+        /// Only print what would be deleted
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 enum DebugCommands {
     /// Test Monday.com connection
@@ -74,6 +226,15 @@ enum DebugCommands {
     Commit,
 }
 
+fn parse_since_arg(value: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    git::parse_since(value).ok_or_else(|| {
+        format!(
+            "'{}' is not a duration (12h, 7d, 2w) or date (2024-01-01)",
+            value
+        )
+    })
+}
+
 #[tokio::main]
 async fn main() -> miette::Result<()> {
     let cli = Cli::parse();
@@ -87,11 +248,47 @@ async fn main() -> miette::Result<()> {
         debug = cli.debug,
         verbose = cli.verbose,
         dev = cli.dev,
+        profile = ?cli.profile,
+        config_file = ?cli.config_file,
+        offline = cli.offline,
+        no_verify = cli.no_verify,
         "🚀 Starting Semantic Release TUI"
     );
 
+    if cli.offline {
+        config::set_offline_mode();
+    }
+    if cli.no_verify {
+        config::set_no_verify_mode();
+    }
+
+    if let Some(profile) = cli.profile.as_deref() {
+        config::set_active_profile(profile).map_err(|e| {
+            miette::miette!(
+                help = "Use the part after `.env.` of the profile's file name",
+                "{}",
+                e
+            )
+        })?;
+    }
+    if let Some(path) = cli.config_file.as_deref() {
+        config::set_config_file(path).map_err(|e| {
+            miette::miette!(
+                help = "Check the path given with --config and the file's syntax",
+                "{}",
+                e
+            )
+        })?;
+    }
+
     // Handle --autocommit flag
     if cli.autocommit {
+        if cli.offline {
+            return Err(miette::miette!(
+                help = "Drop --offline, or run the TUI and fill in the commit by hand",
+                "--autocommit needs Gemini, which is unavailable with --offline"
+            ));
+        }
         // File logging only
         info!("🤖 Running autocommit flow");
         let app = App::new()
@@ -103,13 +300,17 @@ async fn main() -> miette::Result<()> {
         return Ok(());
     }
 
+    let dev = cli.dev;
     let result = match cli.command.unwrap_or(Commands::Tui) {
         Commands::Tui => {
             // File logging only
             info!("🖥️ Starting TUI interface");
-            let app = App::new()
+            let mut app = App::new()
                 .await
                 .map_err(|e| miette::miette!("Failed to initialize app for TUI: {}", e))?;
+            if dev {
+                app.enable_render_stats();
+            }
             app.run().await
         }
         Commands::Config => {
@@ -117,22 +318,123 @@ async fn main() -> miette::Result<()> {
             info!("⚙️ Running configuration");
             config::run_config().await
         }
-        Commands::Commit => {
-            // File logging only
-            info!("📝 Running commit flow");
-            let app = App::new()
+        Commands::Commit {
+            no_tui,
+            dry_run,
+            commit_type,
+            scope,
+            title,
+            description,
+            breaking,
+            test_details,
+            security,
+            tasks,
+            allow_secrets,
+        } => {
+            let mut app = App::new()
                 .await
                 .map_err(|e| miette::miette!("Failed to initialize app for commit: {}", e))?;
-            app.commit_flow().await
+            let has_commit_flags = commit_type.is_some()
+                || scope.is_some()
+                || title.is_some()
+                || description.is_some()
+                || breaking.is_some()
+                || test_details.is_some()
+                || security.is_some()
+                || !tasks.is_empty();
+            if has_commit_flags && !no_tui && !dry_run {
+                return Err(miette::miette!(
+                    help = "Add --no-tui to commit directly, or --dry-run to print the message",
+                    "Commit flags are only used without the TUI"
+                ));
+            }
+
+            if no_tui || dry_run {
+                // File logging only
+                info!("📝 Running non-interactive commit");
+                app.commit_from_flags(CommitFlags {
+                    commit_type,
+                    scope,
+                    title,
+                    description,
+                    breaking,
+                    test_details,
+                    security,
+                    tasks,
+                    dry_run,
+                    allow_secrets,
+                })
+                .await
+            } else {
+                // File logging only
+                info!("📝 Running commit flow");
+                app.commit_flow().await
+            }
         }
-        Commands::ReleaseNotes => {
+        Commands::ReleaseNotes {
+            output_dir,
+            strict,
+            since,
+            until,
+            author,
+            max_non_conventional,
+            ci,
+            responsible,
+            from_document,
+        } => {
             // File logging only
             info!("📝 Running release notes generation");
             let mut app = App::new().await.map_err(|e| {
                 miette::miette!("Failed to initialize app for release notes: {}", e)
             })?;
-            app.current_screen = AppScreen::ReleaseNotes;
-            app.run().await
+            if output_dir.is_some() {
+                app.config.release_notes_dir = output_dir;
+            }
+            if responsible.is_some() {
+                app.config.release_responsible = responsible;
+            }
+            if let Some(document) = from_document {
+                if cli.offline {
+                    return Err(miette::miette!(
+                        "--from-document needs Gemini, which is unavailable with --offline"
+                    ));
+                }
+                info!(document = %document.display(), "📝 Retrying the Gemini step");
+                return app
+                    .release_notes_from_document(&document)
+                    .await
+                    .map_err(|e| miette::miette!("Release notes processing failed: {}", e));
+            }
+            if strict || app.config.release_notes_strict {
+                let template_path =
+                    app::release_notes::require_release_notes_template(&app.config)?;
+                info!(path = %template_path.display(), "Release notes template found");
+            }
+            app.ui_state.release_notes_since = since;
+            app.ui_state.release_notes_until = until;
+            app.ui_state.release_notes_author = author;
+            if let Some(max) = max_non_conventional {
+                app.check_commit_convention(max)?;
+            }
+            // Without a terminal the TUI can't start; run headless instead of failing
+            let has_tty = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
+            if !ci && !has_tty {
+                log_user_message("⚠️  No TTY detected, generating release notes headless (--ci)");
+            }
+            if ci || !has_tty {
+                app.release_notes_headless().await
+            } else {
+                app.current_screen = AppScreen::ReleaseNotes;
+                if dev {
+                    app.enable_render_stats();
+                }
+                app.run().await
+            }
+        }
+        Commands::Search { .. } if cli.offline => {
+            return Err(miette::miette!(
+                "Task search needs Monday.com, which is unavailable with --offline"
+            ));
         }
         Commands::Search { query } => {
             // File logging only
@@ -152,11 +454,45 @@ async fn main() -> miette::Result<()> {
             info!("🔧 Setting up commit template");
             config::setup_commit_template().await
         }
-        Commands::VersionInfo => {
+        Commands::VersionInfo {
+            channel,
+            build,
+            json,
+            package,
+        } => {
             // File logging only
             info!("📦 Analyzing version information");
-            log_user_message("🔍 Analyzing version information...");
-            match git::repository::get_version_info() {
+            if !json {
+                log_user_message("🔍 Analyzing version information...");
+            }
+            let config = config::load_config().unwrap_or_default();
+            let mut options = match package.as_deref().map(|path| path.trim_end_matches('/')) {
+                Some(path) => {
+                    let package = config
+                        .release_packages
+                        .iter()
+                        .find(|package| package.path == path)
+                        .ok_or_else(|| {
+                            miette::miette!(
+                                help = "Packages are configured with RELEASE_PACKAGES",
+                                "'{}' is not one of the configured packages",
+                                path
+                            )
+                        })?;
+                    config.package_version_options(package)
+                }
+                None => config.version_options(),
+            };
+            if channel.is_some() {
+                options.channel = channel;
+            }
+            options.build_metadata = build;
+            match git::repository::get_version_info(&options) {
+                Ok(version_info) if json => {
+                    // stdout carries nothing but the JSON, so it can be piped to jq
+                    println!("{}", serde_json::to_string_pretty(&version_info)?);
+                    Ok(())
+                }
                 Ok(version_info) => {
                     log_user_message("\n📦 VERSION INFORMATION");
                     log_user_message(&"=".repeat(50));
@@ -168,6 +504,12 @@ async fn main() -> miette::Result<()> {
                     }
 
                     log_user_message(&format!("🚀 Next version: {}", version_info.next_version));
+                    if let Some(next_tag) = &version_info.next_tag {
+                        log_user_message(&format!("🔖 Next tag: {}", next_tag));
+                    }
+                    if let Some(channel) = &version_info.channel {
+                        log_user_message(&format!("🧪 Channel: {}", channel));
+                    }
                     log_user_message(&format!("📊 Release type: {}", version_info.version_type));
                     log_user_message(&format!(
                         "📈 Commits since last version: {}",
@@ -192,6 +534,75 @@ async fn main() -> miette::Result<()> {
                 }
             }
         }
+        Commands::History {
+            limit,
+            since,
+            format,
+        } => {
+            // File logging only
+            info!("📜 Listing commit history");
+            let app = App::new()
+                .await
+                .map_err(|e| miette::miette!("Failed to initialize app for history: {}", e))?;
+            app.print_history(HistoryOptions {
+                limit,
+                since,
+                format,
+            })
+            .await
+        }
+        Commands::Artifacts { artifacts_command } => {
+            // File logging only
+            info!(?artifacts_command, "🗂️ Managing release notes artifacts");
+            let app = App::new()
+                .await
+                .map_err(|e| miette::miette!("Failed to initialize app for artifacts: {}", e))?;
+            app.manage_release_notes_artifacts(match artifacts_command {
+                None | Some(ArtifactsCommands::List) => ArtifactsAction::List,
+                Some(ArtifactsCommands::History) => ArtifactsAction::History,
+                Some(ArtifactsCommands::Open { run }) => ArtifactsAction::Open(run),
+                Some(ArtifactsCommands::Delete { run }) => ArtifactsAction::Delete(run),
+                Some(ArtifactsCommands::Clean {
+                    older_than,
+                    failed,
+                    dry_run,
+                }) => ArtifactsAction::Clean {
+                    older_than_days: older_than,
+                    failed,
+                    dry_run,
+                },
+            })
+        }
+        Commands::Verify { since } => {
+            // File logging only
+            info!(?since, "🔎 Verifying commits");
+            let app = App::new()
+                .await
+                .map_err(|e| miette::miette!("Failed to initialize app for verify: {}", e))?;
+            match app.verify_commits(since.as_deref()) {
+                Ok(true) => Ok(()),
+                Ok(false) => std::process::exit(1),
+                Err(e) => Err(e),
+            }
+        }
+        Commands::Doctor => {
+            // File logging only
+            info!("🩺 Running doctor checks");
+            let app = App::new()
+                .await
+                .map_err(|e| miette::miette!("Failed to initialize app for doctor: {}", e))?;
+            match app.doctor().await {
+                Ok(true) => Ok(()),
+                Ok(false) => std::process::exit(1),
+                Err(e) => Err(e),
+            }
+        }
+        Commands::Completions { shell } => {
+            let mut command = Cli::command();
+            let bin_name = command.get_name().to_string();
+            clap_complete::generate(shell, &mut command, bin_name, &mut std::io::stdout());
+            Ok(())
+        }
         Commands::Debug { debug_command } => {
             // File logging only
             info!(?debug_command, "🐛 Running debug command");
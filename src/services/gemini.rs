@@ -1,10 +1,17 @@
-use genai::chat::{ChatMessage, ChatRequest};
+use futures::StreamExt;
+use genai::chat::{ChatMessage, ChatRequest, ChatStreamEvent};
 use genai::Client;
+use serde_json::Value;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::time::Instant;
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
     error::{Result, SemanticReleaseError},
-    types::AppConfig,
+    i18n::{tr, Language},
+    services::shared_http_client,
+    types::{AppConfig, CommitAnalysis},
 };
 
 // =============================================================================
@@ -13,6 +20,12 @@ use crate::{
 
 pub struct GeminiClient {
     client: Client,
+    /// Language of the generated titles, descriptions and release notes
+    language: Language,
+    analysis_timeout: Duration,
+    release_notes_timeout: Duration,
+    /// Receives the commit description as the analysis streams in
+    description_preview: Option<UnboundedSender<String>>,
 }
 
 impl GeminiClient {
@@ -32,10 +45,31 @@ impl GeminiClient {
         // Set the API key as environment variable for genai
         std::env::set_var("GEMINI_API_KEY", &api_key);
 
-        let client = Client::default();
+        let client = Client::builder()
+            .with_reqwest(shared_http_client(config)?)
+            .build();
 
         info!("Gemini AI client initialized successfully");
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            language: config.ai_language(),
+            analysis_timeout: config.ai_timeout(),
+            release_notes_timeout: config.release_notes_timeout(),
+            description_preview: None,
+        })
+    }
+
+    /// Stream the commit analysis and send the description written so far on
+    /// every chunk, so it can be shown while Gemini is still generating it.
+    pub fn with_description_preview(mut self, preview: UnboundedSender<String>) -> Self {
+        self.description_preview = Some(preview);
+        self
+    }
+
+    /// Write in `language` instead of the configured AI language.
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
     }
 }
 
@@ -45,12 +79,24 @@ impl GeminiClient {
 
 impl GeminiClient {
     #[instrument(skip(self), fields(prompt_len = prompt.len()))]
-    async fn call_gemini_with_fallback(&self, prompt: &str) -> Result<String> {
+    async fn call_gemini_with_fallback(&self, prompt: &str, timeout: Duration) -> Result<String> {
+        self.call_gemini_with_fallback_until(prompt, Instant::now() + timeout, timeout)
+            .await
+    }
+
+    /// Try Gemini 2.5 Pro Preview first (most advanced), then 2.0 Flash, both
+    /// within `deadline`. A timed out request is not retried: the fallback
+    /// would only add to the wait. `timeout` is the budget shown in the error.
+    async fn call_gemini_with_fallback_until(
+        &self,
+        prompt: &str,
+        deadline: Instant,
+        timeout: Duration,
+    ) -> Result<String> {
         debug!("Attempting Gemini API call with fallback strategy");
 
-        // Try Gemini 2.5 Pro Preview first (most advanced), then fallback to 2.0 Flash
         match self
-            .call_gemini_api(prompt, "gemini-2.5-pro-preview-06-05")
+            .call_gemini_api(prompt, "gemini-2.5-pro-preview-06-05", deadline, timeout)
             .await
         {
             Ok(response) => {
@@ -60,6 +106,7 @@ impl GeminiClient {
                 );
                 Ok(response)
             }
+            Err(e) if e.is_timeout_error() => Err(e),
             Err(e) => {
                 warn!(
                     model = "gemini-2.5-pro-preview-06-05",
@@ -67,7 +114,9 @@ impl GeminiClient {
                     "Gemini 2.5 Pro Preview failed, trying 2.0 Flash"
                 );
 
-                let fallback_response = self.call_gemini_api(prompt, "gemini-2.0-flash").await?;
+                let fallback_response = self
+                    .call_gemini_api(prompt, "gemini-2.0-flash", deadline, timeout)
+                    .await?;
                 info!(model = "gemini-2.0-flash", "Gemini API fallback successful");
                 Ok(fallback_response)
             }
@@ -75,19 +124,32 @@ impl GeminiClient {
     }
 
     #[instrument(skip(self), fields(model = model, prompt_len = prompt.len()))]
-    async fn call_gemini_api(&self, prompt: &str, model: &str) -> Result<String> {
+    async fn call_gemini_api(
+        &self,
+        prompt: &str,
+        model: &str,
+        deadline: Instant,
+        timeout: Duration,
+    ) -> Result<String> {
         debug!(model = model, "Making Gemini API request");
 
         let chat_req = ChatRequest::new(vec![ChatMessage::user(prompt)]);
 
-        let chat_res = self
-            .client
-            .exec_chat(model, chat_req, None)
-            .await
-            .map_err(|e| {
-                error!(model = model, error = %e, "Gemini API request failed");
-                SemanticReleaseError::ai_error("Gemini", e)
-            })?;
+        let chat_res =
+            tokio::time::timeout_at(deadline, self.client.exec_chat(model, chat_req, None))
+                .await
+                .map_err(|_| {
+                    error!(
+                        model = model,
+                        timeout_secs = timeout.as_secs(),
+                        "Gemini API request timed out"
+                    );
+                    SemanticReleaseError::timeout_error("Gemini", timeout)
+                })?
+                .map_err(|e| {
+                    error!(model = model, error = %e, "Gemini API request failed");
+                    gemini_error(e)
+                })?;
 
         let content = chat_res.content_text_as_str().ok_or_else(|| {
             error!(model = model, "Gemini API returned no response content");
@@ -108,6 +170,99 @@ impl GeminiClient {
 
         Ok(content.to_string())
     }
+
+    /// Call `streamGenerateContent`, sending the partial `description` of the
+    /// JSON answer to `preview` as it grows. Falls back to the regular request
+    /// when streaming fails, within the same `deadline`.
+    async fn call_gemini_streaming(
+        &self,
+        prompt: &str,
+        deadline: Instant,
+        timeout: Duration,
+        preview: &UnboundedSender<String>,
+    ) -> Result<String> {
+        let model = "gemini-2.5-pro-preview-06-05";
+        match tokio::time::timeout_at(deadline, self.stream_gemini_api(prompt, model, preview))
+            .await
+        {
+            Ok(Ok(response)) => {
+                info!(model = model, "Gemini streaming call successful");
+                Ok(response)
+            }
+            Ok(Err(e)) => {
+                warn!(model = model, error = %e, "Gemini streaming failed, using a regular request");
+                self.call_gemini_with_fallback_until(prompt, deadline, timeout)
+                    .await
+            }
+            Err(_) => {
+                error!(
+                    model = model,
+                    timeout_secs = timeout.as_secs(),
+                    "Gemini streaming request timed out"
+                );
+                Err(SemanticReleaseError::timeout_error("Gemini", timeout))
+            }
+        }
+    }
+
+    async fn stream_gemini_api(
+        &self,
+        prompt: &str,
+        model: &str,
+        preview: &UnboundedSender<String>,
+    ) -> Result<String> {
+        debug!(model = model, "Making Gemini streaming request");
+
+        let chat_req = ChatRequest::new(vec![ChatMessage::user(prompt)]);
+        let mut stream = self
+            .client
+            .exec_chat_stream(model, chat_req, None)
+            .await
+            .map_err(gemini_error)?
+            .stream;
+
+        let mut content = String::new();
+        let mut description_len = 0;
+        while let Some(event) = stream.next().await {
+            if let ChatStreamEvent::Chunk(chunk) = event.map_err(gemini_error)? {
+                content.push_str(&chunk.content);
+                if let Some(description) = partial_json_string(&content, "description") {
+                    if description.len() > description_len {
+                        description_len = description.len();
+                        // The receiver may be gone already; the analysis still completes
+                        let _ = preview.send(description);
+                    }
+                }
+            }
+        }
+
+        if content.trim().is_empty() {
+            return Err(SemanticReleaseError::ai_error(
+                "Gemini",
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "No response content from Gemini streaming API",
+                ),
+            ));
+        }
+
+        debug!(
+            model = model,
+            response_len = content.len(),
+            "Gemini streaming response received"
+        );
+        Ok(content)
+    }
+}
+
+fn gemini_error(e: genai::Error) -> SemanticReleaseError {
+    match e {
+        genai::Error::WebModelCall {
+            webc_error: genai::webc::Error::Reqwest(e),
+            ..
+        } if e.is_connect() => SemanticReleaseError::connection_error("Gemini", e),
+        e => SemanticReleaseError::ai_error("Gemini", e),
+    }
 }
 
 // =============================================================================
@@ -121,7 +276,14 @@ impl GeminiClient {
 
         // This method sends the complete structured document to Gemini for processing
         // (like the Node.js script's processWithGemini function)
-        let result = self.call_gemini_with_fallback(document).await?;
+        let prompt = format!(
+            "{}\n\nOUTPUT LANGUAGE: write the release notes in {}.",
+            document,
+            self.language.english_name()
+        );
+        let result = self
+            .call_gemini_with_fallback(&prompt, self.release_notes_timeout)
+            .await?;
 
         info!(
             input_len = document.len(),
@@ -142,14 +304,37 @@ impl GeminiClient {
     pub async fn generate_comprehensive_commit_analysis(
         &self,
         changes: &str,
-    ) -> Result<serde_json::Value> {
-        info!("Generating comprehensive commit analysis with Gemini");
+        risk_analysis: bool,
+    ) -> Result<CommitAnalysis> {
+        info!(
+            risk_analysis,
+            "Generating comprehensive commit analysis with Gemini"
+        );
+
+        // Trivial commits skip the two longest checklists and their answers
+        let (security_checklist, breaking_checklist) = if risk_analysis {
+            (SECURITY_CHECKLIST, BREAKING_CHECKLIST)
+        } else {
+            (SKIPPED_CHECKLIST, SKIPPED_CHECKLIST)
+        };
+
+        let language = self.language.prompt_name();
+        let (imperatives, title_examples) = match self.language {
+            Language::Es => (
+                r#""añade", "corrige", "actualiza""#,
+                r#""añade validación de email", "corrige error de memoria""#,
+            ),
+            Language::En => (
+                r#""add", "fix", "update""#,
+                r#""add email validation", "fix memory leak""#,
+            ),
+        };
 
         let prompt = format!(
             r#"Eres un desarrollador experto y especialista en semantic release que debe analizar cambios de código de forma EXHAUSTIVA y generar un análisis completo de commit.
 
 CAMBIOS EN EL CÓDIGO:
-{}
+{changes}
 
 INSTRUCCIONES CRÍTICAS:
 Analiza MINUCIOSAMENTE los cambios proporcionados y genera un JSON con toda la información del commit. Debes ser EXTREMADAMENTE DETALLADO y PRECISO.
@@ -157,11 +342,11 @@ Analiza MINUCIOSAMENTE los cambios proporcionados y genera un JSON con toda la i
 ANÁLISIS REQUERIDO:
 
 1. **TÍTULO** (title):
-   - Genera un título CONCISO y DESCRIPTIVO en español (máximo 50 caracteres)
-   - Usa presente imperativo (ej: "añade", "corrige", "actualiza")
+   - Genera un título CONCISO y DESCRIPTIVO en {language} (máximo 50 caracteres)
+   - Usa presente imperativo (ej: {imperatives})
    - Debe explicar QUÉ se hizo específicamente
    - NO incluyas el tipo de commit (feat:, fix:, etc.)
-   - Ejemplos: "añade validación de email", "corrige error de memoria"
+   - Ejemplos: {title_examples}
 
 2. **TIPO DE COMMIT** (commitType):
    - Determina el tipo según semantic release:
@@ -176,7 +361,7 @@ ANÁLISIS REQUERIDO:
    - "revert": Reversión de cambios anteriores
 
 3. **DESCRIPCIÓN** (description):
-   - Genera una descripción TÉCNICA EXHAUSTIVA en español (mínimo 150 palabras)
+   - Genera una descripción TÉCNICA EXHAUSTIVA en {language} (mínimo 150 palabras)
    - Explica QUÉ cambios específicos se hicieron
    - Explica POR QUÉ estos cambios son importantes
    - Describe el IMPACTO técnico y funcional
@@ -191,30 +376,10 @@ ANÁLISIS REQUERIDO:
    - Si no hay un ámbito claro, usa "general"
 
 5. **ANÁLISIS DE SEGURIDAD** (securityAnalysis):
-   - Busca EXHAUSTIVAMENTE vulnerabilidades como:
-     * Inyección SQL, XSS, CSRF
-     * Manejo inseguro de datos sensibles (passwords, tokens, keys)
-     * Validación insuficiente de entrada
-     * Exposición de información confidencial
-     * Configuraciones de seguridad débiles
-     * Dependencias con vulnerabilidades
-     * Privilegios elevados innecesarios
-     * Manejo inseguro de archivos/rutas
-   - Si NO hay riesgos: devuelve cadena vacía ""
-   - Si SÍ hay riesgos: describe específicamente qué riesgos encontraste
+{security_checklist}
 
 6. **CAMBIOS QUE ROMPEN COMPATIBILIDAD** (breakingChanges):
-   - Identifica breaking changes como:
-     * Eliminación de APIs, funciones, clases públicas
-     * Cambios en signatures (parámetros, tipos de retorno)
-     * Modificación de contratos de interfaz
-     * Cambios en formatos de datos o protocolos
-     * Eliminación de configuraciones
-     * Cambios en comportamiento esperado de APIs
-     * Modificaciones de esquemas de BD
-     * Cambios en URLs de endpoints
-   - Si NO hay breaking changes: devuelve cadena vacía ""
-   - Si SÍ hay breaking changes: describe específicamente qué se rompió
+{breaking_checklist}
 
 7. **ANÁLISIS DE PRUEBAS** (testAnalysis):
    - Recomienda pruebas manuales específicas que una persona debería realizar para verificar los cambios
@@ -225,7 +390,7 @@ ANÁLISIS REQUERIDO:
    - Sugiere datos de prueba específicos si es necesario
    - Estructura las recomendaciones como una lista clara y accionable
    - Si no hay necesidad de pruebas manuales específicas: devuelve cadena vacía ""
-   - RESPONDE EN ESPAÑOL
+   - RESPONDE EN {language_upper}
 
 FORMATO DE RESPUESTA:
 Responde ÚNICAMENTE con un JSON válido usando EXACTAMENTE esta estructura:
@@ -237,7 +402,7 @@ Responde ÚNICAMENTE con un JSON válido usando EXACTAMENTE esta estructura:
   "scope": "ámbito_del_código",
   "securityAnalysis": "análisis de seguridad o cadena vacía",
   "breakingChanges": "cambios que rompen compatibilidad o cadena vacía",
-  "testAnalysis": "recomendaciones de pruebas manuales en español o cadena vacía"
+  "testAnalysis": "recomendaciones de pruebas manuales en {language} o cadena vacía"
 }}
 
 VALIDACIONES:
@@ -246,77 +411,196 @@ VALIDACIONES:
 - El título debe ser ≤ 50 caracteres
 - La descripción debe ser ≥ 150 palabras
 - commitType debe ser uno de los tipos válidos listados
-- testAnalysis debe estar en español y ser específico para los cambios realizados
+- testAnalysis debe estar en {language} y ser específico para los cambios realizados
 - NO incluyas explicaciones fuera del JSON
 - NO uses comillas triples ni formato markdown
 - NO agregues texto antes o después del JSON"#,
-            changes
+            changes = changes,
+            language = language,
+            language_upper = language.to_uppercase(),
+            imperatives = imperatives,
+            title_examples = title_examples,
+            security_checklist = security_checklist,
+            breaking_checklist = breaking_checklist,
         );
 
         debug!(prompt_len = prompt.len(), "Built commit analysis prompt");
 
-        let response = self.call_gemini_with_fallback(&prompt).await?;
+        // Retry once when nothing at all could be salvaged from the response,
+        // within the same AI_TIMEOUT_SECONDS as the first attempt
+        let timeout = self.analysis_timeout;
+        let deadline = Instant::now() + timeout;
+        for attempt in 1..=ANALYSIS_ATTEMPTS {
+            let response = match &self.description_preview {
+                Some(preview) => {
+                    self.call_gemini_streaming(&prompt, deadline, timeout, preview)
+                        .await?
+                }
+                None => {
+                    self.call_gemini_with_fallback_until(&prompt, deadline, timeout)
+                        .await?
+                }
+            };
+
+            let Some(mut analysis) = self.parse_commit_analysis(&response) else {
+                warn!(attempt, "Could not parse Gemini analysis response");
+                continue;
+            };
+
+            if analysis.title.is_empty() && analysis.description.is_empty() {
+                warn!(attempt, "Gemini JSON response missing required fields");
+                debug!(parsed_analysis = ?analysis, "Incomplete JSON response");
+                continue;
+            }
+
+            // Keep whatever parsed; only fill in the half that is missing
+            if analysis.title.is_empty() {
+                analysis.title = Self::fallback_commit_analysis("").title;
+            }
+            if analysis.description.is_empty() {
+                warn!("Gemini analysis has no description, keeping the rest of it");
+            }
+
+            // Whatever came back anyway was not asked for
+            if !risk_analysis {
+                analysis.security.clear();
+                analysis.breaking_change.clear();
+            }
+
+            info!(attempt, "Commit analysis completed successfully");
+            return Ok(analysis);
+        }
+
+        Ok(Self::fallback_commit_analysis(tr(
+            self.language,
+            "analysis.fallback_description",
+        )))
+    }
+
+    /// Re-run only the security part of the analysis, e.g. after adjusting the
+    /// changes, leaving the rest of the commit alone. Empty when there are no risks.
+    #[instrument(skip(self), fields(changes_len = changes.len()))]
+    pub async fn analyze_security_risks(&self, changes: &str) -> Result<String> {
+        self.analyze_single_field(
+            changes,
+            "securityAnalysis",
+            "ANÁLISIS DE SEGURIDAD",
+            SECURITY_CHECKLIST,
+        )
+        .await
+        .map(|analysis| analysis.security)
+    }
+
+    /// Re-run only the breaking changes part of the analysis. Empty when
+    /// nothing breaks.
+    #[instrument(skip(self), fields(changes_len = changes.len()))]
+    pub async fn analyze_breaking_changes(&self, changes: &str) -> Result<String> {
+        self.analyze_single_field(
+            changes,
+            "breakingChanges",
+            "CAMBIOS QUE ROMPEN COMPATIBILIDAD",
+            BREAKING_CHECKLIST,
+        )
+        .await
+        .map(|analysis| analysis.breaking_change)
+    }
+
+    /// Ask for one field of the analysis JSON, with the same instructions the
+    /// comprehensive analysis gives for it.
+    async fn analyze_single_field(
+        &self,
+        changes: &str,
+        key: &str,
+        heading: &str,
+        checklist: &str,
+    ) -> Result<CommitAnalysis> {
+        info!(field = key, "Generating single field analysis with Gemini");
+
+        let language = self.language.prompt_name();
+        let prompt = format!(
+            r#"Eres un desarrollador experto que debe revisar cambios de código y responder ÚNICAMENTE sobre un aspecto concreto.
 
+CAMBIOS EN EL CÓDIGO:
+{changes}
+
+{heading} ({key}):
+{checklist}
+   - RESPONDE EN {language_upper}
+
+FORMATO DE RESPUESTA:
+Responde ÚNICAMENTE con un JSON válido con esta estructura, sin texto antes o después ni formato markdown:
+
+{{
+  "{key}": "resultado del análisis o cadena vacía"
+}}"#,
+            changes = changes,
+            heading = heading,
+            key = key,
+            checklist = checklist,
+            language_upper = language.to_uppercase(),
+        );
+
+        let response = self
+            .call_gemini_with_fallback(&prompt, self.analysis_timeout)
+            .await?;
+        self.parse_commit_analysis(&response).ok_or_else(|| {
+            SemanticReleaseError::ai_error(
+                "Gemini",
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Could not parse the {} analysis response", key),
+                ),
+            )
+        })
+    }
+
+    /// Parse the analysis JSON, repairing it if needed: take the first balanced
+    /// `{...}` block, drop trailing commas, and keep every field that is valid
+    /// even when others (e.g. `securityAnalysis`) are malformed.
+    fn parse_commit_analysis(&self, response: &str) -> Option<CommitAnalysis> {
         // Clean the response - remove markdown code blocks and extra text
-        let cleaned_response = self.extract_json_from_response(&response);
+        let cleaned_response = self.extract_json_from_response(response);
         debug!(
             raw_response_len = response.len(),
             cleaned_response_len = cleaned_response.len(),
             "Cleaned Gemini response"
         );
 
-        // Try to parse the JSON response
-        match serde_json::from_str::<serde_json::Value>(&cleaned_response) {
-            Ok(json) => {
-                // Validate that all required fields are present
-                if json.get("title").is_some()
-                    && json.get("commitType").is_some()
-                    && json.get("description").is_some()
-                    && json.get("scope").is_some()
-                    && json.get("securityAnalysis").is_some()
-                    && json.get("breakingChanges").is_some()
-                    && json.get("testAnalysis").is_some()
-                {
-                    info!("Commit analysis completed successfully");
-                    Ok(json)
-                } else {
-                    warn!("Gemini JSON response missing required fields, using fallback");
-                    debug!(parsed_json = ?json, "Incomplete JSON response");
-
-                    // Return a fallback JSON structure
-                    Ok(serde_json::json!({
-                        "title": "cambios realizados en el código",
-                        "commitType": "chore",
-                        "description": "Se realizaron cambios en el código del proyecto. Respuesta de Gemini incompleta.",
-                        "scope": "general",
-                        "securityAnalysis": "",
-                        "breakingChanges": "",
-                        "testAnalysis": ""
-                    }))
+        let strict_error = match serde_json::from_str::<CommitAnalysis>(&cleaned_response) {
+            Ok(analysis) => return Some(analysis),
+            Err(e) => e,
+        };
+
+        let repaired = extract_balanced_json_object(response)
+            .map(remove_trailing_commas)
+            .and_then(|candidate| serde_json::from_str::<Value>(&candidate).ok());
+
+        match repaired {
+            Some(value) => {
+                warn!(parse_error = %strict_error, "Gemini JSON needed repair, keeping the valid fields");
+                match serde_json::from_value::<CommitAnalysis>(value.clone()) {
+                    Ok(analysis) => Some(analysis),
+                    Err(_) => partial_commit_analysis(&value),
                 }
             }
-            Err(e) => {
-                error!(
-                    parse_error = %e,
-                    raw_response_preview = %response.chars().take(200).collect::<String>(),
-                    cleaned_response_preview = %cleaned_response.chars().take(200).collect::<String>(),
-                    "Failed to parse Gemini JSON response, using fallback"
-                );
-
-                // Return a fallback JSON structure
-                Ok(serde_json::json!({
-                    "title": "cambios realizados en el código",
-                    "commitType": "chore",
-                    "description": "Se realizaron cambios en el código del proyecto. No se pudo generar un análisis detallado automáticamente.",
-                    "scope": "general",
-                    "securityAnalysis": "",
-                    "breakingChanges": "",
-                    "testAnalysis": ""
-                }))
+            None => {
+                error!(parse_error = %strict_error, "Failed to parse Gemini JSON response");
+                debug!(raw_response = %response, "Unparseable Gemini response");
+                None
             }
         }
     }
 
+    fn fallback_commit_analysis(description: &str) -> CommitAnalysis {
+        CommitAnalysis {
+            title: "cambios realizados en el código".to_string(),
+            description: description.to_string(),
+            suggested_type: Some("chore".to_string()),
+            suggested_scope: Some("general".to_string()),
+            ..Default::default()
+        }
+    }
+
     // Helper method to extract JSON from Gemini response that might be wrapped in markdown
     fn extract_json_from_response(&self, response: &str) -> String {
         let response = response.trim();
@@ -364,6 +648,176 @@ VALIDACIONES:
     }
 }
 
+// =============================================================================
+// JSON REPAIR
+// =============================================================================
+
+/// How many times the analysis is requested before falling back
+const ANALYSIS_ATTEMPTS: usize = 2;
+
+/// What the security analysis looks for, shared by the full and the single field prompts
+const SECURITY_CHECKLIST: &str = r#"   - Busca EXHAUSTIVAMENTE vulnerabilidades como:
+     * Inyección SQL, XSS, CSRF
+     * Manejo inseguro de datos sensibles (passwords, tokens, keys)
+     * Validación insuficiente de entrada
+     * Exposición de información confidencial
+     * Configuraciones de seguridad débiles
+     * Dependencias con vulnerabilidades
+     * Privilegios elevados innecesarios
+     * Manejo inseguro de archivos/rutas
+   - Si NO hay riesgos: devuelve cadena vacía ""
+   - Si SÍ hay riesgos: describe específicamente qué riesgos encontraste"#;
+
+/// Replaces a checklist in the analysis of a trivial commit.
+const SKIPPED_CHECKLIST: &str = r#"   - NO lo analices en este commit: devuelve cadena vacía """#;
+
+/// What counts as a breaking change, shared by the full and the single field prompts
+const BREAKING_CHECKLIST: &str = r#"   - Identifica breaking changes como:
+     * Eliminación de APIs, funciones, clases públicas
+     * Cambios en signatures (parámetros, tipos de retorno)
+     * Modificación de contratos de interfaz
+     * Cambios en formatos de datos o protocolos
+     * Eliminación de configuraciones
+     * Cambios en comportamiento esperado de APIs
+     * Modificaciones de esquemas de BD
+     * Cambios en URLs de endpoints
+   - Si NO hay breaking changes: devuelve cadena vacía ""
+   - Si SÍ hay breaking changes: describe específicamente qué se rompió"#;
+
+/// First balanced `{...}` block in `text`, ignoring braces inside strings.
+fn extract_balanced_json_object(text: &str) -> Option<&str> {
+    let start = text.find('{')?;
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (offset, c) in text[start..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[start..=start + offset]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Drop commas directly before a closing `}` or `]` (outside strings).
+fn remove_trailing_commas(json: &str) -> String {
+    let mut repaired = String::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = json.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let rest = chars.clone().find(|next| !next.is_whitespace());
+            if matches!(rest, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        repaired.push(c);
+    }
+
+    repaired
+}
+
+/// Value of the string field `key` in a JSON text that may still be
+/// incomplete, unescaped up to where the text ends.
+fn partial_json_string(text: &str, key: &str) -> Option<String> {
+    let quoted_key = format!("\"{}\"", key);
+    let start = text.find(&quoted_key)? + quoted_key.len();
+    let rest = text[start..]
+        .trim_start()
+        .strip_prefix(':')?
+        .trim_start()
+        .strip_prefix('"')?;
+
+    let mut value = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => break,
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r') => {}
+                Some('u') => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    if let Some(c) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                        value.push(c);
+                    }
+                }
+                Some(escaped) => value.push(escaped),
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+    Some(value)
+}
+
+/// Build the analysis field by field, skipping the ones with unusable values.
+/// Arrays of strings (a common slip for the long text fields) are joined by lines.
+fn partial_commit_analysis(value: &Value) -> Option<CommitAnalysis> {
+    let object = value.as_object()?;
+    let field = |keys: &[&str]| -> Option<String> {
+        let (key, raw) = keys
+            .iter()
+            .find_map(|key| object.get(*key).map(|raw| (*key, raw)))?;
+        match raw {
+            Value::String(text) => Some(text.clone()),
+            Value::Array(items) if items.iter().all(Value::is_string) => Some(
+                items
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            Value::Null => None,
+            other => {
+                debug!(field = key, value = %other, "Dropping malformed analysis field");
+                None
+            }
+        }
+    };
+
+    Some(CommitAnalysis {
+        title: field(&["title"]).unwrap_or_default(),
+        description: field(&["description"]).unwrap_or_default(),
+        suggested_type: field(&["commitType", "suggested_type"]),
+        suggested_scope: field(&["scope", "suggested_scope"]),
+        security: field(&["securityAnalysis", "security"]).unwrap_or_default(),
+        breaking_change: field(&["breakingChanges", "breaking_change"]).unwrap_or_default(),
+        test_details: field(&["testAnalysis", "test_details"]).unwrap_or_default(),
+    })
+}
+
 // =============================================================================
 // PUBLIC UTILITY FUNCTIONS
 // =============================================================================
@@ -377,28 +831,90 @@ pub async fn test_gemini_connection(config: &AppConfig) -> Result<String> {
     let test_prompt =
         "Responde con 'Conexión exitosa con Google Gemini' si puedes leer este mensaje.";
 
-    match client
-        .call_gemini_api(test_prompt, "gemini-2.5-pro-preview-06-05")
-        .await
-    {
-        Ok(response) => {
-            info!(
-                model = "gemini-2.5-pro-preview-06-05",
-                "Gemini connection test successful"
-            );
-            Ok(response)
-        }
-        Err(_e) => {
-            warn!("Gemini 2.5 Pro Preview failed during connection test, trying fallback");
-            let fallback_response = client
-                .call_gemini_api(test_prompt, "gemini-2.0-flash")
-                .await?;
-
-            info!(
-                model = "gemini-2.0-flash",
-                "Gemini connection test successful with fallback"
-            );
-            Ok(fallback_response)
+    let response = client
+        .call_gemini_with_fallback(test_prompt, client.analysis_timeout)
+        .await?;
+    info!("Gemini connection test successful");
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_extract_balanced_json_object_skips_surrounding_text() {
+        let text = "Here is the analysis:\n```json\n{\"title\": \"a\", \"nested\": {\"b\": 1}}\n```\nDone {}";
+
+        assert_eq!(
+            extract_balanced_json_object(text),
+            Some("{\"title\": \"a\", \"nested\": {\"b\": 1}}")
+        );
+    }
+
+    #[test]
+    fn test_extract_balanced_json_object_ignores_braces_in_strings() {
+        let text = r#"{"title": "fix } and { in \"quotes\"", "scope": "ui"} trailing"#;
+
+        assert_eq!(
+            extract_balanced_json_object(text),
+            Some(r#"{"title": "fix } and { in \"quotes\"", "scope": "ui"}"#)
+        );
+    }
+
+    #[test]
+    fn test_extract_balanced_json_object_without_a_complete_object() {
+        assert_eq!(extract_balanced_json_object("no json here"), None);
+        assert_eq!(extract_balanced_json_object("{\"title\": \"cut"), None);
+    }
+
+    #[test]
+    fn test_remove_trailing_commas() {
+        let cases = [
+            (r#"{"a": 1,}"#, r#"{"a": 1}"#),
+            ("{\"a\": [1, 2,\n  ],\n}", "{\"a\": [1, 2\n  ]\n}"),
+            (r#"{"a": "x,}", "b": 2}"#, r#"{"a": "x,}", "b": 2}"#),
+            (r#"{"a": "\",]"}"#, r#"{"a": "\",]"}"#),
+        ];
+        for (json, expected) in cases {
+            assert_eq!(remove_trailing_commas(json), expected, "{}", json);
         }
     }
+
+    #[test]
+    fn test_partial_commit_analysis_keeps_valid_fields() {
+        let value = json!({
+            "title": "add export",
+            "description": ["First line", "Second line"],
+            "commitType": "feat",
+            "scope": null,
+            "securityAnalysis": 42,
+            "breakingChanges": "",
+        });
+
+        let analysis = partial_commit_analysis(&value).unwrap();
+
+        assert_eq!(analysis.title, "add export");
+        assert_eq!(analysis.description, "First line\nSecond line");
+        assert_eq!(analysis.suggested_type.as_deref(), Some("feat"));
+        assert_eq!(analysis.suggested_scope, None);
+        assert_eq!(analysis.security, "");
+        assert_eq!(analysis.test_details, "");
+    }
+
+    #[test]
+    fn test_partial_commit_analysis_accepts_snake_case_keys() {
+        let value = json!({"suggested_type": "fix", "test_details": "cargo test"});
+
+        let analysis = partial_commit_analysis(&value).unwrap();
+
+        assert_eq!(analysis.suggested_type.as_deref(), Some("fix"));
+        assert_eq!(analysis.test_details, "cargo test");
+    }
+
+    #[test]
+    fn test_partial_commit_analysis_needs_an_object() {
+        assert!(partial_commit_analysis(&json!(["title"])).is_none());
+    }
 }
@@ -3,7 +3,8 @@ use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
     error::{Result, SemanticReleaseError},
-    types::{AppConfig, JiraTask},
+    services::shared_http_client,
+    types::{AppConfig, JiraTask, TaskIdStatus},
 };
 
 // =============================================================================
@@ -36,7 +37,9 @@ impl JiraClient {
                 .authenticate(Auth::Basic {
                     user: username.clone(),
                     password: api_token.clone(),
-                });
+                })
+                // Requests are bounded by `with_timeout`
+                .with_client(shared_http_client(config)?);
 
             Some(instance)
         } else {
@@ -84,7 +87,7 @@ impl JiraClient {
         debug!(jql = %jql, "Built JQL query for search");
 
         // Perform the search using jira_query
-        match instance.search(&jql).await {
+        match self.with_timeout(instance.search(&jql)).await? {
             Ok(issues) => {
                 info!(
                     issue_count = issues.len(),
@@ -124,6 +127,96 @@ impl JiraClient {
         }
     }
 
+    /// Fetch issues by key (`PROJ-123`). Keys that don't exist are left out.
+    #[instrument(skip(self))]
+    pub async fn get_issues(&self, keys: &[String]) -> Result<Vec<JiraTask>> {
+        info!("Fetching JIRA issues by key");
+
+        let instance = self.jira_instance.as_ref().ok_or_else(|| {
+            error!("JIRA lookup attempted but client not configured");
+            SemanticReleaseError::config_error(
+                "JIRA not configured properly - missing URL, username, or API token",
+            )
+        })?;
+
+        let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+        match self.with_timeout(instance.issues(&keys)).await? {
+            Ok(issues) => {
+                info!(issue_count = issues.len(), "JIRA issues fetched");
+                Ok(issues
+                    .into_iter()
+                    .filter_map(|issue| match self.convert_jira_issue_to_task(issue) {
+                        Ok(task) => Some(task),
+                        Err(e) => {
+                            warn!(error = %e, "Failed to convert JIRA issue to task");
+                            None
+                        }
+                    })
+                    .collect())
+            }
+            Err(jira_query::JiraQueryError::NoIssues) => {
+                info!("None of the JIRA keys exist");
+                Ok(Vec::new())
+            }
+            Err(e) => {
+                error!(error = %e, "JIRA issue lookup failed");
+                Err(SemanticReleaseError::jira_error(e))
+            }
+        }
+    }
+
+    /// Whether an issue exists, telling a missing issue (404) apart from one
+    /// the credentials can't see (401/403). Asks the REST API directly because
+    /// `jira_query` reports every failure the same way.
+    #[instrument(skip(self))]
+    pub async fn check_issue_exists(&self, key: &str) -> TaskIdStatus {
+        let (Some(url), Some(username), Some(api_token)) = (
+            &self.config.jira_url,
+            &self.config.jira_username,
+            &self.config.jira_api_token,
+        ) else {
+            return TaskIdStatus::Failed("JIRA not configured".to_string());
+        };
+        let client = match shared_http_client(&self.config) {
+            Ok(client) => client,
+            Err(e) => return TaskIdStatus::Failed(e.to_string()),
+        };
+
+        let timeout = self.config.http_timeout();
+        let response = client
+            .get(format!(
+                "{}/rest/api/2/issue/{}",
+                url.trim_end_matches('/'),
+                key
+            ))
+            .query(&[("fields", "summary")])
+            .basic_auth(username, Some(api_token))
+            .timeout(timeout)
+            .send()
+            .await;
+
+        match response {
+            Ok(response) => match response.status() {
+                status if status.is_success() => TaskIdStatus::Found,
+                reqwest::StatusCode::NOT_FOUND => TaskIdStatus::NotFound,
+                reqwest::StatusCode::UNAUTHORIZED | reqwest::StatusCode::FORBIDDEN => {
+                    TaskIdStatus::Forbidden
+                }
+                status => TaskIdStatus::Failed(format!("HTTP {}", status)),
+            },
+            Err(e) if e.is_timeout() => TaskIdStatus::NetworkError(
+                SemanticReleaseError::timeout_error("JIRA", timeout).to_string(),
+            ),
+            Err(e) if e.is_connect() => TaskIdStatus::NetworkError(
+                SemanticReleaseError::connection_error("JIRA", e).to_string(),
+            ),
+            Err(e) => {
+                warn!(key, error = %e, "JIRA issue check failed");
+                TaskIdStatus::Failed(e.to_string())
+            }
+        }
+    }
+
     #[instrument(skip(self))]
     pub async fn test_connection(&self) -> Result<String> {
         info!("Testing JIRA connection");
@@ -144,7 +237,7 @@ impl JiraClient {
 
         debug!(test_jql = %test_jql, "Testing JIRA connection with JQL query");
 
-        match instance.search(&test_jql).await {
+        match self.with_timeout(instance.search(&test_jql)).await? {
             Ok(issues) => {
                 let message = format!(
                     "✅ JIRA connection successful! Found {} issues",
@@ -167,6 +260,14 @@ impl JiraClient {
     // HELPER METHODS
     // =============================================================================
 
+    async fn with_timeout<T>(&self, request: impl std::future::Future<Output = T>) -> Result<T> {
+        let timeout = self.config.http_timeout();
+        tokio::time::timeout(timeout, request).await.map_err(|_| {
+            error!(timeout_secs = timeout.as_secs(), "JIRA request timed out");
+            SemanticReleaseError::timeout_error("JIRA", timeout)
+        })
+    }
+
     fn build_jql_query(&self, query: &str) -> String {
         let mut jql_parts = Vec::new();
 
@@ -1,7 +1,10 @@
 pub mod gemini;
+pub mod http;
 pub mod jira;
 pub mod monday;
+pub mod rate_limit;
 
 pub use gemini::*;
+pub use http::*;
 pub use jira::*;
 pub use monday::*;
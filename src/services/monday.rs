@@ -1,12 +1,40 @@
+use futures::stream::{self, Stream, StreamExt};
 use reqwest::Client;
 use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
     error::{Result, SemanticReleaseError},
-    types::{AppConfig, MondayColumnValue, MondayTask, MondayUpdate, MondayUser},
+    services::{
+        rate_limit::{RateLimitListener, TokenBucket},
+        shared_http_client,
+    },
+    types::{AppConfig, MondayColumnValue, MondayTask, MondayUpdate, MondayUser, TaskIdStatus},
 };
 
+/// Default Monday.com request budget when `MONDAY_RATE_LIMIT_PER_MINUTE` is not set
+pub const DEFAULT_MONDAY_RATE_LIMIT_PER_MINUTE: u32 = 60;
+
+/// Limiters by API key and requests per minute, so every client of the same
+/// account (and concurrent call) draws from one Monday.com request budget,
+/// while a client built after switching profile or editing the limit gets
+/// its own.
+static MONDAY_RATE_LIMITERS: LazyLock<Mutex<HashMap<(String, u32), Arc<TokenBucket>>>> =
+    LazyLock::new(Default::default);
+
+fn monday_rate_limiter(api_key: &str, requests_per_minute: u32) -> Arc<TokenBucket> {
+    let mut limiters = MONDAY_RATE_LIMITERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    limiters
+        .entry((api_key.to_string(), requests_per_minute))
+        .or_insert_with(|| Arc::new(TokenBucket::per_minute(requests_per_minute)))
+        .clone()
+}
+
 // =============================================================================
 // CORE MONDAY.COM CLIENT STRUCTURE
 // =============================================================================
@@ -15,8 +43,11 @@ pub struct MondayClient {
     client: Client,
     api_key: String,
     account_slug: Option<String>,
-    board_id: Option<String>,
+    board_ids: Vec<String>,
     url_template: Option<String>,
+    rate_limiter: Arc<TokenBucket>,
+    rate_limit_listener: Option<RateLimitListener>,
+    timeout: Duration,
 }
 
 impl MondayClient {
@@ -33,23 +64,40 @@ impl MondayClient {
             })?
             .clone();
 
+        let timeout = config.http_timeout();
+        let rate_limiter = monday_rate_limiter(
+            &api_key,
+            config
+                .monday_rate_limit_per_minute
+                .unwrap_or(DEFAULT_MONDAY_RATE_LIMIT_PER_MINUTE),
+        );
         let client = Self {
-            client: Client::new(),
+            client: shared_http_client(config)?,
             api_key,
             account_slug: config.monday_account_slug.clone(),
-            board_id: config.monday_board_id.clone(),
+            board_ids: config.monday_boards(),
             url_template: config.monday_url_template.clone(),
+            rate_limiter,
+            rate_limit_listener: None,
+            timeout,
         };
 
         info!(
             account_slug = ?client.account_slug,
-            board_id = ?client.board_id,
+            board_ids = ?client.board_ids,
             has_url_template = client.url_template.is_some(),
             "Monday.com client initialized"
         );
 
         Ok(client)
     }
+
+    /// Get notified whenever a request waits on the rate limiter, e.g. to tell
+    /// the user the client is rate-limited rather than stuck.
+    pub fn with_rate_limit_listener(mut self, listener: RateLimitListener) -> Self {
+        self.rate_limit_listener = Some(listener);
+        self
+    }
 }
 
 // =============================================================================
@@ -63,7 +111,7 @@ impl MondayClient {
 
         let graphql_query = self.build_search_query(query);
         debug!(
-            query_type = if self.board_id.is_some() {
+            query_type = if !self.board_ids.is_empty() {
                 "board_specific"
             } else {
                 "global"
@@ -87,19 +135,19 @@ impl MondayClient {
     }
 
     fn build_search_query(&self, query: &str) -> Value {
-        if let Some(board_id) = &self.board_id {
-            // Search in specific board
+        if !self.board_ids.is_empty() {
+            // Search only in the configured boards
             json!({
                 "query": r#"
-                    query ($boardId: [ID!], $limit: Int!, $queryParams: ItemsQuery) {
-                        boards(ids: $boardId) {
+                    query ($boardIds: [ID!], $limit: Int!, $queryParams: ItemsQuery) {
+                        boards(ids: $boardIds) {
                             name
                             items_page(limit: $limit, query_params: $queryParams) {
                                 items {
                                     id
                                     name
                                     state
-                                    board { id }
+                                    board { id name }
                                     updates(limit: 5) {
                                         id
                                         body
@@ -115,7 +163,7 @@ impl MondayClient {
                     }
                 "#,
                 "variables": {
-                    "boardId": [board_id],
+                    "boardIds": self.board_ids,
                     "limit": 20,
                     "queryParams": {
                         "rules": [
@@ -175,7 +223,144 @@ impl MondayClient {
 // TASK DETAILS AND RETRIEVAL
 // =============================================================================
 
-impl MondayClient {}
+impl MondayClient {
+    /// Fetch a single item by ID, whatever its board or state.
+    #[instrument(skip(self))]
+    pub async fn get_task(&self, item_id: &str) -> Result<MondayTask> {
+        let graphql_query = json!({
+            "query": r#"
+                query ($ids: [ID!]) {
+                    items(ids: $ids) {
+                        id
+                        name
+                        state
+                        board { id name }
+                        group { title }
+                    }
+                }
+            "#,
+            "variables": { "ids": [item_id] }
+        });
+
+        let response = self.execute_graphql_request(&graphql_query).await?;
+        let result: Value = response.json().await.map_err(|e| {
+            error!(error = %e, "Failed to parse Monday.com item response as JSON");
+            SemanticReleaseError::monday_error(e)
+        })?;
+
+        result["data"]["items"]
+            .as_array()
+            .and_then(|items| items.first())
+            .and_then(|item| self.parse_task_item(item))
+            .ok_or_else(|| {
+                warn!(item_id, "Monday.com item not found");
+                SemanticReleaseError::monday_error(std::io::Error::other(format!(
+                    "Monday.com item {} not found",
+                    item_id
+                )))
+            })
+    }
+
+    /// Whether an item exists. Monday.com answers the same for a deleted item
+    /// and one on a board the API key can't see, so both are `NotFound`.
+    #[instrument(skip(self))]
+    pub async fn check_task_exists(&self, item_id: &str) -> TaskIdStatus {
+        let graphql_query = json!({
+            "query": r#"
+                query ($ids: [ID!]) {
+                    items(ids: $ids) { id }
+                }
+            "#,
+            "variables": { "ids": [item_id] }
+        });
+
+        let result: Value = match self.execute_graphql_request(&graphql_query).await {
+            Ok(response) => match response.json().await {
+                Ok(result) => result,
+                Err(e) => return TaskIdStatus::Failed(e.to_string()),
+            },
+            Err(SemanticReleaseError::MondayAuthError { .. }) => return TaskIdStatus::Forbidden,
+            Err(
+                e @ (SemanticReleaseError::ConnectionError { .. }
+                | SemanticReleaseError::TimeoutError { .. }),
+            ) => return TaskIdStatus::NetworkError(e.to_string()),
+            Err(e) => return TaskIdStatus::Failed(e.to_string()),
+        };
+
+        if let Some(message) = result["errors"][0]["message"].as_str() {
+            let lowercase = message.to_lowercase();
+            if lowercase.contains("permission") || lowercase.contains("unauthorized") {
+                return TaskIdStatus::Forbidden;
+            }
+            return TaskIdStatus::Failed(message.to_string());
+        }
+
+        match result["data"]["items"].as_array() {
+            Some(items) if !items.is_empty() => TaskIdStatus::Found,
+            _ => TaskIdStatus::NotFound,
+        }
+    }
+
+    /// Fetch several items, at most `concurrency` requests in flight (all still
+    /// drawing from the shared rate limiter). Results arrive in completion order,
+    /// each paired with its ID so a failure only affects that item.
+    pub fn get_task_details(
+        &self,
+        item_ids: Vec<String>,
+        concurrency: usize,
+    ) -> impl Stream<Item = (String, Result<MondayTask>)> + '_ {
+        stream::iter(item_ids)
+            .map(move |item_id| async move {
+                let task = self.get_task(&item_id).await;
+                (item_id, task)
+            })
+            .buffer_unordered(concurrency.max(1))
+    }
+}
+
+// =============================================================================
+// TASK UPDATES
+// =============================================================================
+
+impl MondayClient {
+    /// Post an update (comment) on an item. Returns the ID of the new update.
+    #[instrument(skip(self, body))]
+    pub async fn create_update(&self, item_id: &str, body: &str) -> Result<String> {
+        let graphql_query = json!({
+            "query": r#"
+                mutation ($item_id: ID!, $body: String!) {
+                    create_update(item_id: $item_id, body: $body) { id }
+                }
+            "#,
+            "variables": { "item_id": item_id, "body": body }
+        });
+
+        let response = self.execute_graphql_request(&graphql_query).await?;
+        let result: Value = response.json().await.map_err(|e| {
+            error!(error = %e, "Failed to parse Monday.com update response as JSON");
+            SemanticReleaseError::monday_error(e)
+        })?;
+
+        match result["data"]["create_update"]["id"].as_str() {
+            Some(update_id) => {
+                info!(item_id, update_id, "Posted Monday.com update");
+                Ok(update_id.to_string())
+            }
+            None => {
+                let message = result["errors"][0]["message"]
+                    .as_str()
+                    .unwrap_or("no update ID in response");
+                warn!(item_id, error = message, "Monday.com rejected the update");
+                Err(SemanticReleaseError::monday_error(std::io::Error::other(
+                    format!(
+                        "Could not post update on Monday.com item {}: {}",
+                        item_id, message
+                    ),
+                )))
+            }
+        }
+    }
+}
 
 // =============================================================================
 // GRAPHQL REQUEST EXECUTION
@@ -186,22 +371,52 @@ impl MondayClient {
     async fn execute_graphql_request(&self, query: &Value) -> Result<reqwest::Response> {
         debug!("Executing Monday.com GraphQL request");
 
+        let waited = self
+            .rate_limiter
+            .acquire(self.rate_limit_listener.as_ref())
+            .await;
+        if !waited.is_zero() {
+            info!(
+                waited_ms = waited.as_millis() as u64,
+                "Monday.com request delayed by rate limiter"
+            );
+        }
+
         let response = self
             .client
             .post("https://api.monday.com/v2")
             .header("Authorization", &self.api_key)
             .header("Content-Type", "application/json")
             .header("API-Version", "2024-10")
+            .timeout(self.timeout)
             .json(query)
             .send()
             .await
             .map_err(|e| {
+                if e.is_timeout() {
+                    error!(
+                        timeout_secs = self.timeout.as_secs(),
+                        "Monday.com GraphQL request timed out"
+                    );
+                    return SemanticReleaseError::timeout_error("Monday.com", self.timeout);
+                }
+                if e.is_connect() {
+                    error!(error = %e, "Could not connect to Monday.com");
+                    return SemanticReleaseError::connection_error("Monday.com", e);
+                }
                 error!(error = %e, "Monday.com GraphQL request failed");
                 SemanticReleaseError::monday_error(e)
             })?;
 
-        if !response.status().is_success() {
-            let status = response.status();
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            warn!(status = %status, "Monday.com rejected the API key");
+            return Err(SemanticReleaseError::MondayAuthError {
+                status: status.as_u16(),
+            });
+        }
+
+        if !status.is_success() {
             error!(status = %status, "Monday.com API returned error status");
             return Err(SemanticReleaseError::monday_error(std::io::Error::other(
                 format!("Monday.com API error: HTTP {}", status),
@@ -218,10 +433,12 @@ impl MondayClient {
 // =============================================================================
 
 impl MondayClient {
+    /// Search results in every state (`active`, `archived`, `deleted`); which
+    /// ones to show is up to the caller.
     fn parse_search_results(&self, result: Value) -> Result<Vec<MondayTask>> {
         let mut tasks = Vec::new();
 
-        if let Some(_board_id) = &self.board_id {
+        if !self.board_ids.is_empty() {
             // Parse board-specific results
             tasks.extend(self.parse_board_specific_results(&result));
             debug!(
@@ -246,12 +463,8 @@ impl MondayClient {
                     if let Some(items) = items_page["items"].as_array() {
                         for item in items {
                             if let Some(task) = self.parse_task_item(item) {
-                                if task.state == "active" {
-                                    debug!(task_id = %task.id, "Found active Monday.com task");
-                                    tasks.push(task);
-                                } else {
-                                    debug!(task_id = %task.id, state = %task.state, "Skipping non-active Monday.com task");
-                                }
+                                debug!(task_id = %task.id, state = %task.state, "Found Monday.com task");
+                                tasks.push(task);
                             }
                         }
                     }
@@ -269,12 +482,8 @@ impl MondayClient {
             if let Some(items) = items_page["items"].as_array() {
                 for item in items {
                     if let Some(task) = self.parse_task_item(item) {
-                        if task.state == "active" {
-                            debug!(task_id = %task.id, "Found active Monday.com task");
-                            tasks.push(task);
-                        } else {
-                            debug!(task_id = %task.id, state = %task.state, "Skipping non-active Monday.com task");
-                        }
+                        debug!(task_id = %task.id, state = %task.state, "Found Monday.com task");
+                        tasks.push(task);
                     }
                 }
             }
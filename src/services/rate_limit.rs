@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::{debug, instrument};
+
+// =============================================================================
+// TOKEN BUCKET RATE LIMITER
+// =============================================================================
+
+/// Called with the wait time whenever a request has to wait for a token.
+pub type RateLimitListener = Arc<dyn Fn(Duration) + Send + Sync>;
+
+/// Token bucket shared by every request of a client. The bucket holds up to
+/// `requests_per_minute` tokens and refills continuously.
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_second: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub fn per_minute(requests_per_minute: u32) -> Self {
+        let capacity = f64::from(requests_per_minute.max(1));
+        Self {
+            capacity,
+            refill_per_second: capacity / 60.0,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until a token is available and take it. Returns how long it waited.
+    #[instrument(skip(self, listener))]
+    pub async fn acquire(&self, listener: Option<&RateLimitListener>) -> Duration {
+        let started = Instant::now();
+
+        // Holding the lock while sleeping serialises waiting callers, so tokens
+        // are handed out in order instead of in bursts.
+        let mut state = self.state.lock().await;
+        loop {
+            let now = Instant::now();
+            let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+            state.tokens = (state.tokens + elapsed * self.refill_per_second).min(self.capacity);
+            state.last_refill = now;
+
+            if state.tokens >= 1.0 {
+                state.tokens -= 1.0;
+                return started.elapsed();
+            }
+
+            let wait = Duration::from_secs_f64((1.0 - state.tokens) / self.refill_per_second);
+            debug!(
+                wait_ms = wait.as_millis() as u64,
+                "Rate limit reached, waiting"
+            );
+            if let Some(listener) = listener {
+                listener(wait);
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use std::sync::{Arc, Mutex};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::i18n::Language;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MondayTask {
@@ -14,6 +17,13 @@ pub struct MondayTask {
     pub column_values: Vec<MondayColumnValue>,
 }
 
+impl MondayTask {
+    /// Neither archived nor deleted
+    pub fn is_active(&self) -> bool {
+        self.state == "active"
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MondayColumnValue {
     pub id: String,
@@ -94,9 +104,17 @@ impl TaskLike for JiraTask {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GitCommit {
     pub hash: String,
+    /// First line of the message, as written
+    pub subject: String,
+    pub author: String,
+    /// Commit date, `YYYY-MM-DD`
+    pub date: String,
+    /// Commit time in seconds since the epoch, used to order commits
+    #[serde(skip)]
+    pub timestamp: i64,
     pub description: String,
     pub commit_type: Option<String>,
     pub scope: Option<String>,
@@ -104,6 +122,9 @@ pub struct GitCommit {
     pub breaking_changes: Vec<String>,
     pub monday_tasks: Vec<String>,
     pub jira_tasks: Vec<String>,
+    /// Values of the recognized trailers, keyed by `CommitTrailer::field`
+    #[serde(default)]
+    pub trailers: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,6 +175,21 @@ impl CommitType {
         }
     }
 
+    /// Default gitmoji for the type, used when `USE_GITMOJI` is enabled.
+    pub fn gitmoji(&self) -> &'static str {
+        match self {
+            CommitType::Feat => "✨",
+            CommitType::Fix => "🐛",
+            CommitType::Docs => "📝",
+            CommitType::Style => "🎨",
+            CommitType::Refactor => "♻️",
+            CommitType::Perf => "⚡️",
+            CommitType::Test => "✅",
+            CommitType::Chore => "🔧",
+            CommitType::Revert => "⏪️",
+        }
+    }
+
     pub fn all() -> Vec<CommitType> {
         vec![
             CommitType::Feat,
@@ -167,19 +203,375 @@ impl CommitType {
             CommitType::Revert,
         ]
     }
+
+    pub fn parse(value: &str) -> Option<CommitType> {
+        CommitType::all()
+            .into_iter()
+            .find(|commit_type| commit_type.as_str() == value.trim().to_lowercase())
+    }
 }
 
+/// Structured result of the Gemini commit analysis. Field names follow the JSON
+/// the prompt asks for, with snake_case aliases accepted as well.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct CommitAnalysis {
+    pub title: String,
+    pub description: String,
+    #[serde(rename = "commitType", alias = "suggested_type")]
+    pub suggested_type: Option<String>,
+    #[serde(rename = "scope", alias = "suggested_scope")]
+    pub suggested_scope: Option<String>,
+    #[serde(rename = "securityAnalysis", alias = "security")]
+    pub security: String,
+    #[serde(rename = "breakingChanges", alias = "breaking_change")]
+    pub breaking_change: String,
+    #[serde(rename = "testAnalysis", alias = "test_details")]
+    pub test_details: String,
+}
+
+impl CommitAnalysis {
+    /// Suggested commit type, if Gemini returned a valid one.
+    pub fn commit_type(&self) -> Option<CommitType> {
+        self.suggested_type.as_deref().and_then(CommitType::parse)
+    }
+
+    /// Suggested scope, ignoring the generic "general" placeholder.
+    pub fn scope(&self) -> Option<&str> {
+        self.suggested_scope
+            .as_deref()
+            .map(str::trim)
+            .filter(|scope| !scope.is_empty() && *scope != "general")
+    }
+}
+
+/// Commit types that are noise for stakeholders, collapsed in the public release notes
+pub const DEFAULT_INTERNAL_TYPES: [&str; 4] = ["chore", "ci", "build", "style"];
+
+/// Authors that are bots or CI jobs, matched case-insensitively anywhere in the name
+pub const DEFAULT_BOT_AUTHORS: [&str; 6] = [
+    "[bot]",
+    "dependabot",
+    "renovate",
+    "github-actions",
+    "gitlab-ci",
+    "semantic-release",
+];
+
+/// Commit types analyzed without security and breaking changes by default.
+pub const DEFAULT_TRIVIAL_COMMIT_TYPES: [&str; 3] = ["docs", "style", "chore"];
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppConfig {
     pub monday_api_key: Option<String>,
     pub monday_account_slug: Option<String>,
     pub monday_board_id: Option<String>,
+    /// Boards the task search is limited to; `monday_board_id` is used when empty
+    #[serde(default)]
+    pub monday_board_ids: Vec<String>,
     pub monday_url_template: Option<String>,
+    pub monday_rate_limit_per_minute: Option<u32>,
+    /// Monday.com tasks fetched at once when resolving release notes details
+    pub monday_task_concurrency: Option<usize>,
+    /// Post an update with the commit hash and title on each linked Monday.com task
+    #[serde(default)]
+    pub monday_post_commit_update: bool,
     pub jira_url: Option<String>,
     pub jira_username: Option<String>,
     pub jira_api_token: Option<String>,
     pub jira_project_key: Option<String>,
     pub gemini_token: Option<String>,
+    /// Release notes template path. Relative paths are resolved from the git repository root.
+    pub release_notes_template_path: Option<String>,
+    /// Directory where release notes artifacts are written (default `release-notes`)
+    pub release_notes_dir: Option<String>,
+    /// Abort `release-notes` when the template file is missing instead of using the embedded one
+    #[serde(default)]
+    pub release_notes_strict: bool,
+    /// Leave commit bodies and per-commit task references out of the document sent to Gemini
+    #[serde(default)]
+    pub release_notes_compact: bool,
+    /// Append a section listing the commits that don't follow Conventional Commits
+    #[serde(default)]
+    pub release_notes_convention_warnings: bool,
+    /// Also write a customer-facing variant with the internal commits collapsed
+    /// (on unless set to false; see `release_notes_public()`)
+    pub release_notes_public: Option<bool>,
+    /// Commit types the customer-facing variant collapses; empty means the defaults
+    #[serde(default)]
+    pub release_notes_internal_types: Vec<String>,
+    /// Person responsible for the release; defaults to the most frequent human author
+    pub release_responsible: Option<String>,
+    /// Author name fragments of bots never taken as responsible; empty means the defaults
+    #[serde(default)]
+    pub release_bot_authors: Vec<String>,
+    /// Commit trailers to recognize; empty means the commit form's defaults
+    #[serde(default)]
+    pub commit_trailers: Vec<CommitTrailer>,
+    /// Monorepo packages that get independent versions and release notes
+    #[serde(default)]
+    pub release_packages: Vec<ReleasePackage>,
+    /// Proxy for HTTP requests (and HTTPS ones when `https_proxy` is unset)
+    pub http_proxy: Option<String>,
+    /// Proxy for HTTPS requests
+    pub https_proxy: Option<String>,
+    /// Comma-separated hosts/domains reached without the proxy
+    pub no_proxy: Option<String>,
+    /// Timeout for Monday.com and JIRA requests
+    pub http_timeout_seconds: Option<u64>,
+    /// Timeout for Gemini commit analysis requests
+    pub ai_timeout_seconds: Option<u64>,
+    /// Timeout for Gemini release notes processing (large documents)
+    pub release_notes_timeout_seconds: Option<u64>,
+    /// Configuration profile these settings were loaded from (`None` is the default `.env`)
+    #[serde(skip)]
+    pub profile: Option<String>,
+    /// Started with `--offline`: task search, AI analysis and everything else
+    /// that needs the network is disabled
+    #[serde(skip)]
+    pub offline: bool,
+    /// Started with `--no-verify`: commits skip the `pre-commit` and
+    /// `commit-msg` hooks
+    #[serde(skip)]
+    pub no_verify: bool,
+    /// How commits are grouped into sections in the release notes
+    #[serde(default)]
+    pub release_notes_grouping: GroupingStrategy,
+    /// How multi-line commit bodies are written in the release notes
+    #[serde(default)]
+    pub release_notes_body_format: BodyFormat,
+    /// How the next version is computed
+    #[serde(default)]
+    pub version_strategy: VersionStrategy,
+    /// Pre-release channel of the next version (`beta`, `next`...)
+    pub release_channel: Option<String>,
+    /// Provider of the `origin` remote (`github`, `gitlab`, `bitbucket`) when
+    /// its host name doesn't say, e.g. a self-hosted GitLab
+    pub git_host: Option<String>,
+    /// Prefix commit titles with the gitmoji of their type
+    #[serde(default)]
+    pub use_gitmoji: bool,
+    /// Wrap long lines in the commit preview, keeping their indentation
+    #[serde(default)]
+    pub commit_preview_wrap: bool,
+    /// Length over which a scope made of task IDs triggers a warning
+    pub task_scope_max_length: Option<usize>,
+    /// Shorten a task scope over the limit to its first ID plus "…"; the full
+    /// list stays in the `MONDAY TASKS:` / `JIRA TASKS:` trailer
+    #[serde(default)]
+    pub compact_task_scope: bool,
+    /// Check that the task IDs typed by hand in the scope exist
+    #[serde(default)]
+    pub validate_task_ids: bool,
+    /// Commit types whose AI analysis skips security and breaking changes
+    /// (defaults to `DEFAULT_TRIVIAL_COMMIT_TYPES`; empty turns it off)
+    pub trivial_commit_types: Option<Vec<String>>,
+    /// What to do when the staged changes look like they contain a secret
+    #[serde(default)]
+    pub secret_scan: SecretScanMode,
+    /// Extra secret patterns (regular expressions) on top of the built-in ones
+    #[serde(default)]
+    pub secret_patterns: Vec<String>,
+    /// Regular expressions for matches or paths that are not secrets
+    #[serde(default)]
+    pub secret_allowlist: Vec<String>,
+    /// Per-type gitmoji overrides (`feat` -> `🎉`)
+    #[serde(default)]
+    pub gitmoji_overrides: BTreeMap<String, String>,
+    /// Description templates by commit type (`fix` -> `Problema: {{...}}`)
+    #[serde(default)]
+    pub commit_templates: BTreeMap<String, String>,
+    /// Language of user-facing messages
+    #[serde(default)]
+    pub language: Language,
+    /// Language Gemini writes commit analyses and release notes in (defaults to `language`)
+    pub ai_language: Option<Language>,
+    /// Offer the AI analysis as ghost text in each field instead of writing it
+    #[serde(default)]
+    pub ai_inline_suggestions: bool,
+    /// Key remapping, e.g. `commit.preview_commit=p,help=h` (see `ui::keybindings`)
+    pub keybindings: Option<String>,
+}
+
+/// Section layout of the generated release notes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+pub enum GroupingStrategy {
+    /// One section per conventional commit type
+    #[default]
+    ByType,
+    /// One section per scope, each line prefixed with its type
+    ByScope,
+    /// Sections per type, with a sub-section per scope
+    ByTypeThenScope,
+}
+
+impl GroupingStrategy {
+    /// Parse the `RELEASE_NOTES_GROUPING` setting: `type`, `scope` or `type-scope`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().replace('_', "-").as_str() {
+            "type" => Some(GroupingStrategy::ByType),
+            "scope" => Some(GroupingStrategy::ByScope),
+            "type-scope" | "type-then-scope" => Some(GroupingStrategy::ByTypeThenScope),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupingStrategy::ByType => "type",
+            GroupingStrategy::ByScope => "scope",
+            GroupingStrategy::ByTypeThenScope => "type-scope",
+        }
+    }
+}
+
+/// Layout of multi-line commit bodies in the release notes' commit sections.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum BodyFormat {
+    /// The body's lines joined with ` | ` on a single list line
+    #[default]
+    Collapsed,
+    /// The body's lines kept as written, indented under the commit so that
+    /// bullets and code blocks still render
+    Preserved,
+}
+
+impl BodyFormat {
+    /// Parse the `RELEASE_NOTES_BODY_FORMAT` setting: `collapsed` or `preserved`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "collapsed" | "collapse" => Some(BodyFormat::Collapsed),
+            "preserved" | "preserve" => Some(BodyFormat::Preserved),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BodyFormat::Collapsed => "collapsed",
+            BodyFormat::Preserved => "preserved",
+        }
+    }
+}
+
+/// Reaction to possible secrets in the staged changes before committing.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum SecretScanMode {
+    /// List the findings and commit only after an explicit confirmation
+    #[default]
+    Confirm,
+    /// Refuse to commit until the findings are removed or allowlisted
+    Block,
+    Off,
+}
+
+impl SecretScanMode {
+    /// Parse the `SECRET_SCAN` setting: `confirm`, `block` or `off`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "confirm" | "warn" => Some(SecretScanMode::Confirm),
+            "block" => Some(SecretScanMode::Block),
+            "off" | "false" | "0" | "no" => Some(SecretScanMode::Off),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecretScanMode::Confirm => "confirm",
+            SecretScanMode::Block => "block",
+            SecretScanMode::Off => "off",
+        }
+    }
+}
+
+/// A package inside a monorepo, released with its own tag prefix (e.g. `frontend-v`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReleasePackage {
+    pub path: String,
+    pub tag_prefix: String,
+}
+
+impl ReleasePackage {
+    /// Parse a `path[=tag_prefix]` entry. Without an explicit prefix, `<dir>-v` is used.
+    pub fn parse(entry: &str) -> Option<Self> {
+        let (path, tag_prefix) = match entry.split_once('=') {
+            Some((path, prefix)) => (path.trim(), prefix.trim().to_string()),
+            None => {
+                let path = entry.trim();
+                let dir = path
+                    .trim_end_matches('/')
+                    .rsplit('/')
+                    .next()
+                    .unwrap_or(path);
+                (path, format!("{}-v", dir))
+            }
+        };
+
+        if path.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            path: path.trim_end_matches('/').to_string(),
+            tag_prefix,
+        })
+    }
+
+    /// File-name friendly version of the package path.
+    pub fn slug(&self) -> String {
+        self.path.replace('/', "-")
+    }
+}
+
+/// A `Key: value` trailer read from commit bodies into `GitCommit::trailers[field]`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommitTrailer {
+    /// Trailer key as written in the commit, e.g. `Migraciones Lentas`
+    pub key: String,
+    /// Name the value is stored under, e.g. `migraciones_lentas`
+    pub field: String,
+}
+
+/// Trailers written by the commit form, recognized unless COMMIT_TRAILERS says otherwise.
+const DEFAULT_COMMIT_TRAILERS: [(&str, &str); 4] = [
+    ("Test Details", "test_details"),
+    ("Security", "security"),
+    ("Migraciones Lentas", "migraciones_lentas"),
+    ("Partes a Ejecutar", "partes_a_ejecutar"),
+];
+
+impl CommitTrailer {
+    /// Parse a `Key[=field]` entry. Without a field, the key in snake_case is used.
+    pub fn parse(entry: &str) -> Option<Self> {
+        let (key, field) = match entry.split_once('=') {
+            Some((key, field)) => (key.trim(), field.trim().to_string()),
+            None => {
+                let key = entry.trim();
+                (key, key.to_lowercase().replace([' ', '-'], "_"))
+            }
+        };
+
+        if key.is_empty() || field.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            key: key.to_string(),
+            field,
+        })
+    }
+
+    pub fn defaults() -> Vec<Self> {
+        DEFAULT_COMMIT_TRAILERS
+            .iter()
+            .map(|(key, field)| Self {
+                key: key.to_string(),
+                field: field.to_string(),
+            })
+            .collect()
+    }
 }
 
 impl AppConfig {
@@ -191,6 +583,149 @@ impl AppConfig {
         self.jira_url.is_some() && self.jira_username.is_some() && self.jira_api_token.is_some()
     }
 
+    /// Gitmoji for a commit type, honouring the configured overrides.
+    pub fn gitmoji(&self, commit_type: &CommitType) -> &str {
+        self.gitmoji_overrides
+            .get(commit_type.as_str())
+            .map(String::as_str)
+            .unwrap_or_else(|| commit_type.gitmoji())
+    }
+
+    /// Description template for a commit type (`COMMIT_TEMPLATE_<TYPE>`).
+    pub fn commit_template(&self, commit_type: &CommitType) -> Option<&str> {
+        self.commit_templates
+            .get(commit_type.as_str())
+            .map(String::as_str)
+    }
+
+    /// Whether `text` is one of the templates, still untouched.
+    pub fn is_commit_template(&self, text: &str) -> bool {
+        let text = text.trim();
+        !text.is_empty()
+            && self
+                .commit_templates
+                .values()
+                .any(|template| template.trim() == text)
+    }
+
+    pub fn release_notes_dir(&self) -> std::path::PathBuf {
+        std::path::PathBuf::from(
+            self.release_notes_dir
+                .as_deref()
+                .filter(|dir| !dir.trim().is_empty())
+                .unwrap_or("release-notes"),
+        )
+    }
+
+    pub fn ai_language(&self) -> Language {
+        self.ai_language.unwrap_or(self.language)
+    }
+
+    /// Commits of this type are too small to be worth a security and
+    /// breaking changes analysis (`TRIVIAL_COMMIT_TYPES`).
+    pub fn is_trivial_commit_type(&self, commit_type: &CommitType) -> bool {
+        match &self.trivial_commit_types {
+            Some(types) => types.iter().any(|t| t == commit_type.as_str()),
+            None => DEFAULT_TRIVIAL_COMMIT_TYPES.contains(&commit_type.as_str()),
+        }
+    }
+
+    /// User-facing message for `key` in the configured language.
+    pub fn tr(&self, key: &'static str) -> &'static str {
+        crate::i18n::tr(self.language, key)
+    }
+
+    /// Whether the customer-facing release notes are written too (default on)
+    pub fn release_notes_public(&self) -> bool {
+        self.release_notes_public.unwrap_or(true)
+    }
+
+    /// Commit types collapsed in the customer-facing release notes
+    pub fn release_notes_internal_types(&self) -> Vec<String> {
+        if self.release_notes_internal_types.is_empty() {
+            DEFAULT_INTERNAL_TYPES
+                .iter()
+                .map(|commit_type| commit_type.to_string())
+                .collect()
+        } else {
+            self.release_notes_internal_types.clone()
+        }
+    }
+
+    /// Author name fragments that mark a bot (the built-in list by default)
+    pub fn release_bot_authors(&self) -> Vec<String> {
+        if self.release_bot_authors.is_empty() {
+            DEFAULT_BOT_AUTHORS
+                .iter()
+                .map(|author| author.to_string())
+                .collect()
+        } else {
+            self.release_bot_authors.clone()
+        }
+    }
+
+    /// Trailers to extract from commit bodies (the commit form's ones by default)
+    pub fn commit_trailers(&self) -> Vec<CommitTrailer> {
+        if self.commit_trailers.is_empty() {
+            CommitTrailer::defaults()
+        } else {
+            self.commit_trailers.clone()
+        }
+    }
+
+    /// Boards to search in; empty means every board the token can see
+    pub fn monday_boards(&self) -> Vec<String> {
+        if self.monday_board_ids.is_empty() {
+            self.monday_board_id.iter().cloned().collect()
+        } else {
+            self.monday_board_ids.clone()
+        }
+    }
+
+    /// Provider forced with `GIT_HOST`, if valid
+    pub fn git_host(&self) -> Option<crate::git::GitHost> {
+        self.git_host
+            .as_deref()
+            .and_then(crate::git::GitHost::parse)
+    }
+
+    pub fn version_options(&self) -> VersionOptions {
+        VersionOptions {
+            strategy: self.version_strategy,
+            channel: self.release_channel.clone(),
+            build_metadata: None,
+            package: None,
+        }
+    }
+
+    /// Version options for one of the `RELEASE_PACKAGES`.
+    pub fn package_version_options(&self, package: &ReleasePackage) -> VersionOptions {
+        VersionOptions {
+            package: Some(package.clone()),
+            ..self.version_options()
+        }
+    }
+
+    pub fn task_scope_max_length(&self) -> usize {
+        self.task_scope_max_length.unwrap_or(60).max(1)
+    }
+
+    pub fn monday_task_concurrency(&self) -> usize {
+        self.monday_task_concurrency.unwrap_or(4).max(1)
+    }
+
+    pub fn http_timeout(&self) -> Duration {
+        Duration::from_secs(self.http_timeout_seconds.unwrap_or(30))
+    }
+
+    pub fn ai_timeout(&self) -> Duration {
+        Duration::from_secs(self.ai_timeout_seconds.unwrap_or(120))
+    }
+
+    pub fn release_notes_timeout(&self) -> Duration {
+        Duration::from_secs(self.release_notes_timeout_seconds.unwrap_or(300))
+    }
+
     pub fn get_task_system(&self) -> TaskSystem {
         if self.is_monday_configured() {
             TaskSystem::Monday
@@ -202,6 +737,23 @@ impl AppConfig {
     }
 }
 
+/// Problem found while validating the configuration. Integrations that are not
+/// configured at all are optional and never produce a warning.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigWarning {
+    pub severity: ConfigWarningSeverity,
+    pub setting: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigWarningSeverity {
+    /// Only part of an integration's settings are present (probably a mistake)
+    Incomplete,
+    /// A value is present but looks wrong
+    Suspicious,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TaskSystem {
     Monday,
@@ -209,6 +761,19 @@ pub enum TaskSystem {
     None,
 }
 
+/// Outcome of checking that a task ID typed in the scope exists.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskIdStatus {
+    Found,
+    NotFound,
+    /// The credentials were rejected or can't see the task
+    Forbidden,
+    /// The task system couldn't be reached or didn't answer in time
+    NetworkError(String),
+    /// Any other failure, e.g. an unexpected response
+    Failed(String),
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct CommitForm {
     pub commit_type: Option<CommitType>,
@@ -234,6 +799,9 @@ pub enum AppScreen {
     ReleaseNotes,
     SemanticRelease,
     TaskSearch,
+    History,
+    /// Staged changes, opened from the commit screen
+    StagedDiff,
 }
 
 #[derive(Debug, Clone)]
@@ -242,29 +810,50 @@ pub enum AppState {
     Loading,
     Error(String),
     ConfirmingStageAll,
+    ConfirmingMergeCommit,
+    /// The staged changes look like they contain secrets (`SECRET_SCAN=confirm`)
+    ConfirmingSecrets,
+    /// Monday.com rejected the API key; offers a shortcut to the Config screen
+    MondayAuthFailed,
 }
 
-// Keep SemanticReleaseState for UI display compatibility
+/// Results panel of the semantic-release screen, updated from the
+/// `SemanticRelease*` background events.
 #[derive(Debug, Clone)]
 pub struct SemanticReleaseState {
-    pub status: Arc<Mutex<String>>,
-    pub finished: Arc<Mutex<bool>>,
-    pub success: Arc<Mutex<bool>>,
-    pub result: Arc<Mutex<String>>,
+    pub status: String,
+    pub finished: bool,
+    pub success: bool,
+    pub result: String,
+    /// Structured version analysis, set by the "Version Info" operation
+    pub version_info: Option<VersionInfo>,
+}
+
+impl SemanticReleaseState {
+    /// State of an operation that has just started, showing `status`
+    pub fn running(status: impl Into<String>) -> Self {
+        Self {
+            status: status.into(),
+            success: true,
+            ..Self::default()
+        }
+    }
 }
 
 impl Default for SemanticReleaseState {
     fn default() -> Self {
         Self {
-            status: Arc::new(Mutex::new("Ready".to_string())),
-            finished: Arc::new(Mutex::new(false)),
-            success: Arc::new(Mutex::new(false)),
-            result: Arc::new(Mutex::new(String::new())),
+            status: "Ready".to_string(),
+            finished: false,
+            success: false,
+            result: String::new(),
+            version_info: None,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+/// Next version analysis. Serialized as is by `version-info --json`.
+#[derive(Debug, Clone, Serialize)]
 pub struct VersionInfo {
     pub next_version: String,
     pub current_version: Option<String>,
@@ -272,9 +861,15 @@ pub struct VersionInfo {
     pub commit_count: usize,
     pub has_unreleased_changes: bool,
     pub dry_run_output: String,
+    /// Pre-release channel the next version was computed for
+    pub channel: Option<String>,
+    /// Tag the next version is released as: `<tag_prefix><version>` for a
+    /// package, `v<version>` otherwise; `None` when there is no release
+    pub next_tag: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum VersionType {
     Major,
     Minor,
@@ -292,3 +887,44 @@ impl std::fmt::Display for VersionType {
         }
     }
 }
+
+/// How `get_version_info` computes the next version.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VersionOptions {
+    pub strategy: VersionStrategy,
+    /// Pre-release channel (`beta` gives `1.2.0-beta.1`); `None` is a stable release
+    pub channel: Option<String>,
+    /// Build metadata appended as `+<meta>`; `sha` stands for the short HEAD hash
+    pub build_metadata: Option<String>,
+    /// Monorepo package versioned on its own, from its `<tag_prefix>*` tags
+    /// and the commits touching its path
+    pub package: Option<ReleasePackage>,
+}
+
+/// Where the next version comes from.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub enum VersionStrategy {
+    /// Bump computed from the commit types since the last tag
+    #[default]
+    Native,
+    /// `npx semantic-release --dry-run`, needs Node.js
+    SemanticRelease,
+}
+
+impl VersionStrategy {
+    /// Parse the `VERSION_STRATEGY` setting: `native` or `semantic-release`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().replace('_', "-").as_str() {
+            "native" => Some(VersionStrategy::Native),
+            "semantic-release" | "npm" => Some(VersionStrategy::SemanticRelease),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionStrategy::Native => "native",
+            VersionStrategy::SemanticRelease => "semantic-release",
+        }
+    }
+}
@@ -1,14 +1,76 @@
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-use crate::types::AppState;
+use crate::git::BranchInfo;
+use crate::i18n::{tr, Language};
+use crate::types::{AppScreen, AppState, CommitAnalysis};
+use crate::ui::keybindings::Keymap;
+use crate::ui::{InputMode, RenderStats, StageSelector};
 
-pub fn draw_title_bar(f: &mut Frame, area: Rect) {
-    let title = Paragraph::new("🚀 TEIMAS Release Committer (TERCO)")
+pub fn draw_title_bar(
+    f: &mut Frame,
+    area: Rect,
+    profile: Option<&str>,
+    branch: Option<&BranchInfo>,
+    offline: bool,
+) {
+    let mut spans = vec![Span::raw(format!(
+        "🚀 TEIMAS Release Committer (TERCO)  ·  👤 Perfil: {}",
+        profile.unwrap_or(crate::config::DEFAULT_PROFILE)
+    ))];
+    if offline {
+        spans.push(Span::raw("  ·  "));
+        spans.push(Span::styled(
+            "📴 Offline",
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    if let Some(branch) = branch {
+        spans.push(Span::raw("  ·  "));
+        if branch.detached {
+            spans.push(Span::styled(
+                format!("➰ HEAD detached @ {}", branch.name),
+                Style::default().fg(Color::Yellow),
+            ));
+        } else {
+            spans.push(Span::styled(
+                format!("🌿 {}", branch.name),
+                Style::default().fg(Color::Green),
+            ));
+            match &branch.upstream {
+                Some(upstream) => {
+                    spans.push(Span::raw(format!(" → {}", upstream)));
+                    if branch.ahead > 0 {
+                        spans.push(Span::styled(
+                            format!(" ↑{}", branch.ahead),
+                            Style::default().fg(Color::Yellow),
+                        ));
+                    }
+                    if branch.behind > 0 {
+                        spans.push(Span::styled(
+                            format!(" ↓{}", branch.behind),
+                            Style::default().fg(Color::Red),
+                        ));
+                    }
+                    if branch.ahead == 0 && branch.behind == 0 {
+                        spans.push(Span::styled(" ✓", Style::default().fg(Color::Green)));
+                    }
+                }
+                None => spans.push(Span::styled(
+                    " (no upstream)",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            }
+        }
+    }
+
+    let title = Paragraph::new(Line::from(spans))
         .style(
             Style::default()
                 .fg(Color::Cyan)
@@ -19,29 +81,47 @@ pub fn draw_title_bar(f: &mut Frame, area: Rect) {
     f.render_widget(title, area);
 }
 
-pub fn draw_status_bar(f: &mut Frame, area: Rect, app_state: &AppState, message: Option<&str>) {
+pub fn draw_status_bar(
+    f: &mut Frame,
+    area: Rect,
+    app_state: &AppState,
+    message: Option<&str>,
+    selected_tasks: usize,
+    language: Language,
+) {
     let (status_text, title) = match app_state {
-        AppState::Normal => (message.unwrap_or("Listo"), "Estado"),
+        AppState::Normal => (
+            message.unwrap_or(tr(language, "status.ready")),
+            tr(language, "status.title"),
+        ),
         AppState::Loading => {
-            let loading_message = message.unwrap_or("Procesando...");
+            let loading_message = message.unwrap_or(tr(language, "status.processing"));
             if loading_message.contains("Gemini") {
                 (loading_message, "🧠 Gemini AI")
             } else {
-                (loading_message, "⏳ Cargando")
+                (loading_message, tr(language, "status.loading_title"))
             }
         }
-        AppState::Error(err) => (
-            err.as_str(),
-            "⚠️ ERROR - Presiona cualquier tecla para continuar",
-        ),
+        AppState::Error(err) => (err.as_str(), tr(language, "status.error_title")),
         AppState::ConfirmingStageAll => (
-            message.unwrap_or("Press 'y' to stage all changes (git add -A), 'n' to cancel"),
-            "❓ Confirmation Required",
+            message.unwrap_or(tr(language, "status.confirm_stage_all")),
+            tr(language, "status.confirm_title"),
+        ),
+        AppState::ConfirmingMergeCommit => (
+            message.unwrap_or(tr(language, "status.confirm_merge")),
+            tr(language, "status.merge_title"),
+        ),
+        AppState::ConfirmingSecrets => (
+            message.unwrap_or(tr(language, "secrets.confirm")),
+            tr(language, "secrets.title"),
         ),
+        AppState::MondayAuthFailed => (tr(language, "monday.auth_failed"), "🔑 Monday.com"),
     };
 
     let status_style = match app_state {
-        AppState::Error(_) => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        AppState::Error(_) | AppState::MondayAuthFailed => {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        }
         AppState::Loading => {
             if message.is_some_and(|m| m.contains("Gemini")) {
                 Style::default()
@@ -54,13 +134,218 @@ pub fn draw_status_bar(f: &mut Frame, area: Rect, app_state: &AppState, message:
         AppState::ConfirmingStageAll => Style::default()
             .fg(Color::Blue)
             .add_modifier(Modifier::BOLD),
+        AppState::ConfirmingMergeCommit | AppState::ConfirmingSecrets => Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
         _ => Style::default().fg(Color::Green),
     };
 
+    // The selected tasks stay in sight whatever the screen
+    let mut block = Block::default().borders(Borders::ALL).title(title);
+    if selected_tasks > 0 {
+        block = block.title(
+            Line::from(format!(
+                " {}: {} ",
+                tr(language, "status.selected_tasks"),
+                selected_tasks
+            ))
+            .right_aligned()
+            .style(Style::default().fg(Color::DarkGray)),
+        );
+    }
+
     let status = Paragraph::new(status_text)
         .style(status_style)
         .alignment(Alignment::Left)
         .wrap(Wrap { trim: true })
-        .block(Block::default().borders(Borders::ALL).title(title));
+        .block(block);
     f.render_widget(status, area);
 }
+
+/// One-line summary of the main keys of the current screen.
+pub fn draw_help_bar(
+    f: &mut Frame,
+    area: Rect,
+    keymap: &Keymap,
+    screen: &AppScreen,
+    input_mode: &InputMode,
+) {
+    let key_style = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let mut spans = Vec::new();
+
+    // While typing only the preview has keys of its own; elsewhere Esc leaves the field
+    let editing = *input_mode == InputMode::Editing;
+    if editing && *screen != AppScreen::CommitPreview {
+        spans.push(Span::styled(" Esc", key_style));
+        spans.push(Span::raw(" terminar edición  "));
+        spans.push(Span::styled("Tab", key_style));
+        spans.push(Span::raw(" siguiente campo"));
+    } else {
+        let bindings = if editing {
+            keymap.screen_bindings(screen).iter().collect::<Vec<_>>()
+        } else {
+            keymap.bindings(screen).collect()
+        };
+        for binding in bindings.into_iter().filter(|binding| binding.footer) {
+            spans.push(Span::styled(format!(" {}", binding.label), key_style));
+            spans.push(Span::raw(format!(" {} ", binding.description)));
+        }
+    }
+
+    f.render_widget(
+        Paragraph::new(Line::from(spans)).style(Style::default().fg(Color::DarkGray)),
+        area,
+    );
+}
+
+/// Every key of the current screen, opened with `?`.
+pub fn draw_help_overlay(f: &mut Frame, area: Rect, keymap: &Keymap, screen: &AppScreen) {
+    let lines: Vec<Line> = keymap
+        .bindings(screen)
+        .map(|binding| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>14}  ", binding.label),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(binding.description),
+            ])
+        })
+        .collect();
+
+    let width = 64.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let overlay = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    f.render_widget(Clear, overlay);
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title("❓ Atajos de esta pantalla (?/Esc para cerrar)"),
+        ),
+        overlay,
+    );
+}
+
+/// Small box in the top-right corner with the render timings (`--dev`, F12).
+pub fn draw_render_stats(f: &mut Frame, area: Rect, stats: &RenderStats) {
+    let width = 26.min(area.width);
+    let height = 5.min(area.height);
+    let overlay = Rect::new(area.right().saturating_sub(width), area.y, width, height);
+
+    let lines = vec![
+        Line::from(format!("FPS: {}", stats.fps)),
+        Line::from(format!(
+            "draw: {:.2} ms",
+            stats.last_draw.as_secs_f64() * 1000.0
+        )),
+        Line::from(format!(
+            "máx:  {:.2} ms",
+            stats.max_draw.as_secs_f64() * 1000.0
+        )),
+    ];
+
+    f.render_widget(Clear, overlay);
+    f.render_widget(
+        Paragraph::new(lines)
+            .style(Style::default().fg(Color::Magenta))
+            .block(Block::default().borders(Borders::ALL).title("⏱ Render")),
+        overlay,
+    );
+}
+
+/// Raw result of the last AI analysis, as Gemini returned it ('i').
+pub fn draw_analysis_overlay(f: &mut Frame, area: Rect, analysis: &CommitAnalysis) {
+    let text =
+        serde_json::to_string_pretty(analysis).unwrap_or_else(|_| format!("{:#?}", analysis));
+
+    let width = 90.min(area.width);
+    let height = (area.height * 4 / 5).max(3).min(area.height);
+    let overlay = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    f.render_widget(Clear, overlay);
+    f.render_widget(
+        Paragraph::new(text).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title("🤖 Resultado del último análisis IA (i/Esc para cerrar)"),
+        ),
+        overlay,
+    );
+}
+
+/// Changed files to pick for staging before the commit ('a' on the commit screen).
+pub fn draw_stage_selector(f: &mut Frame, area: Rect, selector: &StageSelector) {
+    let width = 80.min(area.width);
+    let height = (area.height * 4 / 5).max(3).min(area.height);
+    let overlay = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let items: Vec<ListItem> = if selector.entries.is_empty() {
+        vec![ListItem::new("No hay cambios en el repositorio")
+            .style(Style::default().fg(Color::DarkGray))]
+    } else {
+        selector
+            .entries
+            .iter()
+            .map(|entry| {
+                let (status, color) = if entry.untracked {
+                    ("??", Color::Red)
+                } else if entry.staged {
+                    ("A ", Color::Green)
+                } else {
+                    ("M ", Color::Yellow)
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(if entry.selected { "[x] " } else { "[ ] " }),
+                    Span::styled(format!("{} ", status), Style::default().fg(color)),
+                    Span::raw(entry.path.as_str()),
+                ]))
+            })
+            .collect()
+    };
+
+    let mut list_state = ListState::default();
+    if !selector.entries.is_empty() {
+        list_state.select(Some(selector.focused));
+    }
+
+    f.render_widget(Clear, overlay);
+    f.render_stateful_widget(
+        List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan))
+                    .title("📂 Archivos a stagear (Space marcar, a todos, Enter aplicar, Esc cancelar)"),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        overlay,
+        &mut list_state,
+    );
+}
@@ -0,0 +1,1214 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::i18n::{tr, Language};
+use crate::types::AppScreen;
+
+// =============================================================================
+// ACTIONS
+// =============================================================================
+
+/// What a key does on a screen. The key handlers match on these, so the help
+/// bar and the `?` overlay always describe the keys that are really handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    Back,
+    NextTab,
+    PrevTab,
+    Select,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    Help,
+    // Commit screen
+    Filter,
+    SearchMonday,
+    SearchJira,
+    PreviewCommit,
+    Analyze,
+    AnalyzeFull,
+    AnalyzeSecurity,
+    AnalyzeBreaking,
+    ToggleTaskManagement,
+    RemoveTask,
+    OpenTaskUrl,
+    RevertAnalysis,
+    ShowAnalysis,
+    StageFiles,
+    ShowDiff,
+    ExternalEditor,
+    // Commit preview
+    Commit,
+    // Task search
+    StartTyping,
+    ToggleTask,
+    SelectByNumber,
+    ClearSearch,
+    FilterBoard,
+    ToggleClosed,
+    // Release notes
+    RunNpmScript,
+    TogglePathFilter,
+    RetryGemini,
+    // Semantic release
+    ClearResults,
+    // History
+    ToggleDetails,
+    Reload,
+    Amend,
+    // Staged diff
+    CollapseAll,
+    // Configuration
+    Save,
+}
+
+impl Action {
+    const ALL: [Action; 44] = [
+        Action::Quit,
+        Action::Back,
+        Action::NextTab,
+        Action::PrevTab,
+        Action::Select,
+        Action::Up,
+        Action::Down,
+        Action::PageUp,
+        Action::PageDown,
+        Action::Home,
+        Action::End,
+        Action::Help,
+        Action::Filter,
+        Action::SearchMonday,
+        Action::SearchJira,
+        Action::PreviewCommit,
+        Action::Analyze,
+        Action::AnalyzeFull,
+        Action::AnalyzeSecurity,
+        Action::AnalyzeBreaking,
+        Action::ToggleTaskManagement,
+        Action::RemoveTask,
+        Action::OpenTaskUrl,
+        Action::RevertAnalysis,
+        Action::ShowAnalysis,
+        Action::StageFiles,
+        Action::ShowDiff,
+        Action::ExternalEditor,
+        Action::Commit,
+        Action::StartTyping,
+        Action::ToggleTask,
+        Action::SelectByNumber,
+        Action::ClearSearch,
+        Action::FilterBoard,
+        Action::ToggleClosed,
+        Action::RunNpmScript,
+        Action::TogglePathFilter,
+        Action::RetryGemini,
+        Action::ClearResults,
+        Action::ToggleDetails,
+        Action::Reload,
+        Action::Amend,
+        Action::CollapseAll,
+        Action::Save,
+    ];
+
+    /// Name used in `KEYBINDINGS`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Back => "back",
+            Action::NextTab => "next",
+            Action::PrevTab => "previous",
+            Action::Select => "select",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Home => "home",
+            Action::End => "end",
+            Action::Help => "help",
+            Action::Filter => "filter",
+            Action::SearchMonday => "search_monday",
+            Action::SearchJira => "search_jira",
+            Action::PreviewCommit => "preview_commit",
+            Action::Analyze => "analyze",
+            Action::AnalyzeFull => "analyze_full",
+            Action::AnalyzeSecurity => "analyze_security",
+            Action::AnalyzeBreaking => "analyze_breaking",
+            Action::ToggleTaskManagement => "task_management",
+            Action::RemoveTask => "remove_task",
+            Action::OpenTaskUrl => "open_task",
+            Action::RevertAnalysis => "revert_analysis",
+            Action::ShowAnalysis => "show_analysis",
+            Action::StageFiles => "stage_files",
+            Action::ShowDiff => "diff",
+            Action::ExternalEditor => "external_editor",
+            Action::Commit => "commit",
+            Action::StartTyping => "type",
+            Action::ToggleTask => "toggle_task",
+            Action::SelectByNumber => "select_by_number",
+            Action::ClearSearch => "clear_search",
+            Action::FilterBoard => "filter_board",
+            Action::ToggleClosed => "show_closed",
+            Action::RunNpmScript => "npm_script",
+            Action::TogglePathFilter => "path_filter",
+            Action::RetryGemini => "retry_gemini",
+            Action::ClearResults => "clear_results",
+            Action::ToggleDetails => "details",
+            Action::Reload => "reload",
+            Action::Amend => "amend",
+            Action::CollapseAll => "collapse_all",
+            Action::Save => "save",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.name() == name.trim())
+    }
+}
+
+// =============================================================================
+// KEY BINDING TABLES
+// =============================================================================
+
+/// Built-in binding; `KEYBINDINGS` can replace its keys.
+struct DefaultBinding {
+    keys: &'static [KeyCode],
+    /// The keys only trigger the action together with Ctrl
+    ctrl: bool,
+    /// How the keys are written in the help
+    label: &'static str,
+    description: &'static str,
+    action: Action,
+    /// Also listed in the help bar at the bottom of the screen
+    footer: bool,
+}
+
+const fn binding(
+    keys: &'static [KeyCode],
+    label: &'static str,
+    description: &'static str,
+    action: Action,
+    footer: bool,
+) -> DefaultBinding {
+    DefaultBinding {
+        keys,
+        ctrl: false,
+        label,
+        description,
+        action,
+        footer,
+    }
+}
+
+/// Available on every screen outside text editing.
+const GLOBAL_BINDINGS: &[DefaultBinding] = &[binding(
+    &[KeyCode::Char('?')],
+    "?",
+    "Ayuda de esta pantalla",
+    Action::Help,
+    true,
+)];
+
+const MAIN_BINDINGS: &[DefaultBinding] = &[
+    binding(
+        &[KeyCode::Tab],
+        "Tab",
+        "Siguiente opción",
+        Action::NextTab,
+        true,
+    ),
+    binding(
+        &[KeyCode::BackTab],
+        "Shift+Tab",
+        "Opción anterior",
+        Action::PrevTab,
+        false,
+    ),
+    binding(
+        &[KeyCode::Enter],
+        "Enter",
+        "Abrir la opción",
+        Action::Select,
+        true,
+    ),
+    binding(&[KeyCode::Char('q')], "q", "Salir", Action::Quit, true),
+];
+
+const CONFIG_BINDINGS: &[DefaultBinding] = &[
+    binding(
+        &[KeyCode::Up, KeyCode::Char('k')],
+        "↑/k",
+        "Campo anterior",
+        Action::Up,
+        false,
+    ),
+    binding(
+        &[KeyCode::Down, KeyCode::Char('j')],
+        "↓/j",
+        "Campo siguiente",
+        Action::Down,
+        false,
+    ),
+    binding(
+        &[KeyCode::Enter],
+        "Enter",
+        "Editar el campo / cambiar de opción",
+        Action::Select,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('s')],
+        "s",
+        "Validar y guardar",
+        Action::Save,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('q'), KeyCode::Esc],
+        "q/Esc",
+        "Volver (dos veces si hay cambios sin guardar)",
+        Action::Back,
+        true,
+    ),
+];
+
+const COMMIT_BINDINGS: &[DefaultBinding] = &[
+    binding(
+        &[KeyCode::Tab],
+        "Tab",
+        "Siguiente campo (edición automática)",
+        Action::NextTab,
+        true,
+    ),
+    binding(
+        &[KeyCode::BackTab],
+        "Shift+Tab",
+        "Campo anterior",
+        Action::PrevTab,
+        false,
+    ),
+    binding(
+        &[KeyCode::Up],
+        "↑",
+        "Tipo de commit o tarea anterior",
+        Action::Up,
+        false,
+    ),
+    binding(
+        &[KeyCode::Down],
+        "↓",
+        "Siguiente tipo de commit o tarea",
+        Action::Down,
+        false,
+    ),
+    binding(
+        &[KeyCode::Enter],
+        "Enter",
+        "Elegir tipo o editar campo",
+        Action::Select,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('t'), KeyCode::Char('g')],
+        "t",
+        "Análisis IA de los cambios",
+        Action::Analyze,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('T')],
+        "T",
+        "Análisis IA completo, también en commits triviales",
+        Action::AnalyzeFull,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('S')],
+        "S",
+        "Regenerar solo el análisis de seguridad",
+        Action::AnalyzeSecurity,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('B')],
+        "B",
+        "Regenerar solo los breaking changes",
+        Action::AnalyzeBreaking,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('s')],
+        "s",
+        "Buscar tareas de Monday.com",
+        Action::SearchMonday,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('j')],
+        "j",
+        "Buscar tareas de JIRA",
+        Action::SearchJira,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('c')],
+        "c",
+        "Previsualizar el commit",
+        Action::PreviewCommit,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('m')],
+        "m",
+        "Modo gestión de tareas",
+        Action::ToggleTaskManagement,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('/')],
+        "/",
+        "Filtrar las tareas seleccionadas",
+        Action::Filter,
+        false,
+    ),
+    binding(
+        &[KeyCode::Delete, KeyCode::Char(' ')],
+        "Space/Del",
+        "Quitar la tarea seleccionada",
+        Action::RemoveTask,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('o')],
+        "o",
+        "Abrir la tarea seleccionada en el navegador",
+        Action::OpenTaskUrl,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('u')],
+        "u",
+        "Revertir los campos rellenados por la IA",
+        Action::RevertAnalysis,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('i')],
+        "i",
+        "Ver el resultado del último análisis IA",
+        Action::ShowAnalysis,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('a')],
+        "a",
+        "Elegir los archivos a stagear",
+        Action::StageFiles,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('d')],
+        "d",
+        "Ver el diff de los cambios staged",
+        Action::ShowDiff,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('e')],
+        "e",
+        "Editar el campo actual en $EDITOR",
+        Action::ExternalEditor,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('q'), KeyCode::Esc],
+        "q/Esc",
+        "Volver (Esc limpia antes el filtro)",
+        Action::Back,
+        true,
+    ),
+];
+
+/// The preview opens in edit mode, where plain characters are typed instead.
+const COMMIT_PREVIEW_BINDINGS: &[DefaultBinding] = &[
+    DefaultBinding {
+        keys: &[KeyCode::Char('c')],
+        ctrl: true,
+        label: "Ctrl+C",
+        description: "Hacer el commit (o enmendar HEAD)",
+        action: Action::Commit,
+        footer: true,
+    },
+    DefaultBinding {
+        keys: &[KeyCode::Char('e')],
+        ctrl: true,
+        label: "Ctrl+E",
+        description: "Editar el mensaje en $EDITOR",
+        action: Action::ExternalEditor,
+        footer: false,
+    },
+    binding(
+        &[KeyCode::Tab],
+        "Tab",
+        "Salir del editor al formulario",
+        Action::NextTab,
+        false,
+    ),
+    binding(
+        &[KeyCode::Esc, KeyCode::Char('q')],
+        "Esc",
+        "Cancelar (q solo fuera del editor)",
+        Action::Back,
+        true,
+    ),
+];
+
+const RELEASE_NOTES_BINDINGS: &[DefaultBinding] = &[
+    binding(
+        &[KeyCode::Enter, KeyCode::Char('i')],
+        "Enter/i",
+        "Generar notas (interno)",
+        Action::Select,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('o')],
+        "o",
+        "Generar con npm run release-notes",
+        Action::RunNpmScript,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('p')],
+        "p",
+        "Filtrar por el paquete actual",
+        Action::TogglePathFilter,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('g')],
+        "g",
+        "Reintentar solo Gemini sobre la última generación",
+        Action::RetryGemini,
+        true,
+    ),
+    binding(
+        &[KeyCode::Up],
+        "↑",
+        "Subir en la salida de npm",
+        Action::Up,
+        false,
+    ),
+    binding(
+        &[KeyCode::Down],
+        "↓",
+        "Bajar en la salida de npm",
+        Action::Down,
+        false,
+    ),
+    binding(
+        &[KeyCode::PageUp],
+        "PgUp",
+        "Subir una página",
+        Action::PageUp,
+        false,
+    ),
+    binding(
+        &[KeyCode::PageDown],
+        "PgDn",
+        "Bajar una página",
+        Action::PageDown,
+        false,
+    ),
+    binding(
+        &[KeyCode::End],
+        "End",
+        "Seguir la salida",
+        Action::End,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('q'), KeyCode::Esc],
+        "q/Esc",
+        "Volver",
+        Action::Back,
+        true,
+    ),
+];
+
+const SEMANTIC_RELEASE_BINDINGS: &[DefaultBinding] = &[
+    binding(
+        &[KeyCode::Tab],
+        "Tab",
+        "Siguiente opción",
+        Action::NextTab,
+        true,
+    ),
+    binding(
+        &[KeyCode::BackTab],
+        "Shift+Tab",
+        "Opción anterior",
+        Action::PrevTab,
+        false,
+    ),
+    binding(
+        &[KeyCode::Enter],
+        "Enter",
+        "Ejecutar la opción",
+        Action::Select,
+        true,
+    ),
+    binding(
+        &[KeyCode::Up],
+        "↑",
+        "Subir en los resultados",
+        Action::Up,
+        false,
+    ),
+    binding(
+        &[KeyCode::Down],
+        "↓",
+        "Bajar en los resultados",
+        Action::Down,
+        false,
+    ),
+    binding(
+        &[KeyCode::PageUp],
+        "PgUp",
+        "Subir una página",
+        Action::PageUp,
+        false,
+    ),
+    binding(
+        &[KeyCode::PageDown],
+        "PgDn",
+        "Bajar una página",
+        Action::PageDown,
+        false,
+    ),
+    binding(
+        &[KeyCode::Home],
+        "Home",
+        "Inicio de los resultados",
+        Action::Home,
+        false,
+    ),
+    binding(
+        &[KeyCode::End],
+        "End",
+        "Final de los resultados",
+        Action::End,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('r')],
+        "r",
+        "Limpiar resultados",
+        Action::ClearResults,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('q'), KeyCode::Esc],
+        "q/Esc",
+        "Volver",
+        Action::Back,
+        true,
+    ),
+];
+
+/// Navigation mode; while typing in the search box keys go to the input.
+const TASK_SEARCH_BINDINGS: &[DefaultBinding] = &[
+    binding(
+        &[KeyCode::Char('i'), KeyCode::Char('/'), KeyCode::Backspace],
+        "i, /, Backspace",
+        "Escribir la búsqueda",
+        Action::StartTyping,
+        true,
+    ),
+    binding(&[KeyCode::Enter], "Enter", "Buscar", Action::Select, true),
+    binding(&[KeyCode::Up], "↑", "Resultado anterior", Action::Up, false),
+    binding(
+        &[KeyCode::Down],
+        "↓",
+        "Siguiente resultado",
+        Action::Down,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char(' ')],
+        "Space",
+        "Seleccionar/quitar el resultado",
+        Action::ToggleTask,
+        true,
+    ),
+    binding(
+        &[
+            KeyCode::Char('1'),
+            KeyCode::Char('2'),
+            KeyCode::Char('3'),
+            KeyCode::Char('4'),
+            KeyCode::Char('5'),
+            KeyCode::Char('6'),
+            KeyCode::Char('7'),
+            KeyCode::Char('8'),
+            KeyCode::Char('9'),
+            KeyCode::Char('0'),
+        ],
+        "1-9,0",
+        "Seleccionar el resultado por número",
+        Action::SelectByNumber,
+        false,
+    ),
+    binding(
+        &[KeyCode::Delete, KeyCode::Char('r')],
+        "Del/r",
+        "Quitar el resultado de la selección",
+        Action::RemoveTask,
+        false,
+    ),
+    binding(
+        &[KeyCode::Esc],
+        "Esc",
+        "Limpiar la búsqueda",
+        Action::ClearSearch,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('b')],
+        "b",
+        "Filtrar los resultados por tablero",
+        Action::FilterBoard,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('c')],
+        "c",
+        "Incluir/ocultar tareas archivadas o eliminadas",
+        Action::ToggleClosed,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('o')],
+        "o",
+        "Abrir el resultado en el navegador",
+        Action::OpenTaskUrl,
+        false,
+    ),
+    binding(
+        &[KeyCode::Char('q')],
+        "q",
+        "Volver al commit",
+        Action::Back,
+        true,
+    ),
+];
+
+const HISTORY_BINDINGS: &[DefaultBinding] = &[
+    binding(&[KeyCode::Up], "↑", "Commit anterior", Action::Up, false),
+    binding(
+        &[KeyCode::Down],
+        "↓",
+        "Siguiente commit",
+        Action::Down,
+        false,
+    ),
+    binding(
+        &[KeyCode::Enter],
+        "Enter",
+        "Mostrar/ocultar el mensaje",
+        Action::ToggleDetails,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('a')],
+        "a",
+        "Enmendar HEAD",
+        Action::Amend,
+        true,
+    ),
+    binding(&[KeyCode::Char('r')], "r", "Recargar", Action::Reload, true),
+    binding(
+        &[KeyCode::Char('q'), KeyCode::Esc],
+        "q/Esc",
+        "Volver",
+        Action::Back,
+        true,
+    ),
+];
+
+const STAGED_DIFF_BINDINGS: &[DefaultBinding] = &[
+    binding(&[KeyCode::Up], "↑", "Subir una línea", Action::Up, false),
+    binding(
+        &[KeyCode::Down],
+        "↓",
+        "Bajar una línea",
+        Action::Down,
+        false,
+    ),
+    binding(
+        &[KeyCode::PageUp],
+        "PgUp",
+        "Subir una página",
+        Action::PageUp,
+        false,
+    ),
+    binding(
+        &[KeyCode::PageDown],
+        "PgDn",
+        "Bajar una página",
+        Action::PageDown,
+        false,
+    ),
+    binding(
+        &[KeyCode::Home],
+        "Home",
+        "Inicio del diff",
+        Action::Home,
+        false,
+    ),
+    binding(&[KeyCode::End], "End", "Final del diff", Action::End, false),
+    binding(
+        &[KeyCode::Tab],
+        "Tab",
+        "Siguiente archivo",
+        Action::NextTab,
+        true,
+    ),
+    binding(
+        &[KeyCode::BackTab],
+        "Shift+Tab",
+        "Archivo anterior",
+        Action::PrevTab,
+        false,
+    ),
+    binding(
+        &[KeyCode::Enter],
+        "Enter",
+        "Colapsar/expandir el archivo",
+        Action::ToggleDetails,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('c')],
+        "c",
+        "Colapsar/expandir todos",
+        Action::CollapseAll,
+        true,
+    ),
+    binding(
+        &[KeyCode::Char('q'), KeyCode::Esc],
+        "q/Esc",
+        "Volver al commit",
+        Action::Back,
+        true,
+    ),
+];
+
+fn default_bindings(screen: &AppScreen) -> &'static [DefaultBinding] {
+    match screen {
+        AppScreen::Main => MAIN_BINDINGS,
+        AppScreen::Config => CONFIG_BINDINGS,
+        AppScreen::Commit => COMMIT_BINDINGS,
+        AppScreen::CommitPreview => COMMIT_PREVIEW_BINDINGS,
+        AppScreen::ReleaseNotes => RELEASE_NOTES_BINDINGS,
+        AppScreen::SemanticRelease => SEMANTIC_RELEASE_BINDINGS,
+        AppScreen::TaskSearch => TASK_SEARCH_BINDINGS,
+        AppScreen::History => HISTORY_BINDINGS,
+        AppScreen::StagedDiff => STAGED_DIFF_BINDINGS,
+    }
+}
+
+/// Screen names used as prefixes in `KEYBINDINGS`
+const SCREENS: [(&str, AppScreen); 9] = [
+    ("main", AppScreen::Main),
+    ("config", AppScreen::Config),
+    ("commit", AppScreen::Commit),
+    ("preview", AppScreen::CommitPreview),
+    ("release_notes", AppScreen::ReleaseNotes),
+    ("semantic_release", AppScreen::SemanticRelease),
+    ("search", AppScreen::TaskSearch),
+    ("history", AppScreen::History),
+    ("diff", AppScreen::StagedDiff),
+];
+
+// =============================================================================
+// KEYS
+// =============================================================================
+
+/// A key, optionally pressed with Ctrl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl KeyChord {
+    /// Parse `c`, `Ctrl+c`, `Enter`, `Esc`, `Tab`, `Shift+Tab`, `Space`,
+    /// `Backspace`, `Del`, `Up`, `Down`, `Left`, `Right`, `PgUp`, `PgDn`,
+    /// `Home`, `End` or `F1`-`F12`. Names are case-insensitive.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (ctrl, key) = match value.get(..5) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("ctrl+") => (true, &value[5..]),
+            _ => (false, value),
+        };
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(if ctrl { c.to_ascii_lowercase() } else { c }),
+            _ => match key.to_lowercase().as_str() {
+                "enter" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "shift+tab" | "backtab" => KeyCode::BackTab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "del" | "delete" => KeyCode::Delete,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "pgup" | "pageup" => KeyCode::PageUp,
+                "pgdn" | "pagedown" => KeyCode::PageDown,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                name => KeyCode::F(
+                    name.strip_prefix('f')?
+                        .parse()
+                        .ok()
+                        .filter(|n| (1..=12).contains(n))?,
+                ),
+            },
+        };
+        Some(Self { code, ctrl })
+    }
+
+    fn matches(&self, key: KeyEvent) -> bool {
+        self.code == key.code && self.ctrl == key.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::BackTab => "Shift+Tab".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            code => format!("{:?}", code),
+        };
+        if self.ctrl {
+            format!("Ctrl+{}", key.to_uppercase())
+        } else {
+            key
+        }
+    }
+}
+
+// =============================================================================
+// KEYMAP
+// =============================================================================
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub keys: Vec<KeyChord>,
+    /// How the keys are written in the help
+    pub label: String,
+    pub description: &'static str,
+    pub action: Action,
+    /// Also listed in the help bar at the bottom of the screen
+    pub footer: bool,
+    /// Remapped in `KEYBINDINGS`; wins over a built-in binding on the same key
+    pub custom: bool,
+}
+
+impl From<&DefaultBinding> for Binding {
+    fn from(default: &DefaultBinding) -> Self {
+        Self {
+            keys: default
+                .keys
+                .iter()
+                .map(|&code| KeyChord {
+                    code,
+                    ctrl: default.ctrl,
+                })
+                .collect(),
+            label: default.label.to_string(),
+            description: default.description,
+            action: default.action,
+            footer: default.footer,
+            custom: false,
+        }
+    }
+}
+
+/// Key bindings of every screen: the built-in tables with the `KEYBINDINGS`
+/// overrides applied. Handlers resolve keys through it and the help is drawn
+/// from it.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    screens: Vec<(AppScreen, Vec<Binding>)>,
+    global: Vec<Binding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            screens: SCREENS
+                .iter()
+                .map(|(_, screen)| {
+                    let bindings = default_bindings(screen).iter().map(Binding::from).collect();
+                    (screen.clone(), bindings)
+                })
+                .collect(),
+            global: GLOBAL_BINDINGS.iter().map(Binding::from).collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Keymap with the `KEYBINDINGS` overrides applied, plus the problems
+    /// found: unknown screens, actions or keys, and keys bound to two actions
+    /// of the same screen.
+    ///
+    /// Entries look like `commit.preview_commit=p` or `search.toggle_task=x|Space`;
+    /// without a screen prefix (`help=h`) they change a global binding.
+    /// Problems are written in `language`.
+    pub fn from_config(spec: Option<&str>, language: Language) -> (Self, Vec<String>) {
+        let mut keymap = Self::default();
+        let mut problems = Vec::new();
+
+        for entry in spec.unwrap_or_default().split(',').map(str::trim) {
+            if entry.is_empty() {
+                continue;
+            }
+            if let Err(problem) = keymap.apply_override(entry, language) {
+                problems.push(format!("'{}': {}", entry, problem));
+            }
+        }
+
+        problems.extend(keymap.conflicts(language));
+        (keymap, problems)
+    }
+
+    fn apply_override(&mut self, entry: &str, language: Language) -> Result<(), String> {
+        let (target, keys) = entry
+            .split_once('=')
+            .ok_or_else(|| tr(language, "keybindings.expected_entry").to_string())?;
+        let (scope, action_name) = match target.trim().split_once('.') {
+            Some((scope, action)) => (Some(scope.trim()), action),
+            None => (None, target),
+        };
+        let bindings = match scope {
+            None => &mut self.global,
+            Some(scope) => {
+                let screen = SCREENS
+                    .iter()
+                    .find(|(name, _)| *name == scope)
+                    .map(|(_, screen)| screen)
+                    .ok_or_else(|| {
+                        format!("{} '{}'", tr(language, "keybindings.unknown_screen"), scope)
+                    })?;
+                self.screen_bindings_mut(screen)
+            }
+        };
+        let action = Action::parse(action_name).ok_or_else(|| {
+            format!(
+                "{} '{}'",
+                tr(language, "keybindings.unknown_action"),
+                action_name.trim()
+            )
+        })?;
+        let keys = keys
+            .split('|')
+            .map(|key| {
+                KeyChord::parse(key).ok_or_else(|| {
+                    format!(
+                        "{} '{}'",
+                        tr(language, "keybindings.unknown_key"),
+                        key.trim()
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let binding = bindings
+            .iter_mut()
+            .find(|binding| binding.action == action)
+            .ok_or_else(|| {
+                format!(
+                    "'{}' {}",
+                    action.name(),
+                    tr(language, "keybindings.not_on_screen")
+                )
+            })?;
+
+        binding.label = keys
+            .iter()
+            .map(KeyChord::label)
+            .collect::<Vec<_>>()
+            .join("/");
+        binding.keys = keys;
+        binding.custom = true;
+        Ok(())
+    }
+
+    /// Keys bound to more than one action on the same screen.
+    fn conflicts(&self, language: Language) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        for (name, screen) in &SCREENS {
+            let bindings: Vec<&Binding> = self.bindings(screen).collect();
+            for (i, first) in bindings.iter().enumerate() {
+                for second in &bindings[i + 1..] {
+                    if first.action == second.action {
+                        continue;
+                    }
+                    for key in first.keys.iter().filter(|key| second.keys.contains(key)) {
+                        conflicts.push(format!(
+                            "{}: '{}' {} '{}' {} '{}'",
+                            name,
+                            key.label(),
+                            tr(language, "keybindings.bound_to"),
+                            first.action.name(),
+                            tr(language, "keybindings.and"),
+                            second.action.name()
+                        ));
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    fn screen_bindings_mut(&mut self, screen: &AppScreen) -> &mut Vec<Binding> {
+        let index = self
+            .screens
+            .iter()
+            .position(|(candidate, _)| candidate == screen)
+            .expect("every screen has a binding table");
+        &mut self.screens[index].1
+    }
+
+    /// Bindings of `screen` alone, in the order they are shown in the help.
+    pub fn screen_bindings(&self, screen: &AppScreen) -> &[Binding] {
+        self.screens
+            .iter()
+            .find(|(candidate, _)| candidate == screen)
+            .map(|(_, bindings)| bindings.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Screen bindings followed by the global ones.
+    pub fn bindings<'a>(&'a self, screen: &AppScreen) -> impl Iterator<Item = &'a Binding> {
+        self.screen_bindings(screen).iter().chain(&self.global)
+    }
+
+    /// Action bound to `key` on `screen`, if any. A remapped binding wins
+    /// over a built-in one on the same key.
+    pub fn action_for(&self, screen: &AppScreen, key: KeyEvent) -> Option<Action> {
+        let mut matching = self
+            .bindings(screen)
+            .filter(|binding| binding.keys.iter().any(|chord| chord.matches(key)));
+        let first = matching.next()?;
+        Some(
+            matching
+                .find(|binding| binding.custom && !first.custom)
+                .unwrap_or(first)
+                .action,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_key_chord_parse() {
+        let chord = |code, ctrl| Some(KeyChord { code, ctrl });
+
+        assert_eq!(KeyChord::parse("Ctrl+s"), chord(KeyCode::Char('s'), true));
+        assert_eq!(KeyChord::parse("ctrl+S"), chord(KeyCode::Char('s'), true));
+        assert_eq!(KeyChord::parse("S"), chord(KeyCode::Char('S'), false));
+        assert_eq!(KeyChord::parse("Shift+Tab"), chord(KeyCode::BackTab, false));
+        assert_eq!(KeyChord::parse(" space "), chord(KeyCode::Char(' '), false));
+        assert_eq!(KeyChord::parse("F12"), chord(KeyCode::F(12), false));
+        assert_eq!(KeyChord::parse("F13"), None);
+        assert_eq!(KeyChord::parse("F0"), None);
+        assert_eq!(KeyChord::parse("Ctrl+Alt+s"), None);
+    }
+
+    #[test]
+    fn test_from_config_reports_unknown_names() {
+        let (_, problems) = Keymap::from_config(
+            Some("nope.save=x, commit.nope=x, commit.search_jira=F13, main.amend=x, help"),
+            Language::En,
+        );
+
+        assert_eq!(
+            problems,
+            [
+                "'nope.save=x': unknown screen 'nope'",
+                "'commit.nope=x': unknown action 'nope'",
+                "'commit.search_jira=F13': unknown key 'F13'",
+                "'main.amend=x': 'amend' is not on that screen",
+                "'help': expected screen.action=keys",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_built_in_keymap_has_no_conflicts() {
+        let (_, problems) = Keymap::from_config(None, Language::Es);
+
+        assert!(problems.is_empty(), "{:?}", problems);
+    }
+
+    #[test]
+    fn test_remapped_key_conflicts_and_wins_over_built_in() {
+        let (keymap, problems) = Keymap::from_config(Some("commit.search_jira=s"), Language::Es);
+
+        assert_eq!(
+            problems,
+            ["commit: 's' está asignada a 'search_monday' y a 'search_jira'"]
+        );
+        assert_eq!(
+            keymap.action_for(&AppScreen::Commit, press(KeyCode::Char('s'))),
+            Some(Action::SearchJira)
+        );
+        // The old key of the remapped action is free
+        assert_eq!(
+            keymap.action_for(&AppScreen::Commit, press(KeyCode::Char('j'))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ctrl_keys_need_ctrl() {
+        let (keymap, problems) = Keymap::from_config(Some("preview.commit=Ctrl+S"), Language::Es);
+
+        assert!(problems.is_empty(), "{:?}", problems);
+        assert_eq!(
+            keymap.action_for(
+                &AppScreen::CommitPreview,
+                KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)
+            ),
+            Some(Action::Commit)
+        );
+        assert_ne!(
+            keymap.action_for(&AppScreen::CommitPreview, press(KeyCode::Char('s'))),
+            Some(Action::Commit)
+        );
+    }
+}
@@ -11,7 +11,14 @@ pub fn draw_loading_overlay(
     area: Rect,
     animation_frame: usize,
     message: Option<&str>,
+    progress: Option<f32>,
 ) {
+    // Operations that report real progress get a percentage gauge
+    if let Some(progress) = progress {
+        draw_progress_overlay(f, area, animation_frame, message, progress);
+        return;
+    }
+
     // Check if this is a Gemini analysis process
     let is_gemini_analysis = message.is_some_and(|msg| {
         msg.contains("Analizando cambios")
@@ -181,6 +188,10 @@ fn draw_gemini_analysis_overlay(
                 .fg(Color::Gray)
                 .add_modifier(Modifier::ITALIC),
         )),
+        Line::from(Span::styled(
+            "Esc para cancelar",
+            Style::default().fg(Color::DarkGray),
+        )),
     ]);
 
     let info_paragraph = Paragraph::new(info_lines)
@@ -197,6 +208,85 @@ fn draw_gemini_analysis_overlay(
     f.render_widget(info_paragraph, info_area);
 }
 
+fn draw_progress_overlay(
+    f: &mut Frame,
+    area: Rect,
+    animation_frame: usize,
+    message: Option<&str>,
+    progress: f32,
+) {
+    // Spinner characters for animation
+    let spinner_chars = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    let spinner_char = spinner_chars[animation_frame % spinner_chars.len()];
+
+    let loading_area = centered_rect(60, 15, area);
+    f.render_widget(Clear, loading_area);
+
+    let block = Block::default()
+        .title(format!(" {} Procesando ", spinner_char))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(block, loading_area);
+
+    let content_area = Rect {
+        x: loading_area.x + 2,
+        y: loading_area.y + 2,
+        width: loading_area.width.saturating_sub(4),
+        height: loading_area.height.saturating_sub(4),
+    };
+
+    // Current phase as reported by the background operation
+    let message_para = Paragraph::new(message.unwrap_or("⏳ Cargando..."))
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    let message_area = Rect {
+        x: content_area.x,
+        y: content_area.y,
+        width: content_area.width,
+        height: 2,
+    };
+    f.render_widget(message_para, message_area);
+
+    let ratio = progress.clamp(0.0, 1.0) as f64;
+    let gauge = Gauge::default()
+        .block(Block::default().borders(Borders::NONE))
+        .gauge_style(
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )
+        .ratio(ratio)
+        .label(format!("{:.0}%", ratio * 100.0));
+
+    let gauge_area = Rect {
+        x: content_area.x,
+        y: content_area.y + 3,
+        width: content_area.width,
+        height: 1,
+    };
+    f.render_widget(gauge, gauge_area);
+
+    let hint = Paragraph::new("Esc para cancelar")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    let hint_area = Rect {
+        x: content_area.x,
+        y: content_area.y + 5,
+        width: content_area.width,
+        height: 1,
+    };
+    if hint_area.y < content_area.y + content_area.height {
+        f.render_widget(hint, hint_area);
+    }
+}
+
 fn draw_simple_loading_overlay(
     f: &mut Frame,
     area: Rect,
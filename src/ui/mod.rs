@@ -1,16 +1,23 @@
 pub mod components;
+pub mod keybindings;
 pub mod loading;
 pub mod screens;
 pub mod state;
 
 // Re-export the main types and functions for easy access
-pub use components::{draw_status_bar, draw_title_bar};
+pub use components::{
+    draw_analysis_overlay, draw_help_bar, draw_help_overlay, draw_render_stats,
+    draw_stage_selector, draw_status_bar, draw_title_bar,
+};
 pub use loading::draw_loading_overlay;
 pub use screens::{
-    draw_commit_preview_screen, draw_commit_screen, draw_config_screen, draw_main_screen,
-    draw_release_notes_screen, draw_task_search_screen,
+    draw_commit_preview_screen, draw_commit_screen, draw_config_screen, draw_history_screen,
+    draw_main_screen, draw_release_notes_screen, draw_staged_diff_screen, draw_task_search_screen,
+};
+pub use state::{
+    CommitField, ConfigEditor, InputMode, RenderStats, ScriptOutputLine, SearchHistories,
+    StageSelector, StagedDiffView, UIState,
 };
-pub use state::{CommitField, InputMode, UIState};
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -19,7 +26,8 @@ use ratatui::{
 
 use crate::git::GitStatus;
 use crate::types::{
-    AppConfig, AppScreen, AppState, CommitForm, JiraTask, MondayTask, SemanticReleaseState,
+    AppConfig, AppScreen, AppState, CommitForm, GitCommit, JiraTask, MondayTask,
+    SemanticReleaseState,
 };
 use crate::ui::screens::semantic_release::draw_semantic_release_screen;
 
@@ -37,18 +45,26 @@ pub fn draw(
     message: Option<&str>,
     git_status: Option<&GitStatus>,
     semantic_release_state: Option<&SemanticReleaseState>,
+    recent_commits: &[GitCommit],
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),
             Constraint::Min(0),
+            Constraint::Length(1),
             Constraint::Length(3),
         ])
         .split(f.area());
 
     // Title bar
-    draw_title_bar(f, chunks[0]);
+    draw_title_bar(
+        f,
+        chunks[0],
+        config.profile.as_deref(),
+        git_status.and_then(|status| status.branch.as_ref()),
+        config.offline,
+    );
 
     // Update textarea styles before rendering
     ui_state.update_textarea_styles();
@@ -56,10 +72,10 @@ pub fn draw(
     // Main content based on current screen
     match app_screen {
         AppScreen::Main => draw_main_screen(f, chunks[1], ui_state, git_status),
-        AppScreen::Config => draw_config_screen(f, chunks[1]),
-        AppScreen::Commit => draw_commit_screen(f, chunks[1], ui_state, commit_form),
+        AppScreen::Config => draw_config_screen(f, chunks[1], ui_state),
+        AppScreen::Commit => draw_commit_screen(f, chunks[1], ui_state, commit_form, config),
         AppScreen::CommitPreview => draw_commit_preview_screen(f, chunks[1], ui_state),
-        AppScreen::ReleaseNotes => draw_release_notes_screen(f, chunks[1]),
+        AppScreen::ReleaseNotes => draw_release_notes_screen(f, chunks[1], ui_state, config),
         AppScreen::SemanticRelease => draw_semantic_release_screen(
             f,
             chunks[1],
@@ -78,16 +94,60 @@ pub fn draw(
             config,
             commit_form,
         ),
+        AppScreen::History => draw_history_screen(f, chunks[1], ui_state, recent_commits),
+        AppScreen::StagedDiff => draw_staged_diff_screen(f, chunks[1], ui_state),
     }
 
+    // Key help for the current screen
+    draw_help_bar(
+        f,
+        chunks[2],
+        &ui_state.keymap,
+        app_screen,
+        &ui_state.input_mode,
+    );
+
     // Status bar
-    draw_status_bar(f, chunks[2], app_state, message);
+    draw_status_bar(
+        f,
+        chunks[3],
+        app_state,
+        message,
+        screens::tasks::selected_task_count(config, commit_form),
+        config.language,
+    );
 
     // Loading overlay
-    if matches!(app_state, AppState::Loading) {
-        // Increment animation frame for spinner
-        ui_state.animation_frame = ui_state.animation_frame.wrapping_add(1);
-        draw_loading_overlay(f, f.area(), ui_state.animation_frame, message);
+    if matches!(app_state, AppState::Loading) && !ui_state.analysis_streaming {
+        // The frame is advanced by the main loop's animation tick
+        draw_loading_overlay(
+            f,
+            f.area(),
+            ui_state.animation_frame,
+            message,
+            ui_state.loading_progress,
+        );
+    }
+
+    if ui_state.show_analysis {
+        if let Some(analysis) = &ui_state.last_analysis {
+            draw_analysis_overlay(f, f.area(), analysis);
+        }
+    }
+
+    if let Some(selector) = &ui_state.stage_selector {
+        draw_stage_selector(f, f.area(), selector);
+    }
+
+    if ui_state.show_help {
+        draw_help_overlay(f, f.area(), &ui_state.keymap, app_screen);
+    }
+
+    // Dev-mode render metrics, on top of everything else
+    if ui_state.show_render_stats {
+        if let Some(stats) = &ui_state.render_stats {
+            draw_render_stats(f, f.area(), stats);
+        }
     }
 
     // Note: Cursor positioning is now handled by tui-textarea internally
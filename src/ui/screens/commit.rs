@@ -1,14 +1,23 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-use crate::types::{CommitForm, CommitType};
+use tui_textarea::TextArea;
+
+use crate::types::{AppConfig, CommitForm, CommitType};
 use crate::ui::state::{CommitField, InputMode, UIState};
 
-pub fn draw_commit_screen(f: &mut Frame, area: Rect, ui_state: &UIState, commit_form: &CommitForm) {
+pub fn draw_commit_screen(
+    f: &mut Frame,
+    area: Rect,
+    ui_state: &UIState,
+    commit_form: &CommitForm,
+    config: &AppConfig,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -44,7 +53,24 @@ pub fn draw_commit_screen(f: &mut Frame, area: Rect, ui_state: &UIState, commit_
             } else {
                 Style::default()
             };
-            ListItem::new(format!("{}: {}", ct.as_str(), ct.description())).style(style)
+            let suggestion = if Some(ct) == ui_state.suggested_commit_type.as_ref() {
+                "  🤖 sugerido"
+            } else {
+                ""
+            };
+            let gitmoji = if config.use_gitmoji {
+                format!("{} ", config.gitmoji(ct))
+            } else {
+                String::new()
+            };
+            ListItem::new(format!(
+                "{}{}: {}{}",
+                gitmoji,
+                ct.as_str(),
+                ct.description(),
+                suggestion
+            ))
+            .style(style)
         })
         .collect();
 
@@ -62,80 +88,124 @@ pub fn draw_commit_screen(f: &mut Frame, area: Rect, ui_state: &UIState, commit_
 
     f.render_stateful_widget(commit_type_list, chunks[0], &mut list_state);
 
+    // Fields still holding what the last AI analysis wrote get a marker
+    let field_title = |field: CommitField, title: &str| {
+        if ui_state.is_ai_filled(&field, commit_form) {
+            format!("{} 🤖 IA", title)
+        } else {
+            title.to_string()
+        }
+    };
+
     // Render TextArea widgets with titles
     let scope_block = Block::default()
         .borders(Borders::ALL)
-        .title("Scope (auto-edit on Tab)")
+        .title(field_title(CommitField::Scope, "Scope (auto-edit on Tab)"))
         .border_style(ui_state.get_field_border_style(&CommitField::Scope));
-    let mut scope_textarea = ui_state.scope_textarea.clone();
-    scope_textarea.set_block(scope_block);
-    f.render_widget(&scope_textarea, chunks[1]);
+    draw_text_field(f, chunks[1], ui_state, CommitField::Scope, scope_block);
 
     let title_block = Block::default()
         .borders(Borders::ALL)
-        .title("Title")
+        .title(field_title(CommitField::Title, "Title"))
         .border_style(ui_state.get_field_border_style(&CommitField::Title));
-    let mut title_textarea = ui_state.title_textarea.clone();
-    title_textarea.set_block(title_block);
-    f.render_widget(&title_textarea, chunks[2]);
+    draw_text_field(f, chunks[2], ui_state, CommitField::Title, title_block);
 
     let description_block = Block::default()
         .borders(Borders::ALL)
-        .title("Description (multiline, 't' for comprehensive AI analysis)")
+        .title(field_title(
+            CommitField::Description,
+            "Description (multiline, 't' for comprehensive AI analysis)",
+        ))
         .border_style(ui_state.get_field_border_style(&CommitField::Description));
-    let mut description_textarea = ui_state.description_textarea.clone();
-    description_textarea.set_block(description_block);
-    f.render_widget(&description_textarea, chunks[3]);
+    draw_text_field(
+        f,
+        chunks[3],
+        ui_state,
+        CommitField::Description,
+        description_block,
+    );
 
     let breaking_change_block = Block::default()
         .borders(Borders::ALL)
-        .title("Breaking Change")
+        .title(field_title(CommitField::BreakingChange, "Breaking Change"))
         .border_style(ui_state.get_field_border_style(&CommitField::BreakingChange));
-    let mut breaking_change_textarea = ui_state.breaking_change_textarea.clone();
-    breaking_change_textarea.set_block(breaking_change_block);
-    f.render_widget(&breaking_change_textarea, chunks[4]);
+    draw_text_field(
+        f,
+        chunks[4],
+        ui_state,
+        CommitField::BreakingChange,
+        breaking_change_block,
+    );
 
     let test_details_block = Block::default()
         .borders(Borders::ALL)
-        .title("Test Details (multiline, auto-filled by 't' AI analysis)")
+        .title(field_title(
+            CommitField::TestDetails,
+            "Test Details (multiline, auto-filled by 't' AI analysis)",
+        ))
         .border_style(ui_state.get_field_border_style(&CommitField::TestDetails));
-    let mut test_details_textarea = ui_state.test_details_textarea.clone();
-    test_details_textarea.set_block(test_details_block);
-    f.render_widget(&test_details_textarea, chunks[5]);
+    draw_text_field(
+        f,
+        chunks[5],
+        ui_state,
+        CommitField::TestDetails,
+        test_details_block,
+    );
 
     let security_block = Block::default()
         .borders(Borders::ALL)
-        .title("Security (multiline)")
+        .title(field_title(CommitField::Security, "Security (multiline)"))
         .border_style(ui_state.get_field_border_style(&CommitField::Security));
-    let mut security_textarea = ui_state.security_textarea.clone();
-    security_textarea.set_block(security_block);
-    f.render_widget(&security_textarea, chunks[6]);
+    draw_text_field(
+        f,
+        chunks[6],
+        ui_state,
+        CommitField::Security,
+        security_block,
+    );
 
     let migraciones_lentas_block = Block::default()
         .borders(Borders::ALL)
         .title("Migraciones Lentas (multiline)")
         .border_style(ui_state.get_field_border_style(&CommitField::MigracionesLentas));
-    let mut migraciones_lentas_textarea = ui_state.migraciones_lentas_textarea.clone();
-    migraciones_lentas_textarea.set_block(migraciones_lentas_block);
-    f.render_widget(&migraciones_lentas_textarea, chunks[7]);
+    draw_text_field(
+        f,
+        chunks[7],
+        ui_state,
+        CommitField::MigracionesLentas,
+        migraciones_lentas_block,
+    );
 
     let partes_a_ejecutar_block = Block::default()
         .borders(Borders::ALL)
         .title("Partes a Ejecutar (multiline)")
         .border_style(ui_state.get_field_border_style(&CommitField::PartesAEjecutar));
-    let mut partes_a_ejecutar_textarea = ui_state.partes_a_ejecutar_textarea.clone();
-    partes_a_ejecutar_textarea.set_block(partes_a_ejecutar_block);
-    f.render_widget(&partes_a_ejecutar_textarea, chunks[8]);
+    draw_text_field(
+        f,
+        chunks[8],
+        ui_state,
+        CommitField::PartesAEjecutar,
+        partes_a_ejecutar_block,
+    );
 
     // Instructions
-    let instructions = if ui_state.input_mode == InputMode::Editing {
+    let instructions = if ui_state.input_mode == InputMode::Editing
+        && ui_state.ghost_text(&ui_state.current_field).is_some()
+    {
+        "🤖 Sugerencia de IA: Tab la acepta entera, Ctrl+→ palabra a palabra; sigue escribiendo para ignorarla".to_string()
+    } else if ui_state.input_mode == InputMode::Editing {
         if UIState::is_multiline_field(&ui_state.current_field) {
-            "🔤 EDITING MULTILINE - Advanced text editing with TextArea, Tab/arrows to save & move, Esc to cancel"
+            "🔤 EDITING MULTILINE - Advanced text editing with TextArea, Tab/arrows to save & move, Esc to cancel".to_string()
         } else {
-            "🔤 EDITING SINGLE LINE - Advanced text editing with TextArea, Tab/arrows to save & move, Esc to cancel"
+            "🔤 EDITING SINGLE LINE - Advanced text editing with TextArea, Tab/arrows to save & move, Esc to cancel".to_string()
         }
+    } else if CommitField::AI_FILLED
+        .iter()
+        .any(|field| ui_state.is_ai_filled(field, commit_form))
+    {
+        "🤖 Campos rellenados por IA: 'u' revierte a lo que había antes, 'i' muestra el resultado del análisis, 'c' commit, 'q' quit".to_string()
     } else {
-        "📋 Navigation: Tab/Shift+Tab to move & edit, ↑↓ for commit type/tasks, 's' Monday.com/'j' JIRA search, 't' AI analysis, 'm' manage tasks, 'c' commit, 'q' quit"
+        "📋 Navigation: Tab/Shift+Tab to move & edit, ↑↓ for commit type/tasks, 's' Monday.com/'j' JIRA search, 't' AI analysis, 'm' manage tasks, '/' filter tasks, 'c' commit, 'q' quit".to_string()
     };
     let instructions_widget = Paragraph::new(instructions)
         .block(
@@ -147,26 +217,135 @@ pub fn draw_commit_screen(f: &mut Frame, area: Rect, ui_state: &UIState, commit_
         .wrap(Wrap { trim: true });
     f.render_widget(instructions_widget, chunks[9]);
 
-    // Render selected tasks if any
-    if !commit_form.selected_tasks.is_empty() {
-        let task_items: Vec<ListItem> = commit_form
+    draw_selected_tasks(f, chunks[10], ui_state, commit_form);
+}
+
+/// A form field's textarea, or, while it has an AI suggestion (see
+/// `UIState::ghost_text`), the typed text followed by the rest of the
+/// suggestion dimmed, with the cursor where the typed text ends.
+fn draw_text_field(
+    f: &mut Frame,
+    area: Rect,
+    ui_state: &UIState,
+    field: CommitField,
+    block: Block,
+) {
+    let Some(textarea) = ui_state.get_textarea(&field) else {
+        return;
+    };
+    let block = if UIState::is_multiline_field(&field) {
+        let focused = ui_state.current_field == field;
+        match cursor_position_title(textarea, focused) {
+            Some(position) => block.title_bottom(position),
+            None => block,
+        }
+    } else {
+        block
+    };
+    let Some(ghost) = ui_state.ghost_text(&field) else {
+        let mut textarea = textarea.clone();
+        textarea.set_block(block);
+        f.render_widget(&textarea, area);
+        return;
+    };
+
+    let ghost_style = Style::default()
+        .fg(Color::DarkGray)
+        .add_modifier(Modifier::ITALIC);
+    let mut lines: Vec<Line> = textarea
+        .lines()
+        .iter()
+        .map(|line| Line::from(line.clone()))
+        .collect();
+    let cursor_row = lines.len().saturating_sub(1);
+    let mut ghost_lines = ghost.split('\n');
+    let first = ghost_lines.next().unwrap_or_default();
+    if let Some(last) = lines.last_mut() {
+        let editing = ui_state.input_mode == InputMode::Editing && ui_state.current_field == field;
+        let mut chars = first.chars();
+        match chars.next().filter(|_| editing) {
+            Some(under_cursor) => {
+                last.push_span(Span::styled(
+                    under_cursor.to_string(),
+                    ghost_style.add_modifier(Modifier::REVERSED),
+                ));
+                last.push_span(Span::styled(chars.as_str().to_string(), ghost_style));
+            }
+            None if editing => last.push_span(Span::styled(
+                " ",
+                Style::default().add_modifier(Modifier::REVERSED),
+            )),
+            None => last.push_span(Span::styled(first.to_string(), ghost_style)),
+        }
+    }
+    lines.extend(ghost_lines.map(|line| Line::styled(line.to_string(), ghost_style)));
+
+    // Keep the cursor row in view, as the textarea would
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let scroll = (cursor_row + 1).saturating_sub(inner_height) as u16;
+    f.render_widget(Paragraph::new(lines).block(block).scroll((scroll, 0)), area);
+}
+
+/// Editor-like position for the bottom-right corner of a multi-line field:
+/// `Ln 3/24, Col 12 · 540 chars` while focused, only the totals otherwise.
+/// `None` for an empty field.
+fn cursor_position_title(textarea: &TextArea, focused: bool) -> Option<Line<'static>> {
+    let lines = textarea.lines();
+    let chars: usize = lines.iter().map(|line| line.chars().count()).sum();
+    if chars == 0 && !focused {
+        return None;
+    }
+    let (row, col) = textarea.cursor();
+    let text = if focused {
+        format!(
+            " Ln {}/{}, Col {} · {} chars ",
+            row + 1,
+            lines.len(),
+            col + 1,
+            chars
+        )
+    } else {
+        let unit = if lines.len() == 1 { "line" } else { "lines" };
+        format!(" {} {} · {} chars ", lines.len(), unit, chars)
+    };
+    Some(
+        Line::from(text)
+            .right_aligned()
+            .style(Style::default().fg(Color::DarkGray)),
+    )
+}
+
+/// Selected tasks list, narrowed by the live filter typed with '/'. The
+/// highlighted row is `selected_tab`, an index into the filtered list.
+fn draw_selected_tasks(f: &mut Frame, area: Rect, ui_state: &UIState, commit_form: &CommitForm) {
+    let border_style = ui_state.get_field_border_style(&CommitField::SelectedTasks);
+
+    // (label, fields matched by the filter) for whichever task system is in use
+    let tasks: Vec<(String, Vec<&str>)> = if !commit_form.selected_jira_tasks.is_empty() {
+        commit_form
+            .selected_jira_tasks
+            .iter()
+            .map(|task| {
+                (
+                    format!("• {} {}", task.key, task.summary),
+                    vec![task.key.as_str(), task.id.as_str(), task.summary.as_str()],
+                )
+            })
+            .collect()
+    } else {
+        commit_form
             .selected_tasks
             .iter()
-            .map(|task| ListItem::new(format!("• {}", task.title)))
-            .collect();
+            .map(|task| {
+                (
+                    format!("• {}", task.title),
+                    vec![task.id.as_str(), task.title.as_str()],
+                )
+            })
+            .collect()
+    };
 
-        let selected_task_list = List::new(task_items).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!(
-                    "Selected Tasks ({})",
-                    commit_form.selected_tasks.len()
-                ))
-                .border_style(ui_state.get_field_border_style(&CommitField::SelectedTasks)),
-        );
-
-        f.render_widget(selected_task_list, chunks[10]);
-    } else {
+    if tasks.is_empty() {
         let no_tasks = Paragraph::new(
             "No tasks selected. Use 's' for Monday.com or 'j' for JIRA search to add tasks.",
         )
@@ -174,11 +353,66 @@ pub fn draw_commit_screen(f: &mut Frame, area: Rect, ui_state: &UIState, commit_
             Block::default()
                 .borders(Borders::ALL)
                 .title("Selected Tasks (0)")
-                .border_style(ui_state.get_field_border_style(&CommitField::SelectedTasks)),
+                .border_style(border_style),
         )
         .style(Style::default().fg(Color::DarkGray));
-        f.render_widget(no_tasks, chunks[10]);
+        f.render_widget(no_tasks, area);
+        return;
     }
+
+    let task_items: Vec<ListItem> = tasks
+        .iter()
+        .filter(|(_, fields)| ui_state.matches_selected_tasks_filter(fields))
+        .map(|(label, _)| ListItem::new(label.clone()))
+        .collect();
+    let visible = task_items.len();
+
+    let title = if ui_state.editing_selected_tasks_filter {
+        format!(
+            "Selected Tasks ({}/{}) 🔍 {}█",
+            visible,
+            tasks.len(),
+            ui_state.selected_tasks_filter
+        )
+    } else if !ui_state.selected_tasks_filter.is_empty() {
+        format!(
+            "Selected Tasks ({}/{}) 🔍 {} (Esc to clear)",
+            visible,
+            tasks.len(),
+            ui_state.selected_tasks_filter
+        )
+    } else {
+        format!("Selected Tasks ({}) - '/' to filter", tasks.len())
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(border_style);
+
+    if task_items.is_empty() {
+        let no_matches = Paragraph::new(format!(
+            "No selected tasks match '{}'",
+            ui_state.selected_tasks_filter
+        ))
+        .block(block)
+        .style(Style::default().fg(Color::DarkGray));
+        f.render_widget(no_matches, area);
+        return;
+    }
+
+    let focused =
+        ui_state.task_management_mode || ui_state.current_field == CommitField::SelectedTasks;
+    let mut list_state = ListState::default();
+    if focused {
+        list_state.select(Some(ui_state.selected_tab.min(visible - 1)));
+    }
+
+    let selected_task_list = List::new(task_items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_stateful_widget(selected_task_list, area, &mut list_state);
 }
 
 pub fn draw_commit_preview_screen(f: &mut Frame, area: Rect, ui_state: &UIState) {
@@ -190,20 +424,204 @@ pub fn draw_commit_preview_screen(f: &mut Frame, area: Rect, ui_state: &UIState)
         ])
         .split(area);
 
-    // Instructions
-    let instructions =
+    // Instructions, replaced by a warning while a merge/rebase/... is in progress
+    let instructions = if ui_state.repo_state.is_clean() {
         Paragraph::new("📋 Commit Preview: Edit message above, 'c' to commit, Esc to go back")
             .block(Block::default().borders(Borders::ALL).title("Instructions"))
             .style(Style::default().fg(Color::Cyan))
-            .wrap(Wrap { trim: true });
+    } else {
+        Paragraph::new(format!(
+            "⚠️ {}: {}",
+            ui_state.repo_state.description(),
+            ui_state.repo_state.hint()
+        ))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Repository not clean"),
+        )
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    }
+    .wrap(Wrap { trim: true });
     f.render_widget(instructions, chunks[0]);
 
+    let editor_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
     // Commit message editor using TextArea
     let editor_block = Block::default()
         .borders(Borders::ALL)
         .title("Commit Message Editor")
         .border_style(Style::default().fg(Color::Green));
+    let editor_block = match cursor_position_title(&ui_state.commit_preview_textarea, true) {
+        Some(position) => editor_block.title_bottom(position),
+        None => editor_block,
+    };
     let mut commit_editor_textarea = ui_state.commit_preview_textarea.clone();
     commit_editor_textarea.set_block(editor_block);
-    f.render_widget(&commit_editor_textarea, chunks[1]);
+    f.render_widget(&commit_editor_textarea, editor_chunks[0]);
+
+    draw_highlighted_commit_message(f, editor_chunks[1], ui_state);
+}
+
+/// Live, highlighted view of the message being edited. Only the lines that fit
+/// on screen (following the cursor) are parsed, so long messages stay cheap.
+fn draw_highlighted_commit_message(f: &mut Frame, area: Rect, ui_state: &UIState) {
+    let lines = ui_state.commit_preview_textarea.lines();
+    let (cursor_row, _) = ui_state.commit_preview_textarea.cursor();
+    let visible = (area.height as usize).saturating_sub(2).max(1);
+    let width = area.width.saturating_sub(2).max(1);
+
+    let header_ok = lines
+        .first()
+        .is_some_and(|header| parse_commit_header(header).is_some());
+    let (title, border_color) = if header_ok {
+        ("🎨 Vista resaltada - ✅ Formato convencional", Color::Green)
+    } else {
+        (
+            "🎨 Vista resaltada - ⚠️ La cabecera debe ser tipo(scope): título",
+            Color::Red,
+        )
+    };
+
+    let highlight = |index: usize| {
+        if index == 0 {
+            highlight_commit_header(&lines[index])
+        } else {
+            highlight_commit_body_line(&lines[index])
+        }
+    };
+
+    // Screen rows taken by a line: one unless wrapping, where a long line takes several
+    let wrap = Wrap { trim: false };
+    let rows = |index: usize| {
+        if ui_state.commit_preview_wrap {
+            Paragraph::new(highlight(index))
+                .wrap(wrap)
+                .line_count(width)
+                .max(1)
+        } else {
+            1
+        }
+    };
+
+    // Scroll by screen rows so the cursor line stays fully visible
+    let mut first = cursor_row.min(lines.len().saturating_sub(1));
+    let mut used = rows(first);
+    while first > 0 && used + rows(first - 1) <= visible {
+        first -= 1;
+        used += rows(first);
+    }
+
+    let mut highlighted: Vec<Line> = Vec::new();
+    let mut used = 0;
+    for index in first..lines.len() {
+        if used >= visible {
+            break;
+        }
+        used += rows(index);
+        highlighted.push(highlight(index));
+    }
+
+    let mut preview = Paragraph::new(highlighted).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(Style::default().fg(border_color)),
+    );
+    if ui_state.commit_preview_wrap {
+        preview = preview.wrap(wrap);
+    }
+    f.render_widget(preview, area);
+}
+
+/// Trailers written by the commit form, with the colour of their label.
+const COMMIT_TRAILERS: [(&str, Color); 9] = [
+    ("BREAKING CHANGE:", Color::Red),
+    ("Test Details:", Color::Green),
+    ("Security:", Color::Yellow),
+    ("Migraciones Lentas:", Color::Magenta),
+    ("Partes a Ejecutar:", Color::Magenta),
+    ("MONDAY TASKS:", Color::Blue),
+    ("JIRA TASKS:", Color::Blue),
+    ("RELATED TASKS:", Color::Blue),
+    ("Refs:", Color::Blue),
+];
+
+/// Split a `type(scope)!: title` header into its parts.
+fn parse_commit_header(header: &str) -> Option<(&str, Option<&str>, bool, &str)> {
+    let (prefix, title) = header.split_once(": ")?;
+    let (prefix, breaking) = match prefix.strip_suffix('!') {
+        Some(prefix) => (prefix, true),
+        None => (prefix, false),
+    };
+    let (commit_type, scope) = match prefix.split_once('(') {
+        Some((commit_type, rest)) => (commit_type, Some(rest.strip_suffix(')')?)),
+        None => (prefix, None),
+    };
+
+    CommitType::parse(commit_type)?;
+    Some((commit_type, scope, breaking, title))
+}
+
+fn highlight_commit_header(header: &str) -> Line<'_> {
+    let Some((commit_type, scope, breaking, title)) = parse_commit_header(header) else {
+        return Line::from(Span::styled(header, Style::default().fg(Color::Red)));
+    };
+
+    let punctuation = Style::default().fg(Color::DarkGray);
+    let mut spans = vec![Span::styled(
+        commit_type,
+        Style::default()
+            .fg(Color::Magenta)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(scope) = scope {
+        spans.push(Span::styled("(", punctuation));
+        spans.push(Span::styled(scope, Style::default().fg(Color::Cyan)));
+        spans.push(Span::styled(")", punctuation));
+    }
+    if breaking {
+        spans.push(Span::styled(
+            "!",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ));
+    }
+    spans.push(Span::styled(": ", punctuation));
+    spans.push(Span::styled(
+        title,
+        Style::default()
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    ));
+    Line::from(spans)
+}
+
+fn highlight_commit_body_line(line: &str) -> Line<'_> {
+    for (trailer, color) in COMMIT_TRAILERS {
+        if let Some(rest) = line.strip_prefix(trailer) {
+            return Line::from(vec![
+                Span::styled(
+                    trailer,
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(rest, Style::default().fg(Color::White)),
+            ]);
+        }
+    }
+
+    if let Some(rest) = line.strip_prefix("- ") {
+        return Line::from(vec![
+            Span::styled("- ", Style::default().fg(Color::DarkGray)),
+            Span::styled(rest, Style::default().fg(Color::Gray)),
+        ]);
+    }
+
+    Line::from(Span::styled(line, Style::default().fg(Color::White)))
 }
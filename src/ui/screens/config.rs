@@ -1,34 +1,120 @@
 use ratatui::{
-    layout::Rect,
-    text::Line,
-    widgets::{Block, Borders, Paragraph, Wrap},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-pub fn draw_config_screen(f: &mut Frame, area: Rect) {
-    let content = Paragraph::new(vec![
-        Line::from("⚙️ Configuration"),
-        Line::from(""),
-        Line::from("Use 'semantic-release-tui config' command to configure:"),
-        Line::from(""),
-        Line::from("📋 Task Management System (choose one):"),
-        Line::from("  • Monday.com (API Key, Account Slug, Board ID)"),
-        Line::from("  • JIRA (URL, Username, API Token, Project Key)"),
-        Line::from(""),
-        Line::from("🤖 AI Integration (optional):"),
-        Line::from("  • Google Gemini API Token"),
-        Line::from(""),
-        Line::from("Configuration is stored in .env file"),
-        Line::from("Monday.com and JIRA are mutually exclusive"),
-        Line::from(""),
-        Line::from("Press 'q' to go back to main menu."),
-    ])
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title("Configuration"),
-    )
-    .wrap(Wrap { trim: true });
-
-    f.render_widget(content, area);
+use crate::{
+    config::validate_config,
+    types::{ConfigWarning, ConfigWarningSeverity},
+    ui::state::{ConfigEditor, UIState},
+};
+
+pub fn draw_config_screen(f: &mut Frame, area: Rect, ui_state: &UIState) {
+    let editor = &ui_state.config_editor;
+    let fields = editor.fields();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(fields.len() as u16 + 2),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let warnings = validate_config(&editor.to_config());
+    let selected = editor.selected_field();
+    let items: Vec<ListItem> = fields
+        .iter()
+        .map(|field| {
+            let value = match &editor.input {
+                Some(input) if *field == selected => {
+                    let typed = if field.is_secret() {
+                        "•".repeat(input.chars().count())
+                    } else {
+                        input.clone()
+                    };
+                    Span::styled(format!("{}▏", typed), Style::default().fg(Color::Yellow))
+                }
+                _ => Span::raw(editor.display_value(*field)),
+            };
+            // Fields with a problem are marked; the details list what it is
+            let has_problem = warnings
+                .iter()
+                .any(|warning| warning.setting == field.setting());
+            let label_color = if has_problem { Color::Red } else { Color::Cyan };
+            ListItem::new(Line::from(vec![
+                Span::raw(if has_problem { "⚠ " } else { "  " }),
+                Span::styled(
+                    format!("{:<26}", field.label()),
+                    Style::default().fg(label_color),
+                ),
+                value,
+            ]))
+        })
+        .collect();
+
+    let title = if editor.dirty {
+        "⚙️ Configuración (cambios sin guardar)"
+    } else {
+        "⚙️ Configuración"
+    };
+    let mut list_state = ListState::default();
+    list_state.select(Some(editor.selected.min(fields.len() - 1)));
+    f.render_stateful_widget(
+        List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        chunks[0],
+        &mut list_state,
+    );
+
+    let details = Paragraph::new(detail_lines(editor, &warnings))
+        .block(Block::default().borders(Borders::ALL).title("Detalles"))
+        .wrap(Wrap { trim: true });
+    f.render_widget(details, chunks[1]);
+}
+
+/// Hints for the selected field and the problems of the edited settings.
+fn detail_lines(editor: &ConfigEditor, warnings: &[ConfigWarning]) -> Vec<Line<'static>> {
+    let field = editor.selected_field();
+    let mut lines = vec![Line::from(if editor.is_editing() {
+        "Enter para confirmar, Esc para cancelar".to_string()
+    } else if field.is_choice() {
+        "Enter cambia de opción".to_string()
+    } else if field.is_secret() {
+        "Enter para escribir un valor nuevo; vacío conserva el actual".to_string()
+    } else {
+        "Enter para editar; vacío elimina el valor".to_string()
+    })];
+    lines.push(Line::from(
+        "Se guarda en el .env del perfil activo ('s'); las credenciales siguen en el keyring si ya estaban ahí. Monday.com y JIRA son excluyentes: al guardar se eliminan los datos del sistema no elegido.",
+    ));
+    lines.push(Line::from(""));
+
+    if warnings.is_empty() {
+        lines.push(Line::from("✅ No configuration problems detected"));
+    } else {
+        lines.push(Line::from("⚠️ Configuration problems:"));
+        for warning in warnings {
+            let (label, color) = match warning.severity {
+                ConfigWarningSeverity::Incomplete => ("incomplete", Color::Red),
+                ConfigWarningSeverity::Suspicious => ("check", Color::Yellow),
+            };
+            lines.push(
+                Line::from(format!(
+                    "  • [{}] {}: {}",
+                    label, warning.setting, warning.message
+                ))
+                .style(Style::default().fg(color)),
+            );
+        }
+    }
+    lines
 }
@@ -0,0 +1,92 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::git::DiffLineKind;
+use crate::ui::state::{DiffRow, UIState};
+
+pub fn draw_staged_diff_screen(f: &mut Frame, area: Rect, ui_state: &UIState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area);
+
+    let view = &ui_state.staged_diff;
+    let (additions, deletions) = view.files.iter().fold((0, 0), |(added, removed), file| {
+        (added + file.additions, removed + file.deletions)
+    });
+    let title = format!(
+        "🔍 Staged changes - {} files, +{} -{} (row {}/{})",
+        view.files.len(),
+        additions,
+        deletions,
+        (view.scroll + 1).min(view.total_rows()),
+        view.total_rows()
+    );
+
+    // Only the rows that fit are built; lines are not wrapped so rows map to lines
+    let height = chunks[0].height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = if view.files.is_empty() {
+        vec![Line::styled(
+            "No staged changes. Stage files with 'a' on the commit screen.",
+            Style::default().fg(Color::DarkGray),
+        )]
+    } else {
+        view.visible_rows(height)
+            .into_iter()
+            .map(diff_row)
+            .collect()
+    };
+
+    f.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title)),
+        chunks[0],
+    );
+
+    let instructions = Paragraph::new(
+        "📋 ↑↓/PgUp/PgDn scroll, Tab/Shift+Tab next/previous file, Enter collapse file, 'c' collapse all, 'q' back",
+    )
+    .block(Block::default().borders(Borders::ALL).title("Instructions"))
+    .style(Style::default().fg(Color::Cyan))
+    .wrap(Wrap { trim: true });
+    f.render_widget(instructions, chunks[1]);
+}
+
+fn diff_row(row: DiffRow) -> Line<'static> {
+    match row {
+        DiffRow::File { file, collapsed } => Line::from(vec![
+            Span::raw(if collapsed { "▶ " } else { "▼ " }),
+            Span::styled(
+                file.path.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  +{}", file.additions),
+                Style::default().fg(Color::Green),
+            ),
+            Span::styled(
+                format!(" -{}", file.deletions),
+                Style::default().fg(Color::Red),
+            ),
+        ]),
+        DiffRow::Line(line) => {
+            let (prefix, style) = match line.kind {
+                DiffLineKind::Hunk => ("", Style::default().fg(Color::Magenta)),
+                DiffLineKind::Added => ("+", Style::default().fg(Color::Green)),
+                DiffLineKind::Removed => ("-", Style::default().fg(Color::Red)),
+                DiffLineKind::Context => (" ", Style::default()),
+            };
+            // Tabs would throw off the terminal columns
+            Line::styled(
+                format!("{}{}", prefix, line.text.replace('\t', "    ")),
+                style,
+            )
+        }
+    }
+}
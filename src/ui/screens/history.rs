@@ -0,0 +1,149 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::types::{CommitType, GitCommit};
+use crate::ui::screens::semantic_release::commit_type_color;
+use crate::ui::state::UIState;
+
+pub fn draw_history_screen(f: &mut Frame, area: Rect, ui_state: &UIState, commits: &[GitCommit]) {
+    let constraints = if ui_state.history_show_message {
+        vec![
+            Constraint::Percentage(50),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ]
+    } else {
+        vec![
+            Constraint::Min(0),
+            Constraint::Length(0),
+            Constraint::Length(3),
+        ]
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+
+    let non_conventional = commits.iter().filter(|c| c.commit_type.is_none()).count();
+    let title = if non_conventional > 0 {
+        format!(
+            "📜 Recent Commits ({}) - ⚠️ {} not following the convention",
+            commits.len(),
+            non_conventional
+        )
+    } else {
+        format!("📜 Recent Commits ({})", commits.len())
+    };
+
+    if commits.is_empty() {
+        let empty = Paragraph::new("No commits found in this repository.")
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(Style::default().fg(Color::DarkGray));
+        f.render_widget(empty, chunks[0]);
+    } else {
+        let items: Vec<ListItem> = commits.iter().map(history_item).collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ");
+        let mut list_state = ListState::default()
+            .with_selected(Some(ui_state.history_selected.min(commits.len() - 1)));
+        f.render_stateful_widget(list, chunks[0], &mut list_state);
+    }
+
+    if ui_state.history_show_message {
+        if let Some(commit) = commits.get(ui_state.history_selected) {
+            let mut message = commit.subject.clone();
+            if !commit.body.trim().is_empty() {
+                message.push_str("\n\n");
+                message.push_str(&commit.body);
+            }
+            let detail = Paragraph::new(message)
+                .block(Block::default().borders(Borders::ALL).title(format!(
+                    "Commit {} - {} ({})",
+                    short_hash(&commit.hash),
+                    commit.author,
+                    commit.date
+                )))
+                .wrap(Wrap { trim: false });
+            f.render_widget(detail, chunks[1]);
+        }
+    }
+
+    let instructions = Paragraph::new(
+        "📋 ↑↓ navigate, Enter show/hide full message, 'a' amend (latest commit only), 'r' reload, 'q' back",
+    )
+    .block(Block::default().borders(Borders::ALL).title("Instructions"))
+    .style(Style::default().fg(Color::Cyan))
+    .wrap(Wrap { trim: true });
+    f.render_widget(instructions, chunks[2]);
+}
+
+/// One row: hash, date, `type(scope)`, description and linked tasks. Commits
+/// without a known conventional type are flagged so the history can be audited.
+fn history_item(commit: &GitCommit) -> ListItem<'static> {
+    let mut spans = vec![
+        Span::styled(
+            format!("{} ", short_hash(&commit.hash)),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            format!("{} ", commit.date),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ];
+
+    match commit.commit_type.as_deref().and_then(CommitType::parse) {
+        Some(commit_type) => {
+            let header = match &commit.scope {
+                Some(scope) => format!("{}({}): ", commit_type.as_str(), scope),
+                None => format!("{}: ", commit_type.as_str()),
+            };
+            spans.push(Span::styled(
+                header,
+                Style::default()
+                    .fg(commit_type_color(&commit_type))
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::raw(commit.description.clone()));
+        }
+        None => {
+            spans.push(Span::styled(
+                "⚠️ ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                commit.subject.clone(),
+                Style::default().fg(Color::Red),
+            ));
+        }
+    }
+
+    let tasks: Vec<&str> = commit
+        .monday_tasks
+        .iter()
+        .chain(&commit.jira_tasks)
+        .map(String::as_str)
+        .collect();
+    if !tasks.is_empty() {
+        spans.push(Span::styled(
+            format!("  🔗 {}", tasks.join(", ")),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+
+    ListItem::new(Line::from(spans))
+}
+
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(7)]
+}
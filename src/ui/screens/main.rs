@@ -20,6 +20,7 @@ pub fn draw_main_screen(
         "📝 Release Notes",
         "🚀 Semantic Release",
         "⚙️ Config",
+        "📜 History",
         "📋 Help",
     ])
     .block(Block::default().borders(Borders::ALL).title("Menu"))
@@ -185,6 +186,16 @@ pub fn draw_main_screen(
                 .add_modifier(Modifier::BOLD),
         )]));
 
+        if !status.state.is_clean() {
+            content_lines.push(Line::from(vec![
+                Span::styled(
+                    format!("⚠️ {}: ", status.state.description()),
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(status.state.hint(), Style::default().fg(Color::Yellow)),
+            ]));
+        }
+
         if !status.staged.is_empty() {
             content_lines.push(Line::from(vec![
                 Span::styled("✅ ", Style::default().fg(Color::Green)),
@@ -414,6 +425,18 @@ pub fn draw_main_screen(
                 Style::default().fg(Color::White),
             ),
         ]),
+        Line::from(vec![
+            Span::styled(
+                "📜 History: ",
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "Browse recent commits and amend the latest one",
+                Style::default().fg(Color::White),
+            ),
+        ]),
         Line::from(vec![
             Span::styled(
                 "⚙️ Config: ",
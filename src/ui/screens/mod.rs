@@ -1,5 +1,7 @@
 pub mod commit;
 pub mod config;
+pub mod diff;
+pub mod history;
 pub mod main;
 pub mod release_notes;
 pub mod semantic_release;
@@ -7,6 +9,8 @@ pub mod tasks;
 
 pub use commit::{draw_commit_preview_screen, draw_commit_screen};
 pub use config::draw_config_screen;
+pub use diff::draw_staged_diff_screen;
+pub use history::draw_history_screen;
 pub use main::draw_main_screen;
 pub use release_notes::draw_release_notes_screen;
 pub use tasks::draw_task_search_screen;
@@ -1,24 +1,62 @@
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
     text::Line,
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
 
-pub fn draw_release_notes_screen(f: &mut Frame, area: Rect) {
+use crate::{types::AppConfig, ui::UIState};
+
+pub fn draw_release_notes_screen(
+    f: &mut Frame,
+    area: Rect,
+    ui_state: &UIState,
+    config: &AppConfig,
+) {
+    let package_line = match &ui_state.release_notes_path_filter {
+        Some(path) => format!("📦 Package filter: {}", path),
+        None if !config.release_packages.is_empty() => format!(
+            "📦 Packages: {} (separate notes per package)",
+            config
+                .release_packages
+                .iter()
+                .map(|package| format!("{} ({}*)", package.path, package.tag_prefix))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        None => "📦 Package filter: none (whole repository)".to_string(),
+    };
+
     let content = Paragraph::new(vec![
         Line::from("📝 Release Notes Generation"),
         Line::from(""),
         Line::from("This feature will:"),
-        Line::from("1. Analyze commits since the last version tag"),
+        Line::from(match ui_state.release_notes_since {
+            Some(since) => format!(
+                "1. Analyze commits since {}",
+                since.format("%Y-%m-%d %H:%M UTC")
+            ),
+            None => "1. Analyze commits since the last version tag".to_string(),
+        }),
         Line::from("2. Extract Monday.com task information"),
         Line::from("3. Generate structured release notes using AI"),
         Line::from(""),
-        Line::from("Generated files will be saved in the release-notes/ directory:"),
-        Line::from("• release-notes-YYYY-MM-DD.md - Raw data"),
-        Line::from("• release-notes-YYYY-MM-DD_GEMINI.md - AI generated notes"),
+        Line::from(format!(
+            "Generated files will be saved in the {}/ directory:",
+            config.release_notes_dir().display()
+        )),
+        Line::from("• release-notes-YYYY-MM-DD_HHMMSS.md - Raw data"),
+        Line::from("• release-notes-YYYY-MM-DD_HHMMSS_GEMINI.md - AI generated notes"),
+        Line::from("• release-notes-YYYY-MM-DD_HHMMSS.html - HTML export"),
+        Line::from(""),
+        Line::from(package_line),
         Line::from(""),
-        Line::from("Press Enter to generate release notes"),
+        Line::from("Press Enter or 'i' to generate release notes (built-in)"),
+        Line::from("Press 'o' to run `npm run release-notes` instead"),
+        Line::from("Press 'p' to toggle the current package filter"),
+        Line::from("Press 'g' to retry only the Gemini step of the last generation"),
+        Line::from("Press ↑/↓/PgUp/PgDn to scroll the npm output, End to follow it"),
         Line::from("Press 'q' to go back to main menu"),
     ])
     .block(
@@ -28,5 +66,50 @@ pub fn draw_release_notes_screen(f: &mut Frame, area: Rect) {
     )
     .wrap(Wrap { trim: true });
 
-    f.render_widget(content, area);
+    if ui_state.script_output.is_empty() {
+        f.render_widget(content, area);
+        return;
+    }
+
+    // Keep the output below the centered loading overlay so it stays readable while streaming
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+    f.render_widget(content, chunks[0]);
+    draw_script_output(f, chunks[1], ui_state);
+}
+
+fn draw_script_output(f: &mut Frame, area: Rect, ui_state: &UIState) {
+    let output = &ui_state.script_output;
+    let visible = area.height.saturating_sub(2) as usize;
+    let end = output.len().saturating_sub(ui_state.script_output_scroll);
+    let start = end.saturating_sub(visible);
+
+    let lines: Vec<Line> = output[start..end]
+        .iter()
+        .map(|line| {
+            let style = if line.is_stderr {
+                Style::default().fg(Color::Red)
+            } else {
+                Style::default()
+            };
+            Line::styled(line.text.clone(), style)
+        })
+        .collect();
+
+    let title = if ui_state.script_output_scroll > 0 {
+        format!(
+            "npm run release-notes ({}-{} of {})",
+            start + 1,
+            end,
+            output.len()
+        )
+    } else {
+        format!("npm run release-notes ({} lines)", output.len())
+    };
+
+    let paragraph =
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(paragraph, area);
 }
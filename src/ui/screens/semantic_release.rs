@@ -10,7 +10,7 @@ use ratatui::{
 };
 
 use crate::{
-    types::{AppConfig, AppState, SemanticReleaseState},
+    types::{AppConfig, AppState, CommitType, SemanticReleaseState, VersionInfo, VersionType},
     ui::UIState,
 };
 
@@ -24,17 +24,8 @@ pub fn draw_semantic_release_screen(
     semantic_release_state: Option<&SemanticReleaseState>,
 ) {
     // Check if we have results to show
-    let has_results = semantic_release_state
-        .and_then(|state| {
-            state.finished.lock().ok().and_then(|finished| {
-                if *finished {
-                    state.result.lock().ok().map(|result| !result.is_empty())
-                } else {
-                    None
-                }
-            })
-        })
-        .unwrap_or(false);
+    let has_results =
+        semantic_release_state.is_some_and(|state| state.finished && !state.result.is_empty());
 
     if has_results {
         draw_with_results(
@@ -275,21 +266,9 @@ fn draw_results_panel(
         .split(area);
 
     // Get status and result
-    let status = semantic_release_state
-        .status
-        .lock()
-        .map(|s| s.clone())
-        .unwrap_or("Unknown".to_string());
-    let success = semantic_release_state
-        .success
-        .lock()
-        .map(|s| *s)
-        .unwrap_or(false);
-    let result = semantic_release_state
-        .result
-        .lock()
-        .map(|r| r.clone())
-        .unwrap_or("No output available".to_string());
+    let status = &semantic_release_state.status;
+    let success = semantic_release_state.success;
+    let result = &semantic_release_state.result;
 
     // Title with status indicator
     let (title_text, title_color) = if success {
@@ -308,6 +287,12 @@ fn draw_results_panel(
         .block(Block::default().borders(Borders::ALL));
     f.render_widget(title, chunks[0]);
 
+    // Version analysis gets a structured view instead of the plain text dump
+    if let Some(version_info) = &semantic_release_state.version_info {
+        draw_version_info_results(f, chunks[1], ui_state, version_info);
+        return;
+    }
+
     // Results content with scrolling
     let result_lines: Vec<Line> = result
         .lines()
@@ -345,15 +330,20 @@ fn draw_results_panel(
 
     f.render_widget(results_paragraph, chunks[1]);
 
+    let line_count = result.lines().count();
+    let viewport = (chunks[1].height as usize).saturating_sub(2);
+    ui_state.scroll_content_lines = line_count;
+    ui_state.scroll_viewport = viewport;
+
     // Optional: Add scrollbar if content is long
-    if result.lines().count() > (chunks[1].height as usize - 2) {
+    if line_count > viewport {
         let scrollbar = Scrollbar::default()
             .orientation(ScrollbarOrientation::VerticalRight)
             .begin_symbol(None)
             .end_symbol(None);
         let mut scrollbar_state = ScrollbarState::default()
-            .content_length(result.lines().count())
-            .viewport_content_length(chunks[1].height as usize - 2)
+            .content_length(line_count)
+            .viewport_content_length(viewport)
             .position(ui_state.scroll_offset);
 
         f.render_stateful_widget(
@@ -367,6 +357,173 @@ fn draw_results_panel(
     }
 }
 
+fn draw_version_info_results(
+    f: &mut Frame,
+    area: ratatui::layout::Rect,
+    ui_state: &mut UIState,
+    version_info: &VersionInfo,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7), // Summary
+            Constraint::Min(5),    // Dry-run output
+        ])
+        .split(area);
+
+    // Summary
+    let label = Style::default().fg(Color::Gray);
+    let version_color = version_type_color(&version_info.version_type);
+    let summary_lines = vec![
+        Line::from(vec![
+            Span::styled("🏷️  Versión actual: ", label),
+            Span::styled(
+                version_info
+                    .current_version
+                    .as_deref()
+                    .unwrap_or("Sin versiones anteriores"),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("🚀 Próxima versión: ", label),
+            Span::styled(
+                version_info.next_version.as_str(),
+                Style::default()
+                    .fg(version_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("📊 Tipo de release: ", label),
+            Span::styled(
+                version_info.version_type.to_string(),
+                Style::default().fg(version_color),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("📈 Commits desde última versión: ", label),
+            Span::styled(
+                version_info.commit_count.to_string(),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+        if version_info.has_unreleased_changes {
+            Line::from(Span::styled(
+                "✅ Hay cambios para publicar",
+                Style::default().fg(Color::Green),
+            ))
+        } else {
+            Line::from(Span::styled(
+                "⚠️  No hay cambios para publicar",
+                Style::default().fg(Color::Yellow),
+            ))
+        },
+    ];
+    let summary = Paragraph::new(summary_lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("📦 Información de versión"),
+    );
+    f.render_widget(summary, chunks[0]);
+
+    // Dry-run output, with the commits that drive the bump highlighted by type
+    let output_lines: Vec<Line> = version_info
+        .dry_run_output
+        .lines()
+        .map(|line| Line::from(Span::styled(line, dry_run_line_style(line))))
+        .collect();
+    let line_count = output_lines.len();
+
+    let output = Paragraph::new(output_lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🔍 Análisis detallado (↑/↓ PgUp/PgDn Home/End)"),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((ui_state.scroll_offset as u16, 0));
+    f.render_widget(output, chunks[1]);
+
+    let viewport = (chunks[1].height as usize).saturating_sub(2);
+    ui_state.scroll_content_lines = line_count;
+    ui_state.scroll_viewport = viewport;
+    if line_count > viewport {
+        let scrollbar = Scrollbar::default()
+            .orientation(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None);
+        let mut scrollbar_state = ScrollbarState::default()
+            .content_length(line_count)
+            .viewport_content_length(viewport)
+            .position(ui_state.scroll_offset);
+
+        f.render_stateful_widget(
+            scrollbar,
+            chunks[1].inner(ratatui::layout::Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
+}
+
+fn version_type_color(version_type: &VersionType) -> Color {
+    match version_type {
+        VersionType::Major => Color::Red,
+        VersionType::Minor => Color::Green,
+        VersionType::Patch => Color::Yellow,
+        VersionType::None => Color::DarkGray,
+    }
+}
+
+pub(crate) fn commit_type_color(commit_type: &CommitType) -> Color {
+    match commit_type {
+        CommitType::Feat => Color::Green,
+        CommitType::Fix => Color::Yellow,
+        CommitType::Perf => Color::Magenta,
+        CommitType::Refactor => Color::Blue,
+        CommitType::Revert => Color::LightRed,
+        CommitType::Docs | CommitType::Style | CommitType::Test | CommitType::Chore => Color::Cyan,
+    }
+}
+
+/// Style a semantic-release dry-run line: analysed commits are coloured by
+/// their conventional type and breaking changes stand out.
+fn dry_run_line_style(line: &str) -> Style {
+    if line.contains("BREAKING CHANGE") || line.contains("release type for the commit is major") {
+        return Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+    }
+
+    if let Some((_, subject)) = line.split_once("Analyzing commit: ") {
+        let type_end = subject.find(['(', ':', '!']).unwrap_or(subject.len());
+        let style = match CommitType::parse(&subject[..type_end]) {
+            Some(commit_type) => Style::default().fg(commit_type_color(&commit_type)),
+            None => Style::default().fg(Color::Gray),
+        };
+        return if subject[..subject.find(':').unwrap_or(0)].ends_with('!') {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            style
+        };
+    }
+
+    if line.contains("release type for the commit is minor") {
+        Style::default().fg(Color::Green)
+    } else if line.contains("release type for the commit is patch") {
+        Style::default().fg(Color::Yellow)
+    } else if line.contains("Analysis of") || line.contains("next release version") {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else if line.contains("error") || line.contains("Error") || line.contains("ERROR") {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default().fg(Color::White)
+    }
+}
+
 fn create_semantic_release_options(selected: usize, _config: &AppConfig) -> Vec<ListItem> {
     let options = vec![
         (
@@ -395,8 +552,8 @@ fn create_semantic_release_options(selected: usize, _config: &AppConfig) -> Vec<
             true,
         ),
         (
-            "🔧 Setup GitHub Actions",
-            "Configure GitHub Actions for automated semantic-release",
+            "🔧 Setup CI",
+            "Configure GitHub Actions or GitLab CI (per the origin remote) for automated semantic-release",
             true,
         ),
     ];
@@ -8,6 +8,8 @@ use ratatui::{
 
 use crate::types::{AppConfig, CommitForm, JiraTask, MondayTask, TaskLike, TaskSystem};
 use crate::ui::state::{InputMode, UIState};
+use crate::utils::truncate_to_width;
+use unicode_width::UnicodeWidthStr;
 
 // =============================================================================
 // MAIN DRAW FUNCTION
@@ -16,7 +18,7 @@ use crate::ui::state::{InputMode, UIState};
 pub fn draw_task_search_screen(
     f: &mut Frame,
     area: Rect,
-    ui_state: &UIState,
+    ui_state: &mut UIState,
     monday_tasks: &[MondayTask],
     jira_tasks: &[JiraTask],
     config: &AppConfig,
@@ -32,7 +34,7 @@ pub fn draw_task_search_screen(
         .split(area);
 
     // Render each section
-    render_search_input(f, chunks[0], ui_state, config);
+    render_search_input(f, chunks[0], ui_state, config, commit_form);
     render_search_results(
         f,
         chunks[1],
@@ -49,13 +51,19 @@ pub fn draw_task_search_screen(
 // SEARCH INPUT SECTION
 // =============================================================================
 
-fn render_search_input(f: &mut Frame, area: Rect, ui_state: &UIState, config: &AppConfig) {
+fn render_search_input(
+    f: &mut Frame,
+    area: Rect,
+    ui_state: &UIState,
+    config: &AppConfig,
+    commit_form: &CommitForm,
+) {
     let task_system_name = get_task_system_name(config);
 
     let (search_title, search_style) = if ui_state.input_mode == InputMode::Editing {
         (
             format!(
-                "🔍 Search {} Tasks (Typing... Press Enter to search, Esc to stop)",
+                "🔍 Search {} Tasks (Typing... Enter to search, ↑↓ history, Esc to stop)",
                 task_system_name
             ),
             Style::default().fg(Color::Green),
@@ -70,9 +78,16 @@ fn render_search_input(f: &mut Frame, area: Rect, ui_state: &UIState, config: &A
         )
     };
 
+    // Fixed place for the selection count, whatever the results scroll to
+    let selected = selected_task_count(config, commit_form);
     let search_block = Block::default()
         .borders(Borders::ALL)
         .title(search_title.as_str())
+        .title(
+            Line::from(format!("✅ {} seleccionadas", selected))
+                .right_aligned()
+                .style(TaskItemStyles::selected_style(selected > 0)),
+        )
         .border_style(search_style);
 
     let mut search_textarea = ui_state.search_textarea.clone();
@@ -88,24 +103,70 @@ fn render_search_input(f: &mut Frame, area: Rect, ui_state: &UIState, config: &A
 fn render_search_results(
     f: &mut Frame,
     area: Rect,
-    ui_state: &UIState,
+    ui_state: &mut UIState,
     monday_tasks: &[MondayTask],
     jira_tasks: &[JiraTask],
     config: &AppConfig,
     commit_form: &CommitForm,
 ) {
+    // Columns available inside the borders
+    let width = area.width.saturating_sub(2) as usize;
     let (list_title, task_items) = match config.get_task_system() {
-        TaskSystem::Monday => build_monday_task_list(ui_state, monday_tasks, commit_form),
-        TaskSystem::Jira => build_jira_task_list(ui_state, jira_tasks, commit_form),
+        TaskSystem::Monday => build_monday_task_list(ui_state, monday_tasks, commit_form, width),
+        TaskSystem::Jira => build_jira_task_list(ui_state, jira_tasks, commit_form, width),
         TaskSystem::None => build_no_system_list(),
     };
 
-    let tasks_list =
-        List::new(task_items).block(Block::default().borders(Borders::ALL).title(list_title));
+    // Every result takes two rows (title + details) inside the borders
+    let visible = (area.height.saturating_sub(2) / SEARCH_RESULT_HEIGHT).max(1) as usize;
+    let total = task_items.len();
+    let offset = follow_focus(
+        ui_state.search_results_offset,
+        ui_state.focused_search_index,
+        visible,
+        total,
+    );
+    ui_state.search_results_offset = offset;
+
+    let mut block = Block::default().borders(Borders::ALL).title(list_title);
+    if offset > 0 {
+        block = block.title(
+            Line::from(format!("▲ {} más arriba", offset))
+                .right_aligned()
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    }
+    let below = total.saturating_sub(offset + visible);
+    if below > 0 {
+        block = block.title_bottom(
+            Line::from(format!("▼ {} más abajo", below))
+                .right_aligned()
+                .style(Style::default().fg(Color::Cyan)),
+        );
+    }
+
+    let tasks_list = List::new(task_items.into_iter().skip(offset).take(visible)).block(block);
 
     f.render_widget(tasks_list, area);
 }
 
+/// Rows taken by one search result
+const SEARCH_RESULT_HEIGHT: u16 = 2;
+
+/// Scroll offset that keeps `focused` within a window of `visible` items,
+/// moving only when the focus reaches an edge.
+fn follow_focus(offset: usize, focused: usize, visible: usize, total: usize) -> usize {
+    let offset = if focused < offset {
+        focused
+    } else if focused >= offset + visible {
+        focused + 1 - visible
+    } else {
+        offset
+    };
+    // Don't leave empty rows at the end after the results shrink
+    offset.min(total.saturating_sub(visible))
+}
+
 // =============================================================================
 // TASK LIST BUILDERS
 // =============================================================================
@@ -114,6 +175,7 @@ fn build_monday_task_list<'a>(
     ui_state: &UIState,
     monday_tasks: &'a [MondayTask],
     commit_form: &CommitForm,
+    width: usize,
 ) -> (String, Vec<ListItem<'a>>) {
     if monday_tasks.is_empty() {
         return build_empty_results_list(
@@ -143,20 +205,35 @@ fn build_monday_task_list<'a>(
                 ),
                 is_selected,
                 is_focused,
+                width,
             )
         })
         .collect();
 
-    (
-        "Monday.com Search Results (Press 1-9,0 or Space to select tasks)".to_string(),
-        items,
-    )
+    let state_filter = if ui_state.monday_include_closed {
+        "Todas (c: solo activas)"
+    } else {
+        "Solo activas (c: incluir cerradas)"
+    };
+    let title = match &ui_state.monday_board_filter {
+        Some(board) => format!(
+            "Monday.com Search Results · Tablero: {} (b para cambiar) · {}",
+            board, state_filter
+        ),
+        None => format!(
+            "Monday.com Search Results (Press 1-9,0 or Space to select tasks) · {}",
+            state_filter
+        ),
+    };
+
+    (title, items)
 }
 
 fn build_jira_task_list<'a>(
     ui_state: &UIState,
     jira_tasks: &'a [JiraTask],
     commit_form: &CommitForm,
+    width: usize,
 ) -> (String, Vec<ListItem<'a>>) {
     if jira_tasks.is_empty() {
         return build_empty_results_list(
@@ -184,6 +261,7 @@ fn build_jira_task_list<'a>(
                 ),
                 is_selected,
                 is_focused,
+                width,
             )
         })
         .collect();
@@ -233,27 +311,32 @@ fn build_task_item(
     details: &str,
     is_selected: bool,
     is_focused: bool,
+    width: usize,
 ) -> ListItem<'static> {
     // Build styles based on state
     let styles = TaskItemStyles::new(is_focused, is_selected);
 
     // Build number and checkbox
     let number = get_selection_number(index);
-    let checkbox = if is_selected { "☑ " } else { "☐ " };
+    let checkbox = if is_selected { "✅ " } else { "☐ " };
     let focus_indicator = if is_focused { "→ " } else { "  " };
 
-    // Convert borrowed strings to owned strings for static lifetime
-    let title_owned = title.to_string();
-    let details_owned = details.to_string();
+    let number = format!("[{}] ", number);
+
+    // Long titles end in "…" instead of being cut by the border, possibly in
+    // the middle of a wide character
+    let prefix_width = focus_indicator.width() + number.width() + checkbox.width();
+    let title = truncate_to_width(title, width.saturating_sub(prefix_width));
+    let details = truncate_to_width(details, width.saturating_sub(5));
 
     ListItem::new(vec![
         Line::from(vec![
             Span::styled(focus_indicator, styles.focus_style),
-            Span::styled(format!("[{}] ", number), styles.number_style),
+            Span::styled(number, styles.number_style),
             Span::styled(checkbox, styles.checkbox_style),
-            Span::styled(title_owned, styles.title_style),
+            Span::styled(title, styles.title_style),
         ]),
-        Line::from(format!("     {}", details_owned)),
+        Line::from(format!("     {}", details)),
     ])
 }
 
@@ -262,7 +345,10 @@ fn build_task_item(
 // =============================================================================
 
 fn render_selected_tasks(f: &mut Frame, area: Rect, ui_state: &UIState, commit_form: &CommitForm) {
-    let selected_items = build_selected_tasks_list(&commit_form.selected_tasks);
+    let selected_items = build_selected_tasks_list(
+        &commit_form.selected_tasks,
+        area.width.saturating_sub(2) as usize,
+    );
 
     // Create list state for navigation
     let mut selected_list_state = ListState::default();
@@ -283,7 +369,10 @@ fn render_selected_tasks(f: &mut Frame, area: Rect, ui_state: &UIState, commit_f
     f.render_stateful_widget(selected_list, area, &mut selected_list_state);
 }
 
-fn build_selected_tasks_list(selected_tasks: &[MondayTask]) -> Vec<ListItem<'static>> {
+fn build_selected_tasks_list(
+    selected_tasks: &[MondayTask],
+    width: usize,
+) -> Vec<ListItem<'static>> {
     if selected_tasks.is_empty() {
         vec![ListItem::new(vec![
             Line::from("No tasks selected yet"),
@@ -297,10 +386,11 @@ fn build_selected_tasks_list(selected_tasks: &[MondayTask]) -> Vec<ListItem<'sta
                     Line::from(vec![
                         Span::styled("✅ ", Style::default().fg(Color::Green)),
                         Span::styled(
-                            task.get_title().to_string(),
-                            Style::default()
-                                .fg(Color::Green)
-                                .add_modifier(Modifier::BOLD),
+                            truncate_to_width(
+                                task.get_title(),
+                                width.saturating_sub("✅ ".width()),
+                            ),
+                            TaskItemStyles::selected_style(true),
                         ),
                     ]),
                     Line::from(format!(
@@ -326,10 +416,13 @@ struct TaskItemStyles {
 
 impl TaskItemStyles {
     fn new(is_focused: bool, is_selected: bool) -> Self {
-        let (base_color, modifier) = match (is_focused, is_selected) {
-            (true, _) => (Color::Yellow, Modifier::BOLD),
-            (false, true) => (Color::Green, Modifier::BOLD),
-            (false, false) => (Color::White, Modifier::empty()),
+        // Selected results stay green when focused; the arrow and number show focus
+        let title_style = match (is_focused, is_selected) {
+            (_, true) => Self::selected_style(true),
+            (true, false) => Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+            (false, false) => Style::default().fg(Color::White),
         };
 
         Self {
@@ -345,18 +438,27 @@ impl TaskItemStyles {
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD)
             },
-            checkbox_style: if is_focused {
+            checkbox_style: if is_selected {
+                Self::selected_style(true)
+            } else if is_focused {
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD)
-            } else if is_selected {
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(Color::Blue)
             },
-            title_style: Style::default().fg(base_color).add_modifier(modifier),
+            title_style,
+        }
+    }
+
+    /// Style shared by everything that marks a task as already selected
+    fn selected_style(active: bool) -> Style {
+        if active {
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
         }
     }
 }
@@ -373,6 +475,14 @@ fn get_task_system_name(config: &AppConfig) -> &'static str {
     }
 }
 
+/// Tasks selected in the active task system.
+pub fn selected_task_count(config: &AppConfig, commit_form: &CommitForm) -> usize {
+    match config.get_task_system() {
+        TaskSystem::Jira => commit_form.selected_jira_tasks.len(),
+        _ => commit_form.selected_tasks.len(),
+    }
+}
+
 fn get_selection_number(index: usize) -> String {
     match index {
         0..=8 => (index + 1).to_string(),
@@ -1,16 +1,90 @@
+use crate::git::{DiffLine, FileDiff, GitStatus, RepoState};
+use crate::i18n::Language;
+use crate::types::{AppConfig, CommitAnalysis, CommitForm, CommitType, TaskSystem};
+use crate::ui::keybindings::Keymap;
+use chrono::{DateTime, Utc};
 use ratatui::style::{Color, Style};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::time::{Duration, Instant};
 use tui_textarea::TextArea;
 
 #[derive(Debug)]
 pub struct UIState {
     pub selected_tab: usize,
     pub selected_commit_type: usize,
+    /// Commit type suggested by the last AI analysis
+    pub suggested_commit_type: Option<CommitType>,
     pub input_mode: InputMode,
     pub current_field: CommitField,
     pub focused_search_index: usize,
+    /// First search result shown; follows `focused_search_index` when it leaves the view
+    pub search_results_offset: usize,
+    /// Board the Monday.com search results are filtered by, if any
+    pub monday_board_filter: Option<String>,
+    /// Also list archived and deleted Monday.com tasks ('c'); only active ones by default
+    pub monday_include_closed: bool,
     pub task_management_mode: bool,
     pub animation_frame: usize,
+    /// Real progress (0.0..=1.0) of the running background operation, if it reports one
+    pub loading_progress: Option<f32>,
+    /// The AI description is streaming into the form, so the loading overlay stays hidden
+    pub analysis_streaming: bool,
+    /// Raw result of the last AI analysis, kept for re-checking and for the 'i' overlay
+    pub last_analysis: Option<CommitAnalysis>,
+    /// Form as it was when the last AI analysis started, restored with 'u'
+    pub form_before_analysis: Option<CommitForm>,
+    /// AI text offered as ghost text instead of written into the field
+    /// (`AI_INLINE_SUGGESTIONS`), until accepted or typed over
+    pub ai_suggestions: HashMap<CommitField, String>,
+    /// Whether the raw result of the last AI analysis is shown ('i')
+    pub show_analysis: bool,
+    /// Changed files being picked for staging ('a'), while that list is open
+    pub stage_selector: Option<StageSelector>,
+    /// Staged changes shown by the diff view ('d' on the commit screen)
+    pub staged_diff: StagedDiffView,
+    /// Settings being edited on the configuration screen
+    pub config_editor: ConfigEditor,
+    /// Package path the release notes are restricted to, if any
+    pub release_notes_path_filter: Option<String>,
+    /// Take release notes commits from this date instead of from the last tag
+    pub release_notes_since: Option<DateTime<Utc>>,
+    /// Only use release notes commits made up to this date
+    pub release_notes_until: Option<DateTime<Utc>>,
+    /// Only use release notes commits whose author name or email contains this
+    pub release_notes_author: Option<String>,
+    /// Head commit of an earlier generation the user was warned about; the
+    /// next request for the same commits regenerates them
+    pub release_notes_regenerate: Option<String>,
+    /// Repository state when the commit preview was opened
+    pub repo_state: RepoState,
     pub scroll_offset: usize,
+    /// Lines of the scrolled semantic-release output and the rows showing
+    /// them, as last drawn; the scroll keys stop at the end of the content
+    pub scroll_content_lines: usize,
+    pub scroll_viewport: usize,
+    /// Live filter applied to the selected tasks list (matches ID/key or title)
+    pub selected_tasks_filter: String,
+    /// Whether keystrokes are currently being typed into `selected_tasks_filter`
+    pub editing_selected_tasks_filter: bool,
+    /// Highlighted commit on the history screen
+    pub history_selected: usize,
+    /// Whether the full message of the highlighted commit is shown
+    pub history_show_message: bool,
+    /// Output of the last `npm run release-notes` run, as it streams in
+    pub script_output: Vec<ScriptOutputLine>,
+    /// Lines the script output view is scrolled up from the end (0 follows new output)
+    pub script_output_scroll: usize,
+    /// Key bindings, built-in or remapped with `KEYBINDINGS`
+    pub keymap: Keymap,
+    /// Wrap long lines in the highlighted commit preview (`COMMIT_PREVIEW_WRAP`)
+    pub commit_preview_wrap: bool,
+    /// Whether the full key help of the current screen is shown (`?`)
+    pub show_help: bool,
+    /// Render timings, only collected when started with `--dev`
+    pub render_stats: Option<RenderStats>,
+    /// Whether the render stats overlay is shown (toggled with F12)
+    pub show_render_stats: bool,
     // TextArea instances for each editable field
     pub scope_textarea: TextArea<'static>,
     pub title_textarea: TextArea<'static>,
@@ -21,9 +95,402 @@ pub struct UIState {
     pub migraciones_lentas_textarea: TextArea<'static>,
     pub partes_a_ejecutar_textarea: TextArea<'static>,
     pub search_textarea: TextArea<'static>,
+    /// Past task searches, one list per task system
+    pub search_history: SearchHistories,
     pub commit_preview_textarea: TextArea<'static>,
 }
 
+/// One line printed by an external script, tagged with the stream it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptOutputLine {
+    pub text: String,
+    pub is_stderr: bool,
+}
+
+/// Timings of `terminal.draw`, fed by the main loop in dev mode.
+#[derive(Debug, Clone, Default)]
+pub struct RenderStats {
+    /// Duration of the last `terminal.draw` call
+    pub last_draw: Duration,
+    /// Slowest draw seen so far
+    pub max_draw: Duration,
+    /// Frames drawn during the last full second
+    pub fps: u32,
+    window_start: Option<Instant>,
+    window_frames: u32,
+}
+
+impl RenderStats {
+    /// Record a finished frame. Only a couple of clock reads per frame.
+    pub fn record_frame(&mut self, draw_time: Duration) {
+        self.last_draw = draw_time;
+        self.max_draw = self.max_draw.max(draw_time);
+        self.window_frames += 1;
+
+        let now = Instant::now();
+        let window_start = *self.window_start.get_or_insert(now);
+        if now.duration_since(window_start) >= Duration::from_secs(1) {
+            self.fps = self.window_frames;
+            self.window_frames = 0;
+            self.window_start = Some(now);
+        }
+    }
+}
+
+/// Searches kept per task system
+const SEARCH_HISTORY_LIMIT: usize = 50;
+
+/// Queries run against one task system, oldest first, browsed shell-style
+/// with ↑/↓ while typing in the search box.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchHistory {
+    entries: Vec<String>,
+    /// Entry shown while browsing; `None` when not browsing
+    #[serde(skip)]
+    cursor: Option<usize>,
+}
+
+impl SearchHistory {
+    /// Remember `query` as the latest search, dropping older duplicates.
+    pub fn record(&mut self, query: &str) {
+        let query = query.trim();
+        self.cursor = None;
+        if query.is_empty() {
+            return;
+        }
+        self.entries.retain(|entry| entry != query);
+        self.entries.push(query.to_string());
+        let overflow = self.entries.len().saturating_sub(SEARCH_HISTORY_LIMIT);
+        self.entries.drain(..overflow);
+    }
+
+    pub fn last(&self) -> Option<&str> {
+        self.entries.last().map(String::as_str)
+    }
+
+    /// Step to an older entry, staying on the oldest one.
+    pub fn older(&mut self) -> Option<&str> {
+        let index = match self.cursor {
+            Some(index) => index.saturating_sub(1),
+            None => self.entries.len().checked_sub(1)?,
+        };
+        self.cursor = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Step to a newer entry. Past the newest one browsing ends and `None`
+    /// is returned, so the caller can clear the input.
+    pub fn newer(&mut self) -> Option<&str> {
+        let index = self.cursor? + 1;
+        if index >= self.entries.len() {
+            self.cursor = None;
+            return None;
+        }
+        self.cursor = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    pub fn stop_browsing(&mut self) {
+        self.cursor = None;
+    }
+}
+
+/// Search histories of both task systems, saved across sessions in the user
+/// config directory. Queries have different shapes in Monday.com and JIRA,
+/// so they are never mixed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchHistories {
+    #[serde(default)]
+    pub monday: SearchHistory,
+    #[serde(default)]
+    pub jira: SearchHistory,
+}
+
+impl SearchHistories {
+    pub fn for_system(&mut self, system: &TaskSystem) -> Option<&mut SearchHistory> {
+        match system {
+            TaskSystem::Monday => Some(&mut self.monday),
+            TaskSystem::Jira => Some(&mut self.jira),
+            TaskSystem::None => None,
+        }
+    }
+
+    /// Load the saved histories; a missing or unreadable file means no history.
+    pub fn load() -> Self {
+        let Some(path) = crate::config::search_history_path() else {
+            return Self::default();
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                tracing::warn!(path = %path.display(), error = %e, "Ignoring unreadable search history");
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save the histories. Failures are only logged: history is a convenience.
+    pub fn save(&self) {
+        let Some(path) = crate::config::search_history_path() else {
+            return;
+        };
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| {
+                std::fs::write(
+                    &path,
+                    serde_json::to_string_pretty(self).unwrap_or_default(),
+                )
+            });
+        if let Err(e) = result {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to save search history");
+        }
+    }
+}
+
+/// Changed file listed in the staging selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageEntry {
+    /// Path relative to the repository root, as `get_status` lists it
+    pub path: String,
+    /// Whether the file is in the index right now
+    pub staged: bool,
+    /// Untracked (new) file rather than a modified one
+    pub untracked: bool,
+    /// Whether the file should be in the index once the selection is applied
+    pub selected: bool,
+}
+
+/// Staged, modified and untracked files of the repository, ticked with Space
+/// to commit only a subset of the changes without leaving the TUI.
+#[derive(Debug, Clone, Default)]
+pub struct StageSelector {
+    pub entries: Vec<StageEntry>,
+    pub focused: usize,
+}
+
+impl StageSelector {
+    /// Staged files start ticked. A file that is staged and also modified
+    /// again is listed once, so applying keeps its partial staging as is.
+    pub fn from_status(status: &GitStatus) -> Self {
+        let mut entries: Vec<StageEntry> = status
+            .staged
+            .iter()
+            .map(|path| StageEntry {
+                path: path.clone(),
+                staged: true,
+                untracked: false,
+                selected: true,
+            })
+            .collect();
+        for path in &status.modified {
+            if !status.staged.contains(path) {
+                entries.push(StageEntry {
+                    path: path.clone(),
+                    staged: false,
+                    untracked: false,
+                    selected: false,
+                });
+            }
+        }
+        entries.extend(status.untracked.iter().map(|path| StageEntry {
+            path: path.clone(),
+            staged: false,
+            untracked: true,
+            selected: false,
+        }));
+        Self {
+            entries,
+            focused: 0,
+        }
+    }
+
+    pub fn up(&mut self) {
+        self.focused = self.focused.saturating_sub(1);
+    }
+
+    pub fn down(&mut self) {
+        if self.focused + 1 < self.entries.len() {
+            self.focused += 1;
+        }
+    }
+
+    pub fn toggle_focused(&mut self) {
+        if let Some(entry) = self.entries.get_mut(self.focused) {
+            entry.selected = !entry.selected;
+        }
+    }
+
+    /// Tick every file, or untick them all when they already are.
+    pub fn toggle_all(&mut self) {
+        let select = !self.entries.iter().all(|entry| entry.selected);
+        for entry in &mut self.entries {
+            entry.selected = select;
+        }
+    }
+
+    /// Files to add to the index
+    pub fn to_stage(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.selected && !entry.staged)
+            .map(|entry| entry.path.clone())
+            .collect()
+    }
+
+    /// Files to take out of the index
+    pub fn to_unstage(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|entry| !entry.selected && entry.staged)
+            .map(|entry| entry.path.clone())
+            .collect()
+    }
+}
+
+/// Row of the diff view: a file header or one of its diff lines.
+#[derive(Debug, Clone, Copy)]
+pub enum DiffRow<'a> {
+    File { file: &'a FileDiff, collapsed: bool },
+    Line(&'a DiffLine),
+}
+
+/// Staged diff scrolled by rows, each file a header followed by its lines
+/// unless collapsed. Only the rows in view are ever built, so large diffs
+/// cost the same to draw as small ones.
+#[derive(Debug, Clone, Default)]
+pub struct StagedDiffView {
+    pub files: Vec<FileDiff>,
+    collapsed: Vec<bool>,
+    /// First row in view
+    pub scroll: usize,
+}
+
+impl StagedDiffView {
+    pub fn new(files: Vec<FileDiff>) -> Self {
+        Self {
+            collapsed: vec![false; files.len()],
+            files,
+            scroll: 0,
+        }
+    }
+
+    fn file_rows(&self, index: usize) -> usize {
+        if self.collapsed[index] {
+            1
+        } else {
+            1 + self.files[index].lines.len()
+        }
+    }
+
+    pub fn total_rows(&self) -> usize {
+        (0..self.files.len())
+            .map(|index| self.file_rows(index))
+            .sum()
+    }
+
+    /// Up to `height` rows starting at the scroll position.
+    pub fn visible_rows(&self, height: usize) -> Vec<DiffRow<'_>> {
+        let mut rows = Vec::with_capacity(height);
+        let mut skip = self.scroll;
+        for (index, file) in self.files.iter().enumerate() {
+            let file_rows = self.file_rows(index);
+            if skip >= file_rows {
+                skip -= file_rows;
+                continue;
+            }
+            if skip == 0 {
+                rows.push(DiffRow::File {
+                    file,
+                    collapsed: self.collapsed[index],
+                });
+            }
+            if !self.collapsed[index] {
+                let first_line = skip.saturating_sub(1);
+                rows.extend(
+                    file.lines[first_line..]
+                        .iter()
+                        .take(height.saturating_sub(rows.len()))
+                        .map(DiffRow::Line),
+                );
+            }
+            skip = 0;
+            if rows.len() >= height {
+                rows.truncate(height);
+                break;
+            }
+        }
+        rows
+    }
+
+    /// First row of each file
+    fn file_starts(&self) -> Vec<usize> {
+        let mut row = 0;
+        (0..self.files.len())
+            .map(|index| {
+                let start = row;
+                row += self.file_rows(index);
+                start
+            })
+            .collect()
+    }
+
+    /// File the top row in view belongs to
+    pub fn current_file(&self) -> Option<usize> {
+        self.file_starts()
+            .iter()
+            .rposition(|&start| start <= self.scroll)
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) {
+        let last = self.total_rows().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(last);
+    }
+
+    pub fn scroll_to_end(&mut self) {
+        self.scroll = self.total_rows().saturating_sub(1);
+    }
+
+    pub fn next_file(&mut self) {
+        if let Some(start) = self
+            .file_starts()
+            .into_iter()
+            .find(|&start| start > self.scroll)
+        {
+            self.scroll = start;
+        }
+    }
+
+    /// Start of the current file, or of the previous one when already there.
+    pub fn previous_file(&mut self) {
+        if let Some(start) = self
+            .file_starts()
+            .into_iter()
+            .rev()
+            .find(|&start| start < self.scroll)
+        {
+            self.scroll = start;
+        }
+    }
+
+    /// Collapse or expand the current file, keeping its header in view.
+    pub fn toggle_current_file(&mut self) {
+        if let Some(index) = self.current_file() {
+            self.collapsed[index] = !self.collapsed[index];
+            self.scroll = self.file_starts()[index];
+        }
+    }
+
+    /// Collapse every file, or expand them all when they already are.
+    pub fn toggle_all_files(&mut self) {
+        let current = self.current_file();
+        let collapse = !self.collapsed.iter().all(|&collapsed| collapsed);
+        self.collapsed.fill(collapse);
+        self.scroll = current.map_or(0, |index| self.file_starts()[index]);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum InputMode {
     Normal,
@@ -44,17 +511,390 @@ pub enum CommitField {
     SelectedTasks,
 }
 
+impl CommitField {
+    /// Text fields the AI analysis can fill in
+    pub const AI_FILLED: [CommitField; 6] = [
+        CommitField::Scope,
+        CommitField::Title,
+        CommitField::Description,
+        CommitField::BreakingChange,
+        CommitField::TestDetails,
+        CommitField::Security,
+    ];
+
+    /// Name shown in status messages
+    pub fn label(&self) -> &'static str {
+        match self {
+            CommitField::Type => "Tipo",
+            CommitField::Scope => "Scope",
+            CommitField::Title => "Título",
+            CommitField::Description => "Descripción",
+            CommitField::BreakingChange => "Breaking change",
+            CommitField::TestDetails => "Pruebas",
+            CommitField::Security => "Seguridad",
+            CommitField::MigracionesLentas => "Migraciones lentas",
+            CommitField::PartesAEjecutar => "Partes a ejecutar",
+            CommitField::SelectedTasks => "Tareas",
+        }
+    }
+
+    /// Text of this field in `form`; `None` for the type and the tasks
+    pub fn form_text<'a>(&self, form: &'a CommitForm) -> Option<&'a str> {
+        match self {
+            CommitField::Scope => Some(&form.scope),
+            CommitField::Title => Some(&form.title),
+            CommitField::Description => Some(&form.description),
+            CommitField::BreakingChange => Some(&form.breaking_change),
+            CommitField::TestDetails => Some(&form.test_details),
+            CommitField::Security => Some(&form.security),
+            CommitField::MigracionesLentas => Some(&form.migraciones_lentas),
+            CommitField::PartesAEjecutar => Some(&form.partes_a_ejecutar),
+            CommitField::Type | CommitField::SelectedTasks => None,
+        }
+    }
+
+    /// Mutable text of this field in `form`; `None` for the type and the tasks
+    pub fn form_text_mut<'a>(&self, form: &'a mut CommitForm) -> Option<&'a mut String> {
+        match self {
+            CommitField::Scope => Some(&mut form.scope),
+            CommitField::Title => Some(&mut form.title),
+            CommitField::Description => Some(&mut form.description),
+            CommitField::BreakingChange => Some(&mut form.breaking_change),
+            CommitField::TestDetails => Some(&mut form.test_details),
+            CommitField::Security => Some(&mut form.security),
+            CommitField::MigracionesLentas => Some(&mut form.migraciones_lentas),
+            CommitField::PartesAEjecutar => Some(&mut form.partes_a_ejecutar),
+            CommitField::Type | CommitField::SelectedTasks => None,
+        }
+    }
+
+    /// What the analysis suggests for this field, ignoring empty and "N/A" answers
+    pub fn analysis_text<'a>(&self, analysis: &'a CommitAnalysis) -> Option<&'a str> {
+        let text = match self {
+            CommitField::Scope => return analysis.scope(),
+            CommitField::Title => &analysis.title,
+            CommitField::Description => &analysis.description,
+            CommitField::BreakingChange => &analysis.breaking_change,
+            CommitField::TestDetails => &analysis.test_details,
+            CommitField::Security => &analysis.security,
+            _ => return None,
+        };
+        Some(text.as_str()).filter(|text| !text.trim().is_empty() && *text != "N/A")
+    }
+}
+
+/// Setting editable on the configuration screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigField {
+    TaskSystem,
+    MondayApiKey,
+    MondayAccountSlug,
+    MondayBoardId,
+    JiraUrl,
+    JiraUsername,
+    JiraApiToken,
+    JiraProjectKey,
+    GeminiToken,
+    Language,
+}
+
+impl ConfigField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigField::TaskSystem => "Sistema de tareas",
+            ConfigField::MondayApiKey => "Monday.com API key",
+            ConfigField::MondayAccountSlug => "Monday.com account slug",
+            ConfigField::MondayBoardId => "Monday.com board ID",
+            ConfigField::JiraUrl => "JIRA URL",
+            ConfigField::JiraUsername => "JIRA usuario",
+            ConfigField::JiraApiToken => "JIRA API token",
+            ConfigField::JiraProjectKey => "JIRA project key",
+            ConfigField::GeminiToken => "Gemini API token",
+            ConfigField::Language => "Idioma",
+        }
+    }
+
+    /// Setting name, as used in `.env` and by `validate_config`
+    pub fn setting(&self) -> &'static str {
+        match self {
+            ConfigField::TaskSystem => "TASK_SYSTEM",
+            ConfigField::MondayApiKey => "MONDAY_API_KEY",
+            ConfigField::MondayAccountSlug => "ACCOUNT_SLUG",
+            ConfigField::MondayBoardId => "MONDAY_BOARD_ID",
+            ConfigField::JiraUrl => "JIRA_URL",
+            ConfigField::JiraUsername => "JIRA_USERNAME",
+            ConfigField::JiraApiToken => "JIRA_API_TOKEN",
+            ConfigField::JiraProjectKey => "JIRA_PROJECT_KEY",
+            ConfigField::GeminiToken => "GEMINI_TOKEN",
+            ConfigField::Language => "APP_LANGUAGE",
+        }
+    }
+
+    /// Credentials are masked on screen, also while typed
+    pub fn is_secret(&self) -> bool {
+        matches!(
+            self,
+            ConfigField::MondayApiKey | ConfigField::JiraApiToken | ConfigField::GeminiToken
+        )
+    }
+
+    /// Picked from a fixed list with Enter instead of typed
+    pub fn is_choice(&self) -> bool {
+        matches!(self, ConfigField::TaskSystem | ConfigField::Language)
+    }
+
+    fn text<'a>(&self, config: &'a AppConfig) -> Option<&'a str> {
+        match self {
+            ConfigField::MondayApiKey => config.monday_api_key.as_deref(),
+            ConfigField::MondayAccountSlug => config.monday_account_slug.as_deref(),
+            ConfigField::MondayBoardId => config.monday_board_id.as_deref(),
+            ConfigField::JiraUrl => config.jira_url.as_deref(),
+            ConfigField::JiraUsername => config.jira_username.as_deref(),
+            ConfigField::JiraApiToken => config.jira_api_token.as_deref(),
+            ConfigField::JiraProjectKey => config.jira_project_key.as_deref(),
+            ConfigField::GeminiToken => config.gemini_token.as_deref(),
+            ConfigField::TaskSystem | ConfigField::Language => None,
+        }
+    }
+
+    fn text_mut<'a>(&self, config: &'a mut AppConfig) -> Option<&'a mut Option<String>> {
+        match self {
+            ConfigField::MondayApiKey => Some(&mut config.monday_api_key),
+            ConfigField::MondayAccountSlug => Some(&mut config.monday_account_slug),
+            ConfigField::MondayBoardId => Some(&mut config.monday_board_id),
+            ConfigField::JiraUrl => Some(&mut config.jira_url),
+            ConfigField::JiraUsername => Some(&mut config.jira_username),
+            ConfigField::JiraApiToken => Some(&mut config.jira_api_token),
+            ConfigField::JiraProjectKey => Some(&mut config.jira_project_key),
+            ConfigField::GeminiToken => Some(&mut config.gemini_token),
+            ConfigField::TaskSystem | ConfigField::Language => None,
+        }
+    }
+}
+
+/// Settings being edited on the configuration screen. Changes stay in `draft`
+/// until saved.
+#[derive(Debug, Clone)]
+pub struct ConfigEditor {
+    pub draft: AppConfig,
+    /// Monday.com and JIRA are mutually exclusive; saving drops the other one's settings
+    pub task_system: TaskSystem,
+    pub selected: usize,
+    /// Text typed into the selected field, while it is being edited
+    pub input: Option<String>,
+    /// Unsaved changes
+    pub dirty: bool,
+    /// Settings changed on the screen. Saving writes only these to the local
+    /// `.env`, besides the ones already there, so values from the environment
+    /// or the user config are not copied into it.
+    pub edited: BTreeSet<&'static str>,
+    /// Back was pressed once with unsaved changes; pressing it again discards them
+    pub confirm_discard: bool,
+}
+
+impl Default for ConfigEditor {
+    fn default() -> Self {
+        Self::new(&AppConfig::default())
+    }
+}
+
+impl ConfigEditor {
+    pub fn new(config: &AppConfig) -> Self {
+        Self {
+            draft: config.clone(),
+            task_system: config.get_task_system(),
+            selected: 0,
+            input: None,
+            dirty: false,
+            edited: BTreeSet::new(),
+            confirm_discard: false,
+        }
+    }
+
+    /// Fields shown for the chosen task system
+    pub fn fields(&self) -> Vec<ConfigField> {
+        let mut fields = vec![ConfigField::TaskSystem];
+        match self.task_system {
+            TaskSystem::Monday => fields.extend([
+                ConfigField::MondayApiKey,
+                ConfigField::MondayAccountSlug,
+                ConfigField::MondayBoardId,
+            ]),
+            TaskSystem::Jira => fields.extend([
+                ConfigField::JiraUrl,
+                ConfigField::JiraUsername,
+                ConfigField::JiraApiToken,
+                ConfigField::JiraProjectKey,
+            ]),
+            TaskSystem::None => {}
+        }
+        fields.extend([ConfigField::GeminiToken, ConfigField::Language]);
+        fields
+    }
+
+    pub fn selected_field(&self) -> ConfigField {
+        let fields = self.fields();
+        fields[self.selected.min(fields.len() - 1)]
+    }
+
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn down(&mut self) {
+        if self.selected + 1 < self.fields().len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Value of `field` as shown on screen, with credentials masked
+    pub fn display_value(&self, field: ConfigField) -> String {
+        match field {
+            ConfigField::TaskSystem => match self.task_system {
+                TaskSystem::Monday => "Monday.com".to_string(),
+                TaskSystem::Jira => "JIRA".to_string(),
+                TaskSystem::None => "Ninguno".to_string(),
+            },
+            ConfigField::Language => self.draft.language.as_str().to_string(),
+            _ => match field.text(&self.draft) {
+                Some(value) if field.is_secret() => crate::utils::mask_secret(value),
+                Some(value) => value.to_string(),
+                None => "(sin configurar)".to_string(),
+            },
+        }
+    }
+
+    /// Enter on the selected field: choices move to their next value, text
+    /// fields start editing. Credentials start empty so they are never shown.
+    pub fn activate(&mut self) {
+        let field = self.selected_field();
+        match field {
+            ConfigField::TaskSystem => {
+                self.task_system = match self.task_system {
+                    TaskSystem::Monday => TaskSystem::Jira,
+                    TaskSystem::Jira => TaskSystem::None,
+                    TaskSystem::None => TaskSystem::Monday,
+                };
+                self.dirty = true;
+            }
+            ConfigField::Language => {
+                self.draft.language = match self.draft.language {
+                    Language::Es => Language::En,
+                    Language::En => Language::Es,
+                };
+                self.edited.insert(field.setting());
+                self.dirty = true;
+            }
+            _ if field.is_secret() => self.input = Some(String::new()),
+            _ => {
+                self.input = Some(field.text(&self.draft).unwrap_or_default().to_string());
+            }
+        }
+        self.confirm_discard = false;
+    }
+
+    /// Store the typed text in the draft. An empty credential keeps the
+    /// current one; any other empty field is cleared.
+    pub fn finish_editing(&mut self) {
+        let Some(input) = self.input.take() else {
+            return;
+        };
+        let field = self.selected_field();
+        let input = input.trim().to_string();
+        if input.is_empty() && field.is_secret() {
+            return;
+        }
+        if let Some(value) = field.text_mut(&mut self.draft) {
+            let new_value = (!input.is_empty()).then_some(input);
+            if *value != new_value {
+                *value = new_value;
+                self.edited.insert(field.setting());
+                self.dirty = true;
+            }
+        }
+        if field == ConfigField::MondayAccountSlug && self.edited.contains(field.setting()) {
+            // Same item URL the `config` command writes for the slug
+            self.draft.monday_url_template = self.draft.monday_account_slug.as_ref().map(|slug| {
+                format!(
+                    "https://{}.monday.com/boards/{{board_id}}/pulses/{{item_id}}",
+                    slug
+                )
+            });
+            self.edited.insert("MONDAY_URL_TEMPLATE");
+        }
+    }
+
+    pub fn cancel_editing(&mut self) {
+        self.input = None;
+    }
+
+    pub fn is_editing(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Configuration to save: the draft without the settings of the task
+    /// system that wasn't chosen.
+    pub fn to_config(&self) -> AppConfig {
+        let mut config = self.draft.clone();
+        if self.task_system != TaskSystem::Monday {
+            config.monday_api_key = None;
+            config.monday_account_slug = None;
+            config.monday_board_id = None;
+            config.monday_url_template = None;
+        }
+        if self.task_system != TaskSystem::Jira {
+            config.jira_url = None;
+            config.jira_username = None;
+            config.jira_api_token = None;
+            config.jira_project_key = None;
+        }
+        config
+    }
+}
+
 impl Default for UIState {
     fn default() -> Self {
         Self {
             selected_tab: 0,
             selected_commit_type: 0,
+            suggested_commit_type: None,
             input_mode: InputMode::Normal,
             current_field: CommitField::Type,
             focused_search_index: 0,
+            search_results_offset: 0,
+            commit_preview_wrap: false,
+            analysis_streaming: false,
+            last_analysis: None,
+            form_before_analysis: None,
+            ai_suggestions: HashMap::new(),
+            show_analysis: false,
+            stage_selector: None,
+            staged_diff: StagedDiffView::default(),
+            config_editor: ConfigEditor::default(),
+            monday_board_filter: None,
+            monday_include_closed: false,
             task_management_mode: false,
             animation_frame: 0,
+            loading_progress: None,
+            release_notes_path_filter: None,
+            release_notes_since: None,
+            release_notes_until: None,
+            release_notes_author: None,
+            release_notes_regenerate: None,
+            repo_state: RepoState::Clean,
             scroll_offset: 0,
+            scroll_content_lines: 0,
+            scroll_viewport: 0,
+            selected_tasks_filter: String::new(),
+            editing_selected_tasks_filter: false,
+            history_selected: 0,
+            history_show_message: false,
+            script_output: Vec::new(),
+            script_output_scroll: 0,
+            keymap: Keymap::default(),
+            show_help: false,
+            render_stats: None,
+            show_render_stats: false,
             scope_textarea: create_single_line_textarea("Enter scope (e.g., auth, ui, api)..."),
             title_textarea: create_single_line_textarea("Enter commit title..."),
             description_textarea: create_multiline_textarea(
@@ -70,6 +910,7 @@ impl Default for UIState {
             migraciones_lentas_textarea: create_multiline_textarea("Enter migraciones lentas..."),
             partes_a_ejecutar_textarea: create_multiline_textarea("Enter partes a ejecutar..."),
             search_textarea: create_single_line_textarea("Search tasks..."),
+            search_history: SearchHistories::default(),
             commit_preview_textarea: create_multiline_textarea(""),
         }
     }
@@ -92,6 +933,12 @@ fn create_multiline_textarea(placeholder: &str) -> TextArea<'static> {
 
 // Helper functions for commit screen styling
 impl UIState {
+    /// Last `scroll_offset` that still fills the viewport drawn last
+    pub fn max_scroll_offset(&self) -> usize {
+        self.scroll_content_lines
+            .saturating_sub(self.scroll_viewport)
+    }
+
     pub fn get_field_border_style(&self, field: &CommitField) -> Style {
         if self.current_field == *field {
             if self.input_mode == InputMode::Editing {
@@ -104,6 +951,15 @@ impl UIState {
         }
     }
 
+    /// Case-insensitive match of any of the task's fields against the selected tasks filter
+    pub fn matches_selected_tasks_filter(&self, fields: &[&str]) -> bool {
+        let filter = self.selected_tasks_filter.trim().to_lowercase();
+        filter.is_empty()
+            || fields
+                .iter()
+                .any(|field| field.to_lowercase().contains(&filter))
+    }
+
     pub fn get_textarea_mut(&mut self, field: &CommitField) -> Option<&mut TextArea<'static>> {
         match field {
             CommitField::Scope => Some(&mut self.scope_textarea),
@@ -132,6 +988,31 @@ impl UIState {
         }
     }
 
+    /// Rest of the AI suggestion for `field` not typed yet, shown as ghost
+    /// text. `None` once the field no longer matches the start of it.
+    pub fn ghost_text(&self, field: &CommitField) -> Option<&str> {
+        let suggestion = self.ai_suggestions.get(field)?;
+        let typed = self.get_textarea(field)?.lines().join("\n");
+        suggestion
+            .strip_prefix(typed.as_str())
+            .filter(|rest| !rest.is_empty())
+    }
+
+    /// Whether `field` still holds what the last AI analysis wrote into it
+    pub fn is_ai_filled(&self, field: &CommitField, form: &CommitForm) -> bool {
+        let Some(suggested) = self
+            .last_analysis
+            .as_ref()
+            .and_then(|analysis| field.analysis_text(analysis))
+        else {
+            return false;
+        };
+        field.form_text(form).is_some_and(|text| {
+            crate::utils::normalize_line_endings(text).trim()
+                == crate::utils::normalize_line_endings(suggested).trim()
+        })
+    }
+
     pub fn is_multiline_field(field: &CommitField) -> bool {
         matches!(
             field,
@@ -1,5 +1,9 @@
+use regex::Regex;
 use std::fs::OpenOptions;
 use std::io::Write;
+use std::sync::LazyLock;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Log a message to the debug.log file with a component prefix
 pub fn log_debug(component: &str, message: &str) {
@@ -21,3 +25,148 @@ pub fn log_error(component: &str, error: &dyn std::fmt::Display) {
 pub fn log_success(component: &str, message: &str) {
     log_debug(component, &format!("✅ {}", message));
 }
+
+/// Convert CRLF and lone CR line endings to LF, so text pasted or produced on
+/// Windows doesn't leave invisible `\r` characters in commits or release notes.
+pub fn normalize_line_endings(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// `{{...}}` placeholder of a commit template (`COMMIT_TEMPLATE_<TYPE>`)
+static TEMPLATE_PLACEHOLDER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{[^{}]*\}\}").expect("template placeholder pattern is valid"));
+
+/// Remove the `{{...}}` placeholders of a commit template that were never
+/// filled in. A line left with nothing but its label (`Causa:`) goes too.
+pub fn strip_template_placeholders(text: &str) -> String {
+    if !TEMPLATE_PLACEHOLDER_RE.is_match(text) {
+        return text.to_string();
+    }
+    text.lines()
+        .filter_map(|line| {
+            if !TEMPLATE_PLACEHOLDER_RE.is_match(line) {
+                return Some(line.to_string());
+            }
+            let filled = TEMPLATE_PLACEHOLDER_RE.replace_all(line, "");
+            let filled = filled.trim_end();
+            let rest = filled.trim_start_matches(['-', '*', ' ']);
+            (!rest.is_empty() && !rest.ends_with(':')).then(|| filled.to_string())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
+}
+
+/// Credential as shown on screen: dots followed by its last 4 characters, or
+/// only dots when it's too short to give any of it away.
+pub fn mask_secret(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() <= 8 {
+        return "••••".to_string();
+    }
+    format!(
+        "••••{}",
+        chars[chars.len() - 4..].iter().collect::<String>()
+    )
+}
+
+/// Shorten `text` to at most `max_width` terminal columns, ending in "…" when
+/// something was cut. Works on grapheme clusters so wide characters and emoji
+/// (including ZWJ sequences and flags) are never split in half.
+pub fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+    if max_width == 0 {
+        return String::new();
+    }
+
+    // Leave one column for the ellipsis
+    let budget = max_width - 1;
+    let mut used = 0;
+    let mut truncated = String::new();
+    for grapheme in text.graphemes(true) {
+        let width = grapheme.width();
+        if used + width > budget {
+            break;
+        }
+        used += width;
+        truncated.push_str(grapheme);
+    }
+    truncated.push('…');
+    truncated
+}
+
+/// Open a URL or file with the system handler (`open` on macOS, `start` on
+/// Windows, `xdg-open` elsewhere). Fails without a graphical session, e.g.
+/// over SSH, so the caller can show the target instead.
+pub fn open_externally(target: &std::ffi::OsStr) -> std::io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = std::process::Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        let has_display = ["DISPLAY", "WAYLAND_DISPLAY"]
+            .iter()
+            .any(|var| std::env::var_os(var).is_some_and(|value| !value.is_empty()));
+        if !has_display {
+            return Err(std::io::Error::other("no graphical session"));
+        }
+        std::process::Command::new("xdg-open")
+    };
+
+    // Keep the opener's output away from the TUI
+    command
+        .arg(target)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+/// Editor command from `$VISUAL` or `$EDITOR`, split into program and
+/// arguments (e.g. `code --wait`). `None` when neither is set.
+pub fn external_editor() -> Option<Vec<String>> {
+    ["VISUAL", "EDITOR"].iter().find_map(|var| {
+        let command: Vec<String> = std::env::var(var)
+            .ok()?
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        (!command.is_empty()).then_some(command)
+    })
+}
+
+/// Let the user edit `text` in `editor` through a temporary file, waiting for
+/// it to exit. Returns `None` when the editor fails, leaving the text as it was.
+/// The terminal must already be out of raw mode.
+pub fn edit_in_external_editor(editor: &[String], text: &str) -> std::io::Result<Option<String>> {
+    let Some((program, args)) = editor.split_first() else {
+        return Ok(None);
+    };
+    let path =
+        std::env::temp_dir().join(format!("semantic-release-tui-{}.txt", uuid::Uuid::new_v4()));
+    std::fs::write(&path, text)?;
+
+    let status = std::process::Command::new(program)
+        .args(args)
+        .arg(&path)
+        .status();
+    let edited = match status {
+        Ok(status) if status.success() => std::fs::read_to_string(&path).map(Some),
+        Ok(_) => Ok(None),
+        Err(e) => Err(e),
+    };
+    std::fs::remove_file(&path).ok();
+
+    // Editors end the file with a newline the field never had
+    Ok(edited?.map(|text| {
+        normalize_line_endings(&text)
+            .trim_end_matches('\n')
+            .to_string()
+    }))
+}